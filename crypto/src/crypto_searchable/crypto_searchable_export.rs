@@ -3,7 +3,9 @@ use alloc::vec::Vec;
 
 use sentc_crypto_common::content_searchable::SearchableCreateOutput;
 use sentc_crypto_std_keys::util::HmacKey;
-use sentc_crypto_utils::cryptomat::SearchableKeyWrapper;
+use sentc_crypto_utils::cryptomat::{CompoundField, SearchableKeyWrapper, SearchableOptions};
+
+use crate::SdkError;
 
 pub fn create_searchable_raw(key: &str, data: &str, full: bool, limit: Option<usize>) -> Result<Vec<String>, String>
 {
@@ -26,6 +28,63 @@ pub fn search(key: &str, data: &str) -> Result<String, String>
 	Ok(key.search(data)?)
 }
 
+/**
+Create the hashes with padding, truncation or another encoding.
+
+options is the json of the SearchableOptions, e.g. {"bucket_size":32,"truncate":16,"encoding":"Hex"}.
+The search must be done with search_with_options and the same options.
+ */
+pub fn create_searchable_raw_with_options(key: &str, data: &str, full: bool, limit: Option<usize>, options: &str) -> Result<Vec<String>, String>
+{
+	let key: HmacKey = key.parse()?;
+	let options: SearchableOptions = serde_json::from_str(options).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(key.create_searchable_raw_with_options(data, full, limit, &options)?)
+}
+
+pub fn create_searchable_with_options(
+	key: &str,
+	data: &str,
+	full: bool,
+	limit: Option<usize>,
+	options: &str,
+) -> Result<SearchableCreateOutput, String>
+{
+	let key: HmacKey = key.parse()?;
+	let options: SearchableOptions = serde_json::from_str(options).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(key.create_searchable_with_options(data, full, limit, &options)?)
+}
+
+pub fn search_with_options(key: &str, data: &str, options: &str) -> Result<String, String>
+{
+	let key: HmacKey = key.parse()?;
+	let options: SearchableOptions = serde_json::from_str(options).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(key.search_with_options(data, &options)?)
+}
+
+/**
+Create one hash for a combination of fields.
+
+fields is a json array of the fields, e.g. [{"name":"first_name","value":"Max","normalization":"TrimLowercase"}].
+ */
+pub fn create_compound_searchable(key: &str, fields: &str) -> Result<SearchableCreateOutput, String>
+{
+	let key: HmacKey = key.parse()?;
+	let fields: Vec<CompoundField> = serde_json::from_str(fields).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(key.create_compound_searchable(&fields)?)
+}
+
+pub fn search_compound(key: &str, fields: &str) -> Result<String, String>
+{
+	let key: HmacKey = key.parse()?;
+	let fields: Vec<CompoundField> = serde_json::from_str(fields).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(key.search_compound(&fields)?)
+}
+
 #[cfg(test)]
 mod test
 {
@@ -138,4 +197,60 @@ mod test
 
 		assert!(!out.hashes.contains(&search_str2));
 	}
+
+	#[test]
+	fn test_searchable_item_with_options()
+	{
+		let user = create_user_export();
+		let (_, _, _, hmac_keys, _) = create_group_export(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		let text = "123*+^êéèüöß@€&$ 👍 🚀 😎";
+		let options = r#"{"bucket_size":32,"truncate":16,"encoding":"Hex"}"#;
+
+		let out = create_searchable_with_options(hmac_key, text, false, None, options).unwrap();
+
+		//39 hashes padded to the next bucket
+		assert_eq!(out.hashes.len(), 64);
+
+		for hash in &out.hashes {
+			assert_eq!(hash.len(), 32);
+		}
+
+		let search_str = search_with_options(hmac_key, "123", options).unwrap();
+
+		assert!(out.hashes.contains(&search_str));
+
+		//the search without the options creates another hash
+		let search_str = search(hmac_key, "123").unwrap();
+
+		assert!(!out.hashes.contains(&search_str));
+
+		//truncate is too short
+		assert!(search_with_options(hmac_key, "123", r#"{"bucket_size":null,"truncate":8}"#).is_err());
+	}
+
+	#[test]
+	fn test_compound_searchable()
+	{
+		let user = create_user_export();
+		let (_, _, _, hmac_keys, _) = create_group_export(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		let fields = r#"[{"name":"first_name","value":" Max ","normalization":"TrimLowercase"},{"name":"year","value":"1990"}]"#;
+
+		let out = create_compound_searchable(hmac_key, fields).unwrap();
+
+		assert_eq!(out.hashes.len(), 1);
+
+		let search_str = search_compound(
+			hmac_key,
+			r#"[{"name":"first_name","value":"max","normalization":"TrimLowercase"},{"name":"year","value":"1990"}]"#,
+		)
+		.unwrap();
+
+		assert_eq!(out.hashes[0], search_str);
+	}
 }
@@ -117,4 +117,140 @@ mod test
 
 		assert!(!out.hashes.contains(&search_str2));
 	}
+
+	#[test]
+	fn test_searchable_item_with_padding()
+	{
+		use sentc_crypto_utils::cryptomat::{SearchableEncoding, SearchableOptions};
+
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		let options = SearchableOptions {
+			bucket_size: Some(16),
+			truncate: None,
+			encoding: SearchableEncoding::Base64Url,
+		};
+
+		let short = hmac_key
+			.create_searchable_with_options("123", false, None, &options)
+			.unwrap();
+
+		let long = hmac_key
+			.create_searchable_with_options("1234567890", false, None, &options)
+			.unwrap();
+
+		//both are padded to the same bucket
+		assert_eq!(short.hashes.len(), 16);
+		assert_eq!(long.hashes.len(), 16);
+
+		//the real hashes are the same as without the options
+		let out = hmac_key.create_searchable("123", false, None).unwrap();
+
+		assert_eq!(&short.hashes[..3], &out.hashes[..]);
+
+		//dummy hashes are unique
+		for (i, hash) in short.hashes.iter().enumerate() {
+			assert!(!short.hashes[i + 1..].contains(hash));
+		}
+
+		//full hash is padded too
+		let full = hmac_key
+			.create_searchable_with_options("123", true, None, &options)
+			.unwrap();
+
+		assert_eq!(full.hashes.len(), 16);
+		assert!(full
+			.hashes
+			.contains(&hmac_key.search_with_options("123", &options).unwrap()));
+
+		//wrong bucket size
+		let options = SearchableOptions {
+			bucket_size: Some(0),
+			..Default::default()
+		};
+
+		assert!(hmac_key
+			.create_searchable_with_options("123", false, None, &options)
+			.is_err());
+
+		//the error contains the rejected length
+		let options = SearchableOptions {
+			truncate: Some(8),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			hmac_key.search_with_options("123", &options),
+			Err(sentc_crypto_utils::error::SdkUtilError::SearchableTruncateLenInvalid(
+				8
+			))
+		));
+	}
+
+	#[test]
+	fn test_compound_searchable()
+	{
+		use sentc_crypto_utils::cryptomat::{CompoundField, SearchableNormalization};
+
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		let field = |name: &str, value: &str| {
+			CompoundField {
+				name: name.into(),
+				value: value.into(),
+				normalization: SearchableNormalization::None,
+			}
+		};
+
+		let out = hmac_key
+			.create_compound_searchable(&[field("a", "ab"), field("b", "c")])
+			.unwrap();
+
+		assert_eq!(out.hashes.len(), 1);
+		assert_eq!(
+			out.hashes[0],
+			hmac_key
+				.search_compound(&[field("a", "ab"), field("b", "c")])
+				.unwrap()
+		);
+
+		//not ambiguous
+		assert_ne!(
+			out.hashes[0],
+			hmac_key
+				.search_compound(&[field("a", "a"), field("b", "bc")])
+				.unwrap()
+		);
+
+		//the field name is part of the hash
+		assert_ne!(
+			out.hashes[0],
+			hmac_key
+				.search_compound(&[field("b", "ab"), field("a", "c")])
+				.unwrap()
+		);
+
+		//not the same as the normal search of the concatenation
+		assert_ne!(out.hashes[0], hmac_key.search("abc").unwrap());
+
+		//normalization
+		let mut a = field("phone", "+49 (123) 456");
+		a.normalization = SearchableNormalization::Alphanumeric;
+
+		let mut b = field("phone", "49123456");
+		b.normalization = SearchableNormalization::Alphanumeric;
+
+		assert_eq!(
+			hmac_key.search_compound(&[a]).unwrap(),
+			hmac_key.search_compound(&[b]).unwrap()
+		);
+
+		assert!(hmac_key.search_compound(&[]).is_err());
+	}
 }
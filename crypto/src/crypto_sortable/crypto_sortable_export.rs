@@ -1,9 +1,12 @@
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
+use base64ct::{Base64, Encoding};
 use sentc_crypto_common::content_sortable::SortableEncryptOutput;
 use sentc_crypto_core::cryptomat::SortableKey as CoreSort;
 use sentc_crypto_std_keys::util::SortableKey;
-use sentc_crypto_utils::cryptomat::SortableKeyWrapper;
+use sentc_crypto_utils::cryptomat::{SortableKeyWrapper, SortableOreEncryptOutput, SortableRange};
 
 use crate::SdkError;
 
@@ -34,6 +37,59 @@ pub fn encrypt_string(key: &str, data: &str, max_len: Option<usize>) -> Result<S
 	Ok(key.encrypt_string(data, max_len)?)
 }
 
+pub fn encrypt_raw_number_ore(key: &str, data: u64) -> Result<String, String>
+{
+	let key: SortableKey = key.parse()?;
+	let ciphertext = key.encrypt_number_ore_raw(data)?;
+
+	Ok(Base64::encode_string(&ciphertext))
+}
+
+pub fn encrypt_number_ore(key: &str, data: u64) -> Result<SortableOreEncryptOutput, String>
+{
+	let key: SortableKey = key.parse()?;
+	Ok(key.encrypt_number_ore(data)?)
+}
+
+/**
+Compare two ore ciphertexts.
+
+Returns -1 if a is less than b, 0 if both are equal and 1 if a is greater than b.
+ */
+pub fn ore_compare(a: &str, b: &str) -> Result<i8, String>
+{
+	let out = match super::compare_ore(a, b)? {
+		Ordering::Less => -1,
+		Ordering::Equal => 0,
+		Ordering::Greater => 1,
+	};
+
+	Ok(out)
+}
+
+/**
+Encrypts the bounds of a range with every sortable key.
+
+keys is a json array of the exported sortable keys (one for each key generation of the group).
+range is the json of the SortableRange.
+
+Returns a json array with the encrypted bounds for each key id.
+ */
+pub fn prepare_range(keys: &str, range: &str) -> Result<String, String>
+{
+	let keys: Vec<String> = serde_json::from_str(keys).map_err(SdkError::JsonParseFailed)?;
+	let range: SortableRange = serde_json::from_str(range).map_err(SdkError::JsonParseFailed)?;
+
+	let keys = keys
+		.iter()
+		.map(|k| k.parse())
+		.collect::<Result<Vec<SortableKey>, _>>()?;
+
+	let out = super::prepare_range_typed(&keys, &range)?;
+
+	Ok(serde_json::to_string(&out).map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
 #[cfg(test)]
 mod test
 {
@@ -69,6 +125,33 @@ mod test
 		}
 	}
 
+	#[test]
+	fn test_range()
+	{
+		let user = create_user_export();
+		let (_, _, _, _, sortable_keys) = create_group_export(&user.user_keys[0]);
+
+		let keys = serde_json::to_string(&sortable_keys).unwrap();
+
+		let out = prepare_range(&keys, r#"{"Number":{"lower":{"Included":262},"upper":"Unbounded"}}"#).unwrap();
+		let out: Vec<sentc_crypto_utils::cryptomat::SortableRangeOutput> = serde_json::from_str(&out).unwrap();
+
+		assert_eq!(out.len(), 1);
+		assert_eq!(
+			out[0].lower,
+			sentc_crypto_utils::cryptomat::SortableRangeBound::Ope(encrypt_raw_number(&sortable_keys[0], 262).unwrap())
+		);
+		assert!(out[0].exact);
+		assert!(!out[0].clamped);
+
+		//wrong range
+		assert!(prepare_range(
+			&keys,
+			r#"{"Number":{"lower":{"Excluded":10},"upper":{"Excluded":11}}}"#
+		)
+		.is_err());
+	}
+
 	#[test]
 	fn test_with_generated_key()
 	{
@@ -87,4 +170,21 @@ mod test
 		assert_eq!(b, 17488544);
 		assert_eq!(c, 4280794268);
 	}
+
+	#[test]
+	fn test_ore_with_generated_key()
+	{
+		const KEY: &str = r#"{"Ore":{"key":"2xMkPp1gTtmYd2Xy6zXZ4PVzJx8qkN2u+B0mG6e0r6VfQ4Xj+VPIbdkQ1q1Hc1bYy3rAkm6b5h9WYJc0a1rM3Q==","key_id":"1876b629-5795-471f-9704-0cac52eaf9a1"}}"#;
+
+		let a = encrypt_raw_number_ore(KEY, 262).unwrap();
+		let b = encrypt_number_ore(KEY, 263).unwrap();
+		let c = encrypt_raw_number_ore(KEY, 65321).unwrap();
+
+		assert_eq!(ore_compare(&a, &b.ciphertext).unwrap(), -1);
+		assert_eq!(ore_compare(&c, &b.ciphertext).unwrap(), 1);
+		assert_eq!(ore_compare(&a, &a).unwrap(), 0);
+
+		//ore ciphertexts are not deterministic
+		assert_ne!(a, encrypt_raw_number_ore(KEY, 262).unwrap());
+	}
 }
@@ -1,8 +1,49 @@
 #[cfg(feature = "export")]
 mod crypto_sortable_export;
 
+use alloc::vec::Vec;
+
 #[cfg(feature = "export")]
 pub use crypto_sortable_export::*;
+use sentc_crypto_utils::cryptomat::{SortableKeyWrapper, SortableRange, SortableRangeOutput};
+
+use crate::SdkError;
+
+/**
+Encrypts the bounds of a plaintext range with every sortable key of the group.
+
+A group can have a sortable key for every key generation (after a key rotation).
+Values that were encrypted with an older key can only be found with the bounds of this key,
+so the query must be done for every key id of the output.
+ */
+pub fn prepare_range_typed<K: SortableKeyWrapper>(keys: &[K], range: &SortableRange) -> Result<Vec<SortableRangeOutput>, SdkError>
+{
+	keys.iter().map(|k| Ok(k.encrypt_range(range)?)).collect()
+}
+
+/**
+Compares two order-revealing ciphertexts (base64 encoded) without the key.
+
+Returns the ordering of the plaintext of a compared to the plaintext of b.
+This can be used by the server to sort or to filter ore encrypted values.
+ */
+#[cfg(any(feature = "std_keys", feature = "fips_ore_sort", feature = "rec_keys"))]
+pub fn compare_ore(a: &str, b: &str) -> Result<core::cmp::Ordering, SdkError>
+{
+	use base64ct::{Base64, Encoding};
+	//all key impl use the same ore scheme
+	#[cfg(all(not(feature = "std_keys"), feature = "fips_ore_sort"))]
+	use sentc_crypto_fips_keys::core::sortable::ore::ore_compare;
+	#[cfg(all(not(feature = "std_keys"), not(feature = "fips_ore_sort"), feature = "rec_keys"))]
+	use sentc_crypto_rec_keys::core::sortable::ore_compare;
+	#[cfg(feature = "std_keys")]
+	use sentc_crypto_std_keys::core::ore_compare;
+
+	let a = Base64::decode_vec(a).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+	let b = Base64::decode_vec(b).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+	Ok(ore_compare(&a, &b)?)
+}
 
 #[cfg(all(test, any(feature = "std_keys", feature = "rec_keys")))]
 mod test
@@ -22,6 +63,11 @@ mod test
 	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
 	pub type TestKey = sentc_crypto_rec_keys::util::SortableKey;
 
+	#[cfg(feature = "std_keys")]
+	type TestGroupOre = crate::keys::std::StdGroupOre;
+	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+	type TestGroupOre = crate::keys::rec::RecGroupOre;
+
 	#[test]
 	fn test_simple()
 	{
@@ -80,4 +126,214 @@ mod test
 		assert_eq!(b, 17488544);
 		assert_eq!(c, 4280794268);
 	}
+
+	#[test]
+	fn test_range()
+	{
+		use core::ops::Bound;
+
+		use sentc_crypto_utils::cryptomat::{SortableRange, SortableRangeBound};
+		use sentc_crypto_utils::error::SdkUtilError;
+
+		use crate::crypto_sortable::prepare_range_typed;
+
+		let ope = SortableRangeBound::Ope;
+
+		let user = create_user();
+		let (_, _, _, _, sortable_keys) = create_group(&user.user_keys[0]);
+
+		let key = &sortable_keys[0];
+
+		let out = prepare_range_typed(
+			&sortable_keys,
+			&SortableRange::Number {
+				lower: Bound::Excluded(262),
+				upper: Bound::Included(300),
+			},
+		)
+		.unwrap();
+
+		assert_eq!(out.len(), 1);
+		assert_eq!(out[0].lower, ope(key.get_key().encrypt_sortable(263).unwrap()));
+		assert_eq!(out[0].upper, ope(key.get_key().encrypt_sortable(300).unwrap()));
+		assert!(out[0].exact);
+		assert!(!out[0].clamped);
+
+		//upper bound is outside of the domain of the key
+		let out = key
+			.encrypt_range(&SortableRange::Number {
+				lower: Bound::Unbounded,
+				upper: Bound::Included(u64::MAX),
+			})
+			.unwrap();
+
+		assert!(out.clamped);
+		assert_eq!(out.lower, ope(key.get_key().encrypt_sortable(0).unwrap()));
+		assert_eq!(
+			out.upper,
+			ope(key
+				.get_key()
+				.encrypt_sortable(key.get_key().max_sortable())
+				.unwrap())
+		);
+
+		//lower bound is outside of the domain of the key
+		let max = key.get_key().max_sortable();
+
+		assert!(key
+			.encrypt_range(&SortableRange::Number {
+				lower: Bound::Included(max + 1),
+				upper: Bound::Unbounded,
+			})
+			.is_err());
+
+		//empty range
+		assert!(key
+			.encrypt_range(&SortableRange::Number {
+				lower: Bound::Excluded(10),
+				upper: Bound::Excluded(11),
+			})
+			.is_err());
+
+		//prefix
+		let out = key
+			.encrypt_range(&SortableRange::Prefix {
+				prefix: "b".into(),
+				max_len: None,
+			})
+			.unwrap();
+
+		assert!(!out.exact);
+
+		let (lower, upper) = match (out.lower, out.upper) {
+			(SortableRangeBound::Ope(l), SortableRangeBound::Ope(u)) => (l, u),
+			_ => panic!("ope key must have ope bounds"),
+		};
+
+		for value in ["b", "b~", "ba", "baaa", "bzzz", "b~~~"] {
+			let n = key.encrypt_raw_string(value, None).unwrap();
+			assert!(lower <= n && n <= upper);
+		}
+
+		for value in ["a", "azzz", "c"] {
+			let n = key.encrypt_raw_string(value, None).unwrap();
+			assert!(n < lower || n > upper);
+		}
+
+		//prefix must be ascii and not longer than max len
+		assert!(matches!(
+			key.encrypt_range(&SortableRange::Prefix {
+				prefix: "bä".into(),
+				max_len: None,
+			}),
+			Err(SdkUtilError::SortableRangeNotAscii)
+		));
+
+		assert!(matches!(
+			key.encrypt_range(&SortableRange::Prefix {
+				prefix: "abcde".into(),
+				max_len: None,
+			}),
+			Err(SdkUtilError::SortableRangePrefixTooLong(4))
+		));
+
+		//exclusive string bounds
+		assert!(matches!(
+			key.encrypt_range(&SortableRange::String {
+				lower: Bound::Excluded("a".into()),
+				upper: Bound::Unbounded,
+				max_len: None,
+			}),
+			Err(SdkUtilError::SortableRangeExclusiveString)
+		));
+
+		//timestamps per day
+		let day = 86_400_000u64;
+
+		let out = key
+			.encrypt_range(&SortableRange::Timestamp {
+				lower: Bound::Included(day * 10 + 5),
+				upper: Bound::Excluded(day * 12),
+			})
+			.unwrap();
+
+		assert_eq!(out.lower, ope(key.get_key().encrypt_sortable(10).unwrap()));
+		assert_eq!(out.upper, ope(key.get_key().encrypt_sortable(11).unwrap()));
+		assert!(!out.exact);
+	}
+
+	#[test]
+	fn test_ore_group()
+	{
+		use core::cmp::Ordering;
+
+		use sentc_crypto_common::group::GroupSortableData;
+		#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+		use sentc_crypto_rec_keys::core::sortable::ORE_OUT;
+		#[cfg(feature = "std_keys")]
+		use sentc_crypto_std_keys::core::ORE_OUT;
+		use sentc_crypto_utils::cryptomat::{KeyToString, SortableRangeBound};
+
+		use crate::crypto_sortable::compare_ore;
+
+		let user = create_user();
+
+		let (group, _, group_key) = TestGroupOre::prepare_create_batch_typed(&user.user_keys[0].public_key).unwrap();
+
+		assert_eq!(group.encrypted_sortable_alg, ORE_OUT);
+
+		let key = TestGroupOre::decrypt_group_sortable_key(
+			&group_key,
+			GroupSortableData {
+				id: "123".into(),
+				encrypted_sortable_key: group.encrypted_sortable_key,
+				encrypted_sortable_alg: group.encrypted_sortable_alg,
+				encrypted_sortable_encryption_key_id: "".into(),
+				time: 0,
+			},
+		)
+		.unwrap();
+
+		//ore keys have no numeric output
+		assert!(key.encrypt_number(262).is_err());
+
+		//but number ranges are encrypted with ore
+		let range = crate::crypto_sortable::prepare_range_typed(
+			core::slice::from_ref(&key),
+			&sentc_crypto_utils::cryptomat::SortableRange::Number {
+				lower: core::ops::Bound::Included(262),
+				upper: core::ops::Bound::Included(300),
+			},
+		)
+		.unwrap();
+
+		let (lower, upper) = match (&range[0].lower, &range[0].upper) {
+			(SortableRangeBound::Ore(l), SortableRangeBound::Ore(u)) => (l, u),
+			_ => panic!("ore key must have ore bounds"),
+		};
+
+		let value = key.encrypt_number_ore(280).unwrap();
+
+		assert_eq!(compare_ore(lower, &value.ciphertext).unwrap(), Ordering::Less);
+		assert_eq!(compare_ore(upper, &value.ciphertext).unwrap(), Ordering::Greater);
+
+		let a = key.encrypt_number_ore(262).unwrap();
+		let b = key.encrypt_number_ore(263).unwrap();
+		let c = key.encrypt_number_ore(65321).unwrap();
+
+		assert_eq!(a.alg, ORE_OUT);
+		assert_eq!(a.key_id, "123");
+
+		assert_eq!(compare_ore(&a.ciphertext, &b.ciphertext).unwrap(), Ordering::Less);
+		assert_eq!(compare_ore(&c.ciphertext, &b.ciphertext).unwrap(), Ordering::Greater);
+		assert_eq!(compare_ore(&a.ciphertext, &a.ciphertext).unwrap(), Ordering::Equal);
+
+		//export and import the key again
+		let key_str = key.to_string().unwrap();
+		let key = TestKey::from_str(&key_str).unwrap();
+
+		let d = key.encrypt_number_ore(262).unwrap();
+
+		assert_eq!(compare_ore(&a.ciphertext, &d.ciphertext).unwrap(), Ordering::Equal);
+	}
 }
@@ -0,0 +1,251 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::crypto::SignHead;
+use sentc_crypto_common::file::{BelongsToType, FileHead};
+use sentc_crypto_common::{FileId, FileSessionId, SymKeyId};
+use serde::{Deserialize, Serialize};
+
+/**
+The first version of the file format where every part key is encrypted by the key of the previous part
+ */
+pub const FILE_FORMAT_CHAINED: u8 = 1;
+
+/**
+The file format where every part key is derived from the file key and the sequence of the part.
+Every part can be decrypted without the previous parts.
+ */
+pub const FILE_FORMAT_DERIVED: u8 = 2;
+
+/**
+The head of a part in the derived file format.
+
+The part key is not in the head, it is derived from the content key of the file and the sequence.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileHeadDerived
+{
+	pub version: u8,
+	pub sequence: i32,
+	pub sign: Option<SignHead>,
+	pub sym_key_alg: String,
+}
+
+/**
+The head of a part of every file format version.
+
+The chained head has no version, so the derived head must be checked first.
+ */
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum FileHeadVersion
+{
+	Derived(FileHeadDerived),
+	Chained(FileHead),
+}
+
+impl FileHeadVersion
+{
+	pub fn version(&self) -> u8
+	{
+		match self {
+			Self::Derived(h) => h.version,
+			Self::Chained(_) => FILE_FORMAT_CHAINED,
+		}
+	}
+}
+
+/**
+The state of an upload after the part with the sequence was uploaded.
+
+The whole checkpoint is encrypted by the key of the checkpoint (e.g. the group or user key),
+so the checkpoint can be stored on the device and the upload can be continued after the app was closed.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileUploadCheckpoint
+{
+	pub file_id: FileId,
+	pub session_id: FileSessionId,
+	pub sequence: i32,
+	pub key_id: SymKeyId,
+	pub encrypted_file_key: String,
+	pub file_key_alg: String,
+}
+
+/**
+The stored checkpoint. Only the id of the key is readable, the file and the upload session are encrypted.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct EncryptedFileUploadCheckpoint
+{
+	pub key_id: SymKeyId,
+	pub encrypted_checkpoint: String,
+}
+
+pub const FILE_MANIFEST_VERSION: u8 = 1;
+
+/**
+The manifest of a file with the digests of all encrypted parts in the order of the sequence.
+
+The file hash is the hash of all part digests, so a missing, swapped or truncated part changes the file hash.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileManifest
+{
+	pub version: u8,
+	pub file_id: FileId,
+	pub part_count: u32,
+	pub part_digests: Vec<String>,
+	pub file_hash: String,
+}
+
+/**
+The head of the manifest part. The manifest is always signed.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileManifestHead
+{
+	pub manifest_version: u8,
+	pub sign: SignHead,
+}
+
+pub const FILE_META_VERSION: u8 = 1;
+
+/**
+The meta data of a file. It is encrypted with the content key of the file, so the server never sees it.
+
+created_time is in milliseconds like all other times, 0 when it is not set. attributes are for any app specific data.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileMeta
+{
+	#[serde(default)]
+	pub mime_type: Option<String>,
+	#[serde(default)]
+	pub size: Option<u64>,
+	#[serde(default)]
+	pub checksum: Option<String>,
+	#[serde(default)]
+	pub created_time: u128,
+	#[serde(default)]
+	pub attributes: BTreeMap<String, String>,
+}
+
+/**
+The plain text of the encrypted file name of a file with meta data.
+
+The server only stores the encrypted file name, so the file name and the meta data are encrypted together in this field.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileMetaVersioned
+{
+	pub version: u8,
+	#[serde(default)]
+	pub file_name: Option<String>,
+	pub meta: FileMeta,
+}
+
+/**
+The decrypted encrypted file name of the server. Files without meta data only got the file name.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileNameWithMeta
+{
+	pub file_name: Option<String>,
+	pub meta: Option<FileMeta>,
+}
+
+/**
+The header of the offline file container.
+
+The content key is encrypted by the master key with the key id, like the file key at the server.
+When the container is signed, the signed manifest of all parts is at the end of the container.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileContainerHeader
+{
+	pub key_id: SymKeyId,
+	pub encrypted_content_key: String,
+	pub content_key_alg: String,
+	pub signed: bool,
+}
+
+/**
+The content key of a file encrypted by another master key.
+
+The parts of the file are not changed, only the content key is encrypted again.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileKeyRewrap
+{
+	pub master_key_id: String,
+	pub encrypted_key: String,
+	pub encrypted_key_alg: String,
+}
+
+/**
+Moves the file to another group or user. The old key binding is replaced.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileMoveInput
+{
+	#[serde(flatten)]
+	pub key: FileKeyRewrap,
+	pub belongs_to_id: Option<String>,
+	pub belongs_to_type: BelongsToType,
+}
+
+/**
+Gives another group or user read access to the file. The old key binding stays.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileShareInput
+{
+	#[serde(flatten)]
+	pub key: FileKeyRewrap,
+	pub share_with_id: String,
+	pub share_with_type: BelongsToType,
+}
+
+pub const FILE_ARCHIVE_VERSION: u8 = 1;
+
+/**
+A file in the archive.
+
+The body of the file is in the parts from first_sequence to first_sequence + part_count.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FileArchiveEntry
+{
+	pub path: String,
+	pub size: u64,
+	pub first_sequence: i32,
+	pub part_count: u32,
+	#[serde(default)]
+	pub meta: Option<FileMeta>,
+}
+
+/**
+The index of the archive with all files.
+
+The serialized index is split into parts like a file body, so a big index is never in one part.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileArchiveIndex
+{
+	pub version: u8,
+	pub entries: Vec<FileArchiveEntry>,
+}
+
+/**
+The last part of the archive. It points to the parts of the index, which are right before this part.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct FileArchiveIndexHead
+{
+	pub version: u8,
+	pub first_sequence: i32,
+	pub part_count: u32,
+	pub size: u64,
+}
@@ -5,6 +5,7 @@ use sentc_crypto_common::group::{GroupHmacData, GroupKeyServerOutput, GroupSorta
 use sentc_crypto_common::user::UserPublicKeyData;
 use sentc_crypto_common::{EncryptionKeyPairId, GroupId, SymKeyId};
 use sentc_crypto_utils::cryptomat::{PkWrapper, SkWrapper, SymKeyWrapper};
+use sentc_crypto_utils::error::SdkUtilError;
 pub use sentc_crypto_utils::group::*;
 use serde::{Deserialize, Serialize};
 
@@ -188,3 +189,32 @@ impl<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper> TryFrom<GroupKeyData<S, Sk,
 		})
 	}
 }
+
+impl<S, Sk, Pk> TryFrom<GroupKeyDataExport> for GroupKeyData<S, Sk, Pk>
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: GroupKeyDataExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			group_key: value
+				.group_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportSymmetricKeyFailed)?,
+			private_group_key: value
+				.private_group_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?,
+			public_group_key: value
+				.public_group_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportPublicKeyFailed)?,
+			exported_public_key: UserPublicKeyData::from_string(&value.exported_public_key)?,
+			time: value.time,
+		})
+	}
+}
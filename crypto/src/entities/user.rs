@@ -3,13 +3,15 @@ use alloc::vec::Vec;
 
 use sentc_crypto_common::group::GroupHmacData;
 use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
-use sentc_crypto_common::{DeviceId, SymKeyId, UserId};
-use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
+use sentc_crypto_common::{DeviceId, GroupId, SymKeyId, UserId};
+use sentc_crypto_utils::cryptomat::{PkWrapper, SearchableKeyWrapper, SignKWrapper, SkWrapper, SortableKeyWrapper, SymKeyWrapper, VerifyKWrapper};
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::session::wipe_string;
 pub use sentc_crypto_utils::user::DeviceKeyDataExport;
 use sentc_crypto_utils::user::DeviceKeyDataInt;
 use serde::{Deserialize, Serialize};
 
-use crate::entities::group::GroupOutDataHmacKeyExport;
+use crate::entities::group::{GroupKeyData, GroupKeyDataExport, GroupOutDataHmacKeyExport};
 use crate::SdkError;
 
 pub struct UserKeyDataInt<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>
@@ -36,6 +38,172 @@ pub struct UserDataInt<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: Sign
 	pub hmac_keys: Vec<GroupHmacData>,
 }
 
+/**
+The payload of the qr code from the new device.
+
+Only the register token of the new device and the commitment to its register output and nonce, so the qr code stays small.
+The existing device fetches the register output with the token and checks it with the commitment.
+The nonce is revealed after the existing device sent its nonce, so no device can choose the code alone.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct DevicePairingPayload
+{
+	pub token: String,
+	pub commitment: String,
+}
+
+/**
+The state of the new device until the code is shown. The nonce must not leave the device before the response arrived.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct DevicePairingStart
+{
+	pub payload: String,
+	pub nonce: String,
+}
+
+/**
+The response of the existing device with its nonce and the verify key of the user.
+
+The signature covers the payload, so the new device knows that the response is from a device of the user with this verify key.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct DevicePairingResponse
+{
+	pub nonce: String,
+	pub verify_key: UserVerifyKeyData,
+	pub sig: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DevicePairingReveal
+{
+	pub nonce: String,
+}
+
+/**
+The version of the qr payload of the safety number
+ */
+pub const SAFETY_NUMBER_QR_VERSION: u32 = 1;
+
+/**
+The payload of the qr code to verify the safety number by scanning the code of the other user
+ */
+#[derive(Serialize, Deserialize)]
+pub struct SafetyNumberQrPayload
+{
+	pub version: u32,
+	pub user_ids: Vec<UserId>,
+	pub number: String,
+}
+
+/**
+The verify key of a contact after the safety number was compared.
+
+Store this record to detect when the contact got another verify key.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedKeyRecord
+{
+	pub user_id: UserId,
+	pub verify_key_id: String,
+	pub verify_key_hash: String,
+}
+
+/**
+The verify key of a contact that was pinned at the first use.
+
+Every fetched public key of the contact must be signed by this key.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyPin
+{
+	pub verify_key_id: String,
+	pub verify_key_pem: String,
+	pub verify_key_alg: String,
+}
+
+impl From<&UserVerifyKeyData> for KeyPin
+{
+	fn from(verify_key: &UserVerifyKeyData) -> Self
+	{
+		Self {
+			verify_key_id: verify_key.verify_key_id.clone(),
+			verify_key_pem: verify_key.verify_key_pem.clone(),
+			verify_key_alg: verify_key.verify_key_alg.clone(),
+		}
+	}
+}
+
+impl From<&KeyRotationLink> for KeyPin
+{
+	fn from(link: &KeyRotationLink) -> Self
+	{
+		Self {
+			verify_key_id: link.verify_key_id.clone(),
+			verify_key_pem: link.verify_key_pem.clone(),
+			verify_key_alg: link.verify_key_alg.clone(),
+		}
+	}
+}
+
+impl KeyPin
+{
+	pub fn to_verify_key(&self) -> UserVerifyKeyData
+	{
+		UserVerifyKeyData {
+			verify_key_pem: self.verify_key_pem.clone(),
+			verify_key_alg: self.verify_key_alg.clone(),
+			verify_key_id: self.verify_key_id.clone(),
+		}
+	}
+
+	pub fn is_key(&self, verify_key: &UserVerifyKeyData) -> bool
+	{
+		self.verify_key_id == verify_key.verify_key_id &&
+			self.verify_key_pem == verify_key.verify_key_pem &&
+			self.verify_key_alg == verify_key.verify_key_alg
+	}
+}
+
+/**
+Links a new user key to the previous key of the user.
+
+The new verify and public key are signed with the previous sign key.
+A contact who pinned an older verify key can follow the links to the actual key.
+
+The sentc server doesn't store the links. The app must distribute them to the contacts,
+e.g. with its own backend or in a message to the contacts, the signature protects the link on the way.
+
+The links are chained by the hash of the previous verify key, because the ids of the keys are set by the server
+after the link was signed. The ids are not signed, only use them to fetch the keys.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyRotationLink
+{
+	pub previous_verify_key_hash: String,
+	pub verify_key_id: String,
+	pub verify_key_pem: String,
+	pub verify_key_alg: String,
+	pub public_key_id: String,
+	pub public_key_pem: String,
+	pub public_key_alg: String,
+	pub sig: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerifyKeyState
+{
+	/**
+	The key is the same key as in the record
+	 */
+	Verified,
+	/**
+	The key id or the key itself is different. The safety number must be compared again.
+	 */
+	KeyChanged,
+}
+
 //==================================================================================================
 //export
 
@@ -84,6 +252,59 @@ impl<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: Veri
 	}
 }
 
+impl<S, Sk, Pk, SiK, Vk> TryFrom<UserKeyDataExport> for UserKeyDataInt<S, Sk, Pk, SiK, Vk>
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	SiK: SignKWrapper,
+	Vk: VerifyKWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: UserKeyDataExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			group_key: value
+				.group_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportSymmetricKeyFailed)?,
+			private_key: value
+				.private_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?,
+			public_key: value
+				.public_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportPublicKeyFailed)?,
+			time: value.time,
+			sign_key: value
+				.sign_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingSignKeyFailed)?,
+			verify_key: value
+				.verify_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportVerifyKeyFailed)?,
+			exported_public_key: UserPublicKeyData::from_string(&value.exported_public_key)?,
+			exported_verify_key: UserVerifyKeyData::from_string(&value.exported_verify_key)?,
+		})
+	}
+}
+
+impl UserKeyDataExport
+{
+	/**
+	Overwrites the exported secret keys
+	 */
+	pub fn wipe(&mut self)
+	{
+		wipe_string(&mut self.private_key);
+		wipe_string(&mut self.group_key);
+		wipe_string(&mut self.sign_key);
+	}
+}
+
 //__________________________________________________________________________________________________
 
 #[derive(Serialize, Deserialize)]
@@ -124,3 +345,285 @@ impl<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: Veri
 		})
 	}
 }
+
+impl<S, Sk, Pk, SiK, Vk> TryFrom<UserDataExport> for UserDataInt<S, Sk, Pk, SiK, Vk>
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	SiK: SignKWrapper,
+	Vk: VerifyKWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: UserDataExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			jwt: value.jwt,
+			refresh_token: value.refresh_token,
+			user_id: value.user_id,
+			device_id: value.device_id,
+			user_keys: value
+				.user_keys
+				.into_iter()
+				.map(|k| k.try_into())
+				.collect::<Result<_, SdkError>>()?,
+			device_keys: value.device_keys.try_into()?,
+			hmac_keys: value
+				.hmac_keys
+				.into_iter()
+				.map(|k| serde_json::from_str(&k.key_data))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+impl UserDataExport
+{
+	/**
+	Overwrites the tokens and the exported secret keys, e.g. after the data was sealed or at the logout
+	 */
+	pub fn wipe(&mut self)
+	{
+		wipe_string(&mut self.jwt);
+		wipe_string(&mut self.refresh_token);
+
+		self.device_keys.wipe();
+
+		for key in &mut self.user_keys {
+			key.wipe();
+		}
+	}
+}
+
+//__________________________________________________________________________________________________
+
+/**
+The version of the account archive
+ */
+pub const ACCOUNT_ARCHIVE_VERSION: u32 = 1;
+
+/**
+The keys of a group in the account archive.
+
+The hmac and sortable keys are the decrypted keys of the group, so searchable and sortable data can still be used after the import.
+ */
+pub struct AccountArchiveGroup<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, H: SearchableKeyWrapper, O: SortableKeyWrapper>
+{
+	pub group_id: GroupId,
+	pub keys: Vec<GroupKeyData<S, Sk, Pk>>,
+	pub hmac_keys: Vec<H>,
+	pub sortable_keys: Vec<O>,
+}
+
+/**
+All decrypted keys of the account.
+
+The keys keep their ids and times, so data which was encrypted before can still be decrypted after the import.
+ */
+pub struct AccountArchive<
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	SiK: SignKWrapper,
+	Vk: VerifyKWrapper,
+	H: SearchableKeyWrapper,
+	O: SortableKeyWrapper,
+> {
+	pub user_id: UserId,
+	pub user_keys: Vec<UserKeyDataInt<S, Sk, Pk, SiK, Vk>>,
+	pub hmac_keys: Vec<H>,
+	pub groups: Vec<AccountArchiveGroup<S, Sk, Pk, H, O>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AccountArchiveGroupExport
+{
+	pub group_id: GroupId,
+	pub keys: Vec<GroupKeyDataExport>,
+	pub hmac_keys: Vec<String>,
+	pub sortable_keys: Vec<String>,
+}
+
+impl<S, Sk, Pk, H, O> TryFrom<AccountArchiveGroup<S, Sk, Pk, H, O>> for AccountArchiveGroupExport
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	H: SearchableKeyWrapper,
+	O: SortableKeyWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: AccountArchiveGroup<S, Sk, Pk, H, O>) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			group_id: value.group_id,
+			keys: value
+				.keys
+				.into_iter()
+				.map(|k| k.try_into())
+				.collect::<Result<_, SdkError>>()?,
+			hmac_keys: value
+				.hmac_keys
+				.into_iter()
+				.map(|k| k.to_string())
+				.collect::<Result<_, _>>()?,
+			sortable_keys: value
+				.sortable_keys
+				.into_iter()
+				.map(|k| k.to_string())
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+impl<S, Sk, Pk, H, O> TryFrom<AccountArchiveGroupExport> for AccountArchiveGroup<S, Sk, Pk, H, O>
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	H: SearchableKeyWrapper,
+	O: SortableKeyWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: AccountArchiveGroupExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			group_id: value.group_id,
+			keys: value
+				.keys
+				.into_iter()
+				.map(|k| k.try_into())
+				.collect::<Result<_, SdkError>>()?,
+			hmac_keys: value
+				.hmac_keys
+				.iter()
+				.map(|k| k.parse().map_err(|_| SdkError::AccountArchiveWrong))
+				.collect::<Result<_, _>>()?,
+			sortable_keys: value
+				.sortable_keys
+				.iter()
+				.map(|k| k.parse().map_err(|_| SdkError::AccountArchiveWrong))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AccountArchiveExport
+{
+	pub version: u32,
+	pub user_id: UserId,
+	pub user_keys: Vec<UserKeyDataExport>,
+	pub hmac_keys: Vec<String>,
+	pub groups: Vec<AccountArchiveGroupExport>,
+}
+
+impl<S, Sk, Pk, SiK, Vk, H, O> TryFrom<AccountArchive<S, Sk, Pk, SiK, Vk, H, O>> for AccountArchiveExport
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	SiK: SignKWrapper,
+	Vk: VerifyKWrapper,
+	H: SearchableKeyWrapper,
+	O: SortableKeyWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: AccountArchive<S, Sk, Pk, SiK, Vk, H, O>) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			version: ACCOUNT_ARCHIVE_VERSION,
+			user_id: value.user_id,
+			user_keys: value
+				.user_keys
+				.into_iter()
+				.map(|k| k.try_into())
+				.collect::<Result<_, SdkError>>()?,
+			hmac_keys: value
+				.hmac_keys
+				.into_iter()
+				.map(|k| k.to_string())
+				.collect::<Result<_, _>>()?,
+			groups: value
+				.groups
+				.into_iter()
+				.map(|g| g.try_into())
+				.collect::<Result<_, SdkError>>()?,
+		})
+	}
+}
+
+impl<S, Sk, Pk, SiK, Vk, H, O> TryFrom<AccountArchiveExport> for AccountArchive<S, Sk, Pk, SiK, Vk, H, O>
+where
+	S: SymKeyWrapper,
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	SiK: SignKWrapper,
+	Vk: VerifyKWrapper,
+	H: SearchableKeyWrapper,
+	O: SortableKeyWrapper,
+{
+	type Error = SdkError;
+
+	fn try_from(value: AccountArchiveExport) -> Result<Self, Self::Error>
+	{
+		if value.version != ACCOUNT_ARCHIVE_VERSION {
+			return Err(SdkError::AccountArchiveWrong);
+		}
+
+		Ok(Self {
+			user_id: value.user_id,
+			user_keys: value
+				.user_keys
+				.into_iter()
+				.map(|k| k.try_into())
+				.collect::<Result<_, SdkError>>()?,
+			hmac_keys: value
+				.hmac_keys
+				.iter()
+				.map(|k| k.parse().map_err(|_| SdkError::AccountArchiveWrong))
+				.collect::<Result<_, _>>()?,
+			groups: value
+				.groups
+				.into_iter()
+				.map(|g| g.try_into())
+				.collect::<Result<_, SdkError>>()?,
+		})
+	}
+}
+
+impl AccountArchiveExport
+{
+	/**
+	Overwrites the exported secret keys
+	 */
+	pub fn wipe(&mut self)
+	{
+		for key in &mut self.user_keys {
+			key.wipe();
+		}
+
+		for key in &mut self.hmac_keys {
+			wipe_string(key);
+		}
+
+		for group in &mut self.groups {
+			for key in &mut group.keys {
+				wipe_string(&mut key.private_group_key);
+				wipe_string(&mut key.group_key);
+			}
+
+			for key in group
+				.hmac_keys
+				.iter_mut()
+				.chain(group.sortable_keys.iter_mut())
+			{
+				wipe_string(key);
+			}
+		}
+	}
+}
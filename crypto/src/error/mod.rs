@@ -20,6 +20,14 @@ pub enum SdkError
 	KeyDecryptFailed,
 
 	LoginServerOutputWrong,
+	DevicePairingPayloadWrong,
+	DevicePairingCommitmentWrong,
+	DevicePairingResponseWrong,
+	SafetyNumberQrWrong,
+	IdentityNotPinned,
+	IdentityChanged,
+	KeyRotationChainWrong,
+	AccountArchiveWrong,
 	KeyRotationServerOutputWrong,
 	KeyRotationEncryptError(String),
 
@@ -28,6 +36,22 @@ pub enum SdkError
 	GroupRank,
 	GroupUserKickRank,
 	GroupPermission,
+
+	FileCheckpointKeyWrong,
+	FilePartChained,
+	FilePartSequenceWrong,
+	FileFormatNotSupported,
+	FileManifestWrong,
+	FileManifestPartCount,
+	FileManifestPartWrong,
+	FilePartSizeInvalid,
+	FileReadFailed,
+	FileDownloadIncomplete,
+	FileMetaVersionNotSupported,
+	FileContainerWrong,
+	FileContainerKeyWrong,
+	FileArchiveIndexWrong,
+	FileArchiveEntryNotFound,
 }
 
 /**
@@ -95,6 +119,49 @@ pub fn err_to_msg(error: SdkError) -> String
 				"Error in login. Missing user keys. Maybe the Key creation was wrong",
 			)
 		},
+		SdkError::DevicePairingPayloadWrong => out_error("client_131", "The device pairing data is damaged or not valid."),
+		SdkError::DevicePairingCommitmentWrong => {
+			out_error(
+				"client_132",
+				"The revealed nonce of the new device doesn't match the commitment in the qr code.",
+			)
+		},
+		SdkError::DevicePairingResponseWrong => {
+			out_error(
+				"client_138",
+				"The response of the device pairing is not signed by the verify key in the response.",
+			)
+		},
+		SdkError::SafetyNumberQrWrong => {
+			out_error(
+				"client_133",
+				"The qr code of the safety number is damaged or not valid.",
+			)
+		},
+		SdkError::IdentityNotPinned => {
+			out_error(
+				"client_134",
+				"The public key is not signed by the pinned verify key of this identity.",
+			)
+		},
+		SdkError::IdentityChanged => {
+			out_error(
+				"client_135",
+				"The identity changed since it was pinned. The server returned another verify key or a public key with a wrong signature.",
+			)
+		},
+		SdkError::KeyRotationChainWrong => {
+			out_error(
+				"client_136",
+				"The key rotation chain is broken. A key is not signed by the previous key of the user.",
+			)
+		},
+		SdkError::AccountArchiveWrong => {
+			out_error(
+				"client_137",
+				"The account archive is damaged, in an unknown version or a key to decrypt it is missing.",
+			)
+		},
 
 		//group error
 		SdkError::GroupRank => {
@@ -107,6 +174,55 @@ pub fn err_to_msg(error: SdkError) -> String
 		SdkError::GroupUserKickRank => out_error("client_202", "The user to delete has a higher rank"),
 		SdkError::KeyRotationServerOutputWrong => out_error("client_203", "The key rotation data is wrong and can't be decoded."),
 
+		//file error
+		SdkError::FileCheckpointKeyWrong => {
+			out_error(
+				"client_400",
+				"The upload checkpoint was created with another key. Use the same key to continue the upload.",
+			)
+		},
+
+		SdkError::FilePartChained => {
+			out_error(
+				"client_401",
+				"This file part is encrypted by the key of the previous part. Decrypt the file from the first part.",
+			)
+		},
+		SdkError::FilePartSequenceWrong => out_error("client_402", "The file part belongs to another sequence."),
+		SdkError::FileFormatNotSupported => out_error("client_403", "The file format version is not supported."),
+		SdkError::FileManifestWrong => {
+			out_error(
+				"client_404",
+				"The file manifest doesn't match the file. The file was changed after the upload.",
+			)
+		},
+		SdkError::FileManifestPartCount => {
+			out_error(
+				"client_405",
+				"The number of the file parts doesn't match the manifest. Parts were removed or added.",
+			)
+		},
+		SdkError::FileManifestPartWrong => {
+			out_error(
+				"client_406",
+				"A file part doesn't match the manifest. The part was changed or swapped.",
+			)
+		},
+
+		SdkError::FilePartSizeInvalid => out_error("client_407", "The part size must be greater than 0."),
+		SdkError::FileReadFailed => out_error("client_408", "Can't read the file."),
+		SdkError::FileMetaVersionNotSupported => out_error("client_409", "The version of the file meta data is not supported."),
+		SdkError::FileContainerWrong => out_error("client_410", "The file container is damaged or incomplete."),
+		SdkError::FileContainerKeyWrong => out_error("client_411", "The file container was encrypted with another key."),
+		SdkError::FileArchiveIndexWrong => out_error("client_412", "The index of the archive is damaged."),
+		SdkError::FileArchiveEntryNotFound => out_error("client_413", "The file was not found in the archive."),
+		SdkError::FileDownloadIncomplete => {
+			out_error(
+				"client_414",
+				"The download ended before the last part of the file. Parts of the file are missing.",
+			)
+		},
+
 		SdkError::KeyRotationEncryptError(e) => {
 			out_error(
 				"client_204",
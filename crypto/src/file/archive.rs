@@ -0,0 +1,451 @@
+//! Many files in one encrypted sentc file.
+//!
+//! Every file body is split into parts of the derived file format, so every part can be decrypted on its own.
+//! The encrypted index with the paths, sizes and meta data is split into parts after the file bodies.
+//! The last part of the archive is the head of the index with the sequences of the index parts.
+//! To extract one file only the index and the parts of this file are needed.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_core::cryptomat::{SymKeyComposer, SymKeyGen};
+use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
+
+use crate::entities::file::{FileArchiveEntry, FileArchiveIndex, FileArchiveIndexHead, FileMeta, FILE_ARCHIVE_VERSION};
+use crate::file::FileEncryptor;
+use crate::SdkError;
+
+/**
+An encrypted part of the archive with the sequence to upload it
+ */
+pub struct FileArchivePart
+{
+	pub sequence: i32,
+	pub part: Vec<u8>,
+}
+
+/**
+Creates the archive file by file.
+
+Upload the parts of every added file and at last the index parts from finish. The last part gets the end flag.
+All parts are encrypted with the content key of the archive, which is managed like the key of a normal file.
+ */
+pub struct FileArchiveBuilder<'a, K, S, SC, SignK, VC>
+{
+	content_key: &'a K,
+	sign_key: Option<&'a SignK>,
+	part_size: usize,
+	next_sequence: i32,
+	entries: Vec<FileArchiveEntry>,
+	_s: PhantomData<S>,
+	_sc: PhantomData<SC>,
+	_vc: PhantomData<VC>,
+}
+
+impl<'a, K: SymKeyWrapper, S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper>
+	FileArchiveBuilder<'a, K, S, SC, SignK, VC>
+{
+	pub fn new(content_key: &'a K, sign_key: Option<&'a SignK>, part_size: usize) -> Result<Self, SdkError>
+	{
+		if part_size == 0 {
+			return Err(SdkError::FilePartSizeInvalid);
+		}
+
+		Ok(Self {
+			content_key,
+			sign_key,
+			part_size,
+			next_sequence: 0,
+			entries: Vec::new(),
+			_s: PhantomData,
+			_sc: PhantomData,
+			_vc: PhantomData,
+		})
+	}
+
+	pub fn entries(&self) -> &[FileArchiveEntry]
+	{
+		&self.entries
+	}
+
+	/**
+	Starts a file. The body is written in chunks of any size to the returned writer,
+	so the body doesn't need to be in memory at once.
+
+	Call finish of the writer before the next file is started, otherwise the file is not in the index.
+	 */
+	pub fn start_file(&mut self, path: String, meta: Option<FileMeta>) -> FileArchiveFileWriter<'_, 'a, K, S, SC, SignK, VC>
+	{
+		let first_sequence = self.next_sequence;
+
+		FileArchiveFileWriter {
+			builder: self,
+			entry: FileArchiveEntry {
+				path,
+				size: 0,
+				first_sequence,
+				part_count: 0,
+				meta,
+			},
+			buffer: Vec::new(),
+		}
+	}
+
+	/**
+	Adds a file which is already in memory and returns the encrypted parts of the file body.
+	 */
+	pub fn add_file(&mut self, path: String, data: &[u8], meta: Option<FileMeta>) -> Result<Vec<FileArchivePart>, SdkError>
+	{
+		let mut writer = self.start_file(path, meta);
+
+		let mut parts = writer.write(data)?;
+		parts.extend(writer.finish()?);
+
+		Ok(parts)
+	}
+
+	fn encrypt_part(&mut self, part: &[u8]) -> Result<FileArchivePart, SdkError>
+	{
+		let sequence = self.next_sequence;
+
+		let part = FileEncryptor::<S, SC, SignK, VC>::encrypt_file_part_derived(self.content_key, sequence, part, self.sign_key)?;
+
+		self.next_sequence += 1;
+
+		Ok(FileArchivePart {
+			sequence,
+			part,
+		})
+	}
+
+	/**
+	Returns the encrypted parts of the index. The last part is the head of the index.
+	 */
+	pub fn finish(mut self) -> Result<Vec<FileArchivePart>, SdkError>
+	{
+		let index = serde_json::to_vec(&FileArchiveIndex {
+			version: FILE_ARCHIVE_VERSION,
+			entries: core::mem::take(&mut self.entries),
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)?;
+
+		let first_sequence = self.next_sequence;
+
+		let mut parts = Vec::with_capacity(index.len().div_ceil(self.part_size) + 1);
+
+		for chunk in index.chunks(self.part_size) {
+			parts.push(self.encrypt_part(chunk)?);
+		}
+
+		let head = serde_json::to_vec(&FileArchiveIndexHead {
+			version: FILE_ARCHIVE_VERSION,
+			first_sequence,
+			part_count: parts.len() as u32,
+			size: index.len() as u64,
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)?;
+
+		parts.push(self.encrypt_part(&head)?);
+
+		Ok(parts)
+	}
+}
+
+/**
+Writes the body of one file of the archive.
+
+Only the rest of the last part is buffered, every full part is encrypted and returned.
+ */
+pub struct FileArchiveFileWriter<'b, 'a, K, S, SC, SignK, VC>
+{
+	builder: &'b mut FileArchiveBuilder<'a, K, S, SC, SignK, VC>,
+	entry: FileArchiveEntry,
+	buffer: Vec<u8>,
+}
+
+impl<'b, 'a, K: SymKeyWrapper, S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper>
+	FileArchiveFileWriter<'b, 'a, K, S, SC, SignK, VC>
+{
+	/**
+	Returns the encrypted parts which are full after this chunk.
+	 */
+	pub fn write(&mut self, mut data: &[u8]) -> Result<Vec<FileArchivePart>, SdkError>
+	{
+		let part_size = self.builder.part_size;
+
+		let mut parts = Vec::new();
+
+		while !data.is_empty() {
+			let len = (part_size - self.buffer.len()).min(data.len());
+
+			self.buffer.extend_from_slice(&data[..len]);
+			data = &data[len..];
+
+			if self.buffer.len() == part_size {
+				parts.push(self.encrypt_buffer()?);
+			}
+		}
+
+		Ok(parts)
+	}
+
+	/**
+	Adds the file to the index and returns the last part of the body if there is one.
+	 */
+	pub fn finish(mut self) -> Result<Option<FileArchivePart>, SdkError>
+	{
+		let part = if self.buffer.is_empty() { None } else { Some(self.encrypt_buffer()?) };
+
+		self.builder.entries.push(self.entry);
+
+		Ok(part)
+	}
+
+	fn encrypt_buffer(&mut self) -> Result<FileArchivePart, SdkError>
+	{
+		let part = self.builder.encrypt_part(&self.buffer)?;
+
+		self.entry.size += self.buffer.len() as u64;
+		self.entry.part_count += 1;
+		self.buffer.clear();
+
+		Ok(part)
+	}
+}
+
+/**
+Lists and extracts the files of the archive.
+ */
+pub struct FileArchiveReader<'a, K, S, SC, SignK, VC>
+{
+	content_key: &'a K,
+	verify_key: Option<&'a UserVerifyKeyData>,
+	entries: Vec<FileArchiveEntry>,
+	_s: PhantomData<S>,
+	_sc: PhantomData<SC>,
+	_sign_k: PhantomData<SignK>,
+	_vc: PhantomData<VC>,
+}
+
+impl<'a, K: SymKeyWrapper, S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper>
+	FileArchiveReader<'a, K, S, SC, SignK, VC>
+{
+	/**
+	Decrypts the head of the index from the last part of the archive.
+
+	The head contains the sequences of the index parts, download these parts for new.
+	 */
+	pub fn index_head(
+		content_key: &K,
+		last_sequence: i32,
+		last_part: &[u8],
+		verify_key: Option<&UserVerifyKeyData>,
+	) -> Result<FileArchiveIndexHead, SdkError>
+	{
+		let head = FileEncryptor::<S, SC, SignK, VC>::decrypt_file_part_derived(content_key, last_sequence, last_part, verify_key)?;
+
+		let head: FileArchiveIndexHead = serde_json::from_slice(&head)?;
+
+		if head.version != FILE_ARCHIVE_VERSION {
+			return Err(SdkError::FileFormatNotSupported);
+		}
+
+		//the index parts are right before the head
+		if head.first_sequence < 0 || head.first_sequence + head.part_count as i32 != last_sequence {
+			return Err(SdkError::FileArchiveIndexWrong);
+		}
+
+		Ok(head)
+	}
+
+	/**
+	Decrypts the index from the index parts of the head. The parts must be in the order of the sequence.
+	 */
+	pub fn new(
+		content_key: &'a K,
+		head: &FileArchiveIndexHead,
+		index_parts: &[Vec<u8>],
+		verify_key: Option<&'a UserVerifyKeyData>,
+	) -> Result<Self, SdkError>
+	{
+		if index_parts.len() != head.part_count as usize {
+			return Err(SdkError::FileArchiveIndexWrong);
+		}
+
+		let mut index = Vec::with_capacity(head.size as usize);
+
+		for (i, part) in index_parts.iter().enumerate() {
+			index.extend(FileEncryptor::<S, SC, SignK, VC>::decrypt_file_part_derived(
+				content_key,
+				head.first_sequence + i as i32,
+				part,
+				verify_key,
+			)?);
+		}
+
+		if index.len() as u64 != head.size {
+			return Err(SdkError::FileArchiveIndexWrong);
+		}
+
+		let index: FileArchiveIndex = serde_json::from_slice(&index)?;
+
+		if index.version != FILE_ARCHIVE_VERSION {
+			return Err(SdkError::FileFormatNotSupported);
+		}
+
+		//the entries must cover all parts before the index without gaps
+		let mut next_sequence = 0;
+
+		for entry in &index.entries {
+			if entry.first_sequence != next_sequence {
+				return Err(SdkError::FileArchiveIndexWrong);
+			}
+
+			next_sequence += entry.part_count as i32;
+		}
+
+		if next_sequence != head.first_sequence {
+			return Err(SdkError::FileArchiveIndexWrong);
+		}
+
+		Ok(Self {
+			content_key,
+			verify_key,
+			entries: index.entries,
+			_s: PhantomData,
+			_sc: PhantomData,
+			_sign_k: PhantomData,
+			_vc: PhantomData,
+		})
+	}
+
+	pub fn entries(&self) -> &[FileArchiveEntry]
+	{
+		&self.entries
+	}
+
+	pub fn entry(&self, path: &str) -> Result<&FileArchiveEntry, SdkError>
+	{
+		self.entries
+			.iter()
+			.find(|e| e.path == path)
+			.ok_or(SdkError::FileArchiveEntryNotFound)
+	}
+
+	/**
+	Decrypts a part of the file. The parts can be decrypted in any order.
+	 */
+	pub fn decrypt_entry_part(&self, entry: &FileArchiveEntry, sequence: i32, part: &[u8]) -> Result<Vec<u8>, SdkError>
+	{
+		if sequence < entry.first_sequence || sequence >= entry.first_sequence + entry.part_count as i32 {
+			return Err(SdkError::FilePartSequenceWrong);
+		}
+
+		FileEncryptor::<S, SC, SignK, VC>::decrypt_file_part_derived(self.content_key, sequence, part, self.verify_key)
+	}
+}
+
+#[cfg(all(test, feature = "std_keys"))]
+mod test
+{
+	use sentc_crypto_std_keys::util::SymmetricKey;
+
+	use super::*;
+	use crate::group::test_fn::create_group;
+	use crate::keys::std::{StdFileArchiveBuilder, StdFileArchiveReader};
+	use crate::user::test_fn::create_user;
+
+	#[test]
+	fn test_extract_one_file_from_archive()
+	{
+		let user = create_user();
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let key: &SymmetricKey = &group_keys[0].group_key;
+
+		let mut builder = StdFileArchiveBuilder::new(key, None, 4).unwrap();
+
+		let mut parts = Vec::new();
+
+		parts.extend(
+			builder
+				.add_file("a.txt".into(), b"hello world", None)
+				.unwrap(),
+		);
+		parts.extend(builder.add_file("empty.txt".into(), b"", None).unwrap());
+
+		//the body of this file is written in chunks
+		let mut writer = builder.start_file(
+			"dir/b.txt".into(),
+			Some(FileMeta {
+				mime_type: Some("text/plain".into()),
+				..Default::default()
+			}),
+		);
+
+		for chunk in [&b"sec"[..], b"ond", b" ", b"file"] {
+			parts.extend(writer.write(chunk).unwrap());
+		}
+
+		parts.extend(writer.finish().unwrap());
+
+		let mut index_parts = builder.finish().unwrap();
+
+		//the index is split into parts like a file body
+		assert!(index_parts.len() > 2);
+
+		let last = index_parts.pop().unwrap();
+
+		assert_eq!(last.sequence, (parts.len() + index_parts.len()) as i32);
+
+		let head = StdFileArchiveReader::index_head(key, last.sequence, &last.part, None).unwrap();
+
+		assert_eq!(head.first_sequence, parts.len() as i32);
+
+		let index_parts = index_parts.into_iter().map(|p| p.part).collect::<Vec<_>>();
+
+		let reader = StdFileArchiveReader::new(key, &head, &index_parts, None).unwrap();
+
+		assert_eq!(reader.entries().len(), 3);
+
+		let entry = reader.entry("dir/b.txt").unwrap();
+
+		assert_eq!(entry.size, 11);
+		assert_eq!(entry.meta.as_ref().unwrap().mime_type.as_deref(), Some("text/plain"));
+
+		//only decrypt the parts of this file
+		let mut file = Vec::new();
+
+		for part in &parts[entry.first_sequence as usize..(entry.first_sequence + entry.part_count as i32) as usize] {
+			file.extend(
+				reader
+					.decrypt_entry_part(entry, part.sequence, &part.part)
+					.unwrap(),
+			);
+		}
+
+		assert_eq!(file, b"second file");
+
+		//a part of another file
+		assert!(matches!(
+			reader.decrypt_entry_part(entry, 0, &parts[0].part),
+			Err(SdkError::FilePartSequenceWrong)
+		));
+
+		assert!(matches!(
+			reader.entry("c.txt"),
+			Err(SdkError::FileArchiveEntryNotFound)
+		));
+
+		//the head at another sequence
+		assert!(StdFileArchiveReader::index_head(key, last.sequence + 1, &last.part, None).is_err());
+
+		//a missing index part
+		assert!(matches!(
+			StdFileArchiveReader::new(key, &head, &index_parts[1..], None),
+			Err(SdkError::FileArchiveIndexWrong)
+		));
+	}
+}
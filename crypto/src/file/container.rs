@@ -0,0 +1,547 @@
+//! Offline container for encrypted files, e.g. for backups or air-gapped transfers.
+//!
+//! The container is: magic | version | header frame | part frames | manifest frame (if signed) | end frame.
+//! Every frame is: type (1 byte) | length of the data (u32 big endian) | data.
+//! The parts use the same chained key and head as the parts at the server.
+//! The end frame is the number of parts and the final flag, encrypted with the key after the last part,
+//! so parts can't be removed from the end of a container without a manifest.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_core::cryptomat::{CryptoAlg, SymKey, SymKeyComposer, SymKeyGen};
+use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
+
+use crate::entities::file::FileContainerHeader;
+use crate::file::{FileEncryptor, FileManifestBuilder};
+use crate::SdkError;
+
+pub const FILE_CONTAINER_MAGIC: &[u8; 6] = b"SENTCF";
+pub const FILE_CONTAINER_VERSION: u8 = 1;
+
+/**
+The length of the magic and the version at the start of the container
+ */
+pub const FILE_CONTAINER_PREFIX_LEN: usize = 7;
+
+pub const FILE_CONTAINER_FRAME_HEAD_LEN: usize = 5;
+
+/**
+The max size of a part in the container (64 mb)
+ */
+pub const FILE_CONTAINER_MAX_PART_SIZE: usize = 64 * 1024 * 1024;
+
+/**
+The max length of the data of a frame, a part with the file head and the signature
+ */
+const FILE_CONTAINER_MAX_FRAME_LEN: usize = FILE_CONTAINER_MAX_PART_SIZE + 64 * 1024;
+
+const FILE_CONTAINER_END_FINAL: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileContainerFrame
+{
+	Header,
+	Part,
+	Manifest,
+	End,
+}
+
+impl FileContainerFrame
+{
+	fn to_byte(self) -> u8
+	{
+		match self {
+			Self::Header => 1,
+			Self::Part => 2,
+			Self::Manifest => 3,
+			Self::End => 4,
+		}
+	}
+
+	fn from_byte(byte: u8) -> Result<Self, SdkError>
+	{
+		match byte {
+			1 => Ok(Self::Header),
+			2 => Ok(Self::Part),
+			3 => Ok(Self::Manifest),
+			4 => Ok(Self::End),
+			_ => Err(SdkError::FileContainerWrong),
+		}
+	}
+}
+
+fn write_frame(frame: FileContainerFrame, data: &[u8]) -> Result<Vec<u8>, SdkError>
+{
+	if data.len() > FILE_CONTAINER_MAX_FRAME_LEN {
+		return Err(SdkError::FileContainerWrong);
+	}
+
+	let len = data.len() as u32;
+
+	let mut out = Vec::with_capacity(FILE_CONTAINER_FRAME_HEAD_LEN + data.len());
+	out.push(frame.to_byte());
+	out.extend_from_slice(&len.to_be_bytes());
+	out.extend_from_slice(data);
+
+	Ok(out)
+}
+
+/**
+Returns the type of the frame and the length of the frame data.
+
+Fails for a length over the max frame length, so the length can be used to allocate the buffer for the frame data.
+ */
+pub fn read_frame_head(head: &[u8]) -> Result<(FileContainerFrame, usize), SdkError>
+{
+	if head.len() < FILE_CONTAINER_FRAME_HEAD_LEN {
+		return Err(SdkError::FileContainerWrong);
+	}
+
+	let frame = FileContainerFrame::from_byte(head[0])?;
+	let len = u32::from_be_bytes([head[1], head[2], head[3], head[4]]) as usize;
+
+	if len > FILE_CONTAINER_MAX_FRAME_LEN {
+		return Err(SdkError::FileContainerWrong);
+	}
+
+	Ok((frame, len))
+}
+
+fn end_frame_data(part_count: u32) -> [u8; 5]
+{
+	let mut out = [0u8; 5];
+	out[..4].copy_from_slice(&part_count.to_be_bytes());
+	out[4] = FILE_CONTAINER_END_FINAL;
+
+	out
+}
+
+fn split_frame(data: &[u8]) -> Result<(FileContainerFrame, &[u8], &[u8]), SdkError>
+{
+	let (frame, len) = read_frame_head(data)?;
+
+	let rest = &data[FILE_CONTAINER_FRAME_HEAD_LEN..];
+
+	if rest.len() < len {
+		return Err(SdkError::FileContainerWrong);
+	}
+
+	let (frame_data, rest) = rest.split_at(len);
+
+	Ok((frame, frame_data, rest))
+}
+
+/**
+Creates the container part by part.
+
+Write the start of the container from new, then every part and then the output of finish.
+ */
+pub struct FileContainerWriter<'a, S: SymKeyGen, SC, SignK, VC>
+{
+	next_key: S::SymmetricKey,
+	file_id: String,
+	sign_key: Option<&'a SignK>,
+	manifest: FileManifestBuilder,
+	part_count: u32,
+	_sc: PhantomData<SC>,
+	_vc: PhantomData<VC>,
+}
+
+impl<'a, S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper> FileContainerWriter<'a, S, SC, SignK, VC>
+{
+	/**
+	Creates a new content key encrypted by the master key.
+
+	Returns the writer and the start of the container with the header.
+	 */
+	pub fn new(master_key: &impl SymKeyWrapper, sign_key: Option<&'a SignK>) -> Result<(Self, Vec<u8>), SdkError>
+	{
+		let (encrypted_content_key, content_key) = S::generate_symmetric_with_sym_key(master_key.get_key())?;
+
+		let header = FileContainerHeader {
+			key_id: master_key.get_id().to_string(),
+			encrypted_content_key: Base64::encode_string(&encrypted_content_key),
+			content_key_alg: content_key.get_alg_str().to_string(),
+			signed: sign_key.is_some(),
+		};
+
+		let header_data = serde_json::to_vec(&header).map_err(|_| SdkError::JsonToStringFailed)?;
+
+		let mut out = Vec::with_capacity(FILE_CONTAINER_PREFIX_LEN + FILE_CONTAINER_FRAME_HEAD_LEN + header_data.len());
+		out.extend_from_slice(FILE_CONTAINER_MAGIC);
+		out.push(FILE_CONTAINER_VERSION);
+		out.extend_from_slice(&write_frame(FileContainerFrame::Header, &header_data)?);
+
+		Ok((
+			Self {
+				next_key: content_key,
+				//the encrypted content key is unique for every container, so the manifest can't be used for another container
+				file_id: header.encrypted_content_key,
+				sign_key,
+				manifest: FileManifestBuilder::new(),
+				part_count: 0,
+				_sc: PhantomData,
+				_vc: PhantomData,
+			},
+			out,
+		))
+	}
+
+	/**
+	Encrypts the next part and returns the part frame.
+	 */
+	pub fn write_part(&mut self, part: &[u8]) -> Result<Vec<u8>, SdkError>
+	{
+		if part.len() > FILE_CONTAINER_MAX_PART_SIZE {
+			return Err(SdkError::FilePartSizeInvalid);
+		}
+
+		let (encrypted, next_key) = FileEncryptor::<S, SC, SignK, VC>::encrypt_file_part(&self.next_key, part, self.sign_key)?;
+
+		if self.sign_key.is_some() {
+			self.manifest.add_part(&encrypted);
+		}
+
+		self.next_key = next_key;
+		self.part_count = self
+			.part_count
+			.checked_add(1)
+			.ok_or(SdkError::FileContainerWrong)?;
+
+		write_frame(FileContainerFrame::Part, &encrypted)
+	}
+
+	/**
+	Returns the end of the container with the signed manifest and the encrypted end frame.
+	 */
+	pub fn finish(self) -> Result<Vec<u8>, SdkError>
+	{
+		let mut out = match self.sign_key {
+			Some(sk) => write_frame(FileContainerFrame::Manifest, &self.manifest.finish(self.file_id, sk)?)?,
+			None => Vec::new(),
+		};
+
+		let end = self.next_key.encrypt(&end_frame_data(self.part_count))?;
+
+		out.extend_from_slice(&write_frame(FileContainerFrame::End, &end)?);
+
+		Ok(out)
+	}
+
+	/**
+	Creates the whole container in memory.
+	 */
+	pub fn write_all(master_key: &impl SymKeyWrapper, sign_key: Option<&'a SignK>, data: &[u8], part_size: usize) -> Result<Vec<u8>, SdkError>
+	{
+		if part_size == 0 || part_size > FILE_CONTAINER_MAX_PART_SIZE {
+			return Err(SdkError::FilePartSizeInvalid);
+		}
+
+		let (mut writer, mut out) = Self::new(master_key, sign_key)?;
+
+		for part in data.chunks(part_size) {
+			out.extend_from_slice(&writer.write_part(part)?);
+		}
+
+		out.extend_from_slice(&writer.finish()?);
+
+		Ok(out)
+	}
+}
+
+/**
+Reads the container frame by frame.
+
+The decrypted parts are not checked against the manifest until finish is called,
+so the output should be handled as unverified until then.
+ */
+pub struct FileContainerReader<'a, S, SC: SymKeyComposer, SignK, VC>
+{
+	header: FileContainerHeader,
+	next_key: SC::SymmetricKey,
+	verify_key: Option<&'a UserVerifyKeyData>,
+	part_digests: FileManifestBuilder,
+	part_count: u32,
+	verify_key_id: Option<String>,
+	manifest_read: bool,
+	end_read: bool,
+	_s: PhantomData<S>,
+	_sign_k: PhantomData<SignK>,
+	_vc: PhantomData<VC>,
+}
+
+impl<'a, S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper> FileContainerReader<'a, S, SC, SignK, VC>
+{
+	/**
+	Checks the prefix of the container (magic and version).
+	 */
+	pub fn check_prefix(prefix: &[u8]) -> Result<(), SdkError>
+	{
+		if prefix.len() < FILE_CONTAINER_PREFIX_LEN || &prefix[..6] != FILE_CONTAINER_MAGIC {
+			return Err(SdkError::FileContainerWrong);
+		}
+
+		if prefix[6] != FILE_CONTAINER_VERSION {
+			return Err(SdkError::FileFormatNotSupported);
+		}
+
+		Ok(())
+	}
+
+	/**
+	Decrypts the content key from the data of the header frame.
+
+	When a verify key is set, the container must be signed.
+	 */
+	pub fn new(master_key: &impl SymKeyWrapper, header: &[u8], verify_key: Option<&'a UserVerifyKeyData>) -> Result<Self, SdkError>
+	{
+		let header: FileContainerHeader = serde_json::from_slice(header)?;
+
+		if header.key_id != master_key.get_id() {
+			return Err(SdkError::FileContainerKeyWrong);
+		}
+
+		if verify_key.is_some() && !header.signed {
+			return Err(SdkError::FileManifestWrong);
+		}
+
+		let encrypted_content_key = Base64::decode_vec(&header.encrypted_content_key).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+		let content_key = SC::decrypt_key_by_sym_key(master_key.get_key(), &encrypted_content_key, &header.content_key_alg)?;
+
+		Ok(Self {
+			header,
+			next_key: content_key,
+			verify_key,
+			part_digests: FileManifestBuilder::new(),
+			part_count: 0,
+			verify_key_id: None,
+			manifest_read: false,
+			end_read: false,
+			_s: PhantomData,
+			_sign_k: PhantomData,
+			_vc: PhantomData,
+		})
+	}
+
+	pub fn header(&self) -> &FileContainerHeader
+	{
+		&self.header
+	}
+
+	/**
+	Reads the next frame after the header. Returns the decrypted data for a part frame.
+	 */
+	pub fn read_frame(&mut self, frame: FileContainerFrame, data: &[u8]) -> Result<Option<Vec<u8>>, SdkError>
+	{
+		if self.end_read {
+			return Err(SdkError::FileContainerWrong);
+		}
+
+		match frame {
+			FileContainerFrame::Header => Err(SdkError::FileContainerWrong),
+			FileContainerFrame::Part => {
+				if self.manifest_read {
+					return Err(SdkError::FileContainerWrong);
+				}
+
+				if self.header.signed {
+					self.part_digests.add_part(data);
+				}
+
+				let (decrypted, next_key) = FileEncryptor::<S, SC, SignK, VC>::decrypt_file_part(&self.next_key, data, self.verify_key)?;
+
+				self.next_key = next_key;
+				self.part_count = self
+					.part_count
+					.checked_add(1)
+					.ok_or(SdkError::FileContainerWrong)?;
+
+				Ok(Some(decrypted))
+			},
+			FileContainerFrame::Manifest => {
+				if !self.header.signed || self.manifest_read {
+					return Err(SdkError::FileContainerWrong);
+				}
+
+				self.manifest_read = true;
+
+				if let Some(vk) = self.verify_key {
+					let part_digests = core::mem::take(&mut self.part_digests);
+
+					self.verify_key_id = Some(part_digests.verify::<VC>(data, &self.header.encrypted_content_key, vk)?);
+				}
+
+				Ok(None)
+			},
+			FileContainerFrame::End => {
+				if self.header.signed && !self.manifest_read {
+					return Err(SdkError::FileManifestWrong);
+				}
+
+				//only the key after the real last part can decrypt the end frame
+				let end = self
+					.next_key
+					.decrypt(data)
+					.map_err(|_| SdkError::FileContainerWrong)?;
+
+				if end != end_frame_data(self.part_count) {
+					return Err(SdkError::FileContainerWrong);
+				}
+
+				self.end_read = true;
+
+				Ok(None)
+			},
+		}
+	}
+
+	/**
+	Checks if the container is complete.
+
+	Returns the id of the verify key which signed the manifest, if a verify key was set.
+	 */
+	pub fn finish(self) -> Result<Option<String>, SdkError>
+	{
+		if !self.end_read {
+			return Err(SdkError::FileContainerWrong);
+		}
+
+		Ok(self.verify_key_id)
+	}
+
+	/**
+	Reads the whole container from memory.
+	 */
+	pub fn read_all(
+		master_key: &impl SymKeyWrapper,
+		verify_key: Option<&'a UserVerifyKeyData>,
+		container: &[u8],
+	) -> Result<(Vec<u8>, Option<String>), SdkError>
+	{
+		Self::check_prefix(container)?;
+
+		let (frame, header, mut rest) = split_frame(&container[FILE_CONTAINER_PREFIX_LEN..])?;
+
+		if frame != FileContainerFrame::Header {
+			return Err(SdkError::FileContainerWrong);
+		}
+
+		let mut reader = Self::new(master_key, header, verify_key)?;
+		let mut out = Vec::new();
+
+		while !rest.is_empty() {
+			let (frame, data, next) = split_frame(rest)?;
+
+			if let Some(part) = reader.read_frame(frame, data)? {
+				out.extend_from_slice(&part);
+			}
+
+			rest = next;
+		}
+
+		Ok((out, reader.finish()?))
+	}
+}
+
+#[cfg(all(test, feature = "std_keys"))]
+mod test
+{
+	use super::*;
+	use crate::group::test_fn::create_group;
+	use crate::keys::std::{StdFileContainerReader, StdFileContainerWriter};
+	use crate::user::test_fn::create_user;
+
+	#[test]
+	fn test_write_and_read_container()
+	{
+		let user = create_user();
+		let keys = &user.user_keys[0];
+		let (_, group_keys, _, _, _) = create_group(keys);
+
+		let key = &group_keys[0].group_key;
+
+		let data = (0..100u8).collect::<Vec<_>>();
+
+		let container = StdFileContainerWriter::write_all(key, Some(&keys.sign_key), &data, 30).unwrap();
+
+		let (decrypted, verify_key_id) = StdFileContainerReader::read_all(key, Some(&keys.exported_verify_key), &container).unwrap();
+
+		assert_eq!(decrypted, data);
+		assert_eq!(verify_key_id.unwrap(), keys.exported_verify_key.verify_key_id);
+
+		//without verify key
+		let (decrypted, verify_key_id) = StdFileContainerReader::read_all(key, None, &container).unwrap();
+
+		assert_eq!(decrypted, data);
+		assert!(verify_key_id.is_none());
+
+		//truncated container
+		let truncated = &container[..container.len() - FILE_CONTAINER_FRAME_HEAD_LEN];
+
+		assert!(StdFileContainerReader::read_all(key, None, truncated).is_err());
+	}
+
+	#[test]
+	fn test_not_read_unsigned_container_with_verify_key()
+	{
+		let user = create_user();
+		let keys = &user.user_keys[0];
+		let (_, group_keys, _, _, _) = create_group(keys);
+
+		let key = &group_keys[0].group_key;
+
+		let container = StdFileContainerWriter::write_all(key, None, b"hello", 2).unwrap();
+
+		let (decrypted, _) = StdFileContainerReader::read_all(key, None, &container).unwrap();
+
+		assert_eq!(decrypted, b"hello");
+
+		assert!(matches!(
+			StdFileContainerReader::read_all(key, Some(&keys.exported_verify_key), &container),
+			Err(SdkError::FileManifestWrong)
+		));
+	}
+
+	#[test]
+	fn test_not_read_unsigned_container_without_the_last_part()
+	{
+		let user = create_user();
+		let keys = &user.user_keys[0];
+		let (_, group_keys, _, _, _) = create_group(keys);
+
+		let key = &group_keys[0].group_key;
+
+		let container = StdFileContainerWriter::write_all(key, None, b"hello world", 4).unwrap();
+
+		//collect the frames and remove the last part but keep the end frame
+		let mut frames = Vec::new();
+		let mut rest = &container[FILE_CONTAINER_PREFIX_LEN..];
+
+		while !rest.is_empty() {
+			let (frame, data, next) = split_frame(rest).unwrap();
+			frames.push(write_frame(frame, data).unwrap());
+			rest = next;
+		}
+
+		let end = frames.pop().unwrap();
+		frames.pop();
+
+		let mut truncated = container[..FILE_CONTAINER_PREFIX_LEN].to_vec();
+
+		for frame in frames {
+			truncated.extend_from_slice(&frame);
+		}
+
+		truncated.extend_from_slice(&end);
+
+		assert!(matches!(
+			StdFileContainerReader::read_all(key, None, &truncated),
+			Err(SdkError::FileContainerWrong)
+		));
+	}
+}
@@ -3,29 +3,37 @@ use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use base64ct::{Base64, Encoding};
+use sentc_crypto_common::crypto::SignHead;
 use sentc_crypto_common::file::{BelongsToType, FileHead, FileNameUpdate, FileRegisterInput, FileRegisterOutput};
-use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
 use sentc_crypto_common::{FileId, FileSessionId};
-use sentc_crypto_core::cryptomat::{CryptoAlg, SymKey, SymKeyComposer, SymKeyGen};
-use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
+use sentc_crypto_core::cryptomat::{CryptoAlg, Pk, SymKey, SymKeyComposer, SymKeyGen};
+use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SignComposerWrapper, SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
 
 use crate::crypto::crypto::{put_head_and_encrypted_data, split_head_and_encrypted_data};
+use crate::entities::file::{
+	EncryptedFileUploadCheckpoint,
+	FileHeadDerived,
+	FileHeadVersion,
+	FileKeyRewrap,
+	FileMeta,
+	FileMetaVersioned,
+	FileMoveInput,
+	FileNameWithMeta,
+	FileShareInput,
+	FileUploadCheckpoint,
+	FILE_FORMAT_DERIVED,
+	FILE_META_VERSION,
+};
 use crate::util::public::handle_server_response;
 use crate::SdkError;
 
-pub fn prepare_register_file(
-	master_key_id: String,
-	key: &impl SymKeyWrapper,
-	encrypted_content_key: String,
-	belongs_to_id: Option<String>,
-	belongs_to_type: BelongsToType,
-	file_name: Option<String>,
-) -> Result<(String, Option<String>), SdkError>
-{
-	let encrypted_key_alg = key.get_key().get_alg_str();
+const FILE_META_AAD: &str = "sentc_file_meta";
 
+fn check_belongs_to(belongs_to_id: Option<String>, belongs_to_type: BelongsToType) -> (BelongsToType, Option<String>)
+{
 	// this check is already done in the backend too
-	let (belongs_to_type, belongs_to_id) = match belongs_to_type {
+	match belongs_to_type {
 		BelongsToType::None => (belongs_to_type, None),
 		_ => {
 			//check if the id is set
@@ -36,7 +44,21 @@ pub fn prepare_register_file(
 				(belongs_to_type, belongs_to_id)
 			}
 		},
-	};
+	}
+}
+
+fn prepare_register_input(
+	master_key_id: String,
+	key: &impl SymKeyWrapper,
+	encrypted_content_key: String,
+	belongs_to_id: Option<String>,
+	belongs_to_type: BelongsToType,
+	file_name: Option<String>,
+) -> Result<FileRegisterInput, SdkError>
+{
+	let encrypted_key_alg = key.get_key().get_alg_str();
+
+	let (belongs_to_type, belongs_to_id) = check_belongs_to(belongs_to_id, belongs_to_type);
 
 	let encrypted_file_name = match file_name {
 		None => None,
@@ -46,20 +68,94 @@ pub fn prepare_register_file(
 		},
 	};
 
+	Ok(FileRegisterInput {
+		master_key_id,
+		encrypted_key: encrypted_content_key,
+		belongs_to_id,
+		belongs_to_type,
+		encrypted_file_name,
+		encrypted_key_alg: encrypted_key_alg.to_string(),
+	})
+}
+
+pub fn prepare_register_file(
+	master_key_id: String,
+	key: &impl SymKeyWrapper,
+	encrypted_content_key: String,
+	belongs_to_id: Option<String>,
+	belongs_to_type: BelongsToType,
+	file_name: Option<String>,
+) -> Result<(String, Option<String>), SdkError>
+{
+	let input = prepare_register_input(
+		master_key_id,
+		key,
+		encrypted_content_key,
+		belongs_to_id,
+		belongs_to_type,
+		file_name,
+	)?;
+
+	let encrypted_file_name = input.encrypted_file_name.clone();
+
 	Ok((
-		serde_json::to_string(&FileRegisterInput {
-			master_key_id,
-			encrypted_key: encrypted_content_key,
-			belongs_to_id,
-			belongs_to_type,
-			encrypted_file_name: encrypted_file_name.clone(),
-			encrypted_key_alg: encrypted_key_alg.to_string(),
-		})
-		.map_err(|_e| SdkError::JsonToStringFailed)?,
+		serde_json::to_string(&input).map_err(|_e| SdkError::JsonToStringFailed)?,
 		encrypted_file_name,
 	))
 }
 
+/**
+Like prepare_register_file but with the meta data of the file.
+
+The server only stores the encrypted file name, so the file name and the meta data are encrypted together in this field
+and the register input is the same as without meta data. Without meta data only the file name is encrypted.
+Read both back with decrypt_file_name_with_meta from the encrypted file name of the file data.
+
+Returns the server input and the encrypted file name.
+ */
+pub fn prepare_register_file_with_meta(
+	master_key_id: String,
+	key: &impl SymKeyWrapper,
+	encrypted_content_key: String,
+	belongs_to_id: Option<String>,
+	belongs_to_type: BelongsToType,
+	file_name: Option<String>,
+	meta: Option<&FileMeta>,
+) -> Result<(String, Option<String>), SdkError>
+{
+	let meta = match meta {
+		None => {
+			return prepare_register_file(
+				master_key_id,
+				key,
+				encrypted_content_key,
+				belongs_to_id,
+				belongs_to_type,
+				file_name,
+			)
+		},
+		Some(m) => m,
+	};
+
+	let mut input = prepare_register_input(
+		master_key_id,
+		key,
+		encrypted_content_key,
+		belongs_to_id,
+		belongs_to_type,
+		None,
+	)?;
+
+	let encrypted_file_name = encrypt_file_name_with_meta(key, file_name, meta)?;
+
+	input.encrypted_file_name = Some(encrypted_file_name.clone());
+
+	Ok((
+		serde_json::to_string(&input).map_err(|_e| SdkError::JsonToStringFailed)?,
+		Some(encrypted_file_name),
+	))
+}
+
 pub fn done_register_file(server_output: &str) -> Result<(FileId, FileSessionId), SdkError>
 {
 	let out: FileRegisterOutput = handle_server_response(server_output)?;
@@ -83,6 +179,79 @@ pub fn prepare_file_name_update(key: &impl SymKeyWrapper, file_name: Option<Stri
 	.map_err(|_e| SdkError::JsonToStringFailed)
 }
 
+/**
+Encrypt the file name and the meta data with the content key of the file. The output is used as encrypted file name.
+
+The meta data is bound to the file meta via aad, so a file name without meta data is never read as meta data.
+ */
+pub fn encrypt_file_name_with_meta(key: &impl SymKeyWrapper, file_name: Option<String>, meta: &FileMeta) -> Result<String, SdkError>
+{
+	let data = serde_json::to_string(&FileMetaVersioned {
+		version: FILE_META_VERSION,
+		file_name,
+		meta: meta.clone(),
+	})
+	.map_err(|_e| SdkError::JsonToStringFailed)?;
+
+	Ok(key.encrypt_string_with_aad(&data, FILE_META_AAD)?)
+}
+
+/**
+Decrypt the encrypted file name of the file data.
+
+For files without meta data only the file name is returned.
+ */
+pub fn decrypt_file_name_with_meta(key: &impl SymKeyWrapper, encrypted_file_name: &str) -> Result<FileNameWithMeta, SdkError>
+{
+	let data = match key.decrypt_string_with_aad(encrypted_file_name, FILE_META_AAD, None) {
+		Ok(d) => d,
+		Err(_) => {
+			//a file name without meta data
+			return Ok(FileNameWithMeta {
+				file_name: Some(key.decrypt_string(encrypted_file_name, None)?),
+				meta: None,
+			});
+		},
+	};
+
+	let meta: FileMetaVersioned = serde_json::from_str(&data)?;
+
+	if meta.version != FILE_META_VERSION {
+		return Err(SdkError::FileMetaVersionNotSupported);
+	}
+
+	Ok(FileNameWithMeta {
+		file_name: meta.file_name,
+		meta: Some(meta.meta),
+	})
+}
+
+/**
+Updates the file name and the meta data. The input is the same as for the file name update.
+
+Set the meta data to none to delete it, the file name is then encrypted without meta data.
+ */
+pub fn prepare_file_meta_update(key: &impl SymKeyWrapper, file_name: Option<String>, meta: Option<&FileMeta>) -> Result<String, SdkError>
+{
+	let meta = match meta {
+		None => return prepare_file_name_update(key, file_name),
+		Some(m) => m,
+	};
+
+	serde_json::to_string(&FileNameUpdate {
+		encrypted_file_name: Some(encrypt_file_name_with_meta(key, file_name, meta)?),
+	})
+	.map_err(|_e| SdkError::JsonToStringFailed)
+}
+
+fn part_key_info(sequence: i32) -> Vec<u8>
+{
+	let mut info = Vec::from(&b"sentc_file_part"[..]);
+	info.extend_from_slice(&sequence.to_be_bytes());
+
+	info
+}
+
 pub struct FileEncryptor<S, SC, SignK, VC>
 {
 	_s: PhantomData<S>,
@@ -131,6 +300,243 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 		Ok((put_head_and_encrypted_data(&file_head, &encrypted_part)?, file_key))
 	}
 
+	fn decrypt_content_key(old_master_key: &impl SymKeyWrapper, encrypted_key: &str, encrypted_key_alg: &str) -> Result<SC::SymmetricKey, SdkError>
+	{
+		let encrypted_key = Base64::decode_vec(encrypted_key).map_err(|_| SdkError::KeyDecryptFailed)?;
+
+		Ok(SC::decrypt_key_by_sym_key(
+			old_master_key.get_key(),
+			&encrypted_key,
+			encrypted_key_alg,
+		)?)
+	}
+
+	fn rewrap_content_key(
+		old_master_key: &impl SymKeyWrapper,
+		new_master_key: &impl SymKeyWrapper,
+		encrypted_key: &str,
+		encrypted_key_alg: &str,
+	) -> Result<FileKeyRewrap, SdkError>
+	{
+		let content_key = Self::decrypt_content_key(old_master_key, encrypted_key, encrypted_key_alg)?;
+
+		let encrypted_key = content_key.encrypt_with_sym_key(new_master_key.get_key())?;
+
+		Ok(FileKeyRewrap {
+			master_key_id: new_master_key.get_id().to_string(),
+			encrypted_key: Base64::encode_string(&encrypted_key),
+			encrypted_key_alg: content_key.get_alg_str().to_string(),
+		})
+	}
+
+	/**
+	The public key must be signed by the verify key of the user (e.g. the pinned verify key),
+	so the server can't swap the public key.
+	 */
+	fn rewrap_content_key_by_public_key<P: PkFromUserKeyWrapper, SiC: SignComposerWrapper>(
+		old_master_key: &impl SymKeyWrapper,
+		public_key: &UserPublicKeyData,
+		verify_key: &UserVerifyKeyData,
+		encrypted_key: &str,
+		encrypted_key_alg: &str,
+	) -> Result<FileKeyRewrap, SdkError>
+	{
+		let sig = match (&public_key.public_key_sig, &public_key.public_key_sig_key_id) {
+			(Some(sig), Some(id)) if *id == verify_key.verify_key_id => sig,
+			_ => return Err(SdkError::IdentityChanged),
+		};
+
+		let raw_verify_key = SiC::vk_inner_from_pem(&verify_key.verify_key_pem, &verify_key.verify_key_alg)?;
+		let sig = SiC::sig_from_string(sig, &verify_key.verify_key_alg)?;
+
+		let raw_public_key = P::from_user_key(public_key)?;
+
+		if !raw_public_key.verify_public_key(&raw_verify_key, &sig)? {
+			return Err(SdkError::IdentityChanged);
+		}
+
+		let content_key = Self::decrypt_content_key(old_master_key, encrypted_key, encrypted_key_alg)?;
+
+		let encrypted_key = content_key.encrypt_key_with_master_key(&raw_public_key)?;
+
+		Ok(FileKeyRewrap {
+			master_key_id: public_key.public_key_id.to_string(),
+			encrypted_key: Base64::encode_string(&encrypted_key),
+			encrypted_key_alg: content_key.get_alg_str().to_string(),
+		})
+	}
+
+	/**
+	Moves the file to another group or user without encrypting the parts again.
+
+	The content key (encrypted_key of the file data) is decrypted with the old master key and encrypted with the new one.
+	 */
+	pub fn prepare_file_move(
+		old_master_key: &impl SymKeyWrapper,
+		new_master_key: &impl SymKeyWrapper,
+		encrypted_key: &str,
+		encrypted_key_alg: &str,
+		belongs_to_id: Option<String>,
+		belongs_to_type: BelongsToType,
+	) -> Result<String, SdkError>
+	{
+		let key = Self::rewrap_content_key(old_master_key, new_master_key, encrypted_key, encrypted_key_alg)?;
+
+		let (belongs_to_type, belongs_to_id) = check_belongs_to(belongs_to_id, belongs_to_type);
+
+		serde_json::to_string(&FileMoveInput {
+			key,
+			belongs_to_id,
+			belongs_to_type,
+		})
+		.map_err(|_e| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Gives another group read access to the file. The file still belongs to the old group.
+	 */
+	pub fn prepare_file_share(
+		old_master_key: &impl SymKeyWrapper,
+		new_master_key: &impl SymKeyWrapper,
+		encrypted_key: &str,
+		encrypted_key_alg: &str,
+		group_id: String,
+	) -> Result<String, SdkError>
+	{
+		let key = Self::rewrap_content_key(old_master_key, new_master_key, encrypted_key, encrypted_key_alg)?;
+
+		serde_json::to_string(&FileShareInput {
+			key,
+			share_with_id: group_id,
+			share_with_type: BelongsToType::Group,
+		})
+		.map_err(|_e| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Moves the file to a user. The content key is encrypted with the public key of the user.
+
+	The public key must be signed by the verify key, see prepare_file_share_by_public_key.
+	 */
+	pub fn prepare_file_move_by_public_key<P: PkFromUserKeyWrapper, SiC: SignComposerWrapper>(
+		old_master_key: &impl SymKeyWrapper,
+		public_key: &UserPublicKeyData,
+		verify_key: &UserVerifyKeyData,
+		encrypted_key: &str,
+		encrypted_key_alg: &str,
+		user_id: String,
+	) -> Result<String, SdkError>
+	{
+		let key = Self::rewrap_content_key_by_public_key::<P, SiC>(
+			old_master_key,
+			public_key,
+			verify_key,
+			encrypted_key,
+			encrypted_key_alg,
+		)?;
+
+		serde_json::to_string(&FileMoveInput {
+			key,
+			belongs_to_id: Some(user_id),
+			belongs_to_type: BelongsToType::User,
+		})
+		.map_err(|_e| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Gives a user read access to the file. The content key is encrypted with the public key of the user.
+
+	The public key must be signed by the verify key of the user, use the pinned verify key of the user.
+	The user can decrypt the content key with the private key, like a key from generate_non_register_sym_key_by_public_key.
+	 */
+	pub fn prepare_file_share_by_public_key<P: PkFromUserKeyWrapper, SiC: SignComposerWrapper>(
+		old_master_key: &impl SymKeyWrapper,
+		public_key: &UserPublicKeyData,
+		verify_key: &UserVerifyKeyData,
+		encrypted_key: &str,
+		encrypted_key_alg: &str,
+		user_id: String,
+	) -> Result<String, SdkError>
+	{
+		let key = Self::rewrap_content_key_by_public_key::<P, SiC>(
+			old_master_key,
+			public_key,
+			verify_key,
+			encrypted_key,
+			encrypted_key_alg,
+		)?;
+
+		serde_json::to_string(&FileShareInput {
+			key,
+			share_with_id: user_id,
+			share_with_type: BelongsToType::User,
+		})
+		.map_err(|_e| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Create a checkpoint after the part with this sequence was uploaded and acknowledged by the server.
+
+	The key for the next part is encrypted by the checkpoint key (e.g. the group or user key).
+	Store the checkpoint on the device to continue the upload with upload_part_resume.
+	 */
+	pub fn prepare_upload_checkpoint(
+		checkpoint_key: &impl SymKeyWrapper,
+		file_id: FileId,
+		session_id: FileSessionId,
+		sequence: i32,
+		next_file_key: &impl SymKey,
+	) -> Result<String, SdkError>
+	{
+		let encrypted_file_key = next_file_key.encrypt_with_sym_key(checkpoint_key.get_key())?;
+
+		let checkpoint = serde_json::to_vec(&FileUploadCheckpoint {
+			file_id,
+			session_id,
+			sequence,
+			key_id: checkpoint_key.get_id().to_string(),
+			encrypted_file_key: Base64::encode_string(&encrypted_file_key),
+			file_key_alg: next_file_key.get_alg_str().to_string(),
+		})
+		.map_err(|_e| SdkError::JsonToStringFailed)?;
+
+		let encrypted_checkpoint = checkpoint_key.get_key().encrypt(&checkpoint)?;
+
+		serde_json::to_string(&EncryptedFileUploadCheckpoint {
+			key_id: checkpoint_key.get_id().to_string(),
+			encrypted_checkpoint: Base64::encode_string(&encrypted_checkpoint),
+		})
+		.map_err(|_e| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Get the checkpoint and the key for the next part (sequence + 1).
+	 */
+	pub fn load_upload_checkpoint(checkpoint_key: &impl SymKeyWrapper, checkpoint: &str)
+		-> Result<(FileUploadCheckpoint, SC::SymmetricKey), SdkError>
+	{
+		let checkpoint: EncryptedFileUploadCheckpoint = serde_json::from_str(checkpoint)?;
+
+		if checkpoint.key_id != checkpoint_key.get_id() {
+			return Err(SdkError::FileCheckpointKeyWrong);
+		}
+
+		let encrypted_checkpoint = Base64::decode_vec(&checkpoint.encrypted_checkpoint).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+		let checkpoint = checkpoint_key.get_key().decrypt(&encrypted_checkpoint)?;
+		let checkpoint: FileUploadCheckpoint = serde_json::from_slice(&checkpoint)?;
+
+		let encrypted_file_key = Base64::decode_vec(&checkpoint.encrypted_file_key).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+		let file_key = SC::decrypt_key_by_sym_key(
+			checkpoint_key.get_key(),
+			&encrypted_file_key,
+			&checkpoint.file_key_alg,
+		)?;
+
+		Ok((checkpoint, file_key))
+	}
+
 	pub fn decrypt_file_part_start(
 		key: &impl SymKeyWrapper,
 		part: &[u8],
@@ -153,7 +559,137 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 
 		let file_key = SC::decrypt_key_by_sym_key(pre_content_key, &encrypted_key, &head.sym_key_alg)?;
 
-		let decrypted_part = match &head.sign {
+		let decrypted_part = Self::decrypt_part_with_key(&file_key, encrypted_part, head.sign.as_ref(), verify_key)?;
+
+		Ok((decrypted_part, file_key))
+	}
+
+	//______________________________________________________________________________________________
+	//derived file format
+
+	/**
+	Encrypts a part in the derived file format.
+
+	The part key is derived from the content key and the sequence of the part and is not stored in the head.
+	Every part can be decrypted without the previous parts, e.g. for seeking in a video or http range requests.
+	 */
+	pub fn encrypt_file_part_derived(
+		content_key: &impl SymKeyWrapper,
+		sequence: i32,
+		part: &[u8],
+		sign_key: Option<&SignK>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let file_key = SC::derive_key_by_sym_key(content_key.get_key(), &part_key_info(sequence))?;
+
+		let mut encrypted_part = file_key.encrypt(part)?;
+
+		let sign = if let Some(sk) = sign_key {
+			let (sign_head, data_with_sign) = sk.sign_with_head(&encrypted_part)?;
+			encrypted_part = data_with_sign;
+			Some(sign_head)
+		} else {
+			None
+		};
+
+		let file_head = FileHeadDerived {
+			version: FILE_FORMAT_DERIVED,
+			sequence,
+			sign,
+			sym_key_alg: file_key.get_alg_str().to_string(),
+		};
+
+		put_head_and_encrypted_data(&file_head, &encrypted_part)
+	}
+
+	/**
+	Decrypts a single part of the derived file format with the content key of the file.
+
+	Parts of the chained format can't be decrypted without the key of the previous part,
+	use decrypt_file_part_start and decrypt_file_part for these files.
+	 */
+	pub fn decrypt_file_part_derived(
+		content_key: &impl SymKeyWrapper,
+		sequence: i32,
+		part: &[u8],
+		verify_key: Option<&UserVerifyKeyData>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let (head, encrypted_part) = split_head_and_encrypted_data::<FileHeadVersion>(part)?;
+
+		match head {
+			FileHeadVersion::Derived(h) => Self::decrypt_derived_part(content_key, sequence, &h, encrypted_part, verify_key),
+			FileHeadVersion::Chained(_) => Err(SdkError::FilePartChained),
+		}
+	}
+
+	/**
+	Decrypts a part of the chained or the derived file format, the format is detected by the head of the part.
+
+	pre_key is the key returned for the previous part of a chained file, none for the first part.
+	Returns the key for the next part of a chained file. Derived files don't need it, every part is decrypted with the content key.
+	 */
+	pub fn decrypt_file_part_auto(
+		content_key: &impl SymKeyWrapper,
+		pre_key: Option<&SC::SymmetricKey>,
+		sequence: i32,
+		part: &[u8],
+		verify_key: Option<&UserVerifyKeyData>,
+	) -> Result<(Vec<u8>, Option<SC::SymmetricKey>), SdkError>
+	{
+		let (head, encrypted_part) = split_head_and_encrypted_data::<FileHeadVersion>(part)?;
+
+		match head {
+			FileHeadVersion::Derived(h) => {
+				let decrypted_part = Self::decrypt_derived_part(content_key, sequence, &h, encrypted_part, verify_key)?;
+
+				Ok((decrypted_part, None))
+			},
+			FileHeadVersion::Chained(h) => {
+				let encrypted_key = Base64::decode_vec(&h.key).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+				let file_key = match pre_key {
+					Some(k) => SC::decrypt_key_by_sym_key(k, &encrypted_key, &h.sym_key_alg)?,
+					None => SC::decrypt_key_by_sym_key(content_key.get_key(), &encrypted_key, &h.sym_key_alg)?,
+				};
+
+				let decrypted_part = Self::decrypt_part_with_key(&file_key, encrypted_part, h.sign.as_ref(), verify_key)?;
+
+				Ok((decrypted_part, Some(file_key)))
+			},
+		}
+	}
+
+	fn decrypt_derived_part(
+		content_key: &impl SymKeyWrapper,
+		sequence: i32,
+		head: &FileHeadDerived,
+		encrypted_part: &[u8],
+		verify_key: Option<&UserVerifyKeyData>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		if head.version != FILE_FORMAT_DERIVED {
+			return Err(SdkError::FileFormatNotSupported);
+		}
+
+		//the part must be requested for this sequence, so the server can't swap the parts
+		if head.sequence != sequence {
+			return Err(SdkError::FilePartSequenceWrong);
+		}
+
+		let file_key = SC::derive_key_by_sym_key(content_key.get_key(), &part_key_info(sequence))?;
+
+		Self::decrypt_part_with_key(&file_key, encrypted_part, head.sign.as_ref(), verify_key)
+	}
+
+	fn decrypt_part_with_key(
+		file_key: &impl SymKey,
+		encrypted_part: &[u8],
+		sign: Option<&SignHead>,
+		verify_key: Option<&UserVerifyKeyData>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let decrypted_part = match sign {
 			None => file_key.decrypt(encrypted_part)?, //no sig used, go ahead
 			Some(h) => {
 				match verify_key {
@@ -170,6 +706,311 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 			},
 		};
 
-		Ok((decrypted_part, file_key))
+		Ok(decrypted_part)
+	}
+}
+
+#[cfg(all(test, feature = "std_keys"))]
+mod test
+{
+	use sentc_crypto_common::crypto::GeneratedSymKeyHeadServerOutput;
+	use sentc_crypto_std_keys::util::{PublicKey, SignKey};
+
+	use super::*;
+	use crate::group::test_fn::create_group;
+	use crate::keys::std::{StdFileEncryptor, StdKeyGenerator};
+	use crate::user::test_fn::create_user;
+
+	#[test]
+	fn test_resume_upload_from_checkpoint()
+	{
+		let user = create_user();
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+		let (_, mut other_group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let key = &group_keys[0].group_key;
+
+		let (part_1, next_key) = StdFileEncryptor::encrypt_file_part_start(key, b"part 1", None).unwrap();
+
+		let checkpoint = StdFileEncryptor::prepare_upload_checkpoint(key, "file_id".into(), "session_id".into(), 0, &next_key).unwrap();
+
+		//app was closed, load the checkpoint
+		let (loaded, next_key) = StdFileEncryptor::load_upload_checkpoint(key, &checkpoint).unwrap();
+
+		assert_eq!(loaded.sequence, 0);
+		assert_eq!(loaded.session_id, "session_id");
+
+		let (part_2, _) = StdFileEncryptor::encrypt_file_part(&next_key, b"part 2", None).unwrap();
+
+		//decrypt the chain
+		let (decrypted_1, key_1) = StdFileEncryptor::decrypt_file_part_start(key, &part_1, None).unwrap();
+		let (decrypted_2, _) = StdFileEncryptor::decrypt_file_part(&key_1, &part_2, None).unwrap();
+
+		assert_eq!(decrypted_1, b"part 1");
+		assert_eq!(decrypted_2, b"part 2");
+
+		//the file and the session are encrypted
+		assert!(!checkpoint.contains("file_id"));
+		assert!(!checkpoint.contains("session_id"));
+
+		//the test groups have the same key id, so the key id check passes but the decryption must fail
+		assert_eq!(other_group_keys[0].group_key.key_id, key.key_id);
+
+		assert!(matches!(
+			StdFileEncryptor::load_upload_checkpoint(&other_group_keys[0].group_key, &checkpoint),
+			Err(SdkError::Util(_))
+		));
+
+		//a key with another id is rejected before the decryption
+		other_group_keys[0].group_key.key_id = "other_key_id".to_string();
+
+		assert!(matches!(
+			StdFileEncryptor::load_upload_checkpoint(&other_group_keys[0].group_key, &checkpoint),
+			Err(SdkError::FileCheckpointKeyWrong)
+		));
+	}
+
+	#[test]
+	fn test_decrypt_derived_parts_in_any_order()
+	{
+		let user = create_user();
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let key = &group_keys[0].group_key;
+
+		let parts = (0..4)
+			.map(|i| StdFileEncryptor::encrypt_file_part_derived(key, i, &[i as u8; 10], None).unwrap())
+			.collect::<Vec<_>>();
+
+		for i in [3, 1, 0, 2] {
+			let decrypted = StdFileEncryptor::decrypt_file_part_derived(key, i, &parts[i as usize], None).unwrap();
+
+			assert_eq!(decrypted, [i as u8; 10]);
+		}
+
+		//wrong sequence
+		assert!(matches!(
+			StdFileEncryptor::decrypt_file_part_derived(key, 1, &parts[2], None),
+			Err(SdkError::FilePartSequenceWrong)
+		));
+
+		//a chained part can't be decrypted without the previous key
+		let (chained, _) = StdFileEncryptor::encrypt_file_part_start(key, b"part 1", None).unwrap();
+
+		assert!(matches!(
+			StdFileEncryptor::decrypt_file_part_derived(key, 0, &chained, None),
+			Err(SdkError::FilePartChained)
+		));
+
+		//but the chained part can still be decrypted
+		let (decrypted, _) = StdFileEncryptor::decrypt_file_part_start(key, &chained, None).unwrap();
+
+		assert_eq!(decrypted, b"part 1");
+	}
+
+	#[test]
+	fn test_decrypt_both_file_formats_with_auto_detection()
+	{
+		let user = create_user();
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let key = &group_keys[0].group_key;
+
+		//derived
+		let derived = StdFileEncryptor::encrypt_file_part_derived(key, 1, b"derived", None).unwrap();
+
+		let (decrypted, next_key) = StdFileEncryptor::decrypt_file_part_auto(key, None, 1, &derived, None).unwrap();
+
+		assert_eq!(decrypted, b"derived");
+		assert!(next_key.is_none());
+
+		//chained
+		let (part_1, file_key) = StdFileEncryptor::encrypt_file_part_start(key, b"part 1", None).unwrap();
+		let (part_2, _) = StdFileEncryptor::encrypt_file_part(&file_key, b"part 2", None).unwrap();
+
+		let (decrypted_1, next_key) = StdFileEncryptor::decrypt_file_part_auto(key, None, 0, &part_1, None).unwrap();
+		let (decrypted_2, _) = StdFileEncryptor::decrypt_file_part_auto(key, next_key.as_ref(), 1, &part_2, None).unwrap();
+
+		assert_eq!(decrypted_1, b"part 1");
+		assert_eq!(decrypted_2, b"part 2");
+	}
+
+	#[test]
+	fn test_encrypt_and_decrypt_file_meta()
+	{
+		let user = create_user();
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let key = &group_keys[0].group_key;
+
+		let mut meta = FileMeta {
+			mime_type: Some("image/png".into()),
+			size: Some(1024),
+			checksum: Some("abc".into()),
+			created_time: 1700000000000,
+			..Default::default()
+		};
+		meta.attributes.insert("album".into(), "holiday".into());
+
+		let (input, encrypted_file_name) = prepare_register_file_with_meta(
+			"master_key_id".into(),
+			key,
+			"encrypted_content_key".into(),
+			None,
+			BelongsToType::None,
+			Some("image.png".into()),
+			Some(&meta),
+		)
+		.unwrap();
+
+		//the same server input as without meta
+		let input: FileRegisterInput = serde_json::from_str(&input).unwrap();
+
+		assert_eq!(input.encrypted_file_name, encrypted_file_name);
+
+		let decrypted = decrypt_file_name_with_meta(key, &encrypted_file_name.unwrap()).unwrap();
+
+		assert_eq!(decrypted.file_name.as_deref(), Some("image.png"));
+		assert_eq!(decrypted.meta, Some(meta));
+
+		//a file name without meta data
+		let (_, encrypted_file_name) = prepare_register_file_with_meta(
+			"master_key_id".into(),
+			key,
+			"encrypted_content_key".into(),
+			None,
+			BelongsToType::None,
+			Some("image.png".into()),
+			None,
+		)
+		.unwrap();
+
+		let decrypted = decrypt_file_name_with_meta(key, &encrypted_file_name.unwrap()).unwrap();
+
+		assert_eq!(decrypted.file_name.as_deref(), Some("image.png"));
+		assert_eq!(decrypted.meta, None);
+	}
+
+	#[test]
+	fn test_move_and_share_file_without_encrypting_the_parts()
+	{
+		let user = create_user();
+		let keys = &user.user_keys[0];
+		let (_, group_keys, _, _, _) = create_group(keys);
+		let (_, other_group_keys, _, _, _) = create_group(keys);
+
+		let key = &group_keys[0].group_key;
+		let other_key = &other_group_keys[0].group_key;
+
+		let (content_key, encrypted_content_key) = StdKeyGenerator::generate_non_register_sym_key(key).unwrap();
+
+		let (part, _) = StdFileEncryptor::encrypt_file_part_start(&content_key, b"part 1", None).unwrap();
+
+		let input = StdFileEncryptor::prepare_file_move(
+			key,
+			other_key,
+			&encrypted_content_key.encrypted_key_string,
+			&encrypted_content_key.alg,
+			Some("other_group".into()),
+			BelongsToType::Group,
+		)
+		.unwrap();
+
+		let input: FileMoveInput = serde_json::from_str(&input).unwrap();
+
+		assert_eq!(input.belongs_to_id.unwrap(), "other_group");
+
+		let moved_key = StdKeyGenerator::decrypt_sym_key(
+			other_key,
+			&GeneratedSymKeyHeadServerOutput {
+				alg: input.key.encrypted_key_alg,
+				encrypted_key_string: input.key.encrypted_key,
+				master_key_id: input.key.master_key_id,
+				key_id: "non_registered".into(),
+				time: 0,
+			},
+		)
+		.unwrap();
+
+		let (decrypted, _) = StdFileEncryptor::decrypt_file_part_start(&moved_key, &part, None).unwrap();
+
+		assert_eq!(decrypted, b"part 1");
+
+		//share with a user
+		let input = StdFileEncryptor::prepare_file_share_by_public_key::<PublicKey, SignKey>(
+			key,
+			&keys.exported_public_key,
+			&keys.exported_verify_key,
+			&encrypted_content_key.encrypted_key_string,
+			&encrypted_content_key.alg,
+			"user_id".into(),
+		)
+		.unwrap();
+
+		let input: FileShareInput = serde_json::from_str(&input).unwrap();
+
+		let shared_key = StdKeyGenerator::decrypt_sym_key_by_private_key(
+			&keys.private_key,
+			&GeneratedSymKeyHeadServerOutput {
+				alg: input.key.encrypted_key_alg,
+				encrypted_key_string: input.key.encrypted_key,
+				master_key_id: input.key.master_key_id,
+				key_id: "non_registered".into(),
+				time: 0,
+			},
+		)
+		.unwrap();
+
+		let (decrypted, _) = StdFileEncryptor::decrypt_file_part_start(&shared_key, &part, None).unwrap();
+
+		assert_eq!(decrypted, b"part 1");
+
+		//move to a user
+		let input = StdFileEncryptor::prepare_file_move_by_public_key::<PublicKey, SignKey>(
+			key,
+			&keys.exported_public_key,
+			&keys.exported_verify_key,
+			&encrypted_content_key.encrypted_key_string,
+			&encrypted_content_key.alg,
+			"user_id".into(),
+		)
+		.unwrap();
+
+		let input: FileMoveInput = serde_json::from_str(&input).unwrap();
+
+		assert_eq!(input.belongs_to_id.unwrap(), "user_id");
+		assert!(matches!(input.belongs_to_type, BelongsToType::User));
+
+		let moved_key = StdKeyGenerator::decrypt_sym_key_by_private_key(
+			&keys.private_key,
+			&GeneratedSymKeyHeadServerOutput {
+				alg: input.key.encrypted_key_alg,
+				encrypted_key_string: input.key.encrypted_key,
+				master_key_id: input.key.master_key_id,
+				key_id: "non_registered".into(),
+				time: 0,
+			},
+		)
+		.unwrap();
+
+		let (decrypted, _) = StdFileEncryptor::decrypt_file_part_start(&moved_key, &part, None).unwrap();
+
+		assert_eq!(decrypted, b"part 1");
+
+		//a public key without the signature of the verify key is rejected
+		let mut unsigned_public_key: UserPublicKeyData = serde_json::from_str(&serde_json::to_string(&keys.exported_public_key).unwrap()).unwrap();
+		unsigned_public_key.public_key_sig = None;
+
+		assert!(matches!(
+			StdFileEncryptor::prepare_file_share_by_public_key::<PublicKey, SignKey>(
+				key,
+				&unsigned_public_key,
+				&keys.exported_verify_key,
+				&encrypted_content_key.encrypted_key_string,
+				&encrypted_content_key.alg,
+				"user_id".into(),
+			),
+			Err(SdkError::IdentityChanged)
+		));
 	}
 }
@@ -2,9 +2,11 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use sentc_crypto_common::file::BelongsToType;
-use sentc_crypto_std_keys::util::SymmetricKey;
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_std_keys::util::{PublicKey, SignKey, SymmetricKey};
 
 use crate::crypto::{prepare_sign_key, prepare_verify_key};
+use crate::entities::file::{FileMeta, FileUploadCheckpoint};
 use crate::keys::std::StdFileEncryptor;
 use crate::util::{export_core_sym_key_to_string, import_core_sym_key};
 use crate::SdkError;
@@ -34,6 +36,36 @@ pub fn prepare_register_file(
 	Ok((server_input, encrypted_file_name))
 }
 
+/**
+The meta data is a json string of the FileMeta.
+ */
+pub fn prepare_register_file_with_meta(
+	master_key_id: String,
+	key: &str,
+	encrypted_content_key: String,
+	belongs_to_id: Option<String>,
+	belongs_to_type: &str,
+	file_name: Option<String>,
+	meta: Option<String>,
+) -> Result<(String, Option<String>), String>
+{
+	let belongs_to_type: BelongsToType = serde_json::from_str(belongs_to_type).map_err(SdkError::JsonParseFailed)?;
+
+	let key: SymmetricKey = key.parse()?;
+
+	let meta = prepare_file_meta(meta)?;
+
+	Ok(super::file::prepare_register_file_with_meta(
+		master_key_id,
+		&key,
+		encrypted_content_key,
+		belongs_to_id,
+		belongs_to_type,
+		file_name,
+		meta.as_ref(),
+	)?)
+}
+
 pub fn done_register_file(server_output: &str) -> Result<(String, String), String>
 {
 	Ok(super::file::done_register_file(server_output)?)
@@ -45,6 +77,144 @@ pub fn prepare_file_name_update(key: &str, file_name: Option<String>) -> Result<
 	Ok(super::file::prepare_file_name_update(&key, file_name)?)
 }
 
+pub fn prepare_file_move(
+	old_master_key: &str,
+	new_master_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	belongs_to_id: Option<String>,
+	belongs_to_type: &str,
+) -> Result<String, String>
+{
+	let belongs_to_type: BelongsToType = serde_json::from_str(belongs_to_type).map_err(SdkError::JsonParseFailed)?;
+
+	let old_master_key: SymmetricKey = old_master_key.parse()?;
+	let new_master_key: SymmetricKey = new_master_key.parse()?;
+
+	Ok(StdFileEncryptor::prepare_file_move(
+		&old_master_key,
+		&new_master_key,
+		encrypted_key,
+		encrypted_key_alg,
+		belongs_to_id,
+		belongs_to_type,
+	)?)
+}
+
+pub fn prepare_file_share(
+	old_master_key: &str,
+	new_master_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	group_id: String,
+) -> Result<String, String>
+{
+	let old_master_key: SymmetricKey = old_master_key.parse()?;
+	let new_master_key: SymmetricKey = new_master_key.parse()?;
+
+	Ok(StdFileEncryptor::prepare_file_share(
+		&old_master_key,
+		&new_master_key,
+		encrypted_key,
+		encrypted_key_alg,
+		group_id,
+	)?)
+}
+
+/**
+Moves the file to a user. The public key must be signed by the verify key of the user.
+ */
+pub fn prepare_file_move_by_public_key(
+	old_master_key: &str,
+	public_key: &str,
+	verify_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	user_id: String,
+) -> Result<String, String>
+{
+	let old_master_key: SymmetricKey = old_master_key.parse()?;
+	let public_key = UserPublicKeyData::from_string(public_key).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(
+		StdFileEncryptor::prepare_file_move_by_public_key::<PublicKey, SignKey>(
+			&old_master_key,
+			&public_key,
+			&verify_key,
+			encrypted_key,
+			encrypted_key_alg,
+			user_id,
+		)?,
+	)
+}
+
+/**
+Gives a user read access to the file. The public key must be signed by the verify key of the user.
+ */
+pub fn prepare_file_share_by_public_key(
+	old_master_key: &str,
+	public_key: &str,
+	verify_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	user_id: String,
+) -> Result<String, String>
+{
+	let old_master_key: SymmetricKey = old_master_key.parse()?;
+	let public_key = UserPublicKeyData::from_string(public_key).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdFileEncryptor::prepare_file_share_by_public_key::<
+		PublicKey,
+		SignKey,
+	>(
+		&old_master_key,
+		&public_key,
+		&verify_key,
+		encrypted_key,
+		encrypted_key_alg,
+		user_id,
+	)?)
+}
+
+fn prepare_file_meta(meta: Option<String>) -> Result<Option<FileMeta>, SdkError>
+{
+	match meta {
+		None => Ok(None),
+		Some(m) => Ok(Some(serde_json::from_str(&m)?)),
+	}
+}
+
+pub fn encrypt_file_name_with_meta(key: &str, file_name: Option<String>, meta: &str) -> Result<String, String>
+{
+	let key: SymmetricKey = key.parse()?;
+	let meta: FileMeta = serde_json::from_str(meta).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(super::file::encrypt_file_name_with_meta(&key, file_name, &meta)?)
+}
+
+/**
+Returns the file name and the meta data as json string
+ */
+pub fn decrypt_file_name_with_meta(key: &str, encrypted_file_name: &str) -> Result<String, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let out = super::file::decrypt_file_name_with_meta(&key, encrypted_file_name)?;
+
+	Ok(serde_json::to_string(&out).map_err(|_e| SdkError::JsonToStringFailed)?)
+}
+
+pub fn prepare_file_meta_update(key: &str, file_name: Option<String>, meta: Option<String>) -> Result<String, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let meta = prepare_file_meta(meta)?;
+
+	Ok(super::file::prepare_file_meta_update(&key, file_name, meta.as_ref())?)
+}
+
 pub fn encrypt_file_part_start(key: &str, part: &[u8], sign_key: Option<&str>) -> Result<(Vec<u8>, String), String>
 {
 	let sign_key = prepare_sign_key(sign_key)?;
@@ -92,3 +262,66 @@ pub fn decrypt_file_part(pre_content_key: &str, part: &[u8], verify_key: Option<
 
 	Ok((decrypted, exported_file_key))
 }
+
+pub fn encrypt_file_part_derived(key: &str, sequence: i32, part: &[u8], sign_key: Option<&str>) -> Result<Vec<u8>, String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+	let key: SymmetricKey = key.parse()?;
+
+	Ok(StdFileEncryptor::encrypt_file_part_derived(
+		&key,
+		sequence,
+		part,
+		sign_key.as_ref(),
+	)?)
+}
+
+pub fn decrypt_file_part_derived(key: &str, sequence: i32, part: &[u8], verify_key: Option<&str>) -> Result<Vec<u8>, String>
+{
+	let verify_key = prepare_verify_key(verify_key)?;
+	let key: SymmetricKey = key.parse()?;
+
+	Ok(StdFileEncryptor::decrypt_file_part_derived(
+		&key,
+		sequence,
+		part,
+		verify_key.as_ref(),
+	)?)
+}
+
+/**
+Create a checkpoint after the part with this sequence was uploaded.
+
+next_file_key is the exported file key of the uploaded part.
+ */
+pub fn prepare_upload_checkpoint(
+	checkpoint_key: &str,
+	file_id: String,
+	session_id: String,
+	sequence: i32,
+	next_file_key: &str,
+) -> Result<String, String>
+{
+	let key: SymmetricKey = checkpoint_key.parse()?;
+	let next_file_key = import_core_sym_key(next_file_key)?;
+
+	Ok(StdFileEncryptor::prepare_upload_checkpoint(
+		&key,
+		file_id,
+		session_id,
+		sequence,
+		&next_file_key,
+	)?)
+}
+
+/**
+Get the checkpoint and the exported file key for the next part.
+ */
+pub fn load_upload_checkpoint(checkpoint_key: &str, checkpoint: &str) -> Result<(FileUploadCheckpoint, String), String>
+{
+	let key: SymmetricKey = checkpoint_key.parse()?;
+
+	let (checkpoint, file_key) = StdFileEncryptor::load_upload_checkpoint(&key, checkpoint)?;
+
+	Ok((checkpoint, export_core_sym_key_to_string(file_key)?))
+}
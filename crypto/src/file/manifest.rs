@@ -0,0 +1,276 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_common::FileId;
+use sentc_crypto_utils::cryptomat::{SignKWrapper, VerifyKFromUserKeyWrapper};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::crypto::crypto::{put_head_and_encrypted_data, split_head_and_encrypted_data};
+use crate::entities::file::{FileManifest, FileManifestHead, FILE_MANIFEST_VERSION};
+use crate::SdkError;
+
+fn part_digest(encrypted_part: &[u8]) -> String
+{
+	Base64::encode_string(&Sha256::digest(encrypted_part))
+}
+
+fn file_hash(part_digests: &[String]) -> String
+{
+	let mut hasher = Sha256::new();
+
+	//every digest has the same length, so the concatenation is not ambiguous
+	for digest in part_digests {
+		hasher.update(digest.as_bytes());
+	}
+
+	Base64::encode_string(&hasher.finalize())
+}
+
+/**
+Creates the signed manifest of a file while the parts are uploaded.
+
+Add every encrypted part like it is uploaded in the order of the sequence.
+The signed manifest is uploaded after the last part and is stored next to the file, not as a part.
+
+The builder can be serialized to keep the state between the uploads of the parts.
+ */
+#[derive(Serialize, Deserialize, Default)]
+pub struct FileManifestBuilder
+{
+	part_digests: Vec<String>,
+}
+
+impl FileManifestBuilder
+{
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	pub fn add_part(&mut self, encrypted_part: &[u8])
+	{
+		self.part_digests.push(part_digest(encrypted_part));
+	}
+
+	pub fn part_count(&self) -> usize
+	{
+		self.part_digests.len()
+	}
+
+	/**
+	Checks the manifest against the parts which were added to this builder.
+
+	This is for the case when the manifest comes after the parts, e.g. in the offline file container.
+	Returns the id of the verify key which signed the manifest.
+	 */
+	pub fn verify<VC: VerifyKFromUserKeyWrapper>(
+		self,
+		manifest_part: &[u8],
+		file_id: &str,
+		verify_key: &UserVerifyKeyData,
+	) -> Result<String, SdkError>
+	{
+		let mut verifier = FileManifestVerifier::new::<VC>(manifest_part, file_id, verify_key)?;
+
+		if verifier.part_count() != self.part_digests.len() {
+			return Err(SdkError::FileManifestPartCount);
+		}
+
+		for digest in self.part_digests {
+			verifier.verify_digest(digest)?;
+		}
+
+		verifier.finish()
+	}
+
+	/**
+	Creates the manifest part, signed by the sign key of the uploader.
+	 */
+	pub fn finish(self, file_id: FileId, sign_key: &impl SignKWrapper) -> Result<Vec<u8>, SdkError>
+	{
+		let manifest = FileManifest {
+			version: FILE_MANIFEST_VERSION,
+			file_id,
+			part_count: self.part_digests.len() as u32,
+			file_hash: file_hash(&self.part_digests),
+			part_digests: self.part_digests,
+		};
+
+		let data = serde_json::to_vec(&manifest).map_err(|_| SdkError::JsonToStringFailed)?;
+
+		let (sign, data_with_sig) = sign_key.sign_with_head(&data)?;
+
+		put_head_and_encrypted_data(
+			&FileManifestHead {
+				manifest_version: FILE_MANIFEST_VERSION,
+				sign,
+			},
+			&data_with_sig,
+		)
+	}
+}
+
+/**
+Checks the downloaded parts against the signed manifest.
+
+Verify every encrypted part before it is decrypted in the order of the sequence and call finish after the last part.
+ */
+pub struct FileManifestVerifier
+{
+	manifest: FileManifest,
+	verify_key_id: String,
+	part_digests: Vec<String>,
+}
+
+impl FileManifestVerifier
+{
+	/**
+	Verifies the signature of the manifest part with the verify key of the uploader.
+	 */
+	pub fn new<VC: VerifyKFromUserKeyWrapper>(manifest_part: &[u8], file_id: &str, verify_key: &UserVerifyKeyData) -> Result<Self, SdkError>
+	{
+		let (head, data_with_sig) = split_head_and_encrypted_data::<FileManifestHead>(manifest_part)?;
+
+		if head.manifest_version != FILE_MANIFEST_VERSION {
+			return Err(SdkError::FileFormatNotSupported);
+		}
+
+		let data = VC::verify_with_user_key(verify_key, data_with_sig, &head.sign)?;
+
+		let manifest: FileManifest = serde_json::from_slice(data)?;
+
+		if manifest.file_id != file_id || manifest.part_digests.len() != manifest.part_count as usize {
+			return Err(SdkError::FileManifestWrong);
+		}
+
+		Ok(Self {
+			part_digests: Vec::with_capacity(manifest.part_digests.len()),
+			manifest,
+			verify_key_id: head.sign.id,
+		})
+	}
+
+	/**
+	The id of the verify key which signed the manifest
+	 */
+	pub fn verify_key_id(&self) -> &str
+	{
+		&self.verify_key_id
+	}
+
+	pub fn part_count(&self) -> usize
+	{
+		self.manifest.part_digests.len()
+	}
+
+	/**
+	Checks the next part. The parts must be verified in the order of the sequence.
+	 */
+	pub fn verify_part(&mut self, encrypted_part: &[u8]) -> Result<(), SdkError>
+	{
+		self.verify_digest(part_digest(encrypted_part))
+	}
+
+	fn verify_digest(&mut self, digest: String) -> Result<(), SdkError>
+	{
+		let expected = self
+			.manifest
+			.part_digests
+			.get(self.part_digests.len())
+			.ok_or(SdkError::FileManifestPartCount)?;
+
+		if &digest != expected {
+			return Err(SdkError::FileManifestPartWrong);
+		}
+
+		self.part_digests.push(digest);
+
+		Ok(())
+	}
+
+	/**
+	Checks if all parts were verified, e.g. at the end of a stream.
+	 */
+	pub fn check_complete(&self) -> Result<(), SdkError>
+	{
+		if self.part_digests.len() != self.manifest.part_digests.len() {
+			return Err(SdkError::FileManifestPartCount);
+		}
+
+		if file_hash(&self.part_digests) != self.manifest.file_hash {
+			return Err(SdkError::FileManifestWrong);
+		}
+
+		Ok(())
+	}
+
+	/**
+	Checks if all parts were verified. Returns the id of the verify key which signed the manifest.
+	 */
+	pub fn finish(self) -> Result<String, SdkError>
+	{
+		self.check_complete()?;
+
+		Ok(self.verify_key_id)
+	}
+}
+
+#[cfg(all(test, feature = "std_keys"))]
+mod test
+{
+	use sentc_crypto_std_keys::util::VerifyKey;
+
+	use super::*;
+	use crate::user::test_fn::create_user;
+
+	#[test]
+	fn test_verify_manifest()
+	{
+		let user = create_user();
+		let keys = &user.user_keys[0];
+
+		let parts: [&[u8]; 3] = [b"part 1", b"part 2", b"part 3"];
+
+		let mut builder = FileManifestBuilder::new();
+
+		for part in parts {
+			builder.add_part(part);
+		}
+
+		let manifest = builder.finish("file_id".into(), &keys.sign_key).unwrap();
+
+		//all parts in the right order
+		let mut verifier = FileManifestVerifier::new::<VerifyKey>(&manifest, "file_id", &keys.exported_verify_key).unwrap();
+
+		for part in parts {
+			verifier.verify_part(part).unwrap();
+		}
+
+		assert_eq!(verifier.finish().unwrap(), keys.exported_verify_key.verify_key_id);
+
+		//wrong file
+		assert!(matches!(
+			FileManifestVerifier::new::<VerifyKey>(&manifest, "other_file", &keys.exported_verify_key),
+			Err(SdkError::FileManifestWrong)
+		));
+
+		//swapped parts
+		let mut verifier = FileManifestVerifier::new::<VerifyKey>(&manifest, "file_id", &keys.exported_verify_key).unwrap();
+
+		assert!(matches!(
+			verifier.verify_part(parts[1]),
+			Err(SdkError::FileManifestPartWrong)
+		));
+
+		//truncated file
+		let mut verifier = FileManifestVerifier::new::<VerifyKey>(&manifest, "file_id", &keys.exported_verify_key).unwrap();
+
+		verifier.verify_part(parts[0]).unwrap();
+		verifier.verify_part(parts[1]).unwrap();
+
+		assert!(matches!(verifier.finish(), Err(SdkError::FileManifestPartCount)));
+	}
+}
@@ -1,9 +1,25 @@
+mod archive;
+mod container;
 pub(crate) mod file;
 #[cfg(feature = "export")]
 mod file_export;
+mod manifest;
 
+pub use self::archive::{FileArchiveBuilder, FileArchiveFileWriter, FileArchivePart, FileArchiveReader};
+pub use self::container::{
+	read_frame_head,
+	FileContainerFrame,
+	FileContainerReader,
+	FileContainerWriter,
+	FILE_CONTAINER_FRAME_HEAD_LEN,
+	FILE_CONTAINER_MAGIC,
+	FILE_CONTAINER_MAX_PART_SIZE,
+	FILE_CONTAINER_PREFIX_LEN,
+	FILE_CONTAINER_VERSION,
+};
 pub use self::file::FileEncryptor;
 #[cfg(not(feature = "export"))]
 pub use self::file::*;
 #[cfg(feature = "export")]
 pub use self::file_export::*;
+pub use self::manifest::{FileManifestBuilder, FileManifestVerifier};
@@ -230,6 +230,19 @@ where
 		sign_key: Option<&SignC::SignKWrapper>,
 		starter: UserId,
 	) -> Result<String, SdkError>
+	{
+		Self::key_rotation_data(previous_group_key, invoker_public_key, user_group, sign_key, starter)?
+			.to_string()
+			.map_err(|_| SdkError::JsonToStringFailed)
+	}
+
+	pub(crate) fn key_rotation_data(
+		previous_group_key: &impl SymKeyWrapper,
+		invoker_public_key: &impl PkWrapper,
+		user_group: bool,
+		sign_key: Option<&SignC::SignKWrapper>,
+		starter: UserId,
+	) -> Result<KeyRotationData, SdkError>
 	{
 		let out = core_group::key_rotation::<SGen::KeyGen, StGen::KeyGen, SignGen::KeyGen>(
 			previous_group_key.get_key(),
@@ -307,9 +320,7 @@ where
 			public_key_sig,
 		};
 
-		rotation_out
-			.to_string()
-			.map_err(|_| SdkError::JsonToStringFailed)
+		Ok(rotation_out)
 	}
 
 	pub fn done_key_rotation(
@@ -664,13 +675,7 @@ pub fn get_group_light_data(server_output: &str) -> Result<GroupOutDataLight, Sd
 
 pub fn prepare_change_rank(user_id: &str, new_rank: i32, admin_rank: i32) -> Result<String, SdkError>
 {
-	if new_rank < 1 || new_rank > 4 {
-		return Err(SdkError::GroupRank);
-	}
-
-	if admin_rank > 1 {
-		return Err(SdkError::GroupPermission);
-	}
+	super::group_rank_check::check_change_rank(new_rank, admin_rank)?;
 
 	GroupChangeRankServerInput {
 		changed_user_id: user_id.to_string(),
@@ -1,78 +1,273 @@
+//! Permissions of the group members.
+//!
+//! The server stores the rank of a member as integer and checks the actions with the same fixed ranks.
+//! Every rank from 0 (creator) to 4 maps to a default role with a name and a set of permissions.
+//! A lower rank is a higher role, a member can only kick members with the same or a lower role.
+//!
+//! Apps with other roles (e.g. a role that can invite but not kick) can define their own [`Role`]
+//! and check the actions with [`Role::check`].
+
 use crate::SdkError;
 
 pub type VoidRes = Result<(), SdkError>;
 
-#[allow(clippy::needless_return_with_question_mark)]
-pub fn check_kick_user(user_rank: i32, admin_rank: i32) -> VoidRes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission
+{
+	Invite,
+	Kick,
+	ChangeRank,
+	RotateKeys,
+	/**
+	Create child and connected groups and handle the join requests of this group to other groups
+	 */
+	CreateChildGroup,
+	Delete,
+	/**
+	Accept or reject the join requests to the group
+	 */
+	ManageJoinRequests,
+}
+
+const ALL_PERMISSIONS: [Permission; 7] = [
+	Permission::Invite,
+	Permission::Kick,
+	Permission::ChangeRank,
+	Permission::RotateKeys,
+	Permission::CreateChildGroup,
+	Permission::Delete,
+	Permission::ManageJoinRequests,
+];
+
+/**
+A named set of permissions
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Role<'a>
+{
+	pub name: &'a str,
+	pub permissions: &'a [Permission],
+}
+
+impl Role<'_>
 {
-	if admin_rank > 2 {
-		return Err(SdkError::GroupPermission)?;
+	pub fn has_permission(&self, permission: Permission) -> bool
+	{
+		self.permissions.contains(&permission)
 	}
 
-	if admin_rank > user_rank {
-		//user has a higher rank
-		return Err(SdkError::GroupUserKickRank)?;
+	pub fn check(&self, permission: Permission) -> VoidRes
+	{
+		if self.has_permission(permission) {
+			Ok(())
+		} else {
+			Err(SdkError::GroupPermission)
+		}
 	}
+}
 
-	Ok(())
+pub const CREATOR_ROLE: Role<'static> = Role {
+	name: "creator",
+	permissions: &ALL_PERMISSIONS,
+};
+
+pub const ADMIN_ROLE: Role<'static> = Role {
+	name: "admin",
+	permissions: &ALL_PERMISSIONS,
+};
+
+pub const MODERATOR_ROLE: Role<'static> = Role {
+	name: "moderator",
+	permissions: &[Permission::Invite, Permission::Kick, Permission::ManageJoinRequests],
+};
+
+pub const MEMBER_ROLE: Role<'static> = Role {
+	name: "member",
+	permissions: &[],
+};
+
+pub const GUEST_ROLE: Role<'static> = Role {
+	name: "guest",
+	permissions: &[],
+};
+
+/**
+The default roles of the ranks, the index is the rank from 0 (creator) to 4
+ */
+const DEFAULT_ROLES: [Role<'static>; 5] = [CREATOR_ROLE, ADMIN_ROLE, MODERATOR_ROLE, MEMBER_ROLE, GUEST_ROLE];
+
+/**
+The default role of the rank. Ranks outside 0 to 4 got no role.
+ */
+pub fn default_role(rank: i32) -> Option<Role<'static>>
+{
+	usize::try_from(rank)
+		.ok()
+		.and_then(|r| DEFAULT_ROLES.get(r))
+		.copied()
 }
 
-#[allow(clippy::needless_return_with_question_mark)]
-pub fn check_group_delete(admin_rank: i32) -> VoidRes
+/**
+The name of the default role of the rank
+ */
+pub fn role_name(rank: i32) -> Option<&'static str>
+{
+	default_role(rank).map(|role| role.name)
+}
+
+/**
+Checks the permission with the default role of the rank. A rank without a role got no permissions.
+ */
+pub fn check_permission(rank: i32, permission: Permission) -> VoidRes
 {
-	if admin_rank > 1 {
-		return Err(SdkError::GroupPermission)?;
+	match default_role(rank) {
+		Some(role) => role.check(permission),
+		None => Err(SdkError::GroupPermission),
 	}
+}
 
-	Ok(())
+/**
+Only the creator and the admins can delete the group
+ */
+pub fn check_group_delete(admin_rank: i32) -> VoidRes
+{
+	check_permission(admin_rank, Permission::Delete)
 }
 
-#[allow(clippy::needless_return_with_question_mark)]
+/**
+Check before the member list is changed by the admin, e.g. to remove a member
+ */
 pub fn check_delete_user_rank(admin_rank: i32) -> VoidRes
 {
-	if admin_rank > 2 {
-		return Err(SdkError::GroupPermission)?;
-	}
-
-	Ok(())
+	check_permission(admin_rank, Permission::Kick)
 }
 
-#[allow(clippy::needless_return_with_question_mark)]
+/**
+Check before the join requests of the group are fetched, accepted or rejected
+ */
 pub fn check_get_join_reqs(admin_rank: i32) -> VoidRes
 {
-	if admin_rank > 2 {
-		return Err(SdkError::GroupPermission)?;
-	}
-
-	Ok(())
+	check_permission(admin_rank, Permission::ManageJoinRequests)
 }
 
-#[allow(clippy::needless_return_with_question_mark)]
+/**
+Check before a user or a group is invited
+ */
 pub fn check_make_invite_req(admin_rank: i32) -> VoidRes
 {
-	if admin_rank > 2 {
-		return Err(SdkError::GroupPermission)?;
+	check_permission(admin_rank, Permission::Invite)
+}
+
+/**
+Check before a child or a connected group is created
+ */
+pub fn check_create_sub_group(admin_rank: i32) -> VoidRes
+{
+	check_permission(admin_rank, Permission::CreateChildGroup)
+}
+
+/**
+Check before the join requests of this group to other groups are fetched or deleted
+ */
+pub fn check_sent_join_req_list(admin_rank: i32) -> VoidRes
+{
+	check_permission(admin_rank, Permission::CreateChildGroup)
+}
+
+/**
+Check before a new group key is created
+ */
+pub fn check_key_rotation(admin_rank: i32) -> VoidRes
+{
+	check_permission(admin_rank, Permission::RotateKeys)
+}
+
+pub fn check_kick_user(user_rank: i32, admin_rank: i32) -> VoidRes
+{
+	check_permission(admin_rank, Permission::Kick)?;
+
+	if admin_rank > user_rank {
+		//user has a higher rank
+		return Err(SdkError::GroupUserKickRank);
 	}
 
 	Ok(())
 }
 
-#[allow(clippy::needless_return_with_question_mark)]
-pub fn check_create_sub_group(admin_rank: i32) -> VoidRes
+/**
+The new rank must belong to a role. The creator rank 0 can't be given to other members.
+ */
+pub fn check_change_rank(new_rank: i32, admin_rank: i32) -> VoidRes
 {
-	if admin_rank > 1 {
-		return Err(SdkError::GroupPermission)?;
+	if new_rank < 1 || default_role(new_rank).is_none() {
+		return Err(SdkError::GroupRank);
 	}
 
-	Ok(())
+	check_permission(admin_rank, Permission::ChangeRank)
 }
 
-#[allow(clippy::needless_return_with_question_mark)]
-pub fn check_sent_join_req_list(admin_rank: i32) -> VoidRes
+#[cfg(test)]
+mod test
 {
-	if admin_rank > 1 {
-		return Err(SdkError::GroupPermission)?;
+	use super::*;
+
+	#[test]
+	fn test_default_roles_keep_the_rank_checks()
+	{
+		for rank in 0..=4 {
+			assert_eq!(check_group_delete(rank).is_ok(), rank <= 1);
+			assert_eq!(check_create_sub_group(rank).is_ok(), rank <= 1);
+			assert_eq!(check_sent_join_req_list(rank).is_ok(), rank <= 1);
+			assert_eq!(check_key_rotation(rank).is_ok(), rank <= 1);
+			assert_eq!(check_delete_user_rank(rank).is_ok(), rank <= 2);
+			assert_eq!(check_get_join_reqs(rank).is_ok(), rank <= 2);
+			assert_eq!(check_make_invite_req(rank).is_ok(), rank <= 2);
+		}
+
+		assert!(check_kick_user(3, 2).is_ok());
+		assert!(matches!(check_kick_user(1, 2), Err(SdkError::GroupUserKickRank)));
+		assert!(matches!(check_change_rank(5, 0), Err(SdkError::GroupRank)));
+		assert!(matches!(check_change_rank(0, 0), Err(SdkError::GroupRank)));
+		assert!(check_change_rank(4, 1).is_ok());
+		assert!(matches!(check_change_rank(3, 2), Err(SdkError::GroupPermission)));
+
+		//no role for this rank
+		assert!(matches!(
+			check_permission(5, Permission::Invite),
+			Err(SdkError::GroupPermission)
+		));
+		assert!(matches!(
+			check_permission(-1, Permission::Invite),
+			Err(SdkError::GroupPermission)
+		));
+
+		assert_eq!(role_name(2), Some("moderator"));
+		assert_eq!(role_name(5), None);
 	}
 
-	Ok(())
+	#[test]
+	fn test_custom_roles()
+	{
+		let recruiter = Role {
+			name: "recruiter",
+			permissions: &[Permission::Invite],
+		};
+
+		let key_manager = Role {
+			name: "key manager",
+			permissions: &[Permission::RotateKeys],
+		};
+
+		assert!(recruiter.check(Permission::Invite).is_ok());
+		assert!(matches!(
+			recruiter.check(Permission::Kick),
+			Err(SdkError::GroupPermission)
+		));
+
+		assert!(key_manager.check(Permission::RotateKeys).is_ok());
+		assert!(matches!(
+			key_manager.check(Permission::Delete),
+			Err(SdkError::GroupPermission)
+		));
+	}
 }
@@ -5,8 +5,8 @@ use sentc_crypto_fips_keys::util::{HmacKey, PublicKey, SecretKey, SignKey, Sorta
 
 use crate::crypto::KeyGenerator;
 use crate::entities::group::GroupKeyData;
-use crate::entities::user::{UserDataInt, UserKeyDataInt};
-use crate::file::FileEncryptor;
+use crate::entities::user::{AccountArchive, AccountArchiveGroup, UserDataInt, UserKeyDataInt};
+use crate::file::{FileArchiveBuilder, FileArchiveReader, FileContainerReader, FileContainerWriter, FileEncryptor};
 use crate::group::Group;
 use crate::user::User;
 
@@ -25,6 +25,28 @@ pub type FipsGroup = Group<
 	VerifyKey,
 >;
 
+/**
+Same as the fips group but new groups get an order-revealing sortable key.
+
+The ore scheme itself is not fips approved, so it needs the fips_ore_sort feature.
+Groups without a sortable key keep working because the sortable key is decrypted by its alg.
+ */
+#[cfg(feature = "fips_ore_sort")]
+pub type FipsGroupOre = Group<
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	sentc_crypto_fips_keys::core::hmac::HmacKey,
+	sentc_crypto_fips_keys::core::sortable::ore::OreSortableKey,
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	HmacKey,
+	SortableKey,
+	PublicKey,
+	VerifyKey,
+>;
+
 pub type FipsGroupKeyData = GroupKeyData<SymmetricKey, SecretKey, PublicKey>;
 
 pub type FipsUser = User<
@@ -47,9 +69,17 @@ pub type FipsUserDataInt = UserDataInt<SymmetricKey, SecretKey, PublicKey, SignK
 
 pub type FipsUserKeyDataInt = UserKeyDataInt<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey>;
 
+pub type FipsAccountArchive = AccountArchive<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey, HmacKey, SortableKey>;
+
+pub type FipsAccountArchiveGroup = AccountArchiveGroup<SymmetricKey, SecretKey, PublicKey, HmacKey, SortableKey>;
+
 pub type FipsKeyGenerator = KeyGenerator<SymmetricKey, SymmetricKey, PublicKey>;
 
 pub type FipsFileEncryptor = FileEncryptor<Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type FipsFileContainerWriter<'a> = FileContainerWriter<'a, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type FipsFileContainerReader<'a> = FileContainerReader<'a, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type FipsFileArchiveBuilder<'a> = FileArchiveBuilder<'a, SymmetricKey, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type FipsFileArchiveReader<'a> = FileArchiveReader<'a, SymmetricKey, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
 
 #[cfg(any(feature = "full_rustls", feature = "full_wasm"))]
 pub type FipsPreLoginOut = crate::util_req_full::user::PreLoginOut<
@@ -60,3 +90,20 @@ pub type FipsPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	VerifyKey,
 	sentc_crypto_fips_keys::core::pw_hash::DeriveAuthKeyForAuth,
 >;
+
+#[cfg(all(any(feature = "full_rustls", feature = "full_wasm"), not(feature = "export")))]
+pub type FipsSession = crate::util_req_full::session::Session<
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	sentc_crypto_fips_keys::core::hmac::HmacKey,
+	NonSortableKeys,
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	HmacKey,
+	SortableKey,
+	PublicKey,
+	VerifyKey,
+	PwHasherGetter,
+>;
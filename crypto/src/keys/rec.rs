@@ -4,8 +4,8 @@ use sentc_crypto_rec_keys::util::{HmacKey, PublicKey, SecretKey, SignKey, Sortab
 
 use crate::crypto::KeyGenerator;
 use crate::entities::group::GroupKeyData;
-use crate::entities::user::{UserDataInt, UserKeyDataInt};
-use crate::file::FileEncryptor;
+use crate::entities::user::{AccountArchive, AccountArchiveGroup, UserDataInt, UserKeyDataInt};
+use crate::file::{FileArchiveBuilder, FileArchiveReader, FileContainerReader, FileContainerWriter, FileEncryptor};
 use crate::group::Group;
 use crate::user::User;
 
@@ -24,6 +24,26 @@ pub type RecGroup = Group<
 	VerifyKey,
 >;
 
+/**
+Same as the rec group but new groups get an order-revealing sortable key instead of the OPE-16 key.
+
+The ore key is the one of the fips keys. Groups with the ope key keep working because the sortable key is decrypted by its alg.
+ */
+pub type RecGroupOre = Group<
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	sentc_crypto_rec_keys::core::hmac::HmacKey,
+	sentc_crypto_rec_keys::core::sortable::OreSortableKey,
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	HmacKey,
+	SortableKey,
+	PublicKey,
+	VerifyKey,
+>;
+
 pub type RecGroupKeyData = GroupKeyData<SymmetricKey, SecretKey, PublicKey>;
 
 pub type RecUser = User<
@@ -46,9 +66,17 @@ pub type RecUserDataInt = UserDataInt<SymmetricKey, SecretKey, PublicKey, SignKe
 
 pub type RecUserKeyDataInt = UserKeyDataInt<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey>;
 
+pub type RecAccountArchive = AccountArchive<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey, HmacKey, SortableKey>;
+
+pub type RecAccountArchiveGroup = AccountArchiveGroup<SymmetricKey, SecretKey, PublicKey, HmacKey, SortableKey>;
+
 pub type RecKeyGenerator = KeyGenerator<SymmetricKey, SymmetricKey, PublicKey>;
 
 pub type RecFileEncryptor = FileEncryptor<Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type RecFileContainerWriter<'a> = FileContainerWriter<'a, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type RecFileContainerReader<'a> = FileContainerReader<'a, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type RecFileArchiveBuilder<'a> = FileArchiveBuilder<'a, SymmetricKey, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
+pub type RecFileArchiveReader<'a> = FileArchiveReader<'a, SymmetricKey, Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
 
 #[cfg(any(feature = "full_rustls", feature = "full_wasm"))]
 pub type FipsPreLoginOut = crate::util_req_full::user::PreLoginOut<
@@ -59,3 +87,20 @@ pub type FipsPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	VerifyKey,
 	sentc_crypto_rec_keys::core::pw_hash::DeriveAuthKeyForAuth,
 >;
+
+#[cfg(all(any(feature = "full_rustls", feature = "full_wasm"), not(feature = "export")))]
+pub type RecSession = crate::util_req_full::session::Session<
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	sentc_crypto_rec_keys::core::hmac::HmacKey,
+	sentc_crypto_rec_keys::core::sortable::OpeSortableKey,
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	HmacKey,
+	SortableKey,
+	PublicKey,
+	VerifyKey,
+	PwHasher,
+>;
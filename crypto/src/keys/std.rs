@@ -18,6 +18,26 @@ pub type StdGroup = group::Group<
 	VerifyKey,
 >;
 
+/**
+Same as the std group but new groups get an order-revealing sortable key instead of the OPE-16 key.
+
+Existing groups can be used with both types because the sortable key is decrypted by its alg.
+ */
+pub type StdGroupOre = group::Group<
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	sentc_crypto_std_keys::core::HmacKey,
+	sentc_crypto_std_keys::core::OreSortableKey,
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	HmacKey,
+	SortableKey,
+	PublicKey,
+	VerifyKey,
+>;
+
 pub type StdGroupKeyData = entities::group::GroupKeyData<SymmetricKey, SecretKey, PublicKey>;
 
 pub type StdUser = user::User<
@@ -40,11 +60,39 @@ pub type StdUserDataInt = entities::user::UserDataInt<SymmetricKey, SecretKey, P
 
 pub type StdUserKeyDataInt = entities::user::UserKeyDataInt<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey>;
 
+pub type StdAccountArchive = entities::user::AccountArchive<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey, HmacKey, SortableKey>;
+
+pub type StdAccountArchiveGroup = entities::user::AccountArchiveGroup<SymmetricKey, SecretKey, PublicKey, HmacKey, SortableKey>;
+
 pub type StdKeyGenerator = crypto::KeyGenerator<SymmetricKey, SymmetricKey, PublicKey>;
 
 pub type StdFileEncryptor =
 	file::FileEncryptor<sentc_crypto_std_keys::core::SymmetricKey, sentc_crypto_std_keys::core::SymmetricKey, SignKey, VerifyKey>;
 
+pub type StdFileContainerWriter<'a> =
+	file::FileContainerWriter<'a, sentc_crypto_std_keys::core::SymmetricKey, sentc_crypto_std_keys::core::SymmetricKey, SignKey, VerifyKey>;
+
+pub type StdFileContainerReader<'a> =
+	file::FileContainerReader<'a, sentc_crypto_std_keys::core::SymmetricKey, sentc_crypto_std_keys::core::SymmetricKey, SignKey, VerifyKey>;
+
+pub type StdFileArchiveBuilder<'a> = file::FileArchiveBuilder<
+	'a,
+	SymmetricKey,
+	sentc_crypto_std_keys::core::SymmetricKey,
+	sentc_crypto_std_keys::core::SymmetricKey,
+	SignKey,
+	VerifyKey,
+>;
+
+pub type StdFileArchiveReader<'a> = file::FileArchiveReader<
+	'a,
+	SymmetricKey,
+	sentc_crypto_std_keys::core::SymmetricKey,
+	sentc_crypto_std_keys::core::SymmetricKey,
+	SignKey,
+	VerifyKey,
+>;
+
 #[cfg(any(feature = "full_rustls", feature = "full_wasm"))]
 pub type StdPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	SymmetricKey,
@@ -54,3 +102,20 @@ pub type StdPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	VerifyKey,
 	sentc_crypto_std_keys::core::DeriveMasterKeyForAuth,
 >;
+
+#[cfg(all(any(feature = "full_rustls", feature = "full_wasm"), not(feature = "export")))]
+pub type StdSession = crate::util_req_full::session::Session<
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	sentc_crypto_std_keys::core::HmacKey,
+	sentc_crypto_std_keys::core::SortKeys,
+	SymmetricKey,
+	SecretKey,
+	SignKey,
+	HmacKey,
+	SortableKey,
+	PublicKey,
+	VerifyKey,
+	PwHasherGetter,
+>;
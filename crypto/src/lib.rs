@@ -23,6 +23,7 @@
 //!
 //! Online actions like creating a new user or group can be found in the util_req_full mod
 //! and is only available when activating the feature full_rustls or full_wasm.
+//! The session in util_req_full holds the jwt of a logged-in user and refreshes it before the requests.
 //!
 //! For sdk implementations that uses ffi or wasm, the export feature can be used.
 //! All Keys are returned as exported string in base64 and all errors are string as well. This makes it easy to integrate with wasm.
@@ -11,6 +11,7 @@ use sentc_crypto_common::user::DoneLoginServerReturn;
 
 use crate::SdkError;
 
+pub mod pin_store;
 pub(crate) mod user;
 #[cfg(feature = "export")]
 mod user_export;
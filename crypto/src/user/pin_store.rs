@@ -0,0 +1,77 @@
+//! Trust-on-first-use pinning of the verify keys of contacts.
+//!
+//! The server returns the public and verify keys of other users. Without a pin a malicious server can just present other keys.
+//! The verify key of a contact is pinned when it is used the first time.
+//! After that every fetched public key must be signed by the pinned verify key.
+//!
+//! The store is a trait so the app can use its own storage.
+//! The [`SerializablePinStore`] keeps the pins in memory and can be saved as json string.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::user::KeyPin;
+use crate::SdkError;
+
+pub trait PinStore
+{
+	fn get_pin(&self, id: &str) -> Option<KeyPin>;
+
+	fn set_pin(&mut self, id: &str, pin: KeyPin);
+
+	/**
+	Removes the pin, e.g. after the user compared the safety number of the new key of the contact.
+	 */
+	fn remove_pin(&mut self, id: &str);
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct SerializablePinStore
+{
+	pins: BTreeMap<String, KeyPin>,
+}
+
+impl SerializablePinStore
+{
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	/**
+	An empty string is an empty store
+	 */
+	pub fn from_string(store: &str) -> Result<Self, SdkError>
+	{
+		if store.is_empty() {
+			return Ok(Self::default());
+		}
+
+		Ok(serde_json::from_str(store)?)
+	}
+
+	pub fn to_string(&self) -> Result<String, SdkError>
+	{
+		serde_json::to_string(self).map_err(|_| SdkError::JsonToStringFailed)
+	}
+}
+
+impl PinStore for SerializablePinStore
+{
+	fn get_pin(&self, id: &str) -> Option<KeyPin>
+	{
+		self.pins.get(id).cloned()
+	}
+
+	fn set_pin(&mut self, id: &str, pin: KeyPin)
+	{
+		self.pins.insert(id.to_string(), pin);
+	}
+
+	fn remove_pin(&mut self, id: &str)
+	{
+		self.pins.remove(id);
+	}
+}
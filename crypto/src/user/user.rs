@@ -14,16 +14,23 @@
 //! * Register
 //! * Login
 //! * Multi-factor login
-//! * Creating more devices for the user
+//! * Creating more devices for the user, also by pairing with a qr code without a password on the new device
 //! * change or reset password
+//! * lock the user data on the device with a pin or a key of the platform keystore
+//! * safety numbers as digits, words or qr code and the tracking of verified keys
+//! * pinning the verify keys of contacts at the first use
+//! * signed links between the user keys of a key rotation
+//! * export and import of all account keys in an archive sealed with a passphrase
 //!
 
 use alloc::borrow::ToOwned;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use core::marker::PhantomData;
 
 use base64ct::{Base64, Base64UrlUnpadded, Encoding};
-use sentc_crypto_common::group::GroupKeyServerOutput;
+use sentc_crypto_common::group::{GroupKeyServerOutput, KeyRotationData};
 use sentc_crypto_common::user::{
 	DoneLoginServerOutput,
 	KeyDerivedData,
@@ -41,7 +48,18 @@ use sentc_crypto_common::user::{
 	VerifyLoginOutput,
 };
 use sentc_crypto_common::{DeviceId, UserId};
-use sentc_crypto_core::cryptomat::{DeriveMasterKeyForAuth, Pk, PwHash, SearchableKeyGen, SignKeyComposer, SortableKeyGen, StaticKeyPair};
+use sentc_crypto_core::cryptomat::{
+	DeriveMasterKeyForAuth,
+	Pk,
+	PwHash,
+	SearchableKeyGen,
+	SignK,
+	SignKeyComposer,
+	SortableKeyGen,
+	StaticKeyPair,
+	VerifyK,
+};
+use sentc_crypto_core::safety_number::{safety_number_to_digits, safety_number_to_words};
 use sentc_crypto_core::user as core_user;
 use sentc_crypto_utils::cryptomat::{
 	PkFromUserKeyWrapper,
@@ -60,10 +78,29 @@ use sentc_crypto_utils::cryptomat::{
 };
 use sentc_crypto_utils::error::SdkUtilError;
 use sentc_crypto_utils::user::{DeviceKeyDataInt, UserPreVerifyLogin};
-use sentc_crypto_utils::{client_random_value_to_string, hashed_authentication_key_to_string};
-
-use crate::entities::user::{UserDataInt, UserKeyDataInt};
+use sentc_crypto_utils::{client_random_value_to_string, hashed_authentication_key_to_string, session};
+use sha2::{Digest, Sha256};
+
+use crate::entities::user::{
+	AccountArchive,
+	AccountArchiveExport,
+	AccountArchiveGroup,
+	DevicePairingPayload,
+	DevicePairingResponse,
+	DevicePairingReveal,
+	DevicePairingStart,
+	KeyPin,
+	KeyRotationLink,
+	SafetyNumberQrPayload,
+	UserDataExport,
+	UserDataInt,
+	UserKeyDataInt,
+	VerifiedKeyRecord,
+	VerifyKeyState,
+	SAFETY_NUMBER_QR_VERSION,
+};
 use crate::group::Group;
+use crate::user::pin_store::PinStore;
 use crate::util::public::handle_server_response;
 use crate::SdkError;
 
@@ -248,6 +285,121 @@ where
 		))
 	}
 
+	/**
+	Starts the pairing at the new device without a password.
+
+	The new device registers itself first like every other device (prepare_register_device_start),
+	e.g. with the random identifier and password of generate_user_register_data which are stored on the device.
+	The payload contains the register token of the server output and the commitment. Show the payload as qr code.
+
+	The returned nonce must stay on the device until the response of the existing device arrived.
+	The other messages of the pairing must be sent between the devices by the app, e.g. with the pairing relay
+	of the server (fetch_device_pairing_register, only with the unreleased_routes feature).
+	 */
+	pub fn prepare_device_pairing(server_output: &str) -> Result<DevicePairingStart, SdkError>
+	{
+		let out: UserDeviceRegisterOutput = handle_server_response(server_output)?;
+
+		let nonce = Base64::encode_string(&sentc_crypto_core::generate_nonce()?);
+
+		let payload = serde_json::to_string(&DevicePairingPayload {
+			commitment: device_pairing_commitment(&out, &nonce),
+			token: out.token,
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)?;
+
+		Ok(DevicePairingStart {
+			payload,
+			nonce,
+		})
+	}
+
+	/**
+	Answers the scanned payload at the existing device.
+
+	The response contains the nonce of the existing device and the verify key of the user, signed by the sign key of the user.
+	Send the response to the new device.
+	 */
+	pub fn respond_device_pairing(payload: &str, sign_key: &SignC::SignKWrapper, verify_key: &UserVerifyKeyData) -> Result<String, SdkError>
+	{
+		let payload: DevicePairingPayload = serde_json::from_str(payload).map_err(|_| SdkError::DevicePairingPayloadWrong)?;
+
+		let nonce = Base64::encode_string(&sentc_crypto_core::generate_nonce()?);
+
+		let signed = sign_key
+			.get_key()
+			.sign(&device_pairing_response_data(&payload, &nonce, verify_key))?;
+
+		serde_json::to_string(&DevicePairingResponse {
+			nonce,
+			verify_key: UserVerifyKeyData {
+				verify_key_pem: verify_key.verify_key_pem.clone(),
+				verify_key_alg: verify_key.verify_key_alg.clone(),
+				verify_key_id: verify_key.verify_key_id.clone(),
+			},
+			sig: Base64::encode_string(&signed),
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Checks the response at the new device and reveals the nonce of the new device.
+
+	Returns the reveal for the existing device, the code to show and the verify key of the user.
+	After the login of the new device the verify key of the user keys must be the same, e.g. pin it with pin_verify_key.
+	 */
+	pub fn reveal_device_pairing(start: &DevicePairingStart, response: &str) -> Result<(String, String, UserVerifyKeyData), SdkError>
+	{
+		let payload: DevicePairingPayload = serde_json::from_str(&start.payload).map_err(|_| SdkError::DevicePairingPayloadWrong)?;
+		let response: DevicePairingResponse = serde_json::from_str(response).map_err(|_| SdkError::DevicePairingPayloadWrong)?;
+
+		let verify_key = SignC::vk_inner_from_pem(
+			&response.verify_key.verify_key_pem,
+			&response.verify_key.verify_key_alg,
+		)?;
+
+		let signed = Base64::decode_vec(&response.sig).map_err(|_| SdkError::DevicePairingResponseWrong)?;
+
+		let (data, valid) = verify_key
+			.verify(&signed)
+			.map_err(|_| SdkError::DevicePairingResponseWrong)?;
+
+		if !valid || data != device_pairing_response_data(&payload, &response.nonce, &response.verify_key).as_slice() {
+			return Err(SdkError::DevicePairingResponseWrong);
+		}
+
+		let code = device_pairing_code(&payload, &start.nonce, &response);
+
+		let reveal = serde_json::to_string(&DevicePairingReveal {
+			nonce: start.nonce.clone(),
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)?;
+
+		Ok((reveal, code, response.verify_key))
+	}
+
+	/**
+	Checks the revealed nonce and the register output of the new device at the existing device.
+
+	The server output is the output of the register start of the new device, fetched with the token of the payload.
+	Returns the code. Show the code and only register the device (prepare_register_device) with the server output
+	if the user confirmed that both devices show the same code.
+	 */
+	pub fn verify_device_pairing(payload: &str, server_output: &str, response: &str, reveal: &str) -> Result<String, SdkError>
+	{
+		let payload: DevicePairingPayload = serde_json::from_str(payload).map_err(|_| SdkError::DevicePairingPayloadWrong)?;
+		let out: UserDeviceRegisterOutput = handle_server_response(server_output)?;
+		let response: DevicePairingResponse = serde_json::from_str(response).map_err(|_| SdkError::DevicePairingPayloadWrong)?;
+		let reveal: DevicePairingReveal = serde_json::from_str(reveal).map_err(|_| SdkError::DevicePairingPayloadWrong)?;
+
+		//the commitment covers the token too, so the output of another device register can't be used
+		if out.token != payload.token || device_pairing_commitment(&out, &reveal.nonce) != payload.commitment {
+			return Err(SdkError::DevicePairingCommitmentWrong);
+		}
+
+		Ok(device_pairing_code(&payload, &reveal.nonce, &response))
+	}
+
 	//______________________________________________________________________________________________
 
 	/**
@@ -477,6 +629,112 @@ where
 		verify_key_2: Option<&UserVerifyKeyData>,
 		user_id_2: Option<&str>,
 	) -> Result<String, SdkError>
+	{
+		let number = Self::safety_number(verify_key_1, user_id_1, verify_key_2, user_id_2)?;
+
+		Ok(Base64UrlUnpadded::encode_string(&number))
+	}
+
+	/**
+	Create a safety number as groups of decimal digits.
+
+	Every user got 30 digits. The combined number contains the digits of both users,
+	the user id which comes first in the alphabet is the first part. Like the safety numbers of signal with 60 digits.
+	 */
+	pub fn create_safety_number_digits(
+		verify_key_1: &UserVerifyKeyData,
+		user_id_1: &str,
+		verify_key_2: Option<&UserVerifyKeyData>,
+		user_id_2: Option<&str>,
+	) -> Result<String, SdkError>
+	{
+		let digits_1 = safety_number_to_digits(&Self::safety_number(verify_key_1, user_id_1, None, None)?);
+
+		match (verify_key_2, user_id_2) {
+			(Some(k), Some(id)) => {
+				let digits_2 = safety_number_to_digits(&Self::safety_number(k, id, None, None)?);
+
+				if id > user_id_1 {
+					Ok(digits_1 + " " + &digits_2)
+				} else {
+					Ok(digits_2 + " " + &digits_1)
+				}
+			},
+			_ => Ok(digits_1),
+		}
+	}
+
+	/**
+	Create a safety number as words of the PGP word list
+	 */
+	pub fn create_safety_number_words(
+		verify_key_1: &UserVerifyKeyData,
+		user_id_1: &str,
+		verify_key_2: Option<&UserVerifyKeyData>,
+		user_id_2: Option<&str>,
+	) -> Result<Vec<&'static str>, SdkError>
+	{
+		let number = Self::safety_number(verify_key_1, user_id_1, verify_key_2, user_id_2)?;
+
+		Ok(safety_number_to_words(&number))
+	}
+
+	/**
+	Create the payload of a qr code with the safety number.
+
+	The other user scans the code and checks it with verify_safety_number_qr.
+	 */
+	pub fn create_safety_number_qr(
+		verify_key_1: &UserVerifyKeyData,
+		user_id_1: &str,
+		verify_key_2: Option<&UserVerifyKeyData>,
+		user_id_2: Option<&str>,
+	) -> Result<String, SdkError>
+	{
+		let number = Self::safety_number(verify_key_1, user_id_1, verify_key_2, user_id_2)?;
+
+		serde_json::to_string(&SafetyNumberQrPayload {
+			version: SAFETY_NUMBER_QR_VERSION,
+			user_ids: safety_number_user_ids(user_id_1, user_id_2),
+			number: Base64UrlUnpadded::encode_string(&number),
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	Checks the scanned qr code against the own safety number.
+
+	Returns false if the code is for other users or the verify keys are not the same.
+	 */
+	pub fn verify_safety_number_qr(
+		payload: &str,
+		verify_key_1: &UserVerifyKeyData,
+		user_id_1: &str,
+		verify_key_2: Option<&UserVerifyKeyData>,
+		user_id_2: Option<&str>,
+	) -> Result<bool, SdkError>
+	{
+		let payload: SafetyNumberQrPayload = serde_json::from_str(payload).map_err(|_| SdkError::SafetyNumberQrWrong)?;
+
+		if payload.version != SAFETY_NUMBER_QR_VERSION {
+			return Err(SdkError::SafetyNumberQrWrong);
+		}
+
+		if payload.user_ids != safety_number_user_ids(user_id_1, user_id_2) {
+			return Ok(false);
+		}
+
+		let number = Self::safety_number(verify_key_1, user_id_1, verify_key_2, user_id_2)?;
+
+		Ok(payload.number == Base64UrlUnpadded::encode_string(&number))
+	}
+
+	fn safety_number(
+		verify_key_1: &UserVerifyKeyData,
+		user_id_1: &str,
+		verify_key_2: Option<&UserVerifyKeyData>,
+		user_id_2: Option<&str>,
+	) -> Result<Vec<u8>, SdkError>
 	{
 		let verify_key_1 = SignC::vk_inner_from_pem(&verify_key_1.verify_key_pem, &verify_key_1.verify_key_alg)?;
 
@@ -494,7 +752,7 @@ where
 			_ => core_user::safety_number(&verify_key_1, user_id_1, None, None),
 		};
 
-		Ok(Base64UrlUnpadded::encode_string(&number))
+		Ok(number)
 	}
 
 	pub fn verify_user_public_key(verify_key: &UserVerifyKeyData, public_key: &UserPublicKeyData) -> Result<bool, SdkError>
@@ -514,6 +772,426 @@ where
 
 		Ok(public_key.verify_public_key(&raw_verify_key, &sig)?)
 	}
+
+	/**
+	Pins the verify key of the contact at the first use.
+
+	Returns an error if another verify key was pinned for the contact before.
+	After a key rotation of the contact, the old pin must be removed from the store, e.g. after comparing the safety number again.
+	 */
+	pub fn pin_verify_key(store: &mut impl PinStore, user_id: &str, verify_key: &UserVerifyKeyData) -> Result<(), SdkError>
+	{
+		match store.get_pin(user_id) {
+			Some(pin) if pin.is_key(verify_key) => Ok(()),
+			Some(_) => Err(SdkError::IdentityChanged),
+			None => {
+				store.set_pin(user_id, verify_key.into());
+
+				Ok(())
+			},
+		}
+	}
+
+	/**
+	Checks the fetched public key of the contact with the pinned verify key.
+
+	The public key must be signed by the pinned verify key, no matter which verify key the server returns.
+	 */
+	pub fn check_pinned_public_key(store: &impl PinStore, id: &str, public_key: &UserPublicKeyData) -> Result<(), SdkError>
+	{
+		let pin = store.get_pin(id).ok_or(SdkError::IdentityNotPinned)?;
+
+		match &public_key.public_key_sig_key_id {
+			Some(sig_key_id) if *sig_key_id == pin.verify_key_id => {},
+			//the key is not signed by the pinned key
+			_ => return Err(SdkError::IdentityChanged),
+		}
+
+		//a signature which can't be decoded is a wrong signature too
+		match Self::verify_user_public_key(&pin.to_verify_key(), public_key) {
+			Ok(true) => Ok(()),
+			_ => Err(SdkError::IdentityChanged),
+		}
+	}
+
+	/**
+	Signs the new verify and public key of the user with the sign key of the previous user key.
+
+	The previous verify key must be the key of the previous sign key.
+	Share the link with the contacts, so they can follow the rotation from the key they pinned, see KeyRotationLink.
+	For a new key rotation use prepare_signed_key_rotation to create the link with the rotation.
+	 */
+	pub fn create_key_rotation_link(
+		previous_sign_key: &SignC::SignKWrapper,
+		previous_verify_key: &UserVerifyKeyData,
+		verify_key: &UserVerifyKeyData,
+		public_key: &UserPublicKeyData,
+	) -> Result<KeyRotationLink, SdkError>
+	{
+		let mut link = KeyRotationLink {
+			previous_verify_key_hash: verify_key_hash(previous_verify_key),
+			verify_key_id: verify_key.verify_key_id.clone(),
+			verify_key_pem: verify_key.verify_key_pem.clone(),
+			verify_key_alg: verify_key.verify_key_alg.clone(),
+			public_key_id: public_key.public_key_id.clone(),
+			public_key_pem: public_key.public_key_pem.clone(),
+			public_key_alg: public_key.public_key_alg.clone(),
+			sig: String::new(),
+		};
+
+		let signed = previous_sign_key
+			.get_key()
+			.sign(&key_rotation_link_data(&link))?;
+
+		link.sig = Base64::encode_string(&signed);
+
+		Ok(link)
+	}
+
+	/**
+	Creates a new user key like the key rotation of the user group and links it to the previous key.
+
+	Returns the input for the key rotation and the link. The link is signed before the new key is registered,
+	so the ids of the new keys are empty. Set them after the key rotation, they are not signed.
+	 */
+	pub fn prepare_signed_key_rotation(
+		device_public_key: &impl PkWrapper,
+		pre_user_key: &UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+	) -> Result<(String, KeyRotationLink), SdkError>
+	{
+		let (rotation, link) = Self::signed_key_rotation_data(device_public_key, pre_user_key)?;
+
+		Ok((
+			rotation
+				.to_string()
+				.map_err(|_| SdkError::JsonToStringFailed)?,
+			link,
+		))
+	}
+
+	fn signed_key_rotation_data(
+		device_public_key: &impl PkWrapper,
+		pre_user_key: &UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+	) -> Result<(KeyRotationData, KeyRotationLink), SdkError>
+	{
+		let rotation = Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::key_rotation_data(
+			&pre_user_key.group_key,
+			device_public_key,
+			true,
+			None,
+			Default::default(),
+		)?;
+
+		//the user group rotation always creates a new sign key pair
+		let (verify_key_pem, verify_key_alg) = match (&rotation.verify_key, &rotation.keypair_sign_alg) {
+			(Some(pem), Some(alg)) => (pem.clone(), alg.clone()),
+			_ => return Err(SdkError::KeyRotationChainWrong),
+		};
+
+		let key_rotation_link = Self::create_key_rotation_link(
+			&pre_user_key.sign_key,
+			&pre_user_key.exported_verify_key,
+			&UserVerifyKeyData {
+				verify_key_pem,
+				verify_key_alg,
+				verify_key_id: String::new(),
+			},
+			&UserPublicKeyData {
+				public_key_pem: rotation.public_group_key.clone(),
+				public_key_alg: rotation.keypair_encrypt_alg.clone(),
+				public_key_id: String::new(),
+				public_key_sig: rotation.public_key_sig.clone(),
+				public_key_sig_key_id: None,
+			},
+		)?;
+
+		Ok((rotation, key_rotation_link))
+	}
+
+	/**
+	Walks the chain of key rotations from the given verify key to the latest key.
+
+	The links must be in the order of the rotations, starting with the link of the key after the given key.
+	Every link must point to the hash of the key before, the ids of the keys are not checked.
+	Returns the verify key of the last link.
+	 */
+	pub fn verify_key_rotation_chain(verify_key: &UserVerifyKeyData, links: &[KeyRotationLink]) -> Result<UserVerifyKeyData, SdkError>
+	{
+		let mut current = KeyPin::from(verify_key);
+
+		for link in links {
+			if link.previous_verify_key_hash != verify_key_hash(&current.to_verify_key()) {
+				return Err(SdkError::KeyRotationChainWrong);
+			}
+
+			let verify_key = SignC::vk_inner_from_pem(&current.verify_key_pem, &current.verify_key_alg)?;
+
+			let signed = Base64::decode_vec(&link.sig).map_err(|_| SdkError::KeyRotationChainWrong)?;
+
+			let (data, valid) = verify_key
+				.verify(&signed)
+				.map_err(|_| SdkError::KeyRotationChainWrong)?;
+
+			if !valid || data != key_rotation_link_data(link).as_slice() {
+				return Err(SdkError::KeyRotationChainWrong);
+			}
+
+			current = KeyPin::from(link);
+		}
+
+		Ok(current.to_verify_key())
+	}
+
+	/**
+	Moves the pin of the contact to the latest key of the rotation chain.
+
+	The chain must start at the pinned verify key.
+	 */
+	pub fn update_pin_by_rotation_chain(store: &mut impl PinStore, user_id: &str, links: &[KeyRotationLink]) -> Result<(), SdkError>
+	{
+		let pin = store.get_pin(user_id).ok_or(SdkError::IdentityNotPinned)?;
+
+		let verify_key = Self::verify_key_rotation_chain(&pin.to_verify_key(), links)?;
+
+		store.set_pin(user_id, (&verify_key).into());
+
+		Ok(())
+	}
+
+	/**
+	Seals the data of the logged-in user with a key derived from a local pin.
+
+	The returned string can be stored on the device. The exported data is overwritten after the sealing.
+	The pin is hashed with the expensive passphrase hash, but a short pin can still be guessed offline.
+	Prefer lock_user_data_with_key with a key from the keystore of the platform.
+	 */
+	pub fn lock_user_data_with_pin(
+		pin: &str,
+		data: UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+	) -> Result<String, SdkError>
+	{
+		Self::lock_user_data(data, |data| session::seal_with_pin::<PwH>(pin, data))
+	}
+
+	/**
+	Seals the data of the logged-in user with a key from the keystore of the platform.
+	 */
+	pub fn lock_user_data_with_key(
+		key: &impl SymKeyWrapper,
+		data: UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+	) -> Result<String, SdkError>
+	{
+		Self::lock_user_data(data, |data| session::seal_with_key(key.get_key(), data))
+	}
+
+	pub fn unlock_user_data_with_pin(
+		pin: &str,
+		sealed: &str,
+	) -> Result<UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
+	{
+		Self::unlock_user_data(session::unseal_with_pin::<PwH>(pin, sealed)?)
+	}
+
+	pub fn unlock_user_data_with_key(
+		key: &impl SymKeyWrapper,
+		sealed: &str,
+	) -> Result<UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
+	{
+		Self::unlock_user_data(session::unseal_with_key(key.get_key(), sealed)?)
+	}
+
+	/**
+	Bundles the decrypted keys of the user and of the given groups into one archive sealed with the passphrase.
+
+	The hmac keys of the user are decrypted with the matching user key.
+	The archive can be imported into another deployment or kept in a cold storage.
+	The key of the archive is derived from the passphrase with the expensive passphrase hash.
+	 */
+	pub fn export_account(
+		passphrase: &str,
+		data: UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+		groups: Vec<
+			AccountArchiveGroup<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SearchC::SearchableKeyWrapper, SortC::SortableKeyWrapper>,
+		>,
+	) -> Result<String, SdkError>
+	{
+		let mut hmac_keys = Vec::with_capacity(data.hmac_keys.len());
+
+		for hmac_key in data.hmac_keys {
+			let user_key = data
+				.user_keys
+				.iter()
+				.find(|k| k.group_key.get_id() == hmac_key.encrypted_hmac_encryption_key_id)
+				.ok_or(SdkError::AccountArchiveWrong)?;
+
+			hmac_keys.push(Group::<
+				SGen,
+				StGen,
+				SignGen,
+				SearchGen,
+				SortGen,
+				SC,
+				StC,
+				SignC,
+				SearchC,
+				SortC,
+				PC,
+				VC,
+			>::decrypt_group_hmac_key(&user_key.group_key, hmac_key)?);
+		}
+
+		let archive = AccountArchive {
+			user_id: data.user_id,
+			user_keys: data.user_keys,
+			hmac_keys,
+			groups,
+		};
+
+		let mut archive: AccountArchiveExport = archive.try_into()?;
+
+		let res = serde_json::to_string(&archive).map_err(|_| SdkError::JsonToStringFailed);
+
+		archive.wipe();
+
+		let mut json = res?;
+
+		let sealed = session::seal_with_passphrase::<PwH>(passphrase, json.as_bytes());
+
+		session::wipe_string(&mut json);
+
+		Ok(sealed?)
+	}
+
+	/**
+	Opens the account archive and rebuilds the keys with their ids and times
+	 */
+	#[allow(clippy::type_complexity)]
+	pub fn import_account(
+		passphrase: &str,
+		sealed: &str,
+	) -> Result<
+		AccountArchive<
+			SC::SymmetricKeyWrapper,
+			StC::SkWrapper,
+			StC::PkWrapper,
+			SignC::SignKWrapper,
+			SignC::VerifyKWrapper,
+			SearchC::SearchableKeyWrapper,
+			SortC::SortableKeyWrapper,
+		>,
+		SdkError,
+	>
+	{
+		let mut data = session::unseal_with_passphrase::<PwH>(passphrase, sealed)?;
+
+		let res = serde_json::from_slice::<AccountArchiveExport>(&data);
+
+		session::wipe_bytes(&mut data);
+
+		res.map_err(|_| SdkError::AccountArchiveWrong)?.try_into()
+	}
+
+	fn lock_user_data(
+		data: UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+		seal: impl FnOnce(&[u8]) -> Result<String, SdkUtilError>,
+	) -> Result<String, SdkError>
+	{
+		let mut data: UserDataExport = data.try_into()?;
+
+		let res = serde_json::to_string(&data).map_err(|_| SdkError::JsonToStringFailed);
+
+		data.wipe();
+
+		let mut json = res?;
+
+		let sealed = seal(json.as_bytes());
+
+		session::wipe_string(&mut json);
+
+		Ok(sealed?)
+	}
+
+	fn unlock_user_data(
+		mut data: Vec<u8>,
+	) -> Result<UserDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
+	{
+		let res = serde_json::from_slice::<UserDataExport>(&data);
+
+		//the decrypted json contains the secret keys
+		session::wipe_bytes(&mut data);
+
+		res?.try_into()
+	}
+}
+
+/**
+The user ids of the safety number in the same order on both sides
+ */
+fn safety_number_user_ids(user_id_1: &str, user_id_2: Option<&str>) -> Vec<UserId>
+{
+	match user_id_2 {
+		Some(id) if id > user_id_1 => vec![user_id_1.to_string(), id.to_string()],
+		Some(id) => vec![id.to_string(), user_id_1.to_string()],
+		None => vec![user_id_1.to_string()],
+	}
+}
+
+/**
+The signed data of the link. Every value is prefixed with its length, so the values can't be shifted.
+
+The ids of the new keys are set by the server after the link was signed, so only the keys itself are signed.
+ */
+fn key_rotation_link_data(link: &KeyRotationLink) -> Vec<u8>
+{
+	let mut data = b"sentc_key_rotation_link".to_vec();
+
+	for value in [
+		&link.previous_verify_key_hash,
+		&link.verify_key_alg,
+		&link.verify_key_pem,
+		&link.public_key_alg,
+		&link.public_key_pem,
+	] {
+		data.extend_from_slice(&(value.len() as u32).to_be_bytes());
+		data.extend_from_slice(value.as_bytes());
+	}
+
+	data
+}
+
+fn verify_key_hash(verify_key: &UserVerifyKeyData) -> String
+{
+	let mut hasher = Sha256::new();
+	hasher.update(verify_key.verify_key_alg.as_bytes());
+	hasher.update(verify_key.verify_key_pem.as_bytes());
+
+	Base64::encode_string(&hasher.finalize())
+}
+
+/**
+Creates the record of the verify key after the user compared the safety number with the contact
+ */
+pub fn create_verified_key_record(user_id: &str, verify_key: &UserVerifyKeyData) -> VerifiedKeyRecord
+{
+	VerifiedKeyRecord {
+		user_id: user_id.to_string(),
+		verify_key_id: verify_key.verify_key_id.clone(),
+		verify_key_hash: verify_key_hash(verify_key),
+	}
+}
+
+/**
+Checks a fetched verify key of the contact against the verified key.
+
+A new key id after a key rotation is a changed key too.
+ */
+pub fn check_verified_key_record(record: &VerifiedKeyRecord, verify_key: &UserVerifyKeyData) -> VerifyKeyState
+{
+	if record.verify_key_id == verify_key.verify_key_id && record.verify_key_hash == verify_key_hash(verify_key) {
+		VerifyKeyState::Verified
+	} else {
+		VerifyKeyState::KeyChanged
+	}
 }
 
 /**
@@ -557,6 +1235,81 @@ pub fn done_register(server_output: &str) -> Result<UserId, SdkError>
 	Ok(out.user_id)
 }
 
+/**
+Appends the values with their length, so the values can't be shifted.
+ */
+fn device_pairing_data(tag: &[u8], values: &[&str]) -> Vec<u8>
+{
+	let mut data = tag.to_vec();
+
+	for value in values {
+		data.extend_from_slice(&(value.len() as u32).to_be_bytes());
+		data.extend_from_slice(value.as_bytes());
+	}
+
+	data
+}
+
+fn device_pairing_commitment(out: &UserDeviceRegisterOutput, nonce: &str) -> String
+{
+	let data = device_pairing_data(
+		b"sentc_device_pairing_commitment",
+		&[
+			&out.device_id,
+			&out.token,
+			&out.device_identifier,
+			&out.keypair_encrypt_alg,
+			&out.public_key_string,
+			nonce,
+		],
+	);
+
+	Base64::encode_string(&Sha256::digest(data))
+}
+
+fn device_pairing_response_data(payload: &DevicePairingPayload, nonce: &str, verify_key: &UserVerifyKeyData) -> Vec<u8>
+{
+	device_pairing_data(
+		b"sentc_device_pairing_response",
+		&[
+			&payload.token,
+			&payload.commitment,
+			nonce,
+			&verify_key.verify_key_id,
+			&verify_key.verify_key_alg,
+			&verify_key.verify_key_pem,
+		],
+	)
+}
+
+/**
+The short authentication string of the pairing. Six digits from the hash of the data of both devices.
+
+The new device committed to its nonce before the existing device chose its nonce,
+so a device in the middle can't search for a key with the same code.
+ */
+fn device_pairing_code(payload: &DevicePairingPayload, new_device_nonce: &str, response: &DevicePairingResponse) -> String
+{
+	let data = device_pairing_data(
+		b"sentc_device_pairing_code",
+		&[
+			&payload.token,
+			&payload.commitment,
+			new_device_nonce,
+			&response.nonce,
+			&response.verify_key.verify_key_id,
+			&response.verify_key.verify_key_alg,
+			&response.verify_key.verify_key_pem,
+		],
+	);
+
+	let hash = Sha256::digest(data);
+
+	let number = u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) % 1_000_000;
+
+	format!("{:06}", number)
+}
+
 /**
 Call this fn after the register device request in the new device to get the token.
 
@@ -610,9 +1363,12 @@ mod test
 		UserDeviceRegisterOutput,
 	};
 	use sentc_crypto_common::ServerOutput;
+	use sentc_crypto_utils::cryptomat::SymKeyCrypto;
 	use serde_json::to_string;
 
 	use super::*;
+	use crate::group::test_fn::create_group;
+	use crate::user::pin_store::SerializablePinStore;
 	use crate::user::test_fn::{create_user, simulate_server_done_login, simulate_server_prepare_login, simulate_verify_login, TestUser};
 
 	#[test]
@@ -829,6 +1585,130 @@ mod test
 		);
 	}
 
+	#[test]
+	fn test_device_pairing()
+	{
+		let user = create_user();
+
+		//1. the new device registers itself with a random password and shows the payload as qr code
+		let (device_identifier, device_pw) = generate_user_register_data().unwrap();
+		let server_input = TestUser::prepare_register_device_start(&device_identifier, &device_pw).unwrap();
+		let input: UserDeviceRegisterInput = serde_json::from_str(&server_input).unwrap();
+
+		let server_output = to_string(&ServerOutput {
+			status: true,
+			err_msg: None,
+			err_code: None,
+			result: Some(UserDeviceRegisterOutput {
+				device_id: "abc".to_string(),
+				token: "1234567890".to_string(),
+				device_identifier,
+				public_key_string: input.derived.public_key.to_string(),
+				keypair_encrypt_alg: input.derived.keypair_encrypt_alg.to_string(),
+			}),
+		})
+		.unwrap();
+
+		let start = TestUser::prepare_device_pairing(&server_output).unwrap();
+
+		//the nonce and the keys of the new device are not in the qr code
+		assert!(!start.payload.contains(&start.nonce));
+		assert!(!start.payload.contains(&input.derived.public_key));
+
+		//2. the existing device scans the payload and responds
+		let user_key = &user.user_keys[0];
+		let response = TestUser::respond_device_pairing(&start.payload, &user_key.sign_key, &user_key.exported_verify_key).unwrap();
+
+		//3. the new device checks the response and reveals its nonce
+		let (reveal, code_new_device, verify_key) = TestUser::reveal_device_pairing(&start, &response).unwrap();
+
+		assert_eq!(verify_key.verify_key_id, user_key.exported_verify_key.verify_key_id);
+		assert_eq!(code_new_device.len(), 6);
+
+		//4. the existing device fetched the register output with the token, checks the reveal, shows the same code and registers the device
+		let code = TestUser::verify_device_pairing(&start.payload, &server_output, &response, &reveal).unwrap();
+
+		assert_eq!(code, code_new_device);
+
+		TestUser::prepare_register_device(&server_output, &[&user_key.group_key], false).unwrap();
+
+		//a response from another user
+		let other_user = create_user();
+		let mut other_response: DevicePairingResponse = serde_json::from_str(&response).unwrap();
+		other_response.verify_key = KeyPin::from(&other_user.user_keys[0].exported_verify_key).to_verify_key();
+
+		assert!(matches!(
+			TestUser::reveal_device_pairing(&start, &to_string(&other_response).unwrap()),
+			Err(SdkError::DevicePairingResponseWrong)
+		));
+
+		//a nonce which was not committed
+		let other_start = TestUser::prepare_device_pairing(&server_output).unwrap();
+		let other_reveal = to_string(&DevicePairingReveal {
+			nonce: other_start.nonce,
+		})
+		.unwrap();
+
+		assert!(matches!(
+			TestUser::verify_device_pairing(&start.payload, &server_output, &response, &other_reveal),
+			Err(SdkError::DevicePairingCommitmentWrong)
+		));
+
+		//a register output with another key than the committed one
+		let other_server_output = server_output.replace(
+			&input.derived.keypair_encrypt_alg,
+			&(input.derived.keypair_encrypt_alg.clone() + "1"),
+		);
+
+		assert!(matches!(
+			TestUser::verify_device_pairing(&start.payload, &other_server_output, &response, &reveal),
+			Err(SdkError::DevicePairingCommitmentWrong)
+		));
+	}
+
+	#[test]
+	fn test_lock_user_data()
+	{
+		let user = create_user();
+		let user_id = user.user_id.clone();
+		let key_id = user.user_keys[0].group_key.key_id.clone();
+
+		let sealed = TestUser::lock_user_data_with_pin("1234", user).unwrap();
+
+		assert!(TestUser::unlock_user_data_with_pin("4321", &sealed).is_err());
+
+		let user = TestUser::unlock_user_data_with_pin("1234", &sealed).unwrap();
+
+		assert_eq!(user.user_id, user_id);
+		assert_eq!(user.user_keys[0].group_key.key_id, key_id);
+
+		//the hash params are stored in the lock, an unknown derived alg is rejected
+		let mut sealed_json: serde_json::Value = serde_json::from_str(&sealed).unwrap();
+		sealed_json["lock"]["derived_alg"] = "ARGON-2-SHA256".into();
+
+		assert!(matches!(
+			TestUser::unlock_user_data_with_pin("1234", &sealed_json.to_string()),
+			Err(SdkError::Util(SdkUtilError::Base(
+				sentc_crypto_core::Error::AlgNotFound
+			)))
+		));
+
+		//a key from the keystore of the platform
+		let keystore = create_user();
+		let key = &keystore.user_keys[0].group_key;
+
+		let sealed = TestUser::lock_user_data_with_key(key, user).unwrap();
+
+		assert!(matches!(
+			TestUser::unlock_user_data_with_pin("1234", &sealed),
+			Err(SdkError::Util(SdkUtilError::SealedLockWrong))
+		));
+
+		let user = TestUser::unlock_user_data_with_key(key, &sealed).unwrap();
+
+		assert_eq!(user.user_id, user_id);
+	}
+
 	#[test]
 	fn test_safety_number()
 	{
@@ -870,6 +1750,267 @@ mod test
 		assert_ne!(number, number_3);
 	}
 
+	#[test]
+	fn test_safety_number_encodings()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+		let vk_1 = &user_1.user_keys[0].exported_verify_key;
+		let vk_2 = &user_2.user_keys[0].exported_verify_key;
+
+		let digits = TestUser::create_safety_number_digits(vk_1, "abc1", Some(vk_2), Some("abc2")).unwrap();
+		let digits_2 = TestUser::create_safety_number_digits(vk_2, "abc2", Some(vk_1), Some("abc1")).unwrap();
+
+		assert_eq!(digits, digits_2);
+		assert_eq!(digits.replace(' ', "").len(), 60);
+
+		//the first part is the number of the first user
+		let digits_single = TestUser::create_safety_number_digits(vk_1, "abc1", None, None).unwrap();
+		assert!(digits.starts_with(&digits_single));
+
+		let words = TestUser::create_safety_number_words(vk_1, "abc1", Some(vk_2), Some("abc2")).unwrap();
+		assert_eq!(words.len(), 32);
+
+		//scan the qr code of the other user
+		let qr = TestUser::create_safety_number_qr(vk_2, "abc2", Some(vk_1), Some("abc1")).unwrap();
+
+		assert!(TestUser::verify_safety_number_qr(&qr, vk_1, "abc1", Some(vk_2), Some("abc2")).unwrap());
+		assert!(!TestUser::verify_safety_number_qr(&qr, vk_1, "abc1", Some(vk_2), Some("abc3")).unwrap());
+
+		let user_3 = create_user();
+		let vk_3 = &user_3.user_keys[0].exported_verify_key;
+
+		assert!(!TestUser::verify_safety_number_qr(&qr, vk_1, "abc1", Some(vk_3), Some("abc2")).unwrap());
+
+		assert!(matches!(
+			TestUser::verify_safety_number_qr("abc", vk_1, "abc1", None, None),
+			Err(SdkError::SafetyNumberQrWrong)
+		));
+	}
+
+	#[test]
+	fn test_verified_key_record()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+		let vk_1 = &user_1.user_keys[0].exported_verify_key;
+
+		let record = create_verified_key_record("abc1", vk_1);
+
+		assert_eq!(check_verified_key_record(&record, vk_1), VerifyKeyState::Verified);
+
+		//another key with the same id
+		let vk_2 = UserVerifyKeyData {
+			verify_key_pem: user_2.user_keys[0]
+				.exported_verify_key
+				.verify_key_pem
+				.clone(),
+			verify_key_alg: user_2.user_keys[0]
+				.exported_verify_key
+				.verify_key_alg
+				.clone(),
+			verify_key_id: vk_1.verify_key_id.clone(),
+		};
+
+		assert_eq!(check_verified_key_record(&record, &vk_2), VerifyKeyState::KeyChanged);
+	}
+
+	#[test]
+	fn test_pin_verify_key()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+		let vk_1 = &user_1.user_keys[0].exported_verify_key;
+		let vk_2 = &user_2.user_keys[0].exported_verify_key;
+		let pk_1 = &user_1.user_keys[0].exported_public_key;
+		let pk_2 = &user_2.user_keys[0].exported_public_key;
+
+		let mut store = SerializablePinStore::new();
+
+		assert!(matches!(
+			TestUser::check_pinned_public_key(&store, "abc1", pk_1),
+			Err(SdkError::IdentityNotPinned)
+		));
+
+		TestUser::pin_verify_key(&mut store, "abc1", vk_1).unwrap();
+		TestUser::pin_verify_key(&mut store, "abc1", vk_1).unwrap();
+
+		TestUser::check_pinned_public_key(&store, "abc1", pk_1).unwrap();
+
+		//the server presents the key of another user with the same key id
+		assert!(matches!(
+			TestUser::check_pinned_public_key(&store, "abc1", pk_2),
+			Err(SdkError::IdentityChanged)
+		));
+		assert!(matches!(
+			TestUser::pin_verify_key(&mut store, "abc1", vk_2),
+			Err(SdkError::IdentityChanged)
+		));
+
+		//a key without a signature is a changed identity when there is a pin
+		let mut unsigned_pk: UserPublicKeyData = serde_json::from_str(&serde_json::to_string(pk_1).unwrap()).unwrap();
+		unsigned_pk.public_key_sig_key_id = None;
+
+		assert!(matches!(
+			TestUser::check_pinned_public_key(&store, "abc1", &unsigned_pk),
+			Err(SdkError::IdentityChanged)
+		));
+
+		let mut store = SerializablePinStore::new();
+		TestUser::pin_verify_key(&mut store, "abc1", vk_1).unwrap();
+
+		let store = SerializablePinStore::from_string(&store.to_string().unwrap()).unwrap();
+
+		TestUser::check_pinned_public_key(&store, "abc1", pk_1).unwrap();
+	}
+
+	#[test]
+	fn test_key_rotation_chain()
+	{
+		let user_1 = create_user();
+		//the keys of another user as the new keys after the rotation
+		let user_2 = create_user();
+		let vk_1 = &user_1.user_keys[0].exported_verify_key;
+		let vk_2 = &user_2.user_keys[0].exported_verify_key;
+		let pk_2 = &user_2.user_keys[0].exported_public_key;
+
+		let link = TestUser::create_key_rotation_link(&user_1.user_keys[0].sign_key, vk_1, vk_2, pk_2).unwrap();
+
+		let latest = TestUser::verify_key_rotation_chain(vk_1, &[link.clone()]).unwrap();
+
+		assert_eq!(latest.verify_key_pem, vk_2.verify_key_pem);
+
+		//the chain must start at the signing key
+		assert!(matches!(
+			TestUser::verify_key_rotation_chain(vk_2, &[link.clone()]),
+			Err(SdkError::KeyRotationChainWrong)
+		));
+
+		let mut changed_link = link.clone();
+		changed_link.public_key_pem = user_1.user_keys[0]
+			.exported_public_key
+			.public_key_pem
+			.clone();
+
+		assert!(matches!(
+			TestUser::verify_key_rotation_chain(vk_1, &[changed_link]),
+			Err(SdkError::KeyRotationChainWrong)
+		));
+
+		//move the pin to the new key
+		let mut store = SerializablePinStore::new();
+		TestUser::pin_verify_key(&mut store, "abc1", vk_1).unwrap();
+
+		TestUser::update_pin_by_rotation_chain(&mut store, "abc1", &[link]).unwrap();
+
+		TestUser::check_pinned_public_key(&store, "abc1", pk_2).unwrap();
+	}
+
+	#[test]
+	fn test_signed_key_rotation()
+	{
+		let user = create_user();
+		let vk_1 = &user.user_keys[0].exported_verify_key;
+
+		let (input, mut link) = TestUser::prepare_signed_key_rotation(&user.device_keys.public_key, &user.user_keys[0]).unwrap();
+
+		//the input is the normal key rotation input
+		let rotation = KeyRotationData::from_string(&input).unwrap();
+
+		assert_eq!(Some(&link.verify_key_pem), rotation.verify_key.as_ref());
+		assert_eq!(link.public_key_pem, rotation.public_group_key);
+
+		let latest = TestUser::verify_key_rotation_chain(vk_1, &[link.clone()]).unwrap();
+
+		assert_eq!(Some(&latest.verify_key_pem), rotation.verify_key.as_ref());
+
+		//the ids are set after the rotation and are not part of the chain
+		link.verify_key_id = "new_key_id".to_string();
+		link.public_key_id = "new_key_id".to_string();
+
+		let latest = TestUser::verify_key_rotation_chain(vk_1, &[link.clone()]).unwrap();
+
+		assert_eq!(latest.verify_key_id, "new_key_id");
+
+		link.verify_key_pem = vk_1.verify_key_pem.clone();
+
+		assert!(matches!(
+			TestUser::verify_key_rotation_chain(vk_1, &[link]),
+			Err(SdkError::KeyRotationChainWrong)
+		));
+	}
+
+	#[test]
+	fn test_account_archive()
+	{
+		let mut user = create_user();
+		let (_, group_keys, _, group_hmac_keys, group_sortable_keys) = create_group(&user.user_keys[0]);
+
+		//the hmac key of the user is encrypted by the user key
+		let user_key_id = user.user_keys[0].group_key.get_id().to_string();
+
+		for hmac_key in &mut user.hmac_keys {
+			hmac_key.encrypted_hmac_encryption_key_id = user_key_id.clone();
+		}
+
+		let encrypted_by_user = user.user_keys[0]
+			.group_key
+			.encrypt_string("hello user")
+			.unwrap();
+		let encrypted_by_group = group_keys[0]
+			.group_key
+			.encrypt_string("hello group")
+			.unwrap();
+		let verify_key_pem = user.user_keys[0].exported_verify_key.verify_key_pem.clone();
+
+		let group_hmac_key_id = group_hmac_keys[0].get_id().to_string();
+		let group_sortable_key_id = group_sortable_keys[0].get_id().to_string();
+
+		let groups = vec![AccountArchiveGroup {
+			group_id: "123".to_string(),
+			keys: group_keys,
+			hmac_keys: group_hmac_keys,
+			sortable_keys: group_sortable_keys,
+		}];
+
+		let sealed = TestUser::export_account("passphrase", user, groups).unwrap();
+
+		assert!(TestUser::import_account("wrong", &sealed).is_err());
+
+		let archive = TestUser::import_account("passphrase", &sealed).unwrap();
+
+		assert_eq!(archive.user_id, "abc");
+		assert_eq!(archive.user_keys[0].group_key.get_id(), "abc");
+		assert_eq!(archive.user_keys[0].time, 0);
+		assert_eq!(
+			archive.user_keys[0].exported_verify_key.verify_key_pem,
+			verify_key_pem
+		);
+		assert_eq!(archive.hmac_keys[0].get_id(), "123");
+		assert_eq!(archive.groups[0].group_id, "123");
+		assert_eq!(archive.groups[0].keys[0].group_key.get_id(), "123");
+		assert_eq!(archive.groups[0].hmac_keys[0].get_id(), group_hmac_key_id);
+		assert_eq!(archive.groups[0].sortable_keys[0].get_id(), group_sortable_key_id);
+
+		//an archive is no sealed user data
+		assert!(matches!(
+			TestUser::unlock_user_data_with_pin("passphrase", &sealed),
+			Err(SdkError::Util(SdkUtilError::SealedLockWrong))
+		));
+
+		let decrypted = archive.user_keys[0]
+			.group_key
+			.decrypt_string(&encrypted_by_user, None)
+			.unwrap();
+		assert_eq!(decrypted, "hello user");
+
+		let decrypted = archive.groups[0].keys[0]
+			.group_key
+			.decrypt_string(&encrypted_by_group, None)
+			.unwrap();
+		assert_eq!(decrypted, "hello group");
+	}
+
 	#[test]
 	fn test_verify_public_key()
 	{
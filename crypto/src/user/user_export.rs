@@ -8,8 +8,18 @@ use sentc_crypto_std_keys::util::{MasterKeyFormat, PublicKey, SecretKey, SignKey
 use sentc_crypto_utils::user;
 use serde_json::from_str;
 
-use crate::entities::user::{UserDataExport, UserKeyDataExport};
-use crate::keys::std::StdUser;
+use crate::entities::user::{
+	AccountArchiveExport,
+	AccountArchiveGroupExport,
+	DevicePairingStart,
+	KeyRotationLink,
+	UserDataExport,
+	UserKeyDataExport,
+	VerifiedKeyRecord,
+	VerifyKeyState,
+};
+use crate::keys::std::{StdAccountArchiveGroup, StdUser};
+use crate::user::pin_store::SerializablePinStore;
 use crate::{group, SdkError};
 
 pub type StdDeviceKeyDataInt = user::DeviceKeyDataInt<SecretKey, PublicKey, SignKey, VerifyKey>;
@@ -76,6 +86,56 @@ pub fn prepare_register_device(server_output: &str, user_keys: &str, key_session
 	))
 }
 
+/**
+Returns the state of the new device. Show the payload as qr code and keep the nonce on the device.
+ */
+pub fn prepare_device_pairing(server_output: &str) -> Result<DevicePairingStart, String>
+{
+	Ok(StdUser::prepare_device_pairing(server_output)?)
+}
+
+pub fn respond_device_pairing(payload: &str, sign_key: &str, verify_key: &str) -> Result<String, String>
+{
+	let sign_key: SignKey = sign_key.parse()?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdUser::respond_device_pairing(payload, &sign_key, &verify_key)?)
+}
+
+/**
+Returns the reveal for the existing device, the code and the verify key of the user as json string
+ */
+pub fn reveal_device_pairing(payload: &str, nonce: &str, response: &str) -> Result<(String, String, String), String>
+{
+	let start = DevicePairingStart {
+		payload: payload.into(),
+		nonce: nonce.into(),
+	};
+
+	let (reveal, code, verify_key) = StdUser::reveal_device_pairing(&start, response)?;
+
+	Ok((
+		reveal,
+		code,
+		verify_key
+			.to_string()
+			.map_err(|_e| SdkError::JsonToStringFailed)?,
+	))
+}
+
+/**
+Returns the code. The server output is the register output of the new device, fetched with the token of the payload.
+ */
+pub fn verify_device_pairing(payload: &str, server_output: &str, response: &str, reveal: &str) -> Result<String, String>
+{
+	Ok(StdUser::verify_device_pairing(
+		payload,
+		server_output,
+		response,
+		reveal,
+	)?)
+}
+
 pub fn prepare_login_start(user_id: &str) -> Result<String, String>
 {
 	Ok(super::user::prepare_login_start(user_id)?)
@@ -157,7 +217,7 @@ pub fn reset_password(new_password: &str, decrypted_private_key: &str, decrypted
 	)?)
 }
 
-pub fn create_safety_number(verify_key_1: &str, user_id_1: &str, verify_key_2: Option<&str>, user_id_2: Option<&str>) -> Result<String, String>
+fn import_safety_number_keys(verify_key_1: &str, verify_key_2: Option<&str>) -> Result<(UserVerifyKeyData, Option<UserVerifyKeyData>), SdkError>
 {
 	let verify_key_1 = UserVerifyKeyData::from_string(verify_key_1).map_err(SdkError::JsonParseFailed)?;
 	let verify_key_2 = match verify_key_2 {
@@ -165,6 +225,13 @@ pub fn create_safety_number(verify_key_1: &str, user_id_1: &str, verify_key_2: O
 		None => None,
 	};
 
+	Ok((verify_key_1, verify_key_2))
+}
+
+pub fn create_safety_number(verify_key_1: &str, user_id_1: &str, verify_key_2: Option<&str>, user_id_2: Option<&str>) -> Result<String, String>
+{
+	let (verify_key_1, verify_key_2) = import_safety_number_keys(verify_key_1, verify_key_2)?;
+
 	Ok(StdUser::create_safety_number(
 		&verify_key_1,
 		user_id_1,
@@ -173,6 +240,86 @@ pub fn create_safety_number(verify_key_1: &str, user_id_1: &str, verify_key_2: O
 	)?)
 }
 
+pub fn create_safety_number_digits(verify_key_1: &str, user_id_1: &str, verify_key_2: Option<&str>, user_id_2: Option<&str>)
+	-> Result<String, String>
+{
+	let (verify_key_1, verify_key_2) = import_safety_number_keys(verify_key_1, verify_key_2)?;
+
+	Ok(StdUser::create_safety_number_digits(
+		&verify_key_1,
+		user_id_1,
+		verify_key_2.as_ref(),
+		user_id_2,
+	)?)
+}
+
+/**
+Returns the words separated by a space
+ */
+pub fn create_safety_number_words(verify_key_1: &str, user_id_1: &str, verify_key_2: Option<&str>, user_id_2: Option<&str>)
+	-> Result<String, String>
+{
+	let (verify_key_1, verify_key_2) = import_safety_number_keys(verify_key_1, verify_key_2)?;
+
+	let words = StdUser::create_safety_number_words(&verify_key_1, user_id_1, verify_key_2.as_ref(), user_id_2)?;
+
+	Ok(words.join(" "))
+}
+
+pub fn create_safety_number_qr(verify_key_1: &str, user_id_1: &str, verify_key_2: Option<&str>, user_id_2: Option<&str>) -> Result<String, String>
+{
+	let (verify_key_1, verify_key_2) = import_safety_number_keys(verify_key_1, verify_key_2)?;
+
+	Ok(StdUser::create_safety_number_qr(
+		&verify_key_1,
+		user_id_1,
+		verify_key_2.as_ref(),
+		user_id_2,
+	)?)
+}
+
+pub fn verify_safety_number_qr(
+	payload: &str,
+	verify_key_1: &str,
+	user_id_1: &str,
+	verify_key_2: Option<&str>,
+	user_id_2: Option<&str>,
+) -> Result<bool, String>
+{
+	let (verify_key_1, verify_key_2) = import_safety_number_keys(verify_key_1, verify_key_2)?;
+
+	Ok(StdUser::verify_safety_number_qr(
+		payload,
+		&verify_key_1,
+		user_id_1,
+		verify_key_2.as_ref(),
+		user_id_2,
+	)?)
+}
+
+/**
+Returns the record as json string
+ */
+pub fn create_verified_key_record(user_id: &str, verify_key: &str) -> Result<String, String>
+{
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	let record = super::user::create_verified_key_record(user_id, &verify_key);
+
+	Ok(serde_json::to_string(&record).map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
+/**
+Returns true if the verify key is still the verified key
+ */
+pub fn check_verified_key_record(record: &str, verify_key: &str) -> Result<bool, String>
+{
+	let record: VerifiedKeyRecord = from_str(record).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(super::user::check_verified_key_record(&record, &verify_key) == VerifyKeyState::Verified)
+}
+
 pub fn verify_user_public_key(verify_key: &str, public_key: &str) -> Result<bool, String>
 {
 	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
@@ -181,6 +328,117 @@ pub fn verify_user_public_key(verify_key: &str, public_key: &str) -> Result<bool
 	Ok(StdUser::verify_user_public_key(&verify_key, &public_key)?)
 }
 
+/**
+Pins the verify key in the store and returns the new store.
+
+The store is the json string of the serializable pin store. An empty string is an empty store.
+ */
+pub fn pin_verify_key(store: &str, user_id: &str, verify_key: &str) -> Result<String, String>
+{
+	let mut store = SerializablePinStore::from_string(store)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	StdUser::pin_verify_key(&mut store, user_id, &verify_key)?;
+
+	Ok(store.to_string()?)
+}
+
+pub fn check_pinned_public_key(store: &str, id: &str, public_key: &str) -> Result<(), String>
+{
+	let store = SerializablePinStore::from_string(store)?;
+	let public_key = UserPublicKeyData::from_string(public_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdUser::check_pinned_public_key(&store, id, &public_key)?)
+}
+
+pub fn create_key_rotation_link(previous_sign_key: &str, previous_verify_key: &str, verify_key: &str, public_key: &str) -> Result<String, String>
+{
+	let previous_sign_key: SignKey = previous_sign_key.parse()?;
+	let previous_verify_key = UserVerifyKeyData::from_string(previous_verify_key).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+	let public_key = UserPublicKeyData::from_string(public_key).map_err(SdkError::JsonParseFailed)?;
+
+	let link = StdUser::create_key_rotation_link(&previous_sign_key, &previous_verify_key, &verify_key, &public_key)?;
+
+	Ok(serde_json::to_string(&link).map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
+/**
+Returns the latest verify key of the chain. The links are a json array.
+ */
+pub fn verify_key_rotation_chain(verify_key: &str, links: &str) -> Result<String, String>
+{
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+	let links: Vec<KeyRotationLink> = from_str(links).map_err(SdkError::JsonParseFailed)?;
+
+	let latest = StdUser::verify_key_rotation_chain(&verify_key, &links)?;
+
+	Ok(latest
+		.to_string()
+		.map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
+/**
+Returns the new store
+ */
+pub fn update_pin_by_rotation_chain(store: &str, user_id: &str, links: &str) -> Result<String, String>
+{
+	let mut store = SerializablePinStore::from_string(store)?;
+	let links: Vec<KeyRotationLink> = from_str(links).map_err(SdkError::JsonParseFailed)?;
+
+	StdUser::update_pin_by_rotation_chain(&mut store, user_id, &links)?;
+
+	Ok(store.to_string()?)
+}
+
+pub fn lock_user_data_with_pin(pin: &str, data: UserDataExport) -> Result<String, String>
+{
+	Ok(StdUser::lock_user_data_with_pin(pin, data.try_into()?)?)
+}
+
+pub fn lock_user_data_with_key(key: &str, data: UserDataExport) -> Result<String, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	Ok(StdUser::lock_user_data_with_key(&key, data.try_into()?)?)
+}
+
+/**
+Seals the keys of the user and of the groups with the passphrase
+ */
+pub fn export_account(passphrase: &str, data: UserDataExport, groups: Vec<AccountArchiveGroupExport>) -> Result<String, String>
+{
+	let groups = groups
+		.into_iter()
+		.map(|g| g.try_into())
+		.collect::<Result<Vec<StdAccountArchiveGroup>, SdkError>>()?;
+
+	Ok(StdUser::export_account(passphrase, data.try_into()?, groups)?)
+}
+
+pub fn import_account(passphrase: &str, sealed: &str) -> Result<AccountArchiveExport, String>
+{
+	let archive = StdUser::import_account(passphrase, sealed)?;
+
+	Ok(archive.try_into()?)
+}
+
+pub fn unlock_user_data_with_pin(pin: &str, sealed: &str) -> Result<UserDataExport, String>
+{
+	let out = StdUser::unlock_user_data_with_pin(pin, sealed)?;
+
+	Ok(out.try_into()?)
+}
+
+pub fn unlock_user_data_with_key(key: &str, sealed: &str) -> Result<UserDataExport, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let out = StdUser::unlock_user_data_with_key(&key, sealed)?;
+
+	Ok(out.try_into()?)
+}
+
 #[cfg(test)]
 mod test
 {
@@ -2,7 +2,8 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use base64ct::{Base64, Encoding};
-use sentc_crypto_core::cryptomat::{ClientRandomValueComposer, DeriveAuthKeyForAuth, DeriveAuthKeyForAuthComposer, Pk};
+use sentc_crypto_core::cryptomat::{ClientRandomValueComposer, DeriveAuthKeyForAuth, DeriveAuthKeyForAuthComposer, PakeServer, Pk};
+use sentc_crypto_core::Error;
 use sentc_crypto_utils::cryptomat::StaticKeyComposerWrapper;
 
 use crate::util::public::generate_salt_from_base64;
@@ -92,3 +93,105 @@ pub fn encrypt_login_verify_challenge<P: StaticKeyComposerWrapper>(
 
 	Ok(Base64::encode_string(&encrypted_eph_key))
 }
+
+//__________________________________________________________________________________________________
+//opaque, all values are base64 encoded
+//the login contract with the inputs and outputs of sentc_crypto_utils::user:
+//1. login start: PakeLoginStartInput -> pake_login_start, store the state under a new login state id
+//	and return the id and the credential response in the PakeLoginStartServerOutput
+//2. login finish: PakeLoginFinishInput -> pake_login_finish with the stored state of the login state id,
+//	if ok mark the id as authenticated and return the DoneLoginServerOutput with the challenge
+//3. pake verify login: PakeVerifyLoginInput, the login state id must be authenticated for the same device identifier
+//	and the challenge must match, then delete the id and return the VerifyLoginOutput like the verify login endpoint.
+//	The verify login endpoint can't be used because the PakeVerifyLoginInput has no auth key.
+
+fn decode_pake_message(message: &str) -> Result<Vec<u8>, SdkError>
+{
+	Ok(Base64::decode_vec(message).map_err(|_| Error::PakeMessageWrongFormat)?)
+}
+
+/**
+Creates the server setup for opaque.
+
+Create it only once and store it like a secret key. Every registration and login must use the same setup.
+ */
+pub fn pake_server_setup<P: PakeServer>() -> Result<String, SdkError>
+{
+	Ok(Base64::encode_string(&P::setup()?))
+}
+
+/**
+The response for the registration request of the client.
+
+The credential identifier must be unique for the user, e.g. the device id or the user identifier.
+ */
+pub fn pake_registration_response<P: PakeServer>(setup: &str, registration_request: &str, credential_identifier: &str) -> Result<String, SdkError>
+{
+	let res = P::registration_response(
+		&decode_pake_message(setup)?,
+		&decode_pake_message(registration_request)?,
+		credential_identifier.as_bytes(),
+	)?;
+
+	Ok(Base64::encode_string(&res))
+}
+
+/**
+Returns the password file from the registration upload. Store it instead of the hashed auth key.
+ */
+pub fn pake_registration_finish<P: PakeServer>(registration_upload: &str) -> Result<String, SdkError>
+{
+	let password_file = P::registration_finish(&decode_pake_message(registration_upload)?)?;
+
+	Ok(Base64::encode_string(&password_file))
+}
+
+/**
+Returns the server state and the credential response for the client.
+
+Call it with None as password file if the user was not found, so the client can't see if the user exists.
+Store the state under a new login state id until the client sends the credential finalization with this id.
+ */
+pub fn pake_login_start<P: PakeServer>(
+	setup: &str,
+	password_file: Option<&str>,
+	credential_request: &str,
+	credential_identifier: &str,
+) -> Result<(String, String), SdkError>
+{
+	let password_file = match password_file {
+		Some(f) => Some(decode_pake_message(f)?),
+		None => None,
+	};
+
+	let (state, credential_response) = P::login_start(
+		&decode_pake_message(setup)?,
+		password_file.as_deref(),
+		&decode_pake_message(credential_request)?,
+		credential_identifier.as_bytes(),
+	)?;
+
+	Ok((
+		Base64::encode_string(&state),
+		Base64::encode_string(&credential_response),
+	))
+}
+
+/**
+Checks the credential finalization of the client.
+
+Fails if the client used a wrong password. If it is ok, the client is authenticated and gets the done login output
+(the challenge for the device keys) like after the auth key check. The session key of the exchange is not needed for the login,
+so it never leaves the server and the client never sends it.
+
+Only after this fn returned ok, the login state id of the state can be used for the pake verify login.
+ */
+pub fn pake_login_finish<P: PakeServer>(state: &str, credential_finalization: &str) -> Result<(), SdkError>
+{
+	P::login_finish(
+		&decode_pake_message(state)?,
+		&decode_pake_message(credential_finalization)?,
+	)?;
+
+	Ok(())
+}
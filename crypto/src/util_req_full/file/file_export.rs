@@ -1,11 +1,21 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use futures::io::AsyncRead;
+use futures::stream::{Stream, TryStreamExt};
+use sentc_crypto_common::file::FilePartListItem;
+#[cfg(feature = "unreleased_routes")]
+use sentc_crypto_common::user::UserVerifyKeyData;
+#[cfg(feature = "unreleased_routes")]
+use sentc_crypto_std_keys::util::SignKey;
 use sentc_crypto_std_keys::util::SymmetricKey;
 
+use super::TransferOptions;
 use crate::crypto::{prepare_sign_key, prepare_verify_key};
+use crate::file::FileManifestBuilder;
 use crate::keys::std::StdFileEncryptor;
 use crate::util::{export_core_sym_key_to_string, import_core_sym_key};
+use crate::SdkError;
 
 pub async fn download_and_decrypt_file_part_start(
 	base_url: String,
@@ -47,6 +57,78 @@ pub async fn download_and_decrypt_file_part(
 	Ok((decrypted, exported_file_key))
 }
 
+/**
+Download and decrypt a part of the chained or the derived file format.
+
+pre_key is the exported key of the previous part, none for the first part or for derived files.
+Returns the key for the next part, none for derived files.
+ */
+#[allow(clippy::too_many_arguments)]
+pub async fn download_and_decrypt_file_part_auto(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	part_id: &str,
+	sequence: i32,
+	content_key: &str,
+	pre_key: Option<&str>,
+	verify_key_data: Option<&str>,
+) -> Result<(Vec<u8>, Option<String>), String>
+{
+	let verify_key = prepare_verify_key(verify_key_data)?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	let pre_key = match pre_key {
+		Some(k) => Some(import_core_sym_key(k)?),
+		None => None,
+	};
+
+	let (decrypted, next_key) = StdFileEncryptor::download_and_decrypt_file_part_auto(
+		base_url,
+		url_prefix,
+		auth_token,
+		part_id,
+		sequence,
+		&key,
+		pre_key.as_ref(),
+		verify_key.as_ref(),
+	)
+	.await?;
+
+	let exported_file_key = match next_key {
+		Some(k) => Some(export_core_sym_key_to_string(k)?),
+		None => None,
+	};
+
+	Ok((decrypted, exported_file_key))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn download_and_decrypt_file_part_derived(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	part_id: &str,
+	sequence: i32,
+	content_key: &str,
+	verify_key_data: Option<&str>,
+) -> Result<Vec<u8>, String>
+{
+	let verify_key = prepare_verify_key(verify_key_data)?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	Ok(StdFileEncryptor::download_and_decrypt_file_part_derived(
+		base_url,
+		url_prefix,
+		auth_token,
+		part_id,
+		sequence,
+		&key,
+		verify_key.as_ref(),
+	)
+	.await?)
+}
+
 //__________________________________________________________________________________________________
 
 #[allow(clippy::too_many_arguments)]
@@ -116,3 +198,320 @@ pub async fn upload_part(
 
 	Ok(export_core_sym_key_to_string(next_file_key)?)
 }
+
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_part_derived(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	jwt: &str,
+	session_id: &str,
+	end: bool,
+	sequence: i32,
+	content_key: &str,
+	sign_key: Option<&str>,
+	part: &[u8],
+) -> Result<(), String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	Ok(StdFileEncryptor::upload_part_derived(
+		base_url,
+		url_prefix,
+		auth_token,
+		jwt,
+		session_id,
+		end,
+		sequence,
+		&key,
+		sign_key.as_ref(),
+		part,
+	)
+	.await?)
+}
+
+/**
+Upload a part and add it to the manifest.
+
+content_key is the content key of the file. pre_key is the exported file key of the previous part, none for the first part.
+manifest is the returned manifest state of the previous part, none for the first part.
+Returns the next file key and the new manifest state.
+ */
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_part_with_manifest(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	jwt: &str,
+	session_id: &str,
+	end: bool,
+	sequence: i32,
+	content_key: &str,
+	pre_key: Option<&str>,
+	sign_key: Option<&str>,
+	part: &[u8],
+	manifest: Option<&str>,
+) -> Result<(String, String), String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+
+	let mut manifest: FileManifestBuilder = match manifest {
+		Some(m) => serde_json::from_str(m).map_err(SdkError::JsonParseFailed)?,
+		None => FileManifestBuilder::new(),
+	};
+
+	let next_file_key = match pre_key {
+		Some(k) => {
+			let key = import_core_sym_key(k)?;
+
+			StdFileEncryptor::upload_part_with_manifest(
+				base_url,
+				url_prefix,
+				auth_token,
+				jwt,
+				session_id,
+				end,
+				sequence,
+				&key,
+				sign_key.as_ref(),
+				part,
+				&mut manifest,
+			)
+			.await?
+		},
+		None => {
+			let key: SymmetricKey = content_key.parse()?;
+
+			StdFileEncryptor::upload_part_with_manifest(
+				base_url,
+				url_prefix,
+				auth_token,
+				jwt,
+				session_id,
+				end,
+				sequence,
+				&key.key,
+				sign_key.as_ref(),
+				part,
+				&mut manifest,
+			)
+			.await?
+		},
+	};
+
+	Ok((
+		export_core_sym_key_to_string(next_file_key)?,
+		serde_json::to_string(&manifest).map_err(|_| SdkError::JsonToStringFailed)?,
+	))
+}
+
+/**
+Sign the manifest state of the last part and upload it for the file.
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn upload_manifest(base_url: String, auth_token: &str, jwt: &str, file_id: String, manifest: &str, sign_key: &str) -> Result<(), String>
+{
+	let sign_key: SignKey = sign_key.parse()?;
+	let manifest: FileManifestBuilder = serde_json::from_str(manifest).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdFileEncryptor::upload_manifest(base_url, auth_token, jwt, file_id, manifest, &sign_key).await?)
+}
+
+/**
+Continue an upload from a checkpoint and return the new checkpoint.
+ */
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_part_resume(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	jwt: &str,
+	end: bool,
+	checkpoint_key: &str,
+	checkpoint: &str,
+	sign_key: Option<&str>,
+	part: &[u8],
+) -> Result<String, String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+	let key: SymmetricKey = checkpoint_key.parse()?;
+
+	Ok(StdFileEncryptor::upload_part_resume(
+		base_url,
+		url_prefix,
+		auth_token,
+		jwt,
+		end,
+		&key,
+		checkpoint,
+		sign_key.as_ref(),
+		part,
+	)
+	.await?)
+}
+
+//__________________________________________________________________________________________________
+
+/**
+Reads, encrypts and uploads the file part by part. Returns the number of uploaded parts.
+
+concurrency is the number of parts in transfer at the same time, without it the parts are uploaded one after another.
+ */
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_file_from_reader<R: AsyncRead + Unpin>(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	jwt: &str,
+	session_id: &str,
+	content_key: &str,
+	sign_key: Option<&str>,
+	reader: R,
+	part_size: usize,
+	concurrency: Option<usize>,
+) -> Result<i32, String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	Ok(StdFileEncryptor::upload_file_from_reader_concurrent(
+		base_url,
+		url_prefix,
+		auth_token,
+		jwt,
+		session_id,
+		&key,
+		sign_key.as_ref(),
+		reader,
+		part_size,
+		transfer_options(concurrency),
+	)
+	.await?)
+}
+
+/**
+Like upload_file_from_reader but every part is signed and the signed manifest is uploaded after the last part.
+ */
+#[cfg(feature = "unreleased_routes")]
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_file_from_reader_with_manifest<R: AsyncRead + Unpin>(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	jwt: &str,
+	file_id: &str,
+	session_id: &str,
+	content_key: &str,
+	sign_key: &str,
+	reader: R,
+	part_size: usize,
+	concurrency: Option<usize>,
+) -> Result<i32, String>
+{
+	let sign_key: SignKey = sign_key.parse()?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	Ok(StdFileEncryptor::upload_file_from_reader_with_manifest(
+		base_url,
+		url_prefix,
+		auth_token,
+		jwt,
+		file_id,
+		session_id,
+		&key,
+		&sign_key,
+		reader,
+		part_size,
+		transfer_options(concurrency),
+	)
+	.await?)
+}
+
+/**
+Downloads and decrypts the file part by part.
+
+part_list is the json of the first page of the part list of the file.
+The stream owns the keys and all other values, so it can be handed over to a js stream.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn download_file_stream(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	file_id: String,
+	part_list: &str,
+	content_key: &str,
+	verify_key_data: Option<&str>,
+	concurrency: Option<usize>,
+) -> Result<impl Stream<Item = Result<Vec<u8>, String>>, String>
+{
+	let part_list: Vec<FilePartListItem> = serde_json::from_str(part_list).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = prepare_verify_key(verify_key_data)?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	let stream = StdFileEncryptor::download_file_stream_internally::<SymmetricKey, _, _>(
+		base_url,
+		url_prefix,
+		auth_token,
+		file_id,
+		part_list,
+		key,
+		verify_key,
+		transfer_options(concurrency),
+	)
+	.map_err(String::from);
+
+	Ok(stream)
+}
+
+/**
+Downloads and decrypts the file part by part and checks every part against the signed manifest of the file.
+
+Returns the id of the verify key which signed the manifest and the stream.
+The file is only complete when the stream ends without an error.
+ */
+#[cfg(feature = "unreleased_routes")]
+#[allow(clippy::too_many_arguments)]
+pub async fn download_file_stream_verified(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	file_id: String,
+	part_list: &str,
+	content_key: &str,
+	verify_key_data: &str,
+	concurrency: Option<usize>,
+) -> Result<(String, impl Stream<Item = Result<Vec<u8>, String>>), String>
+{
+	let part_list: Vec<FilePartListItem> = serde_json::from_str(part_list).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key_data).map_err(SdkError::JsonParseFailed)?;
+	let key: SymmetricKey = content_key.parse()?;
+
+	let (verify_key_id, stream) = StdFileEncryptor::download_file_stream_verified_internally::<SymmetricKey, _, _>(
+		base_url,
+		url_prefix,
+		auth_token,
+		file_id,
+		part_list,
+		key,
+		verify_key,
+		transfer_options(concurrency),
+	)
+	.await?;
+
+	Ok((verify_key_id, stream.map_err(String::from)))
+}
+
+fn transfer_options(concurrency: Option<usize>) -> TransferOptions
+{
+	match concurrency {
+		Some(concurrency) => {
+			TransferOptions {
+				concurrency,
+				..Default::default()
+			}
+		},
+		None => TransferOptions::sequential(),
+	}
+}
@@ -0,0 +1,822 @@
+//! Up- and download of whole files with streams.
+//!
+//! Only a few parts are in memory at the same time, so big files can be used without loading the whole file.
+//! Parts can be transferred concurrently. The encryption and decryption is still in order because of the key chain of the chained file format.
+
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use futures::future;
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use sentc_crypto_common::file::FilePartListItem;
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_core::cryptomat::{SymKeyComposer, SymKeyGen};
+use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::http::{make_req, make_req_buffer, make_req_buffer_body, Bytes, HttpMethod};
+use sentc_crypto_utils::{handle_general_server_response, handle_server_response};
+
+use crate::file::FileEncryptor;
+#[cfg(feature = "unreleased_routes")]
+use crate::file::{FileManifestBuilder, FileManifestVerifier};
+use crate::SdkError;
+
+/**
+The default size of a part (4 mb)
+ */
+pub const DEFAULT_PART_SIZE: usize = 4 * 1024 * 1024;
+
+/**
+Options for the concurrent up- and download.
+
+concurrency is the max number of parts in transfer at the same time. 0 is handled like 1.
+retries is the number of retries for a part when the request failed.
+Only requests that could not be sent are retried.
+Errors from the server or while reading the response are not retried, because the server might already have the part.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct TransferOptions
+{
+	pub concurrency: usize,
+	pub retries: u32,
+}
+
+impl Default for TransferOptions
+{
+	fn default() -> Self
+	{
+		Self {
+			concurrency: 4,
+			retries: 2,
+		}
+	}
+}
+
+impl TransferOptions
+{
+	/**
+	One part after another and without retries
+	 */
+	pub fn sequential() -> Self
+	{
+		Self {
+			concurrency: 1,
+			retries: 0,
+		}
+	}
+}
+
+fn is_request_err(error: &SdkUtilError) -> bool
+{
+	matches!(error, SdkUtilError::RequestErr(_))
+}
+
+/**
+Reads until the part is full or the reader is at the end.
+ */
+async fn read_part<R: AsyncRead + Unpin>(reader: &mut R, part_size: usize) -> Result<Vec<u8>, SdkError>
+{
+	let mut part = Vec::with_capacity(part_size);
+
+	(&mut *reader)
+		.take(part_size as u64)
+		.read_to_end(&mut part)
+		.await
+		.map_err(|_| SdkError::FileReadFailed)?;
+
+	Ok(part)
+}
+
+struct EncryptedPart
+{
+	sequence: i32,
+	body: Bytes,
+	end: bool,
+}
+
+fn part_url(url_prefix: &str, session_id: &str, part: &EncryptedPart) -> String
+{
+	url_prefix.to_string() + "/" + session_id + "/" + part.sequence.to_string().as_str() + "/" + part.end.to_string().as_str()
+}
+
+async fn upload_encrypted_part(url: &str, body: &Bytes, auth_token: &str, jwt: &str, retries: u32) -> Result<(), SdkError>
+{
+	let mut attempt = 0;
+
+	loop {
+		//clone of the bytes is only a ref count
+		match make_req_buffer_body(HttpMethod::POST, url, auth_token, body.clone(), Some(jwt), None).await {
+			Ok(res) => {
+				handle_general_server_response(&res)?;
+
+				return Ok(());
+			},
+			Err(e) if attempt < retries && is_request_err(&e) => attempt += 1,
+			Err(e) => return Err(e.into()),
+		}
+	}
+}
+
+/**
+Uploads up to options.concurrency parts of the stream at the same time and returns the number of parts.
+
+The uploads run while the next parts are read and encrypted.
+The last part is uploaded after all other parts are done, so the server only gets the end for a complete file.
+ */
+async fn upload_encrypted_part_stream(
+	parts: impl Stream<Item = Result<EncryptedPart, SdkError>>,
+	url_prefix: &str,
+	session_id: &str,
+	auth_token: &str,
+	jwt: &str,
+	options: TransferOptions,
+) -> Result<i32, SdkError>
+{
+	//the last part is not uploaded here but returned, so it can be uploaded after all other parts are done
+	let (part_count, last_part) = parts
+		.map_ok(move |part| {
+			async move {
+				if part.end {
+					return Ok(Some(part));
+				}
+
+				upload_encrypted_part(
+					&part_url(url_prefix, session_id, &part),
+					&part.body,
+					auth_token,
+					jwt,
+					options.retries,
+				)
+				.await?;
+
+				Ok::<_, SdkError>(None)
+			}
+		})
+		.try_buffer_unordered(options.concurrency.max(1))
+		.try_fold((0, None), |(count, last), res| {
+			future::ready(Ok((count + 1, res.or(last))))
+		})
+		.await?;
+
+	//the stream always ends with the last part
+	if let Some(last_part) = last_part {
+		upload_encrypted_part(
+			&part_url(url_prefix, session_id, &last_part),
+			&last_part.body,
+			auth_token,
+			jwt,
+			options.retries,
+		)
+		.await?;
+	}
+
+	Ok(part_count)
+}
+
+async fn download_encrypted_part(url: String, auth_token: &str, retries: u32) -> Result<Vec<u8>, SdkError>
+{
+	let mut attempt = 0;
+
+	loop {
+		match make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await {
+			Ok(res) => return Ok(res),
+			Err(e) if attempt < retries && is_request_err(&e) => attempt += 1,
+			Err(e) => return Err(e.into()),
+		}
+	}
+}
+
+/**
+All parts of the file. The next page of the part list is fetched when the actual page is done.
+
+The stream only ends after the server returned an empty page for the sequence of the last part.
+The parts must come in the order of the sequence without a gap, starting at 0.
+If no part was seen or a part is missing, the stream returns FileDownloadIncomplete instead of ending like a complete file.
+A server can still hold back the last parts, use the signed manifest to check this.
+ */
+fn part_list_stream(
+	base_url: String,
+	auth_token: String,
+	file_id: String,
+	part_list: Vec<FilePartListItem>,
+) -> impl Stream<Item = Result<FilePartListItem, SdkError>>
+{
+	let state: (VecDeque<FilePartListItem>, Option<i32>) = (VecDeque::from(part_list), None);
+
+	stream::try_unfold(state, move |(mut parts, last_sequence)| {
+		let base_url = base_url.clone();
+		let auth_token = auth_token.clone();
+		let file_id = file_id.clone();
+
+		async move {
+			if parts.is_empty() {
+				//every file got at least one part, so the download never saw the last part
+				let last_sequence = match last_sequence {
+					Some(s) => s,
+					None => return Err(SdkError::FileDownloadIncomplete),
+				};
+
+				let url = base_url + "/api/v1/file/" + &file_id + "/part_fetch/" + last_sequence.to_string().as_str();
+
+				let res = make_req(HttpMethod::GET, &url, &auth_token, None, None, None).await?;
+
+				let page: Vec<FilePartListItem> = handle_server_response(&res)?;
+
+				parts.extend(page);
+			}
+
+			let part = match parts.pop_front() {
+				Some(p) => p,
+				//no more pages, the part before was the last part
+				None => return Ok(None),
+			};
+
+			let expected_sequence = match last_sequence {
+				Some(s) => s + 1,
+				None => 0,
+			};
+
+			if part.sequence != expected_sequence {
+				return Err(SdkError::FileDownloadIncomplete);
+			}
+
+			let sequence = part.sequence;
+
+			Ok::<_, SdkError>(Some((part, (parts, Some(sequence)))))
+		}
+	})
+}
+
+/**
+The encrypted parts of the file with the sequence of every part.
+
+Up to options.concurrency parts are downloaded at the same time but the parts are returned in order.
+ */
+fn download_encrypted_part_stream(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	file_id: String,
+	part_list: Vec<FilePartListItem>,
+	options: TransferOptions,
+) -> impl Stream<Item = Result<(i32, Vec<u8>), SdkError>>
+{
+	let url_prefix = match url_prefix {
+		Some(p) => p,
+		None => base_url.clone() + "/api/v1/file/part",
+	};
+
+	part_list_stream(base_url, auth_token.clone(), file_id, part_list)
+		.map_ok(move |part| {
+			let url = url_prefix.clone() + "/" + &part.part_id;
+			let auth_token = auth_token.clone();
+
+			async move {
+				let res = download_encrypted_part(url, &auth_token, options.retries).await?;
+
+				Ok::<_, SdkError>((part.sequence, res))
+			}
+		})
+		.try_buffered(options.concurrency.max(1))
+}
+
+impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper> FileEncryptor<S, SC, SignK, VC>
+{
+	/**
+	Reads the file from the reader in parts of part_size and encrypts every part in the order of the sequence.
+
+	Only the actual and the next part are in memory.
+	The next part is needed to know if the actual part is the last part of the file.
+	 */
+	fn encrypted_part_stream<'a, R: AsyncRead + Unpin + 'a>(
+		reader: R,
+		part_size: usize,
+		content_key: &'a impl SymKeyWrapper,
+		sign_key: Option<&'a SignK>,
+	) -> impl Stream<Item = Result<EncryptedPart, SdkError>> + 'a
+	where
+		S: 'a,
+		SignK: 'a,
+	{
+		//the part to encrypt, none for the first part and after the last part
+		let state: (R, Option<Vec<u8>>, i32, Option<S::SymmetricKey>) = (reader, None, 0, None);
+
+		//the next part is only read when there is space for a new upload
+		stream::try_unfold(state, move |(mut reader, part, sequence, next_key)| {
+			async move {
+				if part_size == 0 {
+					return Err(SdkError::FilePartSizeInvalid);
+				}
+
+				let part = match (part, &next_key) {
+					(Some(p), _) => p,
+					(None, None) => read_part(&mut reader, part_size).await?,
+					//the last part was already returned
+					(None, Some(_)) => return Ok(None),
+				};
+
+				//a part smaller than the part size is always the last part
+				let next_part = if part.len() < part_size {
+					Vec::new()
+				} else {
+					read_part(&mut reader, part_size).await?
+				};
+
+				let end = next_part.is_empty();
+
+				let (encrypted, key) = match &next_key {
+					None => Self::encrypt_file_part_start(content_key, &part, sign_key)?,
+					Some(k) => Self::encrypt_file_part(k, &part, sign_key)?,
+				};
+
+				let encrypted = EncryptedPart {
+					sequence,
+					body: Bytes::from(encrypted),
+					end,
+				};
+
+				let next_part = if end { None } else { Some(next_part) };
+
+				Ok::<_, SdkError>(Some((encrypted, (reader, next_part, sequence + 1, Some(key)))))
+			}
+		})
+	}
+
+	/**
+	Reads the file from the reader in parts of part_size, then encrypts and uploads every part.
+
+	Only the actual and the next part are in memory.
+	The next part is needed to know if the actual part is the last part of the file.
+	Returns the number of uploaded parts.
+
+	For a stream of bytes use TryStreamExt::into_async_read to get a reader.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_file_from_reader<R: AsyncRead + Unpin>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		session_id: &str,
+		content_key: &impl SymKeyWrapper,
+		sign_key: Option<&SignK>,
+		reader: R,
+		part_size: usize,
+	) -> Result<i32, SdkError>
+	{
+		Self::upload_file_from_reader_concurrent(
+			base_url,
+			url_prefix,
+			auth_token,
+			jwt,
+			session_id,
+			content_key,
+			sign_key,
+			reader,
+			part_size,
+			TransferOptions::sequential(),
+		)
+		.await
+	}
+
+	/**
+	Like upload_file_from_reader but uploads up to options.concurrency parts at the same time.
+
+	The next parts are read and encrypted while the other parts are uploaded.
+	The parts are still encrypted in order because every part needs the key of the part before.
+	Every part got its sequence, so the server keeps the order.
+	The last part is uploaded after all other parts are done, so the server only gets the end for a complete file.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_file_from_reader_concurrent<R: AsyncRead + Unpin>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		session_id: &str,
+		content_key: &impl SymKeyWrapper,
+		sign_key: Option<&SignK>,
+		reader: R,
+		part_size: usize,
+		options: TransferOptions,
+	) -> Result<i32, SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let parts = Self::encrypted_part_stream(reader, part_size, content_key, sign_key);
+
+		upload_encrypted_part_stream(parts, &url_prefix, session_id, auth_token, jwt, options).await
+	}
+
+	/**
+	Like upload_file_from_reader_concurrent but every part is signed and added to the manifest of the file.
+
+	The signed manifest is uploaded after the last part, so the file can be downloaded with download_file_stream_verified.
+	 */
+	#[cfg(feature = "unreleased_routes")]
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_file_from_reader_with_manifest<R: AsyncRead + Unpin>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		file_id: &str,
+		session_id: &str,
+		content_key: &impl SymKeyWrapper,
+		sign_key: &SignK,
+		reader: R,
+		part_size: usize,
+		options: TransferOptions,
+	) -> Result<i32, SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url.clone() + "/api/v1/file/part",
+		};
+
+		let mut manifest = FileManifestBuilder::new();
+
+		//the parts are added in the order of the sequence, before the unordered upload
+		let parts = Self::encrypted_part_stream(reader, part_size, content_key, Some(sign_key)).inspect_ok(|part| manifest.add_part(&part.body));
+
+		let part_count = upload_encrypted_part_stream(parts, &url_prefix, session_id, auth_token, jwt, options).await?;
+
+		Self::upload_manifest(base_url, auth_token, jwt, file_id.to_string(), manifest, sign_key).await?;
+
+		Ok(part_count)
+	}
+
+	/**
+	Downloads and decrypts the file part by part.
+
+	part_list is the first page of the part list from the file meta data.
+	The next pages are fetched when all parts of the actual page are downloaded.
+	Only one part is in memory at the same time.
+	The stream returns FileDownloadIncomplete instead of ending when parts are missing,
+	so the file is only complete when the stream ends without an error.
+	 */
+	pub fn download_file_stream<'a, K: SymKeyWrapper>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &'a str,
+		file_id: &'a str,
+		part_list: Vec<FilePartListItem>,
+		content_key: &'a K,
+		verify_key_data: Option<&'a UserVerifyKeyData>,
+	) -> impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a
+	where
+		S: 'a,
+		SC: 'a,
+		SignK: 'a,
+		VC: 'a,
+	{
+		Self::download_file_stream_concurrent(
+			base_url,
+			url_prefix,
+			auth_token,
+			file_id,
+			part_list,
+			content_key,
+			verify_key_data,
+			TransferOptions::sequential(),
+		)
+	}
+
+	/**
+	Like download_file_stream but fetches up to options.concurrency parts ahead.
+
+	The parts are still decrypted and returned in order.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub fn download_file_stream_concurrent<'a, K: SymKeyWrapper>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &'a str,
+		file_id: &'a str,
+		part_list: Vec<FilePartListItem>,
+		content_key: &'a K,
+		verify_key_data: Option<&'a UserVerifyKeyData>,
+		options: TransferOptions,
+	) -> impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a
+	where
+		S: 'a,
+		SC: 'a,
+		SignK: 'a,
+		VC: 'a,
+	{
+		Self::download_file_stream_internally::<K, _, _>(
+			base_url,
+			url_prefix,
+			auth_token.to_string(),
+			file_id.to_string(),
+			part_list,
+			content_key,
+			verify_key_data,
+			options,
+		)
+	}
+
+	/**
+	The part stream of download_file_stream_concurrent.
+
+	The keys can be borrowed or owned, so a stream can also own the keys, e.g. for the export.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub(super) fn download_file_stream_internally<'a, K, CK, VK>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: String,
+		file_id: String,
+		part_list: Vec<FilePartListItem>,
+		content_key: CK,
+		verify_key_data: Option<VK>,
+		options: TransferOptions,
+	) -> impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a
+	where
+		K: SymKeyWrapper,
+		CK: Borrow<K> + 'a,
+		VK: Borrow<UserVerifyKeyData> + 'a,
+		S: 'a,
+		SC: 'a,
+		SignK: 'a,
+		VC: 'a,
+	{
+		let mut next_key: Option<SC::SymmetricKey> = None;
+
+		download_encrypted_part_stream(base_url, url_prefix, auth_token, file_id, part_list, options).and_then(move |(sequence, res)| {
+			let decrypted = Self::decrypt_file_part_auto(
+				content_key.borrow(),
+				next_key.as_ref(),
+				sequence,
+				&res,
+				verify_key_data.as_ref().map(|k| k.borrow()),
+			);
+
+			let decrypted = decrypted.map(|(decrypted, key)| {
+				next_key = key;
+				decrypted
+			});
+
+			future::ready(decrypted)
+		})
+	}
+
+	/**
+	Like download_file_stream_concurrent but every part is checked against the signed manifest of the file before it is decrypted.
+
+	The manifest is downloaded and verified first, the verify key id of the signer is returned with the stream.
+	The stream fails if a part is missing, swapped or changed. After the last part it checks that no part is missing,
+	so the output is only complete and verified when the stream ends without an error.
+	 */
+	#[cfg(feature = "unreleased_routes")]
+	#[allow(clippy::too_many_arguments)]
+	pub async fn download_file_stream_verified<'a, K: SymKeyWrapper>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &'a str,
+		file_id: &'a str,
+		part_list: Vec<FilePartListItem>,
+		content_key: &'a K,
+		verify_key_data: &'a UserVerifyKeyData,
+		options: TransferOptions,
+	) -> Result<(String, impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a), SdkError>
+	where
+		S: 'a,
+		SC: 'a,
+		SignK: 'a,
+		VC: 'a,
+	{
+		Self::download_file_stream_verified_internally::<K, _, _>(
+			base_url,
+			url_prefix,
+			auth_token.to_string(),
+			file_id.to_string(),
+			part_list,
+			content_key,
+			verify_key_data,
+			options,
+		)
+		.await
+	}
+
+	/**
+	The manifest check and the part stream of download_file_stream_verified.
+
+	The keys can be borrowed or owned, so a stream can also own the keys, e.g. for the export.
+	 */
+	#[cfg(feature = "unreleased_routes")]
+	#[allow(clippy::too_many_arguments)]
+	pub(super) async fn download_file_stream_verified_internally<'a, K, CK, VK>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: String,
+		file_id: String,
+		part_list: Vec<FilePartListItem>,
+		content_key: CK,
+		verify_key_data: VK,
+		options: TransferOptions,
+	) -> Result<(String, impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a), SdkError>
+	where
+		K: SymKeyWrapper,
+		CK: Borrow<K> + 'a,
+		VK: Borrow<UserVerifyKeyData> + 'a,
+		S: 'a,
+		SC: 'a,
+		SignK: 'a,
+		VC: 'a,
+	{
+		let manifest = Self::download_manifest(base_url.clone(), &auth_token, &file_id).await?;
+
+		let verifier = FileManifestVerifier::new::<VC>(&manifest, &file_id, verify_key_data.borrow())?;
+		let verify_key_id = verifier.verify_key_id().to_string();
+
+		let parts = download_encrypted_part_stream(base_url, url_prefix, auth_token, file_id, part_list, options);
+
+		Ok((
+			verify_key_id,
+			Self::verify_and_decrypt_part_stream::<K, _, _>(parts, verifier, content_key, verify_key_data),
+		))
+	}
+
+	/**
+	Checks every encrypted part against the manifest before it is decrypted.
+
+	The parts must come in the order of the sequence. After the last part the stream checks that no part is missing.
+	 */
+	#[cfg(feature = "unreleased_routes")]
+	fn verify_and_decrypt_part_stream<'a, K, CK, VK>(
+		parts: impl Stream<Item = Result<(i32, Vec<u8>), SdkError>> + 'a,
+		mut verifier: FileManifestVerifier,
+		content_key: CK,
+		verify_key_data: VK,
+	) -> impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a
+	where
+		K: SymKeyWrapper,
+		CK: Borrow<K> + 'a,
+		VK: Borrow<UserVerifyKeyData> + 'a,
+		S: 'a,
+		SC: 'a,
+		SignK: 'a,
+		VC: 'a,
+	{
+		let mut next_key: Option<SC::SymmetricKey> = None;
+
+		parts
+			.map(Some)
+			//a none after the last part to check if the file is complete
+			.chain(stream::once(future::ready(None)))
+			.filter_map(move |res| {
+				let out = match res {
+					Some(Ok((sequence, res))) => {
+						verifier.verify_part(&res).and_then(|_| {
+							Self::decrypt_file_part_auto(
+								content_key.borrow(),
+								next_key.as_ref(),
+								sequence,
+								&res,
+								Some(verify_key_data.borrow()),
+							)
+							.map(|(decrypted, key)| {
+								next_key = key;
+								decrypted
+							})
+						})
+					},
+					Some(Err(e)) => Err(e),
+					None => {
+						match verifier.check_complete() {
+							Ok(_) => return future::ready(None),
+							Err(e) => Err(e),
+						}
+					},
+				};
+
+				future::ready(Some(out))
+			})
+	}
+}
+
+#[cfg(test)]
+mod test
+{
+	use alloc::{format, vec};
+
+	use futures::executor::block_on;
+
+	use super::*;
+
+	fn part(sequence: i32) -> FilePartListItem
+	{
+		let part = format!(
+			r#"{{"part_id":"part_{}","sequence":{},"extern_storage":false}}"#,
+			sequence, sequence
+		);
+
+		serde_json::from_str(&part).unwrap()
+	}
+
+	fn collect_parts(part_list: Vec<FilePartListItem>) -> Vec<Result<FilePartListItem, SdkError>>
+	{
+		let stream = part_list_stream(
+			"http://127.0.0.1:0".to_string(),
+			"token".to_string(),
+			"file_id".to_string(),
+			part_list,
+		);
+
+		//the stream ends after the first error
+		block_on(stream.take(2).collect())
+	}
+
+	#[test]
+	fn test_download_without_the_last_part_is_an_error()
+	{
+		//no part at all
+		let parts = collect_parts(Vec::new());
+
+		assert_eq!(parts.len(), 1);
+		assert!(matches!(parts[0], Err(SdkError::FileDownloadIncomplete)));
+
+		//the first part is missing
+		let parts = collect_parts(vec![part(1), part(2)]);
+
+		assert!(matches!(parts[0], Err(SdkError::FileDownloadIncomplete)));
+
+		//a part in the middle is missing
+		let parts = collect_parts(vec![part(0), part(2)]);
+
+		assert_eq!(parts[0].as_ref().unwrap().sequence, 0);
+		assert!(matches!(parts[1], Err(SdkError::FileDownloadIncomplete)));
+	}
+
+	#[cfg(all(feature = "std_keys", feature = "unreleased_routes"))]
+	#[test]
+	fn test_upload_with_manifest_and_verified_download()
+	{
+		use sentc_crypto_std_keys::util::{SymmetricKey, VerifyKey};
+
+		use crate::group::test_fn::create_group;
+		use crate::keys::std::StdFileEncryptor;
+		use crate::user::test_fn::create_user;
+
+		let user = create_user();
+		let keys = &user.user_keys[0];
+		let (_, group_keys, _, _, _) = create_group(keys);
+		let content_key = &group_keys[0].group_key;
+
+		let file = (0..100u8).collect::<Vec<_>>();
+
+		//like upload_file_from_reader_with_manifest, but the parts are kept instead of sent to the server
+		let mut manifest = FileManifestBuilder::new();
+
+		let parts = block_on(
+			StdFileEncryptor::encrypted_part_stream(file.as_slice(), 30, content_key, Some(&keys.sign_key))
+				.inspect_ok(|part| manifest.add_part(&part.body))
+				.try_collect::<Vec<_>>(),
+		)
+		.unwrap();
+
+		assert_eq!(parts.len(), 4);
+		assert!(parts[3].end);
+
+		let manifest = manifest.finish("file_id".into(), &keys.sign_key).unwrap();
+
+		let download = |parts: Vec<(i32, Vec<u8>)>| {
+			let verifier = FileManifestVerifier::new::<VerifyKey>(&manifest, "file_id", &keys.exported_verify_key).unwrap();
+
+			let stream = StdFileEncryptor::verify_and_decrypt_part_stream::<SymmetricKey, _, _>(
+				stream::iter(parts.into_iter().map(Ok)),
+				verifier,
+				content_key,
+				&keys.exported_verify_key,
+			);
+
+			block_on(stream.try_concat())
+		};
+
+		let downloaded = parts
+			.iter()
+			.map(|p| (p.sequence, p.body.to_vec()))
+			.collect::<Vec<_>>();
+
+		assert_eq!(download(downloaded.clone()).unwrap(), file);
+
+		//the server held back the last part
+		assert!(matches!(
+			download(downloaded[..3].to_vec()),
+			Err(SdkError::FileManifestPartCount)
+		));
+
+		//swapped parts
+		let mut swapped = downloaded;
+		swapped.swap(1, 2);
+
+		assert!(matches!(download(swapped), Err(SdkError::FileManifestPartWrong)));
+	}
+}
@@ -1,11 +1,13 @@
 #[cfg(feature = "export")]
 mod file_export;
+mod file_stream;
 
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 #[cfg(feature = "export")]
 pub use file_export::*;
+pub use file_stream::{TransferOptions, DEFAULT_PART_SIZE};
 use sentc_crypto_common::file::{FileData, FilePartListItem};
 use sentc_crypto_common::user::UserVerifyKeyData;
 use sentc_crypto_core::cryptomat::{SymKey, SymKeyComposer, SymKeyGen};
@@ -13,7 +15,7 @@ use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUser
 use sentc_crypto_utils::http::{make_req, make_req_buffer, make_req_buffer_body, HttpMethod};
 use sentc_crypto_utils::{handle_general_server_response, handle_server_response};
 
-use crate::file::FileEncryptor;
+use crate::file::{FileArchivePart, FileArchiveReader, FileEncryptor, FileManifestBuilder};
 use crate::SdkError;
 
 impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper> FileEncryptor<S, SC, SignK, VC>
@@ -62,6 +64,156 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 		Self::decrypt_file_part(pre_key, &res, verify_key_data)
 	}
 
+	/**
+	Download and decrypt a part of the chained or the derived file format.
+
+	pre_key is the key returned for the previous part, none for the first part or for derived files.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn download_and_decrypt_file_part_auto(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		part_id: &str,
+		sequence: i32,
+		content_key: &impl SymKeyWrapper,
+		pre_key: Option<&SC::SymmetricKey>,
+		verify_key_data: Option<&UserVerifyKeyData>,
+	) -> Result<(Vec<u8>, Option<SC::SymmetricKey>), SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let url = url_prefix + "/" + part_id;
+
+		let res = make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?;
+
+		//decrypt the part
+		Self::decrypt_file_part_auto(content_key, pre_key, sequence, &res, verify_key_data)
+	}
+
+	/**
+	Download and decrypt a single part of the derived file format without the previous parts.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn download_and_decrypt_file_part_derived(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		part_id: &str,
+		sequence: i32,
+		content_key: &impl SymKeyWrapper,
+		verify_key_data: Option<&UserVerifyKeyData>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let url = url_prefix + "/" + part_id;
+
+		let res = make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?;
+
+		Self::decrypt_file_part_derived(content_key, sequence, &res, verify_key_data)
+	}
+
+	/**
+	Downloads the index of an archive.
+
+	The part list must contain the last parts of the archive, the last item of the list is the last part.
+	The head of the index is downloaded first, then the index parts of the head.
+	 */
+	pub async fn download_archive_index<'a, K: SymKeyWrapper>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		part_list: &[FilePartListItem],
+		content_key: &'a K,
+		verify_key_data: Option<&'a UserVerifyKeyData>,
+	) -> Result<FileArchiveReader<'a, K, S, SC, SignK, VC>, SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let last_part = part_list.last().ok_or(SdkError::FileArchiveIndexWrong)?;
+
+		let url = url_prefix.clone() + "/" + &last_part.part_id;
+
+		let res = make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?;
+
+		let head = FileArchiveReader::<'a, K, S, SC, SignK, VC>::index_head(content_key, last_part.sequence, &res, verify_key_data)?;
+
+		let mut index_parts = Vec::with_capacity(head.part_count as usize);
+
+		for sequence in head.first_sequence..last_part.sequence {
+			let part = part_list
+				.iter()
+				.find(|p| p.sequence == sequence)
+				.ok_or(SdkError::FilePartSequenceWrong)?;
+
+			let url = url_prefix.clone() + "/" + &part.part_id;
+
+			index_parts.push(make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?);
+		}
+
+		FileArchiveReader::new(content_key, &head, &index_parts, verify_key_data)
+	}
+
+	/**
+	Downloads and decrypts only the parts of one file of the archive.
+
+	The part list must contain the parts of this file.
+	 */
+	pub async fn download_archive_entry<K: SymKeyWrapper>(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		reader: &FileArchiveReader<'_, K, S, SC, SignK, VC>,
+		path: &str,
+		part_list: &[FilePartListItem],
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let entry = reader.entry(path)?;
+
+		let mut file = Vec::with_capacity(entry.size as usize);
+
+		for sequence in entry.first_sequence..entry.first_sequence + entry.part_count as i32 {
+			let part = part_list
+				.iter()
+				.find(|p| p.sequence == sequence)
+				.ok_or(SdkError::FilePartSequenceWrong)?;
+
+			let url = url_prefix.clone() + "/" + &part.part_id;
+
+			let res = make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?;
+
+			file.extend_from_slice(&reader.decrypt_entry_part(entry, sequence, &res)?);
+		}
+
+		Ok(file)
+	}
+
+	/**
+	Downloads the signed manifest of the file, see upload_manifest.
+	 */
+	#[cfg(feature = "unreleased_routes")]
+	pub async fn download_manifest(base_url: String, auth_token: &str, file_id: &str) -> Result<Vec<u8>, SdkError>
+	{
+		let url = base_url + "/api/v1/file/" + file_id + "/manifest";
+
+		Ok(make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?)
+	}
+
 	//______________________________________________________________________________________________
 
 	#[allow(clippy::too_many_arguments)]
@@ -123,6 +275,184 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 
 		Ok(next_file_key)
 	}
+
+	/**
+	Upload a part of the chained file format and add it to the manifest.
+
+	For the first part use the key of the content key wrapper.
+	The manifest is not a part of the file, upload it with upload_manifest after the last part
+	(only with the unreleased_routes feature) or finish it and store the signed manifest next to the file.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_part_with_manifest(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		session_id: &str,
+		end: bool,
+		sequence: i32,
+		content_key: &impl SymKey,
+		sign_key: Option<&SignK>,
+		part: &[u8],
+		manifest: &mut FileManifestBuilder,
+	) -> Result<S::SymmetricKey, SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let (encrypted, next_file_key) = Self::encrypt_file_part(content_key, part, sign_key)?;
+
+		manifest.add_part(&encrypted);
+
+		let url = url_prefix + "/" + session_id + "/" + sequence.to_string().as_str() + "/" + end.to_string().as_str();
+
+		let res = make_req_buffer_body(HttpMethod::POST, &url, auth_token, encrypted, Some(jwt), None).await?;
+
+		handle_general_server_response(&res)?;
+
+		Ok(next_file_key)
+	}
+
+	/**
+	Sign the manifest and upload it after the last part of the file.
+
+	The manifest is stored next to the file and not as a part, so the part list only contains the content of the file.
+	The endpoint is not in the sentc server yet, so this fn is only available with the unreleased_routes feature.
+	Server contract: PUT /api/v1/file/<file_id>/manifest with the signed manifest as body (only for the creator of the file)
+	and GET /api/v1/file/<file_id>/manifest returns it for everyone with access to the file.
+	 */
+	#[cfg(feature = "unreleased_routes")]
+	pub async fn upload_manifest(
+		base_url: String,
+		auth_token: &str,
+		jwt: &str,
+		file_id: String,
+		manifest: FileManifestBuilder,
+		sign_key: &SignK,
+	) -> Result<(), SdkError>
+	{
+		let url = base_url + "/api/v1/file/" + &file_id + "/manifest";
+
+		let manifest_part = manifest.finish(file_id, sign_key)?;
+
+		let res = make_req_buffer_body(HttpMethod::PUT, &url, auth_token, manifest_part, Some(jwt), None).await?;
+
+		Ok(handle_general_server_response(&res)?)
+	}
+
+	/**
+	Upload a part in the derived file format.
+
+	Every part is encrypted with a key derived from the content key, so no key must be passed to the next part.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_part_derived(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		session_id: &str,
+		end: bool,
+		sequence: i32,
+		content_key: &impl SymKeyWrapper,
+		sign_key: Option<&SignK>,
+		part: &[u8],
+	) -> Result<(), SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let encrypted = Self::encrypt_file_part_derived(content_key, sequence, part, sign_key)?;
+
+		let url = url_prefix + "/" + session_id + "/" + sequence.to_string().as_str() + "/" + end.to_string().as_str();
+
+		let res = make_req_buffer_body(HttpMethod::POST, &url, auth_token, encrypted, Some(jwt), None).await?;
+
+		Ok(handle_general_server_response(&res)?)
+	}
+
+	/**
+	Upload an encrypted part of an archive. The head of the index from finish is the last part, upload it with end = true.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_archive_part(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		session_id: &str,
+		end: bool,
+		part: FileArchivePart,
+	) -> Result<(), SdkError>
+	{
+		let url_prefix = match url_prefix {
+			Some(p) => p,
+			None => base_url + "/api/v1/file/part",
+		};
+
+		let url = url_prefix + "/" + session_id + "/" + part.sequence.to_string().as_str() + "/" + end.to_string().as_str();
+
+		let res = make_req_buffer_body(HttpMethod::POST, &url, auth_token, part.part, Some(jwt), None).await?;
+
+		Ok(handle_general_server_response(&res)?)
+	}
+
+	/**
+	Continue an upload from a checkpoint.
+
+	Uploads the part with the next sequence after the last acknowledged sequence of the checkpoint
+	and returns the new checkpoint for this part.
+
+	When the app was closed after the server acknowledged the part but before the new checkpoint was stored,
+	the part is sent again with the same sequence and the same part key from the checkpoint.
+	The server replaces a part with the same sequence of the upload session,
+	so the new part and the next key in it are the only part for this sequence and the key chain stays valid.
+	The last part closes the upload session, so when a resent last part fails, check with download_file_meta if the file is done.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_part_resume(
+		base_url: String,
+		url_prefix: Option<String>,
+		auth_token: &str,
+		jwt: &str,
+		end: bool,
+		checkpoint_key: &impl SymKeyWrapper,
+		checkpoint: &str,
+		sign_key: Option<&SignK>,
+		part: &[u8],
+	) -> Result<String, SdkError>
+	{
+		let (checkpoint, file_key) = Self::load_upload_checkpoint(checkpoint_key, checkpoint)?;
+
+		let sequence = checkpoint.sequence + 1;
+
+		let next_file_key = Self::upload_part(
+			base_url,
+			url_prefix,
+			auth_token,
+			jwt,
+			&checkpoint.session_id,
+			end,
+			sequence,
+			&file_key,
+			sign_key,
+			part,
+		)
+		.await?;
+
+		Self::prepare_upload_checkpoint(
+			checkpoint_key,
+			checkpoint.file_id,
+			checkpoint.session_id,
+			sequence,
+			&next_file_key,
+		)
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -200,6 +530,23 @@ pub async fn register_file(
 		file_name,
 	)?;
 
+	let (file_id, session_id) = send_register_file(base_url, auth_token, jwt, input, group_id, group_as_member).await?;
+
+	Ok((file_id, session_id, encrypted_file_name))
+}
+
+/**
+Registers the file with the prepared input and returns the file id and the session id
+ */
+pub(crate) async fn send_register_file(
+	base_url: String,
+	auth_token: &str,
+	jwt: &str,
+	input: String,
+	group_id: Option<&str>,
+	group_as_member: Option<&str>,
+) -> Result<(String, String), SdkError>
+{
 	let url = match group_id {
 		Some(id) => base_url + "/api/v1/group/" + id + "/file",
 		None => base_url + "/api/v1/file",
@@ -215,7 +562,47 @@ pub async fn register_file(
 	)
 	.await?;
 
-	let (file_id, session_id) = crate::file::done_register_file(&res)?;
+	crate::file::file::done_register_file(&res)
+}
+
+/**
+Register the file with the meta data.
+
+The meta data is encrypted together with the file name in the encrypted file name field,
+so this is the same request as register_file. See prepare_register_file_with_meta.
+
+Returns the file id, the session id and the encrypted file name.
+ */
+#[allow(clippy::too_many_arguments)]
+pub async fn register_file_with_meta(
+	base_url: String,
+	auth_token: &str,
+	jwt: &str,
+	master_key_id: String,
+	#[cfg(feature = "export")] content_key: &str,
+	#[cfg(not(feature = "export"))] content_key: &impl SymKeyWrapper,
+	encrypted_content_key: String,
+	belongs_to_id: Option<String>,
+	#[cfg(feature = "export")] belongs_to_type: &str,
+	#[cfg(not(feature = "export"))] belongs_to_type: sentc_crypto_common::file::BelongsToType,
+	file_name: Option<String>,
+	#[cfg(feature = "export")] meta: Option<String>,
+	#[cfg(not(feature = "export"))] meta: Option<&crate::entities::file::FileMeta>,
+	group_id: Option<&str>,
+	group_as_member: Option<&str>,
+) -> FileRegRes
+{
+	let (input, encrypted_file_name) = crate::file::prepare_register_file_with_meta(
+		master_key_id,
+		content_key,
+		encrypted_content_key,
+		belongs_to_id,
+		belongs_to_type,
+		file_name,
+		meta,
+	)?;
+
+	let (file_id, session_id) = send_register_file(base_url, auth_token, jwt, input, group_id, group_as_member).await?;
 
 	Ok((file_id, session_id, encrypted_file_name))
 }
@@ -244,6 +631,110 @@ pub async fn update_file_name(
 	Ok(handle_general_server_response(&res)?)
 }
 
+/**
+Updates the file name and the meta data with the file name update request.
+
+The file name must be set again because both are stored in the encrypted file name field.
+ */
+pub async fn update_file_meta(
+	base_url: String,
+	auth_token: &str,
+	jwt: &str,
+	file_id: &str,
+	#[cfg(feature = "export")] content_key: &str,
+	#[cfg(not(feature = "export"))] content_key: &impl SymKeyWrapper,
+	file_name: Option<String>,
+	#[cfg(feature = "export")] meta: Option<String>,
+	#[cfg(not(feature = "export"))] meta: Option<&crate::entities::file::FileMeta>,
+) -> VoidRes
+{
+	let input = crate::file::prepare_file_meta_update(content_key, file_name, meta)?;
+
+	let url = base_url + "/api/v1/file/" + file_id;
+
+	let res = make_req(HttpMethod::PUT, &url, auth_token, Some(input), Some(jwt), None).await?;
+
+	Ok(handle_general_server_response(&res)?)
+}
+
+/**
+Moves the file to another group or user. The input is from prepare_file_move or prepare_file_move_by_public_key.
+
+The endpoint is not in the sentc server yet, so this fn is only available with the unreleased_routes feature.
+The contract for a server that supports it:
+- PUT /api/v1/file/{file_id}/move or /api/v1/group/{group_id}/file/{file_id}/move with the FileMoveInput as json body
+- only the owner of the file (the user or a group member with the rights to delete the file) can move it
+- the server replaces the master key id, the encrypted key and the alg of the file and sets the new belongs to values
+- the answer is the general server response without a result
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn move_file(
+	base_url: String,
+	auth_token: &str,
+	jwt: &str,
+	file_id: &str,
+	input: String,
+	group_id: Option<&str>,
+	group_as_member: Option<&str>,
+) -> VoidRes
+{
+	let url = match group_id {
+		Some(id) => base_url + "/api/v1/group/" + id + "/file/" + file_id + "/move",
+		None => base_url + "/api/v1/file/" + file_id + "/move",
+	};
+
+	let res = make_req(
+		HttpMethod::PUT,
+		&url,
+		auth_token,
+		Some(input),
+		Some(jwt),
+		group_as_member,
+	)
+	.await?;
+
+	Ok(handle_general_server_response(&res)?)
+}
+
+/**
+Gives another group or user read access to the file. The input is from prepare_file_share or prepare_file_share_by_public_key.
+
+The endpoint is not in the sentc server yet, so this fn is only available with the unreleased_routes feature.
+The contract for a server that supports it:
+- POST /api/v1/file/{file_id}/share or /api/v1/group/{group_id}/file/{file_id}/share with the FileShareInput as json body
+- the server stores the encrypted key for the share_with_id next to the key of the owner, the file still belongs to the owner
+- the file meta data for the group or user of share_with_id contains the key of the share
+- the answer is the general server response without a result
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn share_file(
+	base_url: String,
+	auth_token: &str,
+	jwt: &str,
+	file_id: &str,
+	input: String,
+	group_id: Option<&str>,
+	group_as_member: Option<&str>,
+) -> VoidRes
+{
+	let url = match group_id {
+		Some(id) => base_url + "/api/v1/group/" + id + "/file/" + file_id + "/share",
+		None => base_url + "/api/v1/file/" + file_id + "/share",
+	};
+
+	let res = make_req(
+		HttpMethod::POST,
+		&url,
+		auth_token,
+		Some(input),
+		Some(jwt),
+		group_as_member,
+	)
+	.await?;
+
+	Ok(handle_general_server_response(&res)?)
+}
+
 pub async fn delete_file(
 	base_url: String,
 	auth_token: &str,
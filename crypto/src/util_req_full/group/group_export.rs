@@ -66,11 +66,13 @@ pub async fn create_connected_group(
 
 //__________________________________________________________________________________________________
 
+#[allow(clippy::too_many_arguments)]
 pub async fn key_rotation(
 	base_url: String,
 	auth_token: &str,
 	jwt: &str,
 	group_id: &str,
+	admin_rank: i32,
 	public_key: &str,
 	pre_group_key: &str,
 	user_group: bool,
@@ -88,6 +90,7 @@ pub async fn key_rotation(
 		auth_token,
 		jwt,
 		group_id,
+		admin_rank,
 		&invoker_public_key,
 		&previous_group_key,
 		user_group,
@@ -362,11 +362,13 @@ where
 
 	//______________________________________________________________________________________________
 
+	#[allow(clippy::too_many_arguments)]
 	pub async fn key_rotation_req(
 		base_url: String,
 		auth_token: &str,
 		jwt: &str,
 		group_id: &str,
+		admin_rank: i32,
 		public_key: &impl PkWrapper,
 		pre_group_key: &impl SymKeyWrapper,
 		user_group: bool,
@@ -375,26 +377,20 @@ where
 		group_as_member: Option<&str>,
 	) -> Result<String, SdkError>
 	{
-		let url = match user_group {
-			true => base_url + "/api/v1/user/user_keys/rotation",
-			false => base_url + "/api/v1/group/" + group_id + "/key_rotation",
-		};
+		crate::group::check_key_rotation(admin_rank)?;
 
 		let input = Self::key_rotation(pre_group_key, public_key, user_group, sign_key, starter)?;
 
-		let res = make_req(
-			HttpMethod::POST,
-			&url,
+		send_key_rotation(
+			base_url,
 			auth_token,
-			Some(input),
-			Some(jwt),
+			jwt,
+			group_id,
+			user_group,
+			input,
 			group_as_member,
 		)
-		.await?;
-
-		let out: KeyRotationStartServerOutput = handle_server_response(&res)?;
-
-		Ok(out.key_id)
+		.await
 	}
 
 	/**
@@ -451,6 +447,39 @@ type DataLightRes = Result<crate::entities::group::GroupOutDataLightExport, Stri
 #[cfg(not(feature = "export"))]
 pub type DataLightRes = Result<sentc_crypto_utils::group::GroupOutDataLight, SdkError>;
 
+/**
+Starts the key rotation with the prepared input and returns the id of the new key
+ */
+pub(crate) async fn send_key_rotation(
+	base_url: String,
+	auth_token: &str,
+	jwt: &str,
+	group_id: &str,
+	user_group: bool,
+	input: String,
+	group_as_member: Option<&str>,
+) -> Result<String, SdkError>
+{
+	let url = match user_group {
+		true => base_url + "/api/v1/user/user_keys/rotation",
+		false => base_url + "/api/v1/group/" + group_id + "/key_rotation",
+	};
+
+	let res = make_req(
+		HttpMethod::POST,
+		&url,
+		auth_token,
+		Some(input),
+		Some(jwt),
+		group_as_member,
+	)
+	.await?;
+
+	let out: KeyRotationStartServerOutput = handle_server_response(&res)?;
+
+	Ok(out.key_id)
+}
+
 pub async fn get_group(base_url: String, auth_token: &str, jwt: &str, id: &str, group_as_member: Option<&str>) -> DataRes
 {
 	let url = base_url + "/api/v1/group/" + id;
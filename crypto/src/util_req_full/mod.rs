@@ -2,6 +2,8 @@ use sentc_crypto_common::user::Claims;
 
 pub mod file;
 pub mod group;
+#[cfg(not(feature = "export"))]
+pub mod session;
 pub mod user;
 
 enum SessionKind
@@ -0,0 +1,897 @@
+//! A logged-in user with the actual jwt.
+//!
+//! The session holds the base url, the app token, the user data and the jwt.
+//! Before every request the jwt is refreshed when it expires soon.
+//! When the server rejects the jwt anyway, the jwt is refreshed and the request is sent again once.
+//!
+//! Concurrent requests share one refresh: the jwt is locked while it is refreshed,
+//! so all other requests wait and use the new jwt.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::future::Future;
+
+use futures::io::AsyncRead;
+use futures::lock::Mutex;
+use futures::Stream;
+use sentc_crypto_common::file::{BelongsToType, FileData, FilePartListItem};
+use sentc_crypto_common::group::{GroupUserListItem, ListGroups};
+use sentc_crypto_common::user::{UserDeviceList, UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_core::cryptomat::{PwHash, SearchableKeyGen, SortableKeyGen};
+use sentc_crypto_utils::cryptomat::{
+	PkFromUserKeyWrapper,
+	PkWrapper,
+	SearchableKeyComposerWrapper,
+	SignComposerWrapper,
+	SignKeyPairWrapper,
+	SortableKeyComposerWrapper,
+	StaticKeyComposerWrapper,
+	StaticKeyPairWrapper,
+	SymKeyComposerWrapper,
+	SymKeyGenWrapper,
+	SymKeyWrapper,
+	VerifyKFromUserKeyWrapper,
+};
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::jwt::decode_jwt;
+
+use crate::entities::group::GroupOutData;
+use crate::entities::user::{KeyRotationLink, UserDataInt, UserKeyDataInt};
+use crate::file::FileEncryptor;
+use crate::group::Group;
+use crate::user::User;
+use crate::util_req_full::file::TransferOptions;
+use crate::SdkError;
+
+/**
+The jwt is refreshed when it expires in less than this seconds
+ */
+pub const JWT_REFRESH_BEFORE_EXP: usize = 60;
+
+/**
+The server error code when the jwt is expired or has a wrong signature
+ */
+pub const JWT_VALIDATION_ERR: u32 = 30;
+
+/**
+The server error code when the request got no jwt
+ */
+pub const JWT_NOT_FOUND_ERR: u32 = 31;
+
+/**
+The server error code when the jwt is not in the bearer format
+ */
+pub const JWT_WRONG_FORMAT_ERR: u32 = 32;
+
+fn is_jwt_err(error: &SdkError) -> bool
+{
+	matches!(
+		error,
+		SdkError::Util(SdkUtilError::ServerErr(
+			JWT_VALIDATION_ERR | JWT_NOT_FOUND_ERR | JWT_WRONG_FORMAT_ERR,
+			_
+		))
+	)
+}
+
+/**
+Checks the exp of the jwt. A jwt that can't be decoded is refreshed too.
+ */
+pub fn jwt_needs_refresh(jwt: &str, now: usize) -> bool
+{
+	match decode_jwt(jwt) {
+		Ok(claims) => claims.exp <= now + JWT_REFRESH_BEFORE_EXP,
+		Err(_) => true,
+	}
+}
+
+/**
+The actual unix time in seconds from the system time
+ */
+#[cfg(feature = "full_rustls")]
+pub fn system_time_now() -> usize
+{
+	extern crate std;
+
+	std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|d| d.as_secs() as usize)
+		.unwrap_or(0)
+}
+
+/**
+The actual unix time in seconds from the date of the browser
+ */
+#[cfg(all(feature = "full_wasm", not(feature = "full_rustls")))]
+pub fn system_time_now() -> usize
+{
+	(js_sys::Date::now() / 1000.0) as usize
+}
+
+/**
+The actual jwt. Only one refresh runs at the same time, all other requests wait for the new jwt.
+ */
+struct JwtCache
+{
+	jwt: Mutex<String>,
+}
+
+impl JwtCache
+{
+	async fn get<R, RFut>(&self, now: usize, refresh: &R) -> Result<String, SdkError>
+	where
+		R: Fn(String) -> RFut,
+		RFut: Future<Output = Result<String, SdkError>>,
+	{
+		let mut jwt = self.jwt.lock().await;
+
+		if jwt_needs_refresh(&jwt, now) {
+			*jwt = refresh(jwt.clone()).await?;
+		}
+
+		Ok(jwt.clone())
+	}
+
+	/**
+	Refreshes the jwt after the server rejected it.
+
+	Only refreshes when no other request refreshed the jwt in the meantime.
+	 */
+	async fn refresh_rejected<R, RFut>(&self, rejected_jwt: &str, refresh: &R) -> Result<String, SdkError>
+	where
+		R: Fn(String) -> RFut,
+		RFut: Future<Output = Result<String, SdkError>>,
+	{
+		let mut jwt = self.jwt.lock().await;
+
+		if *jwt == rejected_jwt {
+			*jwt = refresh(jwt.clone()).await?;
+		}
+
+		Ok(jwt.clone())
+	}
+
+	async fn with_jwt<T, F, Fut, R, RFut>(&self, now: usize, refresh: R, req: F) -> Result<T, SdkError>
+	where
+		F: Fn(String) -> Fut,
+		Fut: Future<Output = Result<T, SdkError>>,
+		R: Fn(String) -> RFut,
+		RFut: Future<Output = Result<String, SdkError>>,
+	{
+		let jwt = self.get(now, &refresh).await?;
+
+		match req(jwt.clone()).await {
+			Err(e) if is_jwt_err(&e) => {
+				let jwt = self.refresh_rejected(&jwt, &refresh).await?;
+
+				req(jwt).await
+			},
+			res => res,
+		}
+	}
+}
+
+type SessionUserData<SC, StC, SignC> = UserDataInt<
+	<SC as SymKeyComposerWrapper>::SymmetricKeyWrapper,
+	<StC as StaticKeyComposerWrapper>::SkWrapper,
+	<StC as StaticKeyComposerWrapper>::PkWrapper,
+	<SignC as SignComposerWrapper>::SignKWrapper,
+	<SignC as SignComposerWrapper>::VerifyKWrapper,
+>;
+
+pub struct Session<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>
+where
+	SC: SymKeyComposerWrapper,
+	StC: StaticKeyComposerWrapper,
+	SignC: SignComposerWrapper,
+{
+	base_url: String,
+	auth_token: String,
+	user: SessionUserData<SC, StC, SignC>,
+	jwt: JwtCache,
+	now: fn() -> usize,
+	_user: core::marker::PhantomData<User<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>>,
+}
+
+impl<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>
+	Session<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>
+where
+	SGen: SymKeyGenWrapper,
+	StGen: StaticKeyPairWrapper,
+	SignGen: SignKeyPairWrapper,
+	SearchGen: SearchableKeyGen,
+	SortGen: SortableKeyGen,
+	SC: SymKeyComposerWrapper,
+	StC: StaticKeyComposerWrapper,
+	SignC: SignComposerWrapper,
+	SearchC: SearchableKeyComposerWrapper,
+	SortC: SortableKeyComposerWrapper,
+	PC: PkFromUserKeyWrapper,
+	VC: VerifyKFromUserKeyWrapper,
+	PwH: PwHash,
+{
+	/**
+	Creates the session from the data of the login.
+
+	now returns the actual unix time in seconds. It is used to check the exp of the jwt.
+	 */
+	pub fn new(base_url: String, auth_token: String, user: SessionUserData<SC, StC, SignC>, now: fn() -> usize) -> Self
+	{
+		let jwt = JwtCache {
+			jwt: Mutex::new(user.jwt.clone()),
+		};
+
+		Self {
+			base_url,
+			auth_token,
+			user,
+			jwt,
+			now,
+			_user: core::marker::PhantomData,
+		}
+	}
+
+	/**
+	The user data from the login. The jwt in the user data is not updated, use get_jwt instead.
+	 */
+	pub fn user(&self) -> &SessionUserData<SC, StC, SignC>
+	{
+		&self.user
+	}
+
+	/**
+	Returns the user data, e.g. to lock it on the device at the logout. The jwt is the actual jwt.
+	 */
+	pub fn into_user(self) -> SessionUserData<SC, StC, SignC>
+	{
+		let mut user = self.user;
+		user.jwt = self.jwt.jwt.into_inner();
+
+		user
+	}
+
+	/**
+	Returns a valid jwt and refreshes it before when it expires soon.
+	 */
+	pub async fn get_jwt(&self) -> Result<String, SdkError>
+	{
+		self.jwt
+			.get((self.now)(), &|old_jwt| self.refresh(old_jwt))
+			.await
+	}
+
+	async fn refresh(&self, old_jwt: String) -> Result<String, SdkError>
+	{
+		Ok(sentc_crypto_utils::full::user::refresh_jwt(
+			self.base_url.clone(),
+			&self.auth_token,
+			&old_jwt,
+			self.user.refresh_token.clone(),
+		)
+		.await?)
+	}
+
+	/**
+	Calls the request with a valid jwt.
+
+	When the server rejects the jwt, the jwt is refreshed and the request is called again once.
+	The request must only send one request to the server, because everything in it is done again.
+	Prepare the data before, like the key rotations.
+	Use this fn for every request that has no method in the session.
+	 */
+	pub async fn with_jwt<T, F, Fut>(&self, req: F) -> Result<T, SdkError>
+	where
+		F: Fn(String) -> Fut,
+		Fut: Future<Output = Result<T, SdkError>>,
+	{
+		self.jwt
+			.with_jwt((self.now)(), |old_jwt| self.refresh(old_jwt), req)
+			.await
+	}
+
+	//______________________________________________________________________________________________
+	//user
+
+	pub async fn fetch_user_key(
+		&self,
+		key_id: &str,
+	) -> Result<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				User::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>::fetch_user_key(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					key_id,
+					&self.user.device_keys.private_key,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn get_user_devices(&self, last_fetched_time: &str, last_fetched_id: &str) -> Result<Vec<UserDeviceList>, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::user::get_user_devices(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					last_fetched_time,
+					last_fetched_id,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn update_user(&self, user_identifier: String) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			let user_identifier = user_identifier.clone();
+
+			async move { super::user::update(self.base_url.clone(), &self.auth_token, &jwt, user_identifier).await }
+		})
+		.await
+	}
+
+	/**
+	Creates a new user key for all devices and links it to the previous key, see User::signed_key_rotation.
+
+	The keys are created once before the request, so a retry with a new jwt sends the same keys again.
+	 */
+	pub async fn user_key_rotation(
+		&self,
+		pre_user_key: &UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+	) -> Result<(String, KeyRotationLink), SdkError>
+	{
+		let (input, link) =
+			User::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>::prepare_signed_key_rotation(
+				&self.user.device_keys.public_key,
+				pre_user_key,
+			)?;
+
+		let key_id = self
+			.with_jwt(|jwt| {
+				let input = input.clone();
+
+				async move { super::group::send_key_rotation(self.base_url.clone(), &self.auth_token, &jwt, "", true, input, None).await }
+			})
+			.await?;
+
+		Ok(super::user::done_key_rotation_link(key_id, link))
+	}
+
+	//______________________________________________________________________________________________
+	//group
+
+	pub async fn create_group(&self, creators_public_key: &impl PkWrapper, group_as_member: Option<&str>) -> Result<String, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::create(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					creators_public_key,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn get_group(&self, id: &str, group_as_member: Option<&str>) -> Result<GroupOutData, SdkError>
+	{
+		self.with_jwt(|jwt| async move { super::group::get_group(self.base_url.clone(), &self.auth_token, &jwt, id, group_as_member).await })
+			.await
+	}
+
+	pub async fn get_groups_for_user(
+		&self,
+		last_fetched_time: &str,
+		last_fetched_group_id: &str,
+		group_id: Option<&str>,
+	) -> Result<Vec<ListGroups>, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::group::get_groups_for_user(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					last_fetched_time,
+					last_fetched_group_id,
+					group_id,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn get_group_member(
+		&self,
+		id: &str,
+		last_fetched_time: &str,
+		last_fetched_id: &str,
+		group_as_member: Option<&str>,
+	) -> Result<Vec<GroupUserListItem>, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::group::get_member(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					id,
+					last_fetched_time,
+					last_fetched_id,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	pub async fn invite_user(
+		&self,
+		id: &str,
+		user_to_invite_id: &str,
+		key_count: i32,
+		rank: Option<i32>,
+		admin_rank: i32,
+		auto_invite: bool,
+		group_invite: bool,
+		re_invite: bool,
+		user_public_key: &UserPublicKeyData,
+		group_keys: &[&impl SymKeyWrapper],
+		group_as_member: Option<&str>,
+	) -> Result<Option<String>, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::invite_user(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					id,
+					user_to_invite_id,
+					key_count,
+					rank,
+					admin_rank,
+					auto_invite,
+					group_invite,
+					re_invite,
+					user_public_key,
+					group_keys,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn accept_invite(&self, group_id_to_accept: &str, group_id: Option<&str>, group_as_member: Option<&str>) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::group::accept_invite(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					group_id_to_accept,
+					group_id,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn leave_group(&self, group_id: &str, group_as_member: Option<&str>) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::group::leave_group(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					group_id,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn kick_user(&self, group_id: &str, user_id: &str, admin_rank: i32, group_as_member: Option<&str>) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::group::kick_user(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					group_id,
+					user_id,
+					admin_rank,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn delete_group(&self, group_id: &str, admin_rank: i32, group_as_member: Option<&str>) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::group::delete_group(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					group_id,
+					admin_rank,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	/**
+	Starts the key rotation of the group and returns the id of the new key.
+
+	The keys are created once before the request, so a retry with a new jwt sends the same keys again.
+	When a sign key is set, the new key is signed by the user.
+	 */
+	pub async fn group_key_rotation(
+		&self,
+		group_id: &str,
+		admin_rank: i32,
+		public_key: &impl PkWrapper,
+		pre_group_key: &impl SymKeyWrapper,
+		sign_key: Option<&SignC::SignKWrapper>,
+		group_as_member: Option<&str>,
+	) -> Result<String, SdkError>
+	{
+		crate::group::check_key_rotation(admin_rank)?;
+
+		let input = Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::key_rotation(
+			pre_group_key,
+			public_key,
+			false,
+			sign_key,
+			self.user.user_id.clone(),
+		)?;
+
+		self.with_jwt(|jwt| {
+			let input = input.clone();
+
+			async move {
+				super::group::send_key_rotation(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					group_id,
+					false,
+					input,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	//______________________________________________________________________________________________
+	//file
+
+	/**
+	Registers the file and returns the file id, the session id for the upload and the encrypted file name.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn register_file(
+		&self,
+		master_key_id: &str,
+		content_key: &impl SymKeyWrapper,
+		encrypted_content_key: &str,
+		belongs_to_id: Option<&str>,
+		belongs_to_type: BelongsToType,
+		file_name: Option<&str>,
+		group_id: Option<&str>,
+		group_as_member: Option<&str>,
+	) -> Result<(String, String, Option<String>), SdkError>
+	{
+		let (input, encrypted_file_name) = crate::file::prepare_register_file(
+			master_key_id.to_string(),
+			content_key,
+			encrypted_content_key.to_string(),
+			belongs_to_id.map(|id| id.to_string()),
+			belongs_to_type,
+			file_name.map(|name| name.to_string()),
+		)?;
+
+		let (file_id, session_id) = self
+			.with_jwt(|jwt| {
+				let input = input.clone();
+
+				async move {
+					super::file::send_register_file(
+						self.base_url.clone(),
+						&self.auth_token,
+						&jwt,
+						input,
+						group_id,
+						group_as_member,
+					)
+					.await
+				}
+			})
+			.await?;
+
+		Ok((file_id, session_id, encrypted_file_name))
+	}
+
+	/**
+	Encrypts and uploads the file from the reader, see FileEncryptor::upload_file_from_reader_concurrent.
+
+	The upload is not started again when the server rejects the jwt, because the server already has some parts.
+	The jwt is refreshed before the upload, use a checkpoint to resume a failed upload.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub async fn upload_file<R: AsyncRead + Unpin>(
+		&self,
+		session_id: &str,
+		content_key: &impl SymKeyWrapper,
+		sign_key: Option<&SignC::SignKWrapper>,
+		reader: R,
+		part_size: usize,
+		options: TransferOptions,
+	) -> Result<i32, SdkError>
+	{
+		let jwt = self.get_jwt().await?;
+
+		FileEncryptor::<SGen::KeyGen, SC::Composer, SignC::SignKWrapper, VC>::upload_file_from_reader_concurrent(
+			self.base_url.clone(),
+			None,
+			&self.auth_token,
+			&jwt,
+			session_id,
+			content_key,
+			sign_key,
+			reader,
+			part_size,
+			options,
+		)
+		.await
+	}
+
+	/**
+	Downloads and decrypts the file part by part, see FileEncryptor::download_file_stream_concurrent.
+
+	The part list is in the file meta data from download_file_meta. The parts are downloaded without the jwt.
+	 */
+	pub fn download_file<'a, K: SymKeyWrapper>(
+		&'a self,
+		file_id: &'a str,
+		part_list: Vec<FilePartListItem>,
+		content_key: &'a K,
+		verify_key_data: Option<&'a UserVerifyKeyData>,
+		options: TransferOptions,
+	) -> impl Stream<Item = Result<Vec<u8>, SdkError>> + 'a
+	where
+		SGen::KeyGen: 'a,
+		SC::Composer: 'a,
+		SignC::SignKWrapper: 'a,
+		VC: 'a,
+	{
+		FileEncryptor::<SGen::KeyGen, SC::Composer, SignC::SignKWrapper, VC>::download_file_stream_concurrent(
+			self.base_url.clone(),
+			None,
+			&self.auth_token,
+			file_id,
+			part_list,
+			content_key,
+			verify_key_data,
+			options,
+		)
+	}
+
+	pub async fn download_file_meta(&self, file_id: &str, group_id: Option<&str>, group_as_member: Option<&str>) -> Result<FileData, SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::file::download_file_meta(
+					self.base_url.clone(),
+					&self.auth_token,
+					file_id,
+					Some(&jwt),
+					group_id,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn update_file_name(&self, file_id: &str, content_key: &impl SymKeyWrapper, file_name: Option<String>) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			let file_name = file_name.clone();
+
+			async move {
+				super::file::update_file_name(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					file_id,
+					content_key,
+					file_name,
+				)
+				.await
+			}
+		})
+		.await
+	}
+
+	pub async fn delete_file(&self, file_id: &str, group_id: Option<&str>, group_as_member: Option<&str>) -> Result<(), SdkError>
+	{
+		self.with_jwt(|jwt| {
+			async move {
+				super::file::delete_file(
+					self.base_url.clone(),
+					&self.auth_token,
+					&jwt,
+					file_id,
+					group_id,
+					group_as_member,
+				)
+				.await
+			}
+		})
+		.await
+	}
+}
+
+#[cfg(test)]
+mod test
+{
+	use alloc::format;
+	use alloc::string::ToString;
+	use core::cell::Cell;
+	use core::task::Poll;
+
+	use base64ct::{Base64UrlUnpadded, Encoding};
+	use futures::executor::block_on;
+	use futures::future;
+
+	use super::*;
+
+	fn jwt(exp: usize) -> String
+	{
+		let claims = format!(r#"{{"aud":"user","sub":"app","exp":{},"iat":0,"fresh":false}}"#, exp);
+
+		format!("header.{}.sig", Base64UrlUnpadded::encode_string(claims.as_bytes()))
+	}
+
+	fn jwt_cache(jwt: String) -> JwtCache
+	{
+		JwtCache {
+			jwt: Mutex::new(jwt),
+		}
+	}
+
+	async fn yield_now()
+	{
+		let mut yielded = false;
+
+		future::poll_fn(|cx| {
+			if yielded {
+				Poll::Ready(())
+			} else {
+				yielded = true;
+				cx.waker().wake_by_ref();
+				Poll::Pending
+			}
+		})
+		.await
+	}
+
+	#[test]
+	fn test_jwt_needs_refresh()
+	{
+		assert!(!jwt_needs_refresh(&jwt(1000), 100));
+		assert!(jwt_needs_refresh(&jwt(1000), 1000 - JWT_REFRESH_BEFORE_EXP));
+		assert!(jwt_needs_refresh(&jwt(1000), 2000));
+
+		//refresh a jwt that can't be decoded
+		assert!(jwt_needs_refresh("abc", 0));
+		assert!(jwt_needs_refresh("header.abc.sig", 0));
+	}
+
+	#[test]
+	fn test_concurrent_requests_share_one_refresh()
+	{
+		let cache = jwt_cache(jwt(10));
+		let refreshed = Cell::new(0);
+
+		let refresh = |_old_jwt: String| {
+			refreshed.set(refreshed.get() + 1);
+
+			async {
+				//the other request must wait for the refresh
+				yield_now().await;
+
+				Ok(jwt(10_000))
+			}
+		};
+
+		let (jwt_1, jwt_2) = block_on(future::join(cache.get(100, &refresh), cache.get(100, &refresh)));
+
+		assert_eq!(jwt_1.unwrap(), jwt(10_000));
+		assert_eq!(jwt_2.unwrap(), jwt(10_000));
+		assert_eq!(refreshed.get(), 1);
+	}
+
+	#[test]
+	fn test_retry_once_after_rejected_jwt()
+	{
+		let cache = jwt_cache(jwt(10_000));
+		let calls = Cell::new(0);
+
+		let res = block_on(cache.with_jwt(
+			100,
+			|_old_jwt| async { Ok(jwt(20_000)) },
+			|jwt| {
+				calls.set(calls.get() + 1);
+				let first = calls.get() == 1;
+
+				async move {
+					if first {
+						Err(SdkError::Util(SdkUtilError::ServerErr(
+							JWT_VALIDATION_ERR,
+							"expired".to_string(),
+						)))
+					} else {
+						Ok(jwt)
+					}
+				}
+			},
+		));
+
+		//the request is sent again with the new jwt
+		assert_eq!(res.unwrap(), jwt(20_000));
+		assert_eq!(calls.get(), 2);
+
+		//other errors are not retried
+		calls.set(0);
+
+		let res: Result<(), SdkError> = block_on(cache.with_jwt(
+			100,
+			|_old_jwt| async { Ok(jwt(30_000)) },
+			|_jwt| {
+				calls.set(calls.get() + 1);
+
+				async { Err(SdkError::Util(SdkUtilError::ServerErr(100, "other".to_string()))) }
+			},
+		));
+
+		assert!(res.is_err());
+		assert_eq!(calls.get(), 1);
+	}
+}
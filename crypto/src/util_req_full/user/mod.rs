@@ -30,8 +30,9 @@ use sentc_crypto_utils::{handle_general_server_response, handle_server_response}
 #[cfg(feature = "export")]
 pub use user_export::*;
 
-use crate::entities::user::{UserDataInt, UserKeyDataInt};
+use crate::entities::user::{KeyRotationLink, UserDataInt, UserKeyDataInt, VerifiedKeyRecord, VerifyKeyState};
 use crate::group::Group;
+use crate::user::pin_store::PinStore;
 use crate::user::User;
 use crate::util_req_full::SessionKind;
 use crate::SdkError;
@@ -332,19 +333,37 @@ where
 		pre_user_key: &impl SymKeyWrapper,
 	) -> Result<String, SdkError>
 	{
-		Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::key_rotation_req(
-			base_url,
-			auth_token,
-			jwt,
-			"",
-			device_public_key,
+		//the user is the only member of the own group, so no rank check like for groups
+		let input = Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::key_rotation(
 			pre_user_key,
+			device_public_key,
 			true,
 			None,
 			Default::default(),
-			None,
-		)
-		.await
+		)?;
+
+		super::group::send_key_rotation(base_url, auth_token, jwt, "", true, input, None).await
+	}
+
+	/**
+	Creates a new user key like key_rotation and links the new key to the previous key.
+
+	The link is signed with the sign key of the previous key. The server doesn't store the link,
+	share the returned link with the contacts, see KeyRotationLink.
+	 */
+	pub async fn signed_key_rotation(
+		base_url: String,
+		auth_token: &str,
+		jwt: &str,
+		device_public_key: &impl PkWrapper,
+		pre_user_key: &UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>,
+	) -> Result<(String, KeyRotationLink), SdkError>
+	{
+		let (input, link) = Self::prepare_signed_key_rotation(device_public_key, pre_user_key)?;
+
+		let key_id = super::group::send_key_rotation(base_url, auth_token, jwt, "", true, input, None).await?;
+
+		Ok(done_key_rotation_link(key_id, link))
 	}
 
 	/// Register a new user group key for the current device.
@@ -373,6 +392,80 @@ where
 		)
 		.await
 	}
+
+	/**
+	Fetches the public key of the user and checks it with the pinned verify key of the user.
+
+	At the first use the verify key which signed the public key gets pinned.
+	 */
+	pub async fn fetch_user_public_key_pinned(
+		base_url: String,
+		auth_token: &str,
+		user_id: &str,
+		store: &mut impl PinStore,
+	) -> Result<UserPublicKeyData, SdkError>
+	{
+		let url = base_url.clone() + "/api/v1/user/" + user_id + "/public_key";
+
+		let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+		let public_key = crate::util::public::import_public_key_from_string_into_format(res.as_str())?;
+
+		if store.get_pin(user_id).is_none() {
+			let sig_key_id = public_key
+				.public_key_sig_key_id
+				.as_deref()
+				.ok_or(SdkError::IdentityNotPinned)?;
+
+			let url = base_url + "/api/v1/user/" + user_id + "/verify_key/" + sig_key_id;
+
+			let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+			let verify_key = crate::util::public::import_verify_key_from_string_into_format(res.as_str())?;
+
+			Self::pin_verify_key(store, user_id, &verify_key)?;
+		}
+
+		Self::check_pinned_public_key(store, user_id, &public_key)?;
+
+		Ok(public_key)
+	}
+
+	/**
+	Fetches the public key of the group and checks it with the verify key which was pinned for the group id.
+
+	Only signed group public keys can pass the check.
+	 */
+	pub async fn get_group_public_key_pinned(
+		base_url: String,
+		auth_token: &str,
+		group_id: &str,
+		store: &impl PinStore,
+	) -> Result<UserPublicKeyData, SdkError>
+	{
+		let url = base_url + "/api/v1/group/" + group_id + "/public_key";
+
+		let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+		let public_key = crate::util::public::import_public_key_from_string_into_format(res.as_str())?;
+
+		Self::check_pinned_public_key(store, group_id, &public_key)?;
+
+		Ok(public_key)
+	}
+}
+
+/**
+The server uses the id of the new key for the new key pairs.
+
+The ids are only set to fetch the keys, the chain of the links uses the hash of the keys.
+ */
+pub(crate) fn done_key_rotation_link(key_id: String, mut link: KeyRotationLink) -> (String, KeyRotationLink)
+{
+	link.verify_key_id = key_id.clone();
+	link.public_key_id = key_id.clone();
+
+	(key_id, link)
 }
 
 //__________________________________________________________________________________________________
@@ -553,9 +646,130 @@ pub async fn fetch_user_verify_key_by_id(base_url: String, auth_token: &str, use
 	Ok(public_data)
 }
 
+#[cfg(feature = "export")]
+type VerifyKeyStateRes = Result<bool, String>;
+#[cfg(not(feature = "export"))]
+type VerifyKeyStateRes = Result<VerifyKeyState, SdkError>;
+
+/**
+Fetches the verified key of the contact again by the key id and checks if the server returns a different key.
+
+The export version takes the record as json string and returns true if the key is still the verified key.
+ */
+pub async fn check_verified_verify_key(
+	base_url: String,
+	auth_token: &str,
+	#[cfg(feature = "export")] record: &str,
+	#[cfg(not(feature = "export"))] record: &VerifiedKeyRecord,
+) -> VerifyKeyStateRes
+{
+	#[cfg(feature = "export")]
+	let record: VerifiedKeyRecord = serde_json::from_str(record).map_err(SdkError::JsonParseFailed)?;
+
+	let url = base_url + "/api/v1/user/" + &record.user_id + "/verify_key/" + &record.verify_key_id;
+
+	let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+	let verify_key = crate::util::public::import_verify_key_from_string_into_format(res.as_str())?;
+
+	#[cfg(not(feature = "export"))]
+	return Ok(crate::user::user::check_verified_key_record(record, &verify_key));
+
+	#[cfg(feature = "export")]
+	return Ok(crate::user::user::check_verified_key_record(&record, &verify_key) == VerifyKeyState::Verified);
+}
+
 //__________________________________________________________________________________________________
 
 pub fn prepare_done_key_rotation<'a>(base_url: String, auth_token: &'a str, jwt: &'a str) -> impl Future<Output = super::group::KeyRotationRes> + 'a
 {
 	super::group::prepare_done_key_rotation(base_url, auth_token, jwt, "", true, None)
 }
+
+//__________________________________________________________________________________________________
+//device pairing relay
+
+#[cfg(all(feature = "unreleased_routes", feature = "export"))]
+type PairingMessageRes = Result<Option<String>, String>;
+#[cfg(all(feature = "unreleased_routes", not(feature = "export")))]
+type PairingMessageRes = Result<Option<String>, SdkError>;
+
+/**
+Fetches the output of the device register start of the new device with the register token from the qr code.
+
+The pairing messages are relayed by the server between the new and the existing device.
+The endpoints are not in the sentc server yet, so the relay fns are only available with the unreleased_routes feature.
+The contract for a server that supports it, the register token of the new device identifies the pairing:
+- GET /api/v1/user/device/pairing/{token} with the jwt of the existing device returns the output of the register device start
+- PUT /api/v1/user/device/pairing/{token}/response with the jwt of the existing device stores the response
+- GET /api/v1/user/device/pairing/{token}/response without jwt returns the response or null if it was not sent yet
+- PUT /api/v1/user/device/pairing/{token}/reveal without jwt stores the reveal of the new device
+- GET /api/v1/user/device/pairing/{token}/reveal with the jwt of the existing device returns the reveal or null
+- the server deletes the messages after the device was registered or when the token expired
+
+Pass the output to verify_device_pairing and after the confirmation to the device register.
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn fetch_device_pairing_register(base_url: String, auth_token: &str, jwt: &str, token: &str) -> Res
+{
+	let url = base_url + "/api/v1/user/device/pairing/" + token;
+
+	let res = auth_req(HttpMethod::GET, url.as_str(), auth_token, None, jwt).await?;
+
+	//check the output here, the server output itself is needed for the device register
+	let _out: sentc_crypto_common::user::UserDeviceRegisterOutput = handle_server_response(&res)?;
+
+	Ok(res)
+}
+
+/**
+Sends the response of respond_device_pairing to the new device. See fetch_device_pairing_register for the relay.
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn send_device_pairing_response(base_url: String, auth_token: &str, jwt: &str, token: &str, response: String) -> VoidRes
+{
+	let url = base_url + "/api/v1/user/device/pairing/" + token + "/response";
+
+	let res = auth_req(HttpMethod::PUT, url.as_str(), auth_token, Some(response), jwt).await?;
+
+	Ok(handle_general_server_response(&res)?)
+}
+
+/**
+Fetches the response of the existing device at the new device. Returns None until the existing device sent it.
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn fetch_device_pairing_response(base_url: String, auth_token: &str, token: &str) -> PairingMessageRes
+{
+	let url = base_url + "/api/v1/user/device/pairing/" + token + "/response";
+
+	let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+	Ok(handle_server_response(&res)?)
+}
+
+/**
+Sends the reveal of reveal_device_pairing to the existing device.
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn send_device_pairing_reveal(base_url: String, auth_token: &str, token: &str, reveal: String) -> VoidRes
+{
+	let url = base_url + "/api/v1/user/device/pairing/" + token + "/reveal";
+
+	let res = non_auth_req(HttpMethod::PUT, url.as_str(), auth_token, Some(reveal)).await?;
+
+	Ok(handle_general_server_response(&res)?)
+}
+
+/**
+Fetches the reveal of the new device at the existing device. Returns None until the new device sent it.
+ */
+#[cfg(feature = "unreleased_routes")]
+pub async fn fetch_device_pairing_reveal(base_url: String, auth_token: &str, jwt: &str, token: &str) -> PairingMessageRes
+{
+	let url = base_url + "/api/v1/user/device/pairing/" + token + "/reveal";
+
+	let res = auth_req(HttpMethod::GET, url.as_str(), auth_token, None, jwt).await?;
+
+	Ok(handle_server_response(&res)?)
+}
@@ -1,13 +1,14 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 
-use sentc_crypto_std_keys::util::{SecretKey, SignKey, SymKeyFormatExport, SymmetricKey};
+use sentc_crypto_std_keys::util::{PublicKey, SecretKey, SignKey, SymKeyFormatExport, SymmetricKey};
 use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
 use serde_json::from_str;
 
 use crate::entities::user::{UserDataExport, UserKeyDataExport};
 use crate::group::prepare_prepare_group_keys_for_new_member;
 use crate::keys::std::{StdGroup, StdPreLoginOut, StdUser};
+use crate::user::pin_store::SerializablePinStore;
 use crate::util_req_full::SessionKind;
 use crate::{group, SdkError};
 
@@ -231,19 +232,10 @@ pub async fn reset_password(
 
 pub async fn key_rotation(base_url: String, auth_token: &str, jwt: &str, device_public_key: &str, pre_user_key: &str) -> Result<String, String>
 {
-	super::super::group::key_rotation(
-		base_url,
-		auth_token,
-		jwt,
-		"",
-		device_public_key,
-		pre_user_key,
-		true,
-		None,
-		Default::default(),
-		None,
-	)
-	.await
+	let device_public_key: PublicKey = device_public_key.parse()?;
+	let pre_user_key: SymmetricKey = pre_user_key.parse()?;
+
+	Ok(StdUser::key_rotation(base_url, auth_token, jwt, &device_public_key, &pre_user_key).await?)
 }
 
 pub async fn done_key_rotation(
@@ -271,3 +263,20 @@ pub async fn done_key_rotation(
 	)
 	.await
 }
+
+/**
+Returns the public key and the new pin store as json strings
+ */
+pub async fn fetch_user_public_key_pinned(base_url: String, auth_token: &str, user_id: &str, store: &str) -> Result<(String, String), String>
+{
+	let mut store = SerializablePinStore::from_string(store)?;
+
+	let public_key = StdUser::fetch_user_public_key_pinned(base_url, auth_token, user_id, &mut store).await?;
+
+	Ok((
+		public_key
+			.to_string()
+			.map_err(|_| SdkError::JsonToStringFailed)?,
+		store.to_string()?,
+	))
+}
@@ -71,6 +71,17 @@ pub trait SymKeyComposer
 
 		Self::from_bytes_owned(decrypted_bytes, alg_str)
 	}
+
+	/**
+	Derives a new key from the master key and the info.
+
+	The same master key and info always create the same key.
+	Every key crate must implement the kdf for its own alg, the default returns AlgNotFound.
+	 */
+	fn derive_key_by_sym_key<M: SymKey>(_master_key: &M, _info: &[u8]) -> Result<Self::SymmetricKey, Error>
+	{
+		Err(Error::AlgNotFound)
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -183,6 +194,34 @@ pub trait SortableKey: CryptoAlg
 	fn encrypt_key_with_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>;
 
 	fn encrypt_sortable(&self, data: u64) -> Result<u64, Error>;
+
+	/**
+	The largest number that can be encrypted with this key.
+	 */
+	fn max_sortable(&self) -> u64
+	{
+		u64::MAX
+	}
+
+	/**
+	Order-revealing encryption of a number.
+
+	The output is the left and the right ciphertext in one vec.
+	Two outputs can be compared with the compare fn of the alg without the key.
+	Order-preserving algorithms don't support this and return KeyOperationNotSupported.
+	 */
+	fn encrypt_sortable_ore(&self, _data: u64) -> Result<Vec<u8>, Error>
+	{
+		Err(Error::KeyOperationNotSupported)
+	}
+
+	/**
+	True if the key only supports the order-revealing encryption (encrypt_sortable_ore).
+	 */
+	fn is_order_revealing(&self) -> bool
+	{
+		false
+	}
 }
 
 pub trait SortableKeyGen
@@ -241,9 +280,206 @@ pub trait PwHash
 	 */
 	fn derive_keys_for_auth(password: &[u8], salt_bytes: &[u8], alg: &str) -> Result<(Self::DMK, Self::DAK), Error>;
 
+	/**
+	# Prepare the registration of a passkey
+
+	Like derived_keys_from_password but for a 32 bytes secret, e.g. the output of the WebAuthn prf extension.
+	The secret got enough entropy, so the keys are derived with hkdf and not with the password hash.
+
+	The encrypted master key is another wrapper of the same master key, the password wrapper is still valid.
+	Hasher without passkey support will return AlgNotFound.
+	 */
+	#[allow(clippy::type_complexity)]
+	fn derived_keys_from_secret<M: SymKey>(_secret: &[u8], _master_key: &M) -> Result<(Self::CRV, Self::HAK, Vec<u8>, &'static str), Error>
+	{
+		Err(Error::AlgNotFound)
+	}
+
+	/**
+	# Prepare the login with a passkey
+
+	Like derive_keys_for_auth but with the secret of the passkey and the salt of the passkey wrapper.
+	 */
+	fn derive_keys_for_auth_from_secret(_secret: &[u8], _salt_bytes: &[u8], _alg: &str) -> Result<(Self::DMK, Self::DAK), Error>
+	{
+		Err(Error::AlgNotFound)
+	}
+
+	/**
+	Returns true if the alg of the stored password hash is weaker than the alg for new password hashes.
+	 */
+	fn needs_upgrade(_alg: &str) -> bool
+	{
+		false
+	}
+
 	fn password_to_encrypt(password: &[u8]) -> Result<(Self::PWS, impl SymKey), Error>;
 
 	fn password_to_decrypt(password: &[u8], salt: &[u8]) -> Result<impl SymKey, Error>;
+
+	/**
+	Like password_to_encrypt but with a much more expensive hash.
+
+	Use it for secrets with low entropy like a pin or for data that can be attacked offline for a long time like an account archive.
+	A short pin is still weak against offline guessing even with this hash.
+	Hasher without a passphrase hash will return AlgNotFound.
+
+	@return: the salt, the key and the derived alg (the hash and its parameters). Store the alg next to the encrypted data.
+	 */
+	fn passphrase_to_encrypt(_password: &[u8]) -> Result<(Self::PWS, impl SymKey, &'static str), Error>
+	{
+		Err::<(Self::PWS, NoSymKey, &'static str), _>(Error::AlgNotFound)
+	}
+
+	/**
+	Derives the key with the parameters of the derived alg from passphrase_to_encrypt.
+
+	Returns AlgNotFound for an alg of another hasher or for parameters that are not known by this hasher.
+	 */
+	fn passphrase_to_decrypt(_password: &[u8], _salt: &[u8], _alg: &str) -> Result<impl SymKey, Error>
+	{
+		Err::<NoSymKey, _>(Error::AlgNotFound)
+	}
+}
+
+/**
+The key type of the default impl of the fns that return impl SymKey. It can't be created.
+ */
+enum NoSymKey {}
+
+impl CryptoAlg for NoSymKey
+{
+	fn get_alg_str(&self) -> &'static str
+	{
+		match *self {}
+	}
+}
+
+impl AsRef<[u8]> for NoSymKey
+{
+	fn as_ref(&self) -> &[u8]
+	{
+		match *self {}
+	}
+}
+
+impl SymKey for NoSymKey
+{
+	fn encrypt_key_with_master_key<M: Pk>(&self, _master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		match *self {}
+	}
+
+	fn encrypt_with_sym_key<M: SymKey>(&self, _master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		match *self {}
+	}
+
+	fn encrypt(&self, _data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		match *self {}
+	}
+
+	fn decrypt(&self, _ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		match *self {}
+	}
+
+	fn encrypt_with_aad(&self, _data: &[u8], _aad: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		match *self {}
+	}
+
+	fn decrypt_with_aad(&self, _ciphertext: &[u8], _aad: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		match *self {}
+	}
+}
+
+/**
+# Client side of a password authenticated key exchange (OPAQUE)
+
+Unlike PwHash, the server never gets a password-equivalent value, neither the auth key nor a salt for offline guessing.
+The export key of the protocol is turned into the encryption key of the master key.
+
+All states and messages are the serialized bytes of the protocol, so they can be stored between the requests.
+ */
+pub trait PakeClient
+{
+	type DMK: DeriveMasterKeyForAuth;
+
+	fn get_alg_str() -> &'static str;
+
+	/**
+	Returns the client state and the registration request for the server
+	 */
+	fn registration_start(password: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error>;
+
+	/**
+	Returns the registration upload for the server and the master key encrypted by the export key
+	 */
+	#[allow(clippy::type_complexity)]
+	fn registration_finish<M: SymKey>(
+		state: &[u8],
+		password: &[u8],
+		registration_response: &[u8],
+		master_key: &M,
+	) -> Result<
+		(
+			Vec<u8>,      //registration upload
+			Vec<u8>,      //encrypted master key
+			&'static str, //describe how the master key is encrypted
+		),
+		Error,
+	>;
+
+	/**
+	Returns the client state and the credential request for the server
+	 */
+	fn login_start(password: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error>;
+
+	/**
+	Returns the credential finalization for the server, the encryption key for the master key and the session key.
+
+	Fails if the password is wrong or the server is not the server of the registration.
+	 */
+	#[allow(clippy::type_complexity)]
+	fn login_finish(state: &[u8], password: &[u8], credential_response: &[u8]) -> Result<(Vec<u8>, Self::DMK, Vec<u8>), Error>;
+}
+
+/**
+# Server side of the password authenticated key exchange
+
+The server setup contains the long term key pair and the oprf seed of the server. It must be the same for every request.
+The password file from the registration is stored instead of the hashed auth key.
+ */
+pub trait PakeServer
+{
+	fn setup() -> Result<Vec<u8>, Error>;
+
+	fn registration_response(setup: &[u8], registration_request: &[u8], credential_identifier: &[u8]) -> Result<Vec<u8>, Error>;
+
+	/**
+	Returns the password file
+	 */
+	fn registration_finish(registration_upload: &[u8]) -> Result<Vec<u8>, Error>;
+
+	/**
+	Returns the server state and the credential response for the client.
+
+	Without a password file (unknown user) a fake response is created, so the client can't find out if the user exists.
+	 */
+	fn login_start(
+		setup: &[u8],
+		password_file: Option<&[u8]>,
+		credential_request: &[u8],
+		credential_identifier: &[u8],
+	) -> Result<(Vec<u8>, Vec<u8>), Error>;
+
+	/**
+	Returns the session key. This is the same as the session key of the client, if the login was successful.
+	 */
+	fn login_finish(state: &[u8], credential_finalization: &[u8]) -> Result<Vec<u8>, Error>;
 }
 
 pub trait PwPrepareExport
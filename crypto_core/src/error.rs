@@ -13,6 +13,10 @@ pub enum Error
 	PwSplitFailedLeft,
 	PwSplitFailedRight,
 	HashAuthKeyFailed,
+	PasskeySecretWrongLength,
+
+	PakeFailed,
+	PakeMessageWrongFormat,
 
 	KeyDecryptFailed,
 
@@ -22,6 +26,7 @@ pub enum Error
 	InitVerifyFailed,
 
 	AlgNotFound,
+	KeyOperationNotSupported,
 
 	DecodePrivateKeyFailed,
 
@@ -30,4 +35,6 @@ pub enum Error
 	OpeRangeError,
 	OpeHdgInvalidInputs,
 	OpeStringToLarge,
+
+	OreCiphertextInvalid,
 }
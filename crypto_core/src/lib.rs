@@ -23,6 +23,7 @@ extern crate alloc;
 pub mod cryptomat;
 mod error;
 pub mod group;
+pub mod safety_number;
 pub mod user;
 
 use rand_core::{CryptoRng, OsRng, RngCore};
@@ -51,6 +52,20 @@ pub fn generate_user_register_data() -> Result<([u8; 20], [u8; 40]), Error>
 	Ok((identifier, password))
 }
 
+/**
+32 random bytes, e.g. for the commitment of a device pairing
+ */
+pub fn generate_nonce() -> Result<[u8; 32], Error>
+{
+	let mut nonce = [0u8; 32];
+
+	get_rand()
+		.try_fill_bytes(&mut nonce)
+		.map_err(|_| Error::KeyCreationFailed)?;
+
+	Ok(nonce)
+}
+
 pub fn split_sig_and_data(data_with_sig: &[u8], len: usize) -> Result<(&[u8], &[u8]), Error>
 {
 	if data_with_sig.len() <= len {
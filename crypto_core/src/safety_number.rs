@@ -0,0 +1,608 @@
+//! Encodings of the safety number for the comparison by humans.
+//!
+//! The safety number from user::safety_number are the raw bytes of a sha256 hash.
+//! These bytes can be shown as groups of decimal digits or as words of the PGP word list.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/**
+The number of bytes for one group of digits
+ */
+const DIGIT_GROUP_BYTES: usize = 5;
+
+/**
+Converts the number into groups of five decimal digits, separated by a space.
+
+Every group is created from 5 bytes of the number (like the safety numbers of signal).
+A sha256 number got 30 digits, for a combined number use the digits of both users.
+Bytes at the end which are not enough for a group are not used.
+ */
+pub fn safety_number_to_digits(number: &[u8]) -> String
+{
+	let groups: Vec<String> = number
+		.chunks_exact(DIGIT_GROUP_BYTES)
+		.map(|chunk| {
+			let value = chunk.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+
+			format!("{:05}", value % 100000)
+		})
+		.collect();
+
+	groups.join(" ")
+}
+
+/**
+Converts every byte of the number into a word of the PGP word list.
+
+Bytes at an even position are taken from the even list and bytes at an odd position from the odd list.
+So a swapped or missing word is detected when reading the words aloud.
+ */
+pub fn safety_number_to_words(number: &[u8]) -> Vec<&'static str>
+{
+	number
+		.iter()
+		.enumerate()
+		.map(|(i, b)| {
+			if i % 2 == 0 {
+				PGP_EVEN_WORDS[*b as usize]
+			} else {
+				PGP_ODD_WORDS[*b as usize]
+			}
+		})
+		.collect()
+}
+
+/**
+The two syllable words of the PGP word list for the bytes at an even position
+ */
+pub const PGP_EVEN_WORDS: [&str; 256] = [
+	"aardvark",
+	"absurd",
+	"accrue",
+	"acme",
+	"adrift",
+	"adult",
+	"afflict",
+	"ahead",
+	"aimless",
+	"Algol",
+	"allow",
+	"alone",
+	"ammo",
+	"ancient",
+	"apple",
+	"artist",
+	"assume",
+	"Athens",
+	"atlas",
+	"Aztec",
+	"baboon",
+	"backfield",
+	"backward",
+	"banjo",
+	"beaming",
+	"bedlamp",
+	"beehive",
+	"beeswax",
+	"befriend",
+	"Belfast",
+	"berserk",
+	"billiard",
+	"bison",
+	"blackjack",
+	"blockade",
+	"blowtorch",
+	"bluebird",
+	"bombast",
+	"bookshelf",
+	"brackish",
+	"breadline",
+	"breakup",
+	"brickyard",
+	"briefcase",
+	"Burbank",
+	"button",
+	"buzzard",
+	"cement",
+	"chairlift",
+	"chatter",
+	"checkup",
+	"chisel",
+	"choking",
+	"chopper",
+	"Christmas",
+	"clamshell",
+	"classic",
+	"classroom",
+	"cleanup",
+	"clockwork",
+	"cobra",
+	"commence",
+	"concert",
+	"cowbell",
+	"crackdown",
+	"cranky",
+	"crowfoot",
+	"crucial",
+	"crumpled",
+	"crusade",
+	"cubic",
+	"dashboard",
+	"deadbolt",
+	"deckhand",
+	"dogsled",
+	"dragnet",
+	"drainage",
+	"dreadful",
+	"drifter",
+	"dropper",
+	"drumbeat",
+	"drunken",
+	"Dupont",
+	"dwelling",
+	"eating",
+	"edict",
+	"egghead",
+	"eightball",
+	"endorse",
+	"endow",
+	"enlist",
+	"erase",
+	"escape",
+	"exceed",
+	"eyeglass",
+	"eyetooth",
+	"facial",
+	"fallout",
+	"flagpole",
+	"flatfoot",
+	"flytrap",
+	"fracture",
+	"framework",
+	"freedom",
+	"frighten",
+	"gazelle",
+	"Geiger",
+	"glitter",
+	"glucose",
+	"goggles",
+	"goldfish",
+	"gremlin",
+	"guidance",
+	"hamlet",
+	"highchair",
+	"hockey",
+	"indoors",
+	"indulge",
+	"inverse",
+	"involve",
+	"island",
+	"jawbone",
+	"keyboard",
+	"kickoff",
+	"kiwi",
+	"klaxon",
+	"locale",
+	"lockup",
+	"merit",
+	"minnow",
+	"miser",
+	"Mohawk",
+	"mural",
+	"music",
+	"necklace",
+	"Neptune",
+	"newborn",
+	"nightbird",
+	"Oakland",
+	"obtuse",
+	"offload",
+	"optic",
+	"orca",
+	"payday",
+	"peachy",
+	"pheasant",
+	"physique",
+	"playhouse",
+	"Pluto",
+	"preclude",
+	"prefer",
+	"preshrunk",
+	"printer",
+	"prowler",
+	"pupil",
+	"puppy",
+	"python",
+	"quadrant",
+	"quiver",
+	"quota",
+	"ragtime",
+	"ratchet",
+	"rebirth",
+	"reform",
+	"regain",
+	"reindeer",
+	"rematch",
+	"repay",
+	"retouch",
+	"revenge",
+	"reward",
+	"rhythm",
+	"ribcage",
+	"ringbolt",
+	"robust",
+	"rocker",
+	"ruffled",
+	"sailboat",
+	"sawdust",
+	"scallion",
+	"scenic",
+	"scorecard",
+	"Scotland",
+	"seabird",
+	"select",
+	"sentence",
+	"shadow",
+	"shamrock",
+	"showgirl",
+	"skullcap",
+	"skydive",
+	"slingshot",
+	"slowdown",
+	"snapline",
+	"snapshot",
+	"snowcap",
+	"snowslide",
+	"solo",
+	"southward",
+	"soybean",
+	"spaniel",
+	"spearhead",
+	"spellbind",
+	"spheroid",
+	"spigot",
+	"spindle",
+	"spyglass",
+	"stagehand",
+	"stagnate",
+	"stairway",
+	"standard",
+	"stapler",
+	"steamship",
+	"sterling",
+	"stockman",
+	"stopwatch",
+	"stormy",
+	"sugar",
+	"surmount",
+	"suspense",
+	"sweatband",
+	"swelter",
+	"tactics",
+	"talon",
+	"tapeworm",
+	"tempest",
+	"tiger",
+	"tissue",
+	"tonic",
+	"topmost",
+	"tracker",
+	"transit",
+	"trauma",
+	"treadmill",
+	"Trojan",
+	"trouble",
+	"tumor",
+	"tunnel",
+	"tycoon",
+	"uncut",
+	"unearth",
+	"unwind",
+	"uproot",
+	"upset",
+	"upshot",
+	"vapor",
+	"village",
+	"virus",
+	"Vulcan",
+	"waffle",
+	"wallet",
+	"watchword",
+	"wayside",
+	"willow",
+	"woodlark",
+	"Zulu",
+];
+
+/**
+The three syllable words of the PGP word list for the bytes at an odd position
+ */
+pub const PGP_ODD_WORDS: [&str; 256] = [
+	"adroitness",
+	"adviser",
+	"aftermath",
+	"aggregate",
+	"alkali",
+	"almighty",
+	"amulet",
+	"amusement",
+	"antenna",
+	"applicant",
+	"Apollo",
+	"armistice",
+	"article",
+	"asteroid",
+	"Atlantic",
+	"atmosphere",
+	"autopsy",
+	"Babylon",
+	"backwater",
+	"barbecue",
+	"belowground",
+	"bifocals",
+	"bodyguard",
+	"bookseller",
+	"borderline",
+	"bottomless",
+	"Bradbury",
+	"bravado",
+	"Brazilian",
+	"breakaway",
+	"Burlington",
+	"businessman",
+	"butterfat",
+	"Camelot",
+	"candidate",
+	"cannonball",
+	"Capricorn",
+	"caravan",
+	"caretaker",
+	"celebrate",
+	"cellulose",
+	"certify",
+	"chambermaid",
+	"Cherokee",
+	"Chicago",
+	"clergyman",
+	"coherence",
+	"combustion",
+	"commando",
+	"company",
+	"component",
+	"concurrent",
+	"confidence",
+	"conformist",
+	"congregate",
+	"consensus",
+	"consulting",
+	"corporate",
+	"corrosion",
+	"councilman",
+	"crossover",
+	"crucifix",
+	"cumbersome",
+	"customer",
+	"Dakota",
+	"decadence",
+	"December",
+	"decimal",
+	"designing",
+	"detector",
+	"detergent",
+	"determine",
+	"dictator",
+	"dinosaur",
+	"direction",
+	"disable",
+	"disbelief",
+	"disruptive",
+	"distortion",
+	"document",
+	"embezzle",
+	"enchanting",
+	"enrollment",
+	"enterprise",
+	"equation",
+	"equipment",
+	"escapade",
+	"Eskimo",
+	"everyday",
+	"examine",
+	"existence",
+	"exodus",
+	"fascinate",
+	"filament",
+	"finicky",
+	"forever",
+	"fortitude",
+	"frequency",
+	"gadgetry",
+	"Galveston",
+	"getaway",
+	"glossary",
+	"gossamer",
+	"graduate",
+	"gravity",
+	"guitarist",
+	"hamburger",
+	"Hamilton",
+	"handiwork",
+	"hazardous",
+	"headwaters",
+	"hemisphere",
+	"hesitate",
+	"hideaway",
+	"holiness",
+	"hurricane",
+	"hydraulic",
+	"impartial",
+	"impetus",
+	"inception",
+	"indigo",
+	"inertia",
+	"infancy",
+	"inferno",
+	"informant",
+	"insincere",
+	"insurgent",
+	"integrate",
+	"intention",
+	"inventive",
+	"Istanbul",
+	"Jamaica",
+	"Jupiter",
+	"leprosy",
+	"letterhead",
+	"liberty",
+	"maritime",
+	"matchmaker",
+	"maverick",
+	"Medusa",
+	"megaton",
+	"microscope",
+	"microwave",
+	"midsummer",
+	"millionaire",
+	"miracle",
+	"misnomer",
+	"molasses",
+	"molecule",
+	"Montana",
+	"monument",
+	"mosquito",
+	"narrative",
+	"nebula",
+	"newsletter",
+	"Norwegian",
+	"October",
+	"Ohio",
+	"onlooker",
+	"opulent",
+	"Orlando",
+	"outfielder",
+	"Pacific",
+	"pandemic",
+	"Pandora",
+	"paperweight",
+	"paragon",
+	"paragraph",
+	"paramount",
+	"passenger",
+	"pedigree",
+	"Pegasus",
+	"penetrate",
+	"perceptive",
+	"performance",
+	"pharmacy",
+	"phonetic",
+	"photograph",
+	"pioneer",
+	"pocketful",
+	"politeness",
+	"positive",
+	"potato",
+	"processor",
+	"provincial",
+	"proximate",
+	"puberty",
+	"publisher",
+	"pyramid",
+	"quantity",
+	"racketeer",
+	"rebellion",
+	"recipe",
+	"recover",
+	"repellent",
+	"replica",
+	"reproduce",
+	"resistor",
+	"responsive",
+	"retraction",
+	"retrieval",
+	"retrospect",
+	"revenue",
+	"revival",
+	"revolver",
+	"sandalwood",
+	"sardonic",
+	"Saturday",
+	"savagery",
+	"scavenger",
+	"sensation",
+	"sociable",
+	"souvenir",
+	"specialist",
+	"speculate",
+	"stethoscope",
+	"stupendous",
+	"supportive",
+	"surrender",
+	"suspicious",
+	"sympathy",
+	"tambourine",
+	"telephone",
+	"therapist",
+	"tobacco",
+	"tolerance",
+	"tomorrow",
+	"torpedo",
+	"tradition",
+	"travesty",
+	"trombonist",
+	"truncated",
+	"typewriter",
+	"ultimate",
+	"undaunted",
+	"underfoot",
+	"unicorn",
+	"unify",
+	"universe",
+	"unravel",
+	"upcoming",
+	"vacancy",
+	"vagabond",
+	"vertigo",
+	"Virginia",
+	"visitor",
+	"vocalist",
+	"voyager",
+	"warranty",
+	"Waterloo",
+	"whimsical",
+	"Wichita",
+	"Wilmington",
+	"Wyoming",
+	"yesteryear",
+	"Yucatan",
+];
+
+#[cfg(test)]
+mod test
+{
+	use super::*;
+
+	#[test]
+	fn test_safety_number_to_digits()
+	{
+		let number = [0u8, 0, 0, 0, 1, 0xff, 0xff, 0xff, 0xff, 0xff, 7];
+
+		//the last byte is not enough for a group
+		assert_eq!(safety_number_to_digits(&number), "00001 27775");
+
+		assert_eq!(safety_number_to_digits(&[0u8; 32]).len(), 6 * 5 + 5);
+	}
+
+	#[test]
+	fn test_safety_number_to_words()
+	{
+		//the fingerprint example from the pgp word list
+		let number = [0xe5, 0x82, 0x94, 0xf2];
+
+		assert_eq!(
+			safety_number_to_words(&number),
+			["topmost", "Istanbul", "Pluto", "vagabond"]
+		);
+	}
+}
@@ -8,6 +8,7 @@ use crate::cryptomat::{
 	DeriveAuthKeyForAuth,
 	DeriveMasterKeyForAuth,
 	HashedAuthenticationKey,
+	PakeClient,
 	Pk,
 	PwHash,
 	SignK,
@@ -70,6 +71,43 @@ pub struct ResetPasswordOutput<CRV: ClientRandomValue, HAK: HashedAuthentication
 	pub encrypted_sign_key: Vec<u8>,
 }
 
+/**
+Another encrypted version of the same master key, e.g. by a passkey
+ */
+pub struct MasterKeyWrapperOutput<CRV: ClientRandomValue, HAK: HashedAuthenticationKey>
+{
+	pub client_random_value: CRV,
+	pub hashed_authentication_key_bytes: HAK,
+	pub encrypted_master_key: Vec<u8>,
+	pub encrypted_master_key_alg: &'static str,
+	pub derived_alg: &'static str,
+}
+
+pub struct PakeRegisterOutput<P: Pk, V: VerifyK>
+{
+	pub master_key_alg: &'static str,
+
+	//for the server instead of the hashed auth key
+	pub registration_upload: Vec<u8>,
+	pub encrypted_master_key: Vec<u8>,
+	pub encrypted_master_key_alg: &'static str,
+	pub derived_alg: &'static str,
+
+	pub public_key: P,
+	pub encrypted_private_key: Vec<u8>,
+	pub keypair_encrypt_alg: &'static str,
+	pub verify_key: V,
+	pub encrypted_sign_key: Vec<u8>,
+	pub keypair_sign_alg: &'static str,
+}
+
+pub struct PakeLoginOutput<DMK: DeriveMasterKeyForAuth>
+{
+	pub credential_finalization: Vec<u8>,
+	pub master_key_encryption_key: DMK,
+	pub session_key: Vec<u8>,
+}
+
 pub struct PrepareLoginOutput<DMK: DeriveMasterKeyForAuth, DAK: DeriveAuthKeyForAuth>
 {
 	pub master_key_encryption_key: DMK,
@@ -100,6 +138,28 @@ Then login again and encrypt everything what was encrypted by the old keys with
 pub fn register<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
 	password: &str,
 ) -> Result<RegisterOutPut<St::PublicKey, Sign::VerifyKey, H::CRV, H::HAK>, Error>
+{
+	register_internally::<S, St, Sign, H>(|master_key| H::derived_keys_from_password(password.as_bytes(), master_key, None))
+}
+
+/**
+# Register a new user with a passkey
+
+Like register but the master key is encrypted by a key derived from the 32 bytes secret of the passkey
+(e.g. the output of the WebAuthn prf extension) instead of the password.
+ */
+#[allow(clippy::type_complexity)]
+pub fn register_with_passkey<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
+	secret: &[u8],
+) -> Result<RegisterOutPut<St::PublicKey, Sign::VerifyKey, H::CRV, H::HAK>, Error>
+{
+	register_internally::<S, St, Sign, H>(|master_key| H::derived_keys_from_secret(secret, master_key))
+}
+
+#[allow(clippy::type_complexity)]
+fn register_internally<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
+	derive: impl FnOnce(&S::SymmetricKey) -> Result<(H::CRV, H::HAK, Vec<u8>, &'static str), Error>,
+) -> Result<RegisterOutPut<St::PublicKey, Sign::VerifyKey, H::CRV, H::HAK>, Error>
 {
 	//1. create master key
 	let master_key = S::generate()?;
@@ -113,8 +173,7 @@ pub fn register<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
 	let encrypted_private_key = sk.encrypt_by_master_key(&master_key)?;
 	let encrypted_sign_key = sign_k.encrypt_by_master_key(&master_key)?;
 
-	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) =
-		H::derived_keys_from_password(password.as_bytes(), &master_key, None)?;
+	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) = derive(&master_key)?;
 
 	Ok(RegisterOutPut {
 		master_key_alg: master_key.get_alg_str(),
@@ -147,6 +206,102 @@ pub fn prepare_login<H: PwHash>(password: &str, salt: &[u8], derived_encryption_
 	})
 }
 
+/**
+# Start the login with a passkey
+
+Like prepare_login but with the secret of the passkey. The salt and the alg are the values of the passkey wrapper, not of the password.
+The output is used in done_login like the output of prepare_login.
+ */
+pub fn prepare_login_with_passkey<H: PwHash>(
+	secret: &[u8],
+	salt: &[u8],
+	derived_encryption_key_alg: &str,
+) -> Result<PrepareLoginOutput<H::DMK, H::DAK>, Error>
+{
+	let (master_key_encryption_key, auth_key) = H::derive_keys_for_auth_from_secret(secret, salt, derived_encryption_key_alg)?;
+
+	Ok(PrepareLoginOutput {
+		master_key_encryption_key,
+		auth_key,
+	})
+}
+
+/**
+# Start the registration with a password authenticated key exchange
+
+Returns the state for register_pake_finish and the registration request for the server.
+ */
+pub fn register_pake_start<P: PakeClient>(password: &str) -> Result<(Vec<u8>, Vec<u8>), Error>
+{
+	P::registration_start(password.as_bytes())
+}
+
+/**
+# Finish the registration with a password authenticated key exchange
+
+Like register but the master key is encrypted by the export key of the protocol.
+The server gets the registration upload, which is not password-equivalent.
+ */
+#[allow(clippy::type_complexity)]
+pub fn register_pake_finish<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, P: PakeClient>(
+	state: &[u8],
+	password: &str,
+	registration_response: &[u8],
+) -> Result<PakeRegisterOutput<St::PublicKey, Sign::VerifyKey>, Error>
+{
+	let master_key = S::generate()?;
+
+	let (sk, public_key) = St::generate_static_keypair()?;
+	let (sign_k, verify_key) = Sign::generate_key_pair()?;
+
+	let encrypted_private_key = sk.encrypt_by_master_key(&master_key)?;
+	let encrypted_sign_key = sign_k.encrypt_by_master_key(&master_key)?;
+
+	let (registration_upload, encrypted_master_key, encrypted_master_key_alg) =
+		P::registration_finish(state, password.as_bytes(), registration_response, &master_key)?;
+
+	Ok(PakeRegisterOutput {
+		master_key_alg: master_key.get_alg_str(),
+		registration_upload,
+		encrypted_master_key,
+		encrypted_master_key_alg,
+		derived_alg: P::get_alg_str(),
+		public_key,
+		encrypted_private_key,
+		keypair_encrypt_alg: sk.get_alg_str(),
+		verify_key,
+		encrypted_sign_key,
+		keypair_sign_alg: sign_k.get_alg_str(),
+	})
+}
+
+/**
+# Start the login with a password authenticated key exchange
+
+Returns the state for prepare_login_pake_finish and the credential request for the server.
+ */
+pub fn prepare_login_pake_start<P: PakeClient>(password: &str) -> Result<(Vec<u8>, Vec<u8>), Error>
+{
+	P::login_start(password.as_bytes())
+}
+
+/**
+# Finish the key exchange of the login
+
+The master key encryption key is used in done_login like the output of prepare_login.
+The credential finalization is sent to the server instead of the auth key.
+ */
+pub fn prepare_login_pake_finish<P: PakeClient>(state: &[u8], password: &str, credential_response: &[u8]) -> Result<PakeLoginOutput<P::DMK>, Error>
+{
+	let (credential_finalization, master_key_encryption_key, session_key) = P::login_finish(state, password.as_bytes(), credential_response)?;
+
+	Ok(PakeLoginOutput {
+		credential_finalization,
+		master_key_encryption_key,
+		session_key,
+	})
+}
+
 /**
 # End the login process
 
@@ -174,6 +329,58 @@ pub fn done_login<SkC: SkComposer, SiC: SignKeyComposer>(
 	})
 }
 
+/**
+# Add a passkey to the account
+
+Encrypts the same master key with the secret of the passkey.
+The master key is decrypted with the derived key of the actual login, this can be the password or another passkey.
+The password wrapper stays valid, so the user can log in with both.
+ */
+pub fn add_passkey<H: PwHash>(
+	derived_encryption_key: &impl DeriveMasterKeyForAuth,
+	encrypted_master_key: &[u8],
+	secret: &[u8],
+) -> Result<MasterKeyWrapperOutput<H::CRV, H::HAK>, Error>
+{
+	let master_key = derived_encryption_key.get_master_key(encrypted_master_key)?;
+
+	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) =
+		H::derived_keys_from_secret(secret, &master_key)?;
+
+	Ok(MasterKeyWrapperOutput {
+		derived_alg: client_random_value.get_alg_str(),
+		client_random_value,
+		hashed_authentication_key_bytes,
+		encrypted_master_key,
+		encrypted_master_key_alg,
+	})
+}
+
+/**
+# Add a password to the account
+
+For users who registered with a passkey. Like add_passkey but the master key is encrypted by the password.
+ */
+pub fn add_password<H: PwHash>(
+	derived_encryption_key: &impl DeriveMasterKeyForAuth,
+	encrypted_master_key: &[u8],
+	password: &str,
+) -> Result<MasterKeyWrapperOutput<H::CRV, H::HAK>, Error>
+{
+	let master_key = derived_encryption_key.get_master_key(encrypted_master_key)?;
+
+	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) =
+		H::derived_keys_from_password(password.as_bytes(), &master_key, None)?;
+
+	Ok(MasterKeyWrapperOutput {
+		derived_alg: client_random_value.get_alg_str(),
+		client_random_value,
+		hashed_authentication_key_bytes,
+		encrypted_master_key,
+		encrypted_master_key_alg,
+	})
+}
+
 /**
 # Prepare Password change
 
@@ -224,6 +431,29 @@ pub fn change_password<H: PwHash>(
 	})
 }
 
+/**
+# Upgrade the password hash
+
+Like change_password with the same password. The master key is encrypted again with the parameters of the actual policy.
+Use it after a login if PwHash::needs_upgrade is true for the alg of the user and send the output to the change password endpoint.
+ */
+#[allow(clippy::type_complexity)]
+pub fn upgrade_password_hash<H: PwHash>(
+	password: &str,
+	salt: &[u8],
+	encrypted_master_key: &[u8],
+	derived_encryption_key_alg: &str,
+) -> Result<ChangePasswordOutput<H::CRV, H::HAK, H::DAK>, Error>
+{
+	change_password::<H>(
+		password,
+		password,
+		salt,
+		encrypted_master_key,
+		derived_encryption_key_alg,
+	)
+}
+
 /**
 # Reset the users password
 
@@ -44,6 +44,9 @@ const RECOMMENDED_LENGTH: usize = 16;
 
 const FIPS_MIN_ITER: usize = 210_000;
 
+//for the passphrase encryption of data which is stored on the device or offline
+const FIPS_PASSPHRASE_ITER: usize = 2_100_000;
+
 const SALT_STRING_MAX_LENGTH: usize = 200; //200 chars
 
 const SALT_HASH_INPUT_LENGTH: usize = RECOMMENDED_LENGTH + SALT_STRING_MAX_LENGTH; //216 bytes
@@ -54,6 +57,9 @@ pub(super) const HALF_DERIVED_KEY_LENGTH: usize = DERIVED_KEY_LENGTH / 2;
 
 pub const FIPS_OPENSSL_PW_HASH_ALG: &str = "fips_openssl_pbkdf2_hmac";
 
+//the derived alg of the passphrase hash with the iterations
+pub const FIPS_OPENSSL_PASSPHRASE_I2100000: &str = "fips_openssl_pbkdf2_hmac-passphrase-i2100000";
+
 pub struct ClientRandomValue([u8; 16]);
 
 crypto_alg_str_impl!(ClientRandomValue, FIPS_OPENSSL_PW_HASH_ALG);
@@ -153,6 +159,33 @@ impl PwHash for PwHasherGetter
 
 		Ok(Aes256GcmKey::from_raw_key(key))
 	}
+
+	fn passphrase_to_encrypt(password: &[u8]) -> Result<(Self::PWS, impl SymKey, &'static str), Error>
+	{
+		let mut salt = [0u8; RECOMMENDED_LENGTH];
+		rand_bytes(&mut salt).map_err(|_| Error::PwHashFailed)?;
+
+		let key = get_derived_single_key_with_iter(password, &salt, FIPS_PASSPHRASE_ITER)?;
+
+		Ok((
+			PasswordEncryptSalt(salt),
+			Aes256GcmKey::from_raw_key(key),
+			FIPS_OPENSSL_PASSPHRASE_I2100000,
+		))
+	}
+
+	fn passphrase_to_decrypt(password: &[u8], salt: &[u8], alg: &str) -> Result<impl SymKey, Error>
+	{
+		//a changed iteration count must get a new alg
+		let iter = match alg {
+			FIPS_OPENSSL_PASSPHRASE_I2100000 => FIPS_PASSPHRASE_ITER,
+			_ => return Err(Error::AlgNotFound),
+		};
+
+		let key = get_derived_single_key_with_iter(password, salt, iter)?;
+
+		Ok(Aes256GcmKey::from_raw_key(key))
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -280,17 +313,15 @@ fn derived_single_key(password: &[u8]) -> Result<([u8; 32], [u8; RECOMMENDED_LEN
 }
 
 fn get_derived_single_key(password: &[u8], salt: &[u8]) -> Result<[u8; 32], Error>
+{
+	get_derived_single_key_with_iter(password, salt, FIPS_MIN_ITER)
+}
+
+fn get_derived_single_key_with_iter(password: &[u8], salt: &[u8], iter: usize) -> Result<[u8; 32], Error>
 {
 	let mut derived_key = [0u8; 32];
 
-	pbkdf2_hmac(
-		password,
-		salt,
-		FIPS_MIN_ITER,
-		MessageDigest::sha512(),
-		derived_key.as_mut(),
-	)
-	.map_err(|_| Error::PwHashFailed)?;
+	pbkdf2_hmac(password, salt, iter, MessageDigest::sha512(), derived_key.as_mut()).map_err(|_| Error::PwHashFailed)?;
 
 	Ok(derived_key)
 }
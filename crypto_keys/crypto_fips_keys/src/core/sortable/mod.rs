@@ -0,0 +1,182 @@
+use sentc_crypto_core::cryptomat::{CryptoAlg, SortableKey, SortableKeyComposer, SortableKeyGen, SymKey};
+use sentc_crypto_core::{crypto_alg_str_impl, Error};
+
+#[cfg(feature = "ore_sort")]
+use crate::core::sortable::ore::OreSortableKey;
+
+#[cfg(feature = "ore_sort")]
+pub mod ore;
+
+pub const FIPS_OPENSSL_SORTABLE: &str = "fips_openssl_sortable_none";
+
+macro_rules! deref_macro {
+    ($self:expr, $method:ident $(, $args:expr)*) => {
+        match $self {
+           	Self::None(inner) => inner.$method($($args),*),
+           	#[cfg(feature = "ore_sort")]
+           	Self::Ore(inner) => inner.$method($($args),*),
+        }
+    };
+}
+
+/**
+The sortable keys of fips.
+
+Ope is not available with openssl, so the default key can't encrypt.
+The order-revealing key is not fips approved and only available with the ore_sort feature.
+ */
+pub enum SortKeys
+{
+	None(NonSortableKeys),
+	#[cfg(feature = "ore_sort")]
+	Ore(OreSortableKey),
+}
+
+impl SortKeys
+{
+	#[cfg(feature = "ore_sort")]
+	pub fn ore_key_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+	{
+		Ok(SortKeys::Ore(bytes.try_into()?))
+	}
+}
+
+impl CryptoAlg for SortKeys
+{
+	fn get_alg_str(&self) -> &'static str
+	{
+		deref_macro!(self, get_alg_str)
+	}
+}
+
+impl SortableKey for SortKeys
+{
+	fn encrypt_key_with_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		deref_macro!(self, encrypt_key_with_master_key, master_key)
+	}
+
+	fn encrypt_sortable(&self, data: u64) -> Result<u64, Error>
+	{
+		deref_macro!(self, encrypt_sortable, data)
+	}
+
+	fn max_sortable(&self) -> u64
+	{
+		deref_macro!(self, max_sortable)
+	}
+
+	fn encrypt_sortable_ore(&self, data: u64) -> Result<Vec<u8>, Error>
+	{
+		deref_macro!(self, encrypt_sortable_ore, data)
+	}
+
+	fn is_order_revealing(&self) -> bool
+	{
+		deref_macro!(self, is_order_revealing)
+	}
+}
+
+impl SortableKeyGen for SortKeys
+{
+	type SortableKey = Self;
+
+	fn generate() -> Result<Self::SortableKey, Error>
+	{
+		Ok(NonSortableKeys::generate()?.into())
+	}
+}
+
+impl SortableKeyComposer for SortKeys
+{
+	type Key = Self;
+
+	#[cfg_attr(not(feature = "ore_sort"), allow(unused_variables))]
+	fn decrypt_by_master_key<M: SymKey>(master_key: &M, encrypted_key: &[u8], alg_str: &str) -> Result<Self::Key, Error>
+	{
+		match alg_str {
+			FIPS_OPENSSL_SORTABLE => Ok(SortKeys::None(NonSortableKeys)),
+			#[cfg(feature = "ore_sort")]
+			ore::ORE_OUT => Ok(SortKeys::Ore(master_key.decrypt(encrypted_key)?.try_into()?)),
+			_ => Err(Error::AlgNotFound),
+		}
+	}
+}
+
+//__________________________________________________________________________________________________
+
+pub struct NonSortableKeys;
+
+crypto_alg_str_impl!(NonSortableKeys, FIPS_OPENSSL_SORTABLE);
+
+impl SortableKey for NonSortableKeys
+{
+	fn encrypt_key_with_master_key<M: SymKey>(&self, _master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		Ok(Default::default())
+	}
+
+	fn encrypt_sortable(&self, _data: u64) -> Result<u64, Error>
+	{
+		Err(Error::KeyOperationNotSupported)
+	}
+}
+
+impl Into<SortKeys> for NonSortableKeys
+{
+	fn into(self) -> SortKeys
+	{
+		SortKeys::None(self)
+	}
+}
+
+impl SortableKeyGen for NonSortableKeys
+{
+	type SortableKey = Self;
+
+	fn generate() -> Result<Self::SortableKey, Error>
+	{
+		Ok(Self)
+	}
+}
+
+impl SortableKeyComposer for NonSortableKeys
+{
+	type Key = Self;
+
+	fn decrypt_by_master_key<M: SymKey>(_master_key: &M, _encrypted_key: &[u8], alg_str: &str) -> Result<Self::Key, Error>
+	{
+		if alg_str != FIPS_OPENSSL_SORTABLE {
+			return Err(Error::AlgNotFound);
+		}
+
+		Ok(Self)
+	}
+}
+
+#[cfg(test)]
+mod test
+{
+	use super::*;
+
+	#[test]
+	fn test_gen_key()
+	{
+		let _ = NonSortableKeys::generate().unwrap();
+	}
+
+	#[test]
+	fn test_encrypt()
+	{
+		let key = NonSortableKeys::generate().unwrap();
+
+		let numbers = [262u64, 300, 1000, 65531];
+
+		for number in numbers {
+			let res = key.encrypt_sortable(number);
+
+			//not implemented for fips
+			assert!(matches!(res, Err(Error::KeyOperationNotSupported)));
+		}
+	}
+}
@@ -0,0 +1,318 @@
+//! Block order-revealing encryption after Lewi and Wu with the openssl hmac and sha256.
+//!
+//! This is the same scheme as the ore key of the std keys, so the ciphertexts can be compared with both compare fns.
+//! Only the primitives are fips approved, the order-revealing scheme itself is not.
+//! Because of this the key is only available with the ore_sort feature.
+
+use std::cmp::Ordering;
+
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::rand::rand_bytes;
+use openssl::sha::Sha256;
+use openssl::sign::Signer;
+use sentc_crypto_core::cryptomat::{SortableKey, SortableKeyGen, SymKey};
+use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
+use zeroize::Zeroize;
+
+use crate::core::sortable::SortKeys;
+
+pub const ORE_OUT: &str = "ORE-LW-4";
+
+const BLOCK_BITS: u32 = 4;
+const BLOCKS: usize = 16;
+const DOMAIN: usize = 16;
+
+const PRF_LEN: usize = 32;
+const NONCE_LEN: usize = 16;
+
+const LEFT_BLOCK_LEN: usize = PRF_LEN + 1;
+const LEFT_LEN: usize = BLOCKS * LEFT_BLOCK_LEN;
+const RIGHT_LEN: usize = NONCE_LEN + BLOCKS * DOMAIN;
+
+/**
+The length of one ore output (left and right ciphertext)
+ */
+pub const ORE_CIPHERTEXT_LEN: usize = LEFT_LEN + RIGHT_LEN;
+
+//first half is the prf key, the 2nd half the key for the block permutation
+type OreKey = [u8; 64];
+
+pub struct OreSortableKey(OreKey);
+
+impl Drop for OreSortableKey
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
+try_from_bytes_owned_single_value!(OreSortableKey);
+as_ref_bytes_single_value!(OreSortableKey);
+
+crypto_alg_str_impl!(OreSortableKey, ORE_OUT);
+
+impl SortableKey for OreSortableKey
+{
+	fn encrypt_key_with_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		master_key.encrypt(&self.0)
+	}
+
+	fn encrypt_sortable(&self, _data: u64) -> Result<u64, Error>
+	{
+		//ore has no numeric output, use encrypt_sortable_ore
+		Err(Error::KeyOperationNotSupported)
+	}
+
+	fn encrypt_sortable_ore(&self, data: u64) -> Result<Vec<u8>, Error>
+	{
+		encrypt_internally(&self.0, data)
+	}
+
+	fn is_order_revealing(&self) -> bool
+	{
+		true
+	}
+}
+
+impl Into<SortKeys> for OreSortableKey
+{
+	fn into(self) -> SortKeys
+	{
+		SortKeys::Ore(self)
+	}
+}
+
+impl SortableKeyGen for OreSortableKey
+{
+	type SortableKey = Self;
+
+	fn generate() -> Result<Self::SortableKey, Error>
+	{
+		let mut key = [0u8; 64];
+
+		rand_bytes(&mut key).map_err(|_| Error::KeyCreationFailed)?;
+
+		Ok(Self(key))
+	}
+}
+
+/**
+Compares two ore ciphertexts without the key.
+
+Returns the ordering of the plaintext of a compared to the plaintext of b.
+Both ciphertexts must be created by the same key.
+ */
+pub fn ore_compare(a: &[u8], b: &[u8]) -> Result<Ordering, Error>
+{
+	if a.len() != ORE_CIPHERTEXT_LEN || b.len() != ORE_CIPHERTEXT_LEN {
+		return Err(Error::OreCiphertextInvalid);
+	}
+
+	//use the left part of a and the right part of b
+	let left = &a[..LEFT_LEN];
+	let right = &b[LEFT_LEN..];
+
+	let nonce = &right[..NONCE_LEN];
+	let values = &right[NONCE_LEN..];
+
+	for i in 0..BLOCKS {
+		let left_block = &left[i * LEFT_BLOCK_LEN..(i + 1) * LEFT_BLOCK_LEN];
+		let prf = &left_block[..PRF_LEN];
+		let h = left_block[PRF_LEN] as usize;
+
+		if h >= DOMAIN {
+			return Err(Error::OreCiphertextInvalid);
+		}
+
+		let v = values[i * DOMAIN + h];
+
+		if v > 2 {
+			return Err(Error::OreCiphertextInvalid);
+		}
+
+		match (v + 3 - mask(prf, nonce)) % 3 {
+			0 => continue,
+			1 => return Ok(Ordering::Greater),
+			_ => return Ok(Ordering::Less),
+		}
+	}
+
+	Ok(Ordering::Equal)
+}
+
+//__________________________________________________________________________________________________
+
+fn encrypt_internally(key: &OreKey, data: u64) -> Result<Vec<u8>, Error>
+{
+	let (prf_key, perm_key) = key.split_at(32);
+
+	let mut nonce = [0u8; NONCE_LEN];
+
+	rand_bytes(&mut nonce).map_err(|_| Error::EncryptionFailedRng)?;
+
+	let mut out = Vec::with_capacity(ORE_CIPHERTEXT_LEN);
+	let mut right = Vec::with_capacity(RIGHT_LEN);
+
+	right.extend_from_slice(&nonce);
+
+	for i in 0..BLOCKS {
+		let prefix = block_prefix(data, i);
+		let block = block_value(data, i);
+
+		let perm = permutation(perm_key, &prefix)?;
+
+		//left: prf of the prefix and the permuted block, plus the permuted block as index for the right ciphertext
+		let h = perm[block];
+
+		out.extend_from_slice(&prf(prf_key, &prefix, h)?);
+		out.push(h as u8);
+
+		//right: for every possible block value the comparison to the real block value, masked with the prf of the left side
+		let mut inverse = [0usize; DOMAIN];
+
+		for (j, p) in perm.iter().enumerate() {
+			inverse[*p] = j;
+		}
+
+		for (j, z) in inverse.iter().enumerate() {
+			let cmp = match z.cmp(&block) {
+				Ordering::Equal => 0u8,
+				Ordering::Greater => 1,
+				Ordering::Less => 2,
+			};
+
+			let m = mask(&prf(prf_key, &prefix, j)?, &nonce);
+
+			right.push((cmp + m) % 3);
+		}
+	}
+
+	out.extend_from_slice(&right);
+
+	Ok(out)
+}
+
+/**
+The value of the block i, starting from the most significant bits
+ */
+fn block_value(data: u64, i: usize) -> usize
+{
+	let shift = BLOCK_BITS * (BLOCKS - 1 - i) as u32;
+
+	((data >> shift) & 0xF) as usize
+}
+
+/**
+All blocks before the block i and the block index
+ */
+fn block_prefix(data: u64, i: usize) -> [u8; 9]
+{
+	let prefix = if i == 0 { 0 } else { data >> (BLOCK_BITS * (BLOCKS - i) as u32) };
+
+	let mut out = [0u8; 9];
+	out[0] = i as u8;
+	out[1..].copy_from_slice(&prefix.to_be_bytes());
+
+	out
+}
+
+fn hmac(key: &[u8], data: &[&[u8]]) -> Result<Vec<u8>, Error>
+{
+	let key = PKey::hmac(key).map_err(|_| Error::HmacAuthFailedLength)?;
+
+	let mut signer = Signer::new(MessageDigest::sha256(), &key).map_err(|_| Error::HmacAuthFailedLength)?;
+
+	for d in data {
+		signer.update(d).map_err(|_| Error::HmacAuthFailedLength)?;
+	}
+
+	signer
+		.sign_to_vec()
+		.map_err(|_| Error::HmacAuthFailedLength)
+}
+
+fn prf(key: &[u8], prefix: &[u8], value: usize) -> Result<[u8; PRF_LEN], Error>
+{
+	let mac = hmac(key, &[prefix, &[value as u8]])?;
+
+	let mut out = [0u8; PRF_LEN];
+	out.copy_from_slice(&mac);
+
+	Ok(out)
+}
+
+/**
+Keyed permutation of the block domain for a prefix (Fisher–Yates with hmac output as randomness)
+ */
+fn permutation(key: &[u8], prefix: &[u8]) -> Result<[usize; DOMAIN], Error>
+{
+	let rand = hmac(key, &[prefix])?;
+
+	let mut perm = [0usize; DOMAIN];
+
+	for (i, p) in perm.iter_mut().enumerate() {
+		*p = i;
+	}
+
+	for i in (1..DOMAIN).rev() {
+		let r = u16::from_be_bytes([rand[2 * i], rand[2 * i + 1]]) as usize % (i + 1);
+
+		perm.swap(i, r);
+	}
+
+	Ok(perm)
+}
+
+/**
+Hash of the prf output and the nonce of the right ciphertext, reduced to mod 3
+ */
+fn mask(prf: &[u8], nonce: &[u8]) -> u8
+{
+	let mut hasher = Sha256::new();
+
+	hasher.update(prf);
+	hasher.update(nonce);
+
+	let hash = hasher.finish();
+
+	let mut n = [0u8; 8];
+	n.copy_from_slice(&hash[..8]);
+
+	(u64::from_be_bytes(n) % 3) as u8
+}
+
+#[cfg(test)]
+mod test
+{
+	use super::*;
+
+	#[test]
+	fn test_encrypt()
+	{
+		let key = OreSortableKey::generate().unwrap();
+
+		let numbers = [0u64, 262, 300, 1000, 65531, 65532, u32::MAX as u64, u64::MAX];
+
+		let out = numbers
+			.iter()
+			.map(|n| key.encrypt_sortable_ore(*n).unwrap())
+			.collect::<Vec<_>>();
+
+		for (i, a) in out.iter().enumerate() {
+			assert_eq!(a.len(), ORE_CIPHERTEXT_LEN);
+
+			for (j, b) in out.iter().enumerate() {
+				assert_eq!(ore_compare(a, b).unwrap(), i.cmp(&j));
+			}
+		}
+
+		//ore keys have no numeric output
+		assert!(matches!(
+			key.encrypt_sortable(42),
+			Err(Error::KeyOperationNotSupported)
+		));
+	}
+}
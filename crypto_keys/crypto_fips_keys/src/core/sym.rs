@@ -1,7 +1,11 @@
+use openssl::md::Md;
+use openssl::pkey::Id;
+use openssl::pkey_ctx::PkeyCtx;
 use openssl::rand::rand_bytes;
 use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
 use sentc_crypto_core::cryptomat::{Pk, SymKey, SymKeyComposer, SymKeyGen};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, try_from_bytes_single_value, Error};
+use zeroize::Zeroize;
 
 pub const FIPS_OPENSSL_AES_GCM: &str = "FIPS_OPENSSL_AES_GCM-256";
 
@@ -12,6 +16,14 @@ pub(crate) type AesKey = [u8; 32];
 
 pub struct Aes256GcmKey(AesKey);
 
+impl Drop for Aes256GcmKey
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 impl Aes256GcmKey
 {
 	pub fn from_raw_key(raw: AesKey) -> Self
@@ -82,6 +94,18 @@ impl SymKeyComposer for Aes256GcmKey
 
 		Self::try_from(bytes)
 	}
+
+	/**
+	Hkdf sha256 from openssl with the master key as ikm.
+	 */
+	fn derive_key_by_sym_key<M: SymKey>(master_key: &M, info: &[u8]) -> Result<Self::SymmetricKey, Error>
+	{
+		let mut key = [0u8; 32];
+
+		raw_hkdf_sha256(master_key.as_ref(), info, &mut key).map_err(|_| Error::KeyCreationFailed)?;
+
+		Ok(Aes256GcmKey(key))
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -104,6 +128,19 @@ pub fn raw_generate() -> Result<AesKey, Error>
 	Ok(key)
 }
 
+fn raw_hkdf_sha256(ikm: &[u8], info: &[u8], out: &mut [u8]) -> Result<(), openssl::error::ErrorStack>
+{
+	let mut ctx = PkeyCtx::new_id(Id::HKDF)?;
+
+	ctx.derive_init()?;
+	ctx.set_hkdf_md(Md::sha256())?;
+	ctx.set_hkdf_key(ikm)?;
+	ctx.add_hkdf_info(info)?;
+	ctx.derive(Some(out))?;
+
+	Ok(())
+}
+
 fn encrypt_internally(key: &[u8], data: &[u8], aad: Option<&[u8]>) -> Result<Vec<u8>, Error>
 {
 	//IV
@@ -184,6 +221,22 @@ mod test
 		assert_eq!(text, decrypted_text);
 	}
 
+	#[test]
+	fn test_derive_key()
+	{
+		openssl::provider::Provider::load(None, "fips").unwrap();
+
+		let master_key = Aes256GcmKey::generate().unwrap();
+
+		let key_1 = Aes256GcmKey::derive_key_by_sym_key(&master_key, b"part_0").unwrap();
+		let key_2 = Aes256GcmKey::derive_key_by_sym_key(&master_key, b"part_0").unwrap();
+		let key_3 = Aes256GcmKey::derive_key_by_sym_key(&master_key, b"part_1").unwrap();
+
+		assert_eq!(key_1.as_ref(), key_2.as_ref());
+		assert_ne!(key_1.as_ref(), key_3.as_ref());
+		assert_ne!(key_1.as_ref(), master_key.as_ref());
+	}
+
 	#[test]
 	fn test_not_decrypt_with_wrong_key()
 	{
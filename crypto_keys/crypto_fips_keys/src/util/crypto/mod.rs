@@ -3,14 +3,14 @@ use std::str::FromStr;
 use openssl::base64::{decode_block, encode_block};
 use sentc_crypto_common::content_sortable::SortableEncryptOutput;
 use sentc_crypto_common::SymKeyId;
-use sentc_crypto_core::cryptomat::SortableKeyComposer;
-use sentc_crypto_utils::cryptomat::{KeyToString, SortableKeyComposerWrapper, SortableKeyWrapper};
+use sentc_crypto_core::Error;
+use sentc_crypto_utils::cryptomat::{KeyToString, SortableKeyWrapper};
 use sentc_crypto_utils::error::SdkUtilError;
-use sentc_crypto_utils::{from_string_impl, to_string_try_impl};
+use sentc_crypto_utils::{from_string_impl, sortable_composer, to_string_try_impl};
 use serde::{Deserialize, Serialize};
 
 use crate::core::hmac::HmacKey as CoreHmacKey;
-use crate::core::sortable::NonSortableKeys;
+use crate::core::sortable::{NonSortableKeys, SortKeys};
 
 mod asym;
 mod searchable;
@@ -65,19 +65,40 @@ impl TryInto<HmacKey> for HmacFormatExport
 
 //__________________________________________________________________________________________________
 
-//Sortable key is not complained with fips
+//Sortable key is not complained with fips. Only the order-revealing key of the ore_sort feature can encrypt.
 
 pub struct SortableKey
 {
-	pub key: NonSortableKeys,
+	pub key: SortKeys,
 	pub key_id: SymKeyId,
 }
 
+#[derive(Serialize, Deserialize)]
+pub enum SortableFormatExport
+{
+	Ore
+	{
+		key: String, key_id: SymKeyId
+	},
+}
+
 impl KeyToString for SortableKey
 {
 	fn to_string(self) -> Result<String, SdkUtilError>
 	{
-		Ok(self.key_id)
+		match self.key {
+			//only the id for the keys without encryption
+			SortKeys::None(_) => Ok(self.key_id),
+			#[cfg(feature = "ore_sort")]
+			SortKeys::Ore(k) => {
+				let export = SortableFormatExport::Ore {
+					key: encode_block(k.as_ref()),
+					key_id: self.key_id,
+				};
+
+				serde_json::to_string(&export).map_err(|_e| SdkUtilError::JsonToStringFailed)
+			},
+		}
 	}
 }
 
@@ -87,30 +108,37 @@ impl FromStr for SortableKey
 
 	fn from_str(s: &str) -> Result<Self, Self::Err>
 	{
-		Ok(Self {
-			key: NonSortableKeys,
-			key_id: s.to_string(),
-		})
-	}
-}
-
-impl SortableKeyComposerWrapper for SortableKey
-{
-	type SortableKeyWrapper = Self;
-	type Composer = NonSortableKeys;
-
-	fn from_inner(inner: <<Self as SortableKeyComposerWrapper>::Composer as SortableKeyComposer>::Key, id: String) -> Self::SortableKeyWrapper
-	{
-		Self {
-			key: inner,
-			key_id: id,
+		match serde_json::from_str::<SortableFormatExport>(s) {
+			#[cfg(feature = "ore_sort")]
+			Ok(SortableFormatExport::Ore {
+				key,
+				key_id,
+			}) => {
+				let bytes = decode_block(&key).map_err(|_| SdkUtilError::ImportSymmetricKeyFailed)?;
+
+				Ok(Self {
+					key: SortKeys::ore_key_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
+			#[cfg(not(feature = "ore_sort"))]
+			Ok(_) => Err(SdkUtilError::AlgNotFound),
+			Err(_) => {
+				//the non sortable key is exported as the key id only
+				Ok(Self {
+					key: SortKeys::None(NonSortableKeys),
+					key_id: s.to_string(),
+				})
+			},
 		}
 	}
 }
 
+sortable_composer!(SortableKey, SortKeys);
+
 impl SortableKeyWrapper for SortableKey
 {
-	type Inner = NonSortableKeys;
+	type Inner = SortKeys;
 
 	fn get_id(&self) -> &str
 	{
@@ -124,11 +152,12 @@ impl SortableKeyWrapper for SortableKey
 
 	fn encrypt_raw_string(&self, _data: &str, _max_len: Option<usize>) -> Result<u64, SdkUtilError>
 	{
-		Err(SdkUtilError::AlgNotFound)
+		//strings are only encrypted with ope
+		Err(SdkUtilError::Base(Error::KeyOperationNotSupported))
 	}
 
 	fn encrypt_string(&self, _data: &str, _max_len: Option<usize>) -> Result<SortableEncryptOutput, SdkUtilError>
 	{
-		Err(SdkUtilError::AlgNotFound)
+		Err(SdkUtilError::Base(Error::KeyOperationNotSupported))
 	}
 }
@@ -3,6 +3,7 @@ use safe_oqs::kem::{Kem, PublicKey, SecretKey};
 use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, from_bytes_owned_single_value, into_bytes_from_bytes_inner, Error};
 use sentc_crypto_fips_keys::core::sym::raw_decrypt;
+use zeroize::Zeroize;
 
 use crate::core::sym::raw_encrypt;
 
@@ -44,8 +45,26 @@ impl Into<super::PublicKey> for MlKemPk
 }
 
 pub struct MlKemSk(Vec<u8>);
+
+impl Drop for MlKemSk
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 crypto_alg_str_impl!(MlKemSk, ML_KEM_REC_OUTPUT);
-into_bytes_from_bytes_inner!(MlKemSk);
+
+impl From<MlKemSk> for Vec<u8>
+{
+	fn from(mut key: MlKemSk) -> Self
+	{
+		//the key is zeroized on drop, so take the bytes out of it
+		core::mem::take(&mut key.0)
+	}
+}
+
 from_bytes_owned_single_value!(MlKemSk);
 as_ref_bytes_single_value!(MlKemSk);
 
@@ -40,6 +40,9 @@ macro_rules! pw_hash_composer_impl {
 
 pub const REC_PW_HASH_ALG: &str = "Rec_argon2_hmac";
 
+//the derived alg of the passphrase hash with the parameters
+pub const REC_PASSPHRASE_M65536_T4_P1: &str = "Rec_argon2_hmac-passphrase-m65536-t4-p1";
+
 const RECOMMENDED_LENGTH: usize = 16;
 
 const SALT_STRING_MAX_LENGTH: usize = 200; //200 chars
@@ -48,6 +51,11 @@ const SALT_HASH_INPUT_LENGTH: usize = RECOMMENDED_LENGTH + SALT_STRING_MAX_LENGT
 
 const DERIVED_KEY_LENGTH: usize = 64;
 
+//for the passphrase encryption of data which is stored on the device or offline (64 mb and 4 passes)
+const PASSPHRASE_M_COST: u32 = 65536;
+const PASSPHRASE_T_COST: u32 = 4;
+const PASSPHRASE_P_COST: u32 = 1;
+
 pub(super) const HALF_DERIVED_KEY_LENGTH: usize = DERIVED_KEY_LENGTH / 2;
 
 pub struct ClientRandomValue([u8; RECOMMENDED_LENGTH]);
@@ -149,6 +157,39 @@ impl PwHash for PwHasher
 
 		Ok(Aes256GcmKey::from_raw_key(key))
 	}
+
+	fn passphrase_to_encrypt(password: &[u8]) -> Result<(Self::PWS, impl SymKey, &'static str), Error>
+	{
+		let mut salt = [0u8; RECOMMENDED_LENGTH];
+		rand_bytes(&mut salt).map_err(|_| Error::PwHashFailed)?;
+
+		let key = get_derived_single_key_with_params(
+			password,
+			&salt,
+			PASSPHRASE_M_COST,
+			PASSPHRASE_T_COST,
+			PASSPHRASE_P_COST,
+		)?;
+
+		Ok((
+			PasswordEncryptSalt(salt),
+			Aes256GcmKey::from_raw_key(key),
+			REC_PASSPHRASE_M65536_T4_P1,
+		))
+	}
+
+	fn passphrase_to_decrypt(password: &[u8], salt: &[u8], alg: &str) -> Result<impl SymKey, Error>
+	{
+		//changed parameters must get a new alg
+		let (m_cost, t_cost, p_cost) = match alg {
+			REC_PASSPHRASE_M65536_T4_P1 => (PASSPHRASE_M_COST, PASSPHRASE_T_COST, PASSPHRASE_P_COST),
+			_ => return Err(Error::AlgNotFound),
+		};
+
+		let key = get_derived_single_key_with_params(password, salt, m_cost, t_cost, p_cost)?;
+
+		Ok(Aes256GcmKey::from_raw_key(key))
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -284,14 +325,19 @@ fn derived_single_key(password: &[u8]) -> Result<([u8; 32], [u8; RECOMMENDED_LEN
 
 fn get_derived_single_key(password: &[u8], salt: &[u8]) -> Result<[u8; 32], Error>
 {
-	//aes 256 key
-	let params = Params::new(
+	get_derived_single_key_with_params(
+		password,
+		salt,
 		Params::DEFAULT_M_COST,
 		Params::DEFAULT_T_COST,
 		Params::DEFAULT_P_COST,
-		Some(32),
 	)
-	.map_err(|_| Error::PwHashFailed)?;
+}
+
+fn get_derived_single_key_with_params(password: &[u8], salt: &[u8], m_cost: u32, t_cost: u32, p_cost: u32) -> Result<[u8; 32], Error>
+{
+	//aes 256 key
+	let params = Params::new(m_cost, t_cost, p_cost, Some(32)).map_err(|_| Error::PwHashFailed)?;
 
 	let argon2 = Argon2::new(Algorithm::default(), Version::default(), params);
 
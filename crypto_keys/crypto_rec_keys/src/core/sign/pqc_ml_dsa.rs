@@ -3,6 +3,7 @@ use safe_oqs::sig;
 use safe_oqs::sig::{PublicKey, SecretKey, Sig as OqsSig};
 use sentc_crypto_core::cryptomat::{Sig, SignK, SignKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, from_bytes_owned_single_value, into_bytes_from_bytes_inner, Error};
+use zeroize::Zeroize;
 
 use crate::core::sign::Signature;
 
@@ -58,8 +59,26 @@ impl VerifyK for MlDsaVk
 //__________________________________________________________________________________________________
 
 pub struct MlDsaSk(Vec<u8>);
+
+impl Drop for MlDsaSk
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 crypto_alg_str_impl!(MlDsaSk, ML_DSA_REC_OUTPUT);
-into_bytes_from_bytes_inner!(MlDsaSk);
+
+impl From<MlDsaSk> for Vec<u8>
+{
+	fn from(mut key: MlDsaSk) -> Self
+	{
+		//the key is zeroized on drop, so take the bytes out of it
+		core::mem::take(&mut key.0)
+	}
+}
+
 from_bytes_owned_single_value!(MlDsaSk);
 as_ref_bytes_single_value!(MlDsaSk);
 
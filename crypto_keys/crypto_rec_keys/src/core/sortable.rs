@@ -1,10 +1,13 @@
 use ope::{get_ope, OpeError, OpeKey};
 use openssl::rand::rand_bytes;
-use sentc_crypto_core::cryptomat::{SortableKey, SortableKeyComposer, SortableKeyGen, SymKey};
+use sentc_crypto_core::cryptomat::{CryptoAlg, SortableKey, SortableKeyComposer, SortableKeyGen, SymKey};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, try_from_bytes_single_value, Error};
+pub use sentc_crypto_fips_keys::core::sortable::ore::{ore_compare, OreSortableKey, ORE_CIPHERTEXT_LEN, ORE_OUT};
 
 pub const OPE_REC_OUT: &str = "OPE_REC-16";
 
+const OPE_MAX: u64 = 65532;
+
 pub struct OpeSortableKey(OpeKey);
 
 try_from_bytes_single_value!(OpeSortableKey);
@@ -20,9 +23,14 @@ impl SortableKey for OpeSortableKey
 		master_key.encrypt(&self.0)
 	}
 
+	fn max_sortable(&self) -> u64
+	{
+		OPE_MAX
+	}
+
 	fn encrypt_sortable(&self, data: u64) -> Result<u64, Error>
 	{
-		if data > 65532 {
+		if data > OPE_MAX {
 			return Err(Error::OpeStringToLarge);
 		}
 
@@ -42,6 +50,14 @@ impl SortableKey for OpeSortableKey
 	}
 }
 
+impl Into<SortKeys> for OpeSortableKey
+{
+	fn into(self) -> SortKeys
+	{
+		SortKeys::Ope(self)
+	}
+}
+
 impl SortableKeyGen for OpeSortableKey
 {
 	type SortableKey = Self;
@@ -71,6 +87,109 @@ impl SortableKeyComposer for OpeSortableKey
 	}
 }
 
+//__________________________________________________________________________________________________
+
+macro_rules! deref_macro {
+    ($self:expr, $method:ident $(, $args:expr)*) => {
+        match $self {
+           	Self::Ope(inner) => inner.$method($($args),*),
+           	Self::Ore(inner) => inner.$method($($args),*),
+        }
+    };
+}
+
+/**
+The ope key of rec or the order-revealing key of fips
+ */
+pub enum SortKeys
+{
+	Ope(OpeSortableKey),
+	Ore(OreSortableKey),
+}
+
+impl SortKeys
+{
+	pub fn ope_key_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+	{
+		Ok(SortKeys::Ope(bytes.try_into()?))
+	}
+
+	pub fn ore_key_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+	{
+		Ok(SortKeys::Ore(bytes.try_into()?))
+	}
+}
+
+impl CryptoAlg for SortKeys
+{
+	fn get_alg_str(&self) -> &'static str
+	{
+		deref_macro!(self, get_alg_str)
+	}
+}
+
+impl AsRef<[u8]> for SortKeys
+{
+	fn as_ref(&self) -> &[u8]
+	{
+		deref_macro!(self, as_ref)
+	}
+}
+
+impl SortableKey for SortKeys
+{
+	fn encrypt_key_with_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		deref_macro!(self, encrypt_key_with_master_key, master_key)
+	}
+
+	fn encrypt_sortable(&self, data: u64) -> Result<u64, Error>
+	{
+		deref_macro!(self, encrypt_sortable, data)
+	}
+
+	fn max_sortable(&self) -> u64
+	{
+		deref_macro!(self, max_sortable)
+	}
+
+	fn encrypt_sortable_ore(&self, data: u64) -> Result<Vec<u8>, Error>
+	{
+		deref_macro!(self, encrypt_sortable_ore, data)
+	}
+
+	fn is_order_revealing(&self) -> bool
+	{
+		deref_macro!(self, is_order_revealing)
+	}
+}
+
+impl SortableKeyGen for SortKeys
+{
+	type SortableKey = Self;
+
+	fn generate() -> Result<Self::SortableKey, Error>
+	{
+		Ok(OpeSortableKey::generate()?.into())
+	}
+}
+
+impl SortableKeyComposer for SortKeys
+{
+	type Key = Self;
+
+	fn decrypt_by_master_key<M: SymKey>(master_key: &M, encrypted_key: &[u8], alg_str: &str) -> Result<Self::Key, Error>
+	{
+		let key = master_key.decrypt(encrypted_key)?;
+
+		match alg_str {
+			OPE_REC_OUT => Ok(SortKeys::Ope(key.try_into()?)),
+			ORE_OUT => Ok(SortKeys::Ore(key.try_into()?)),
+			_ => Err(Error::AlgNotFound),
+		}
+	}
+}
+
 #[cfg(test)]
 mod test
 {
@@ -105,4 +224,21 @@ mod test
 			past_item = item;
 		}
 	}
+
+	#[test]
+	fn test_encrypt_ore()
+	{
+		let key = SortKeys::Ore(OreSortableKey::generate().unwrap());
+
+		let a = key.encrypt_sortable_ore(262).unwrap();
+		let b = key.encrypt_sortable_ore(65531).unwrap();
+
+		assert_eq!(key.get_alg_str(), ORE_OUT);
+		assert_eq!(ore_compare(&a, &b).unwrap(), std::cmp::Ordering::Less);
+
+		assert!(matches!(
+			key.encrypt_sortable(262),
+			Err(Error::KeyOperationNotSupported)
+		));
+	}
 }
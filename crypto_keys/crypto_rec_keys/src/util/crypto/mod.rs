@@ -6,7 +6,7 @@ use sentc_crypto_utils::error::SdkUtilError;
 use sentc_crypto_utils::{from_string_impl, to_string_impl};
 use serde::{Deserialize, Serialize};
 
-use crate::core::sortable::OpeSortableKey;
+use crate::core::sortable::SortKeys;
 
 mod asym;
 mod sign;
@@ -15,7 +15,7 @@ mod symmetric_key;
 
 pub struct SortableKey
 {
-	pub key: OpeSortableKey,
+	pub key: SortKeys,
 	pub key_id: SymKeyId,
 }
 
@@ -29,6 +29,10 @@ pub enum SortableFormatExport
 	{
 		key: String, key_id: SymKeyId
 	},
+	Ore
+	{
+		key: String, key_id: SymKeyId
+	},
 }
 
 impl From<SortableKey> for SortableFormatExport
@@ -37,9 +41,19 @@ impl From<SortableKey> for SortableFormatExport
 	{
 		let key = encode_block(value.key.as_ref());
 
-		Self::Ope16 {
-			key,
-			key_id: value.key_id,
+		match value.key {
+			SortKeys::Ope(_) => {
+				Self::Ope16 {
+					key,
+					key_id: value.key_id,
+				}
+			},
+			SortKeys::Ore(_) => {
+				Self::Ore {
+					key,
+					key_id: value.key_id,
+				}
+			},
 		}
 	}
 }
@@ -58,7 +72,18 @@ impl TryInto<SortableKey> for SortableFormatExport
 				let bytes = decode_block(&key).map_err(|_| SdkUtilError::ImportSymmetricKeyFailed)?;
 
 				Ok(SortableKey {
-					key: bytes.try_into()?,
+					key: SortKeys::ope_key_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
+			SortableFormatExport::Ore {
+				key,
+				key_id,
+			} => {
+				let bytes = decode_block(&key).map_err(|_| SdkUtilError::ImportSymmetricKeyFailed)?;
+
+				Ok(SortableKey {
+					key: SortKeys::ore_key_from_bytes_owned(bytes)?,
 					key_id,
 				})
 			},
@@ -6,14 +6,14 @@ use sentc_crypto_utils::cryptomat::SortableKeyWrapper;
 use sentc_crypto_utils::error::SdkUtilError;
 use sentc_crypto_utils::sortable_composer;
 
-use crate::core::sortable::OpeSortableKey;
+use crate::core::sortable::SortKeys;
 use crate::util::crypto::SortableKey;
 
-sortable_composer!(SortableKey, OpeSortableKey);
+sortable_composer!(SortableKey, SortKeys);
 
 impl SortableKeyWrapper for SortableKey
 {
-	type Inner = OpeSortableKey;
+	type Inner = SortKeys;
 
 	fn get_id(&self) -> &str
 	{
@@ -6,6 +6,7 @@ use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
 use sha2::Sha256;
 use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
+use zeroize::Zeroize;
 
 use crate::core::asym::SecretKey;
 use crate::core::sym::aes_gcm::{raw_decrypt as aes_decrypt, raw_encrypt as aes_encrypt, AesKey};
@@ -52,6 +53,14 @@ impl Pk for EciesPk
 
 pub struct EciesSk([u8; 32]);
 
+impl Drop for EciesSk
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 try_from_bytes_owned_single_value!(EciesSk);
 crypto_alg_str_impl!(EciesSk, ECIES_OUTPUT);
 as_ref_bytes_single_value!(EciesSk);
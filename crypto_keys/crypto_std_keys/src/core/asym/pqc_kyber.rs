@@ -4,6 +4,7 @@ use pqc_kyber_edit::{decapsulate, encapsulate, keypair, PublicKey, SecretKey, KY
 use rand_core::{CryptoRng, RngCore};
 use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
+use zeroize::Zeroize;
 
 use crate::core::sym::aes_gcm::{raw_decrypt as aes_decrypt, raw_encrypt as aes_encrypt};
 use crate::get_rand;
@@ -43,6 +44,15 @@ impl Pk for KyberPk
 }
 
 pub struct KyberSk([u8; KYBER_SECRETKEYBYTES]);
+
+impl Drop for KyberSk
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 try_from_bytes_owned_single_value!(KyberSk);
 crypto_alg_str_impl!(KyberSk, KYBER_OUTPUT);
 as_ref_bytes_single_value!(KyberSk);
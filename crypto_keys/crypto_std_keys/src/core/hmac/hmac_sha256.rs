@@ -4,6 +4,7 @@ use hmac::{Hmac, Mac};
 use sentc_crypto_core::cryptomat::{SearchableKey, SearchableKeyGen, SymKey};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
 use sha2::Sha256;
+use zeroize::Zeroize;
 
 use crate::core::hmac::HmacKey;
 use crate::core::sym;
@@ -15,6 +16,14 @@ type HmacSha256 = Hmac<Sha256>;
 
 pub struct HmacSha256Key(AesKey);
 
+impl Drop for HmacSha256Key
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 try_from_bytes_owned_single_value!(HmacSha256Key);
 as_ref_bytes_single_value!(HmacSha256Key);
 crypto_alg_str_impl!(HmacSha256Key, HMAC_SHA256_OUTPUT);
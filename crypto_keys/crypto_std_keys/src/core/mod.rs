@@ -11,13 +11,25 @@ pub use self::asym::pqc_kyber::{KyberKeyPair, KyberPk, KyberSk, KYBER_OUTPUT};
 pub use self::asym::{PublicKey, SecretKey};
 pub use self::hmac::hmac_sha256::{HmacSha256Key, HMAC_SHA256_OUTPUT};
 pub use self::hmac::HmacKey;
-pub use self::pw_hash::argon2::ARGON_2_OUTPUT;
+pub use self::pw_hash::argon2::{
+	decode_argon2_alg,
+	Argon2DefaultPolicy,
+	Argon2HighPolicy,
+	Argon2ModeratePolicy,
+	Argon2Params,
+	Argon2Policy,
+	ARGON_2_M19456_T2_P1,
+	ARGON_2_M65536_T3_P4,
+	ARGON_2_OUTPUT,
+};
+pub use self::pw_hash::opaque::{OpaqueGetter, OPAQUE_OUTPUT};
 pub use self::pw_hash::{
 	ClientRandomValue,
 	DeriveAuthKeyForAuth,
 	DeriveMasterKeyForAuth,
 	HashedAuthenticationKey,
 	PasswordEncryptSalt,
+	PwHasher,
 	PwHasherGetter,
 };
 pub use self::sign::ed25519::{Ed25519KeyPair, Ed25519Sig, Ed25519SignK, Ed25519VerifyK, ED25519_OUTPUT};
@@ -30,6 +42,7 @@ pub use self::sign::ed25519_dilithium_hybrid::{
 };
 pub use self::sign::pqc_dilithium::DILITHIUM_OUTPUT;
 pub use self::sign::{SignKey, Signature, VerifyKey};
+pub use self::sortable::ore::{ore_compare, OreSortableKey, ORE_CIPHERTEXT_LEN, ORE_OUT};
 pub use self::sortable::SortKeys;
 pub use self::sym::aes_gcm::{Aes256GcmKey, AES_GCM_OUTPUT};
 pub use self::sym::SymmetricKey;
@@ -21,8 +21,141 @@ const DERIVED_KEY_LENGTH: usize = 64;
 
 pub(super) const HALF_DERIVED_KEY_LENGTH: usize = DERIVED_KEY_LENGTH / 2;
 
+/**
+The alg without parameters uses the default parameters of argon2 v0.3 (m = 4096, t = 3, p = 1).
+
+Other parameters are encoded in the alg, e.g. ARGON-2-SHA256-m19456-t2-p1.
+Only the algs of this file are accepted, the server can't choose other parameters (e.g. to downgrade the hash or to exhaust the memory).
+ */
 pub const ARGON_2_OUTPUT: &str = "ARGON-2-SHA256";
 
+pub const ARGON_2_M19456_T2_P1: &str = "ARGON-2-SHA256-m19456-t2-p1";
+
+pub const ARGON_2_M65536_T3_P4: &str = "ARGON-2-SHA256-m65536-t3-p4";
+
+/**
+The derived alg of the passphrase hash. It is not accepted for the login.
+ */
+pub const ARGON_2_PASSPHRASE_M65536_T4_P1: &str = "ARGON-2-SHA256-passphrase-m65536-t4-p1";
+
+/**
+The cost parameters of argon2. m_cost is in KiB.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params
+{
+	pub m_cost: u32,
+	pub t_cost: u32,
+	pub p_cost: u32,
+}
+
+impl Argon2Params
+{
+	/**
+	Returns true if any cost is lower than the cost of the other parameters
+	 */
+	pub fn is_below(&self, other: &Self) -> bool
+	{
+		self.m_cost < other.m_cost || self.t_cost < other.t_cost || self.p_cost < other.p_cost
+	}
+}
+
+const ARGON_2_DEFAULT_PARAMS: Argon2Params = Argon2Params {
+	m_cost: Params::DEFAULT_M_COST,
+	t_cost: Params::DEFAULT_T_COST,
+	p_cost: Params::DEFAULT_P_COST,
+};
+
+/**
+For the passphrase encryption of data which is stored on the device or offline (64 mb and 4 passes).
+
+Only one lane because the browser and the most phones can't use more threads for the hash.
+ */
+const ARGON_2_PASSPHRASE_PARAMS: Argon2Params = Argon2Params {
+	m_cost: 65536,
+	t_cost: 4,
+	p_cost: 1,
+};
+
+/**
+Returns the parameters of a known alg.
+
+Returns AlgNotFound for every other alg, also for valid argon2 parameters which are not in this file.
+ */
+pub fn decode_argon2_alg(alg: &str) -> Result<Argon2Params, Error>
+{
+	match alg {
+		ARGON_2_OUTPUT => Ok(ARGON_2_DEFAULT_PARAMS),
+		ARGON_2_M19456_T2_P1 => {
+			Ok(Argon2Params {
+				m_cost: 19456,
+				t_cost: 2,
+				p_cost: 1,
+			})
+		},
+		ARGON_2_M65536_T3_P4 => {
+			Ok(Argon2Params {
+				m_cost: 65536,
+				t_cost: 3,
+				p_cost: 4,
+			})
+		},
+		_ => Err(Error::AlgNotFound),
+	}
+}
+
+/**
+Returns the static alg of the known parameters.
+
+The client random value only holds static algs, so only parameters of a policy can be imported.
+ */
+pub(super) fn get_argon2_alg(alg: &str) -> Result<&'static str, Error>
+{
+	match alg {
+		ARGON_2_M19456_T2_P1 => Ok(ARGON_2_M19456_T2_P1),
+		ARGON_2_M65536_T3_P4 => Ok(ARGON_2_M65536_T3_P4),
+		_ => Err(Error::AlgNotFound),
+	}
+}
+
+/**
+The policy for new password hashes.
+
+The alg must be static because it is the derived alg of the client random value.
+Logins with an alg below the policy should upgrade the password hash.
+ */
+pub trait Argon2Policy
+{
+	const ALG: &'static str;
+}
+
+/**
+The parameters used before the parameters were tunable
+ */
+pub struct Argon2DefaultPolicy;
+
+impl Argon2Policy for Argon2DefaultPolicy
+{
+	const ALG: &'static str = ARGON_2_OUTPUT;
+}
+
+/**
+The minimum of the owasp recommendation
+ */
+pub struct Argon2ModeratePolicy;
+
+impl Argon2Policy for Argon2ModeratePolicy
+{
+	const ALG: &'static str = ARGON_2_M19456_T2_P1;
+}
+
+pub struct Argon2HighPolicy;
+
+impl Argon2Policy for Argon2HighPolicy
+{
+	const ALG: &'static str = ARGON_2_M65536_T3_P4;
+}
+
 /**
 # Prepare registration
 
@@ -30,16 +163,25 @@ pub const ARGON_2_OUTPUT: &str = "ARGON-2-SHA256";
 pub(crate) fn derived_keys_from_password<M: SymKey>(
 	password: &[u8],
 	master_key: &M,
+	alg: &'static str,
 ) -> Result<(ClientRandomValue, HashedAuthenticationKey, Vec<u8>, &'static str), Error>
 {
-	let (client_random_value, hashed_authentication_key_16bytes, encrypted_master_key, alg) =
-		derive_key_with_pw_internally(password, master_key.as_ref(), &mut get_rand())?;
+	let params = decode_argon2_alg(alg)?;
+
+	let (client_random_value, hashed_authentication_key_16bytes, encrypted_master_key, encrypted_master_key_alg) =
+		derive_key_with_pw_internally(password, master_key.as_ref(), &params, &mut get_rand())?;
+
+	let client_random_value = if alg == ARGON_2_OUTPUT {
+		ClientRandomValue::Argon2(client_random_value)
+	} else {
+		ClientRandomValue::Argon2Params(client_random_value, alg)
+	};
 
 	Ok((
-		ClientRandomValue::Argon2(client_random_value),
+		client_random_value,
 		HashedAuthenticationKey::Argon2(hashed_authentication_key_16bytes),
 		encrypted_master_key,
-		alg,
+		encrypted_master_key_alg,
 	))
 }
 
@@ -51,9 +193,13 @@ pub(crate) fn derived_keys_from_password<M: SymKey>(
 3. return the encryption key and
 	return the auth key to send it to the server so the server can check the hashed auth key
  */
-pub(crate) fn derive_keys_for_auth(password: &[u8], salt_bytes: &[u8]) -> Result<(DeriveMasterKeyForAuth, DeriveAuthKeyForAuth), Error>
+pub(crate) fn derive_keys_for_auth(
+	password: &[u8],
+	salt_bytes: &[u8],
+	params: &Argon2Params,
+) -> Result<(DeriveMasterKeyForAuth, DeriveAuthKeyForAuth), Error>
 {
-	let (master_key_encryption_key, auth_key) = derived_keys(password, salt_bytes)?;
+	let (master_key_encryption_key, auth_key) = derived_keys(password, salt_bytes, params)?;
 
 	Ok((
 		DeriveMasterKeyForAuth::Argon2(master_key_encryption_key),
@@ -78,6 +224,32 @@ pub(crate) fn password_to_decrypt(password: &[u8], salt: &[u8]) -> Result<impl S
 	Ok(Aes256GcmKey::from_raw_key(key))
 }
 
+pub(crate) fn passphrase_to_encrypt(password: &[u8]) -> Result<(PasswordEncryptSalt, impl SymKey, &'static str), Error>
+{
+	let salt = generate_random_value(&mut get_rand());
+
+	let key = get_derived_single_key_with_params(password, &salt, &ARGON_2_PASSPHRASE_PARAMS)?;
+
+	Ok((
+		PasswordEncryptSalt::Argon2(salt),
+		Aes256GcmKey::from_raw_key(key),
+		ARGON_2_PASSPHRASE_M65536_T4_P1,
+	))
+}
+
+pub(crate) fn passphrase_to_decrypt(password: &[u8], salt: &[u8], alg: &str) -> Result<impl SymKey, Error>
+{
+	//only the params of the passphrase algs, a changed default must get a new alg
+	let params = match alg {
+		ARGON_2_PASSPHRASE_M65536_T4_P1 => ARGON_2_PASSPHRASE_PARAMS,
+		_ => return Err(Error::AlgNotFound),
+	};
+
+	let key = get_derived_single_key_with_params(password, salt, &params)?;
+
+	Ok(Aes256GcmKey::from_raw_key(key))
+}
+
 /**
 # Hash the auth key
 
@@ -112,6 +284,7 @@ pub(crate) fn get_master_key(derived_encryption_key: &[u8; HALF_DERIVED_KEY_LENG
 fn derive_key_with_pw_internally<R: CryptoRng + RngCore>(
 	password: &[u8],
 	master_key: &[u8],
+	params: &Argon2Params,
 	rng: &mut R,
 ) -> Result<([u8; 16], [u8; 16], Vec<u8>, &'static str), Error>
 {
@@ -121,7 +294,7 @@ fn derive_key_with_pw_internally<R: CryptoRng + RngCore>(
 
 	let salt = generate_salt(client_random_value, "");
 
-	let (derived_encryption_key_bytes, derived_authentication_key_bytes) = derived_keys(password, &salt)?;
+	let (derived_encryption_key_bytes, derived_authentication_key_bytes) = derived_keys(password, &salt, params)?;
 
 	let hashed_authentication_key_16bytes = hash_auth_key(&derived_authentication_key_bytes)?;
 
@@ -135,7 +308,7 @@ fn derive_key_with_pw_internally<R: CryptoRng + RngCore>(
 	))
 }
 
-fn hash_auth_key(derived_authentication_key_bytes: &[u8]) -> Result<[u8; 16], Error>
+pub(super) fn hash_auth_key(derived_authentication_key_bytes: &[u8]) -> Result<[u8; 16], Error>
 {
 	// Get a hash of the Authentication Key which the API will use for authentication at login time
 	let mut hasher = Sha256::new();
@@ -152,15 +325,13 @@ fn hash_auth_key(derived_authentication_key_bytes: &[u8]) -> Result<[u8; 16], Er
 	Ok(hashed_authentication_key_16bytes)
 }
 
-fn derived_keys(password: &[u8], salt_bytes: &[u8]) -> Result<([u8; HALF_DERIVED_KEY_LENGTH], [u8; HALF_DERIVED_KEY_LENGTH]), Error>
+fn derived_keys(
+	password: &[u8],
+	salt_bytes: &[u8],
+	params: &Argon2Params,
+) -> Result<([u8; HALF_DERIVED_KEY_LENGTH], [u8; HALF_DERIVED_KEY_LENGTH]), Error>
 {
-	let params = Params::new(
-		Params::DEFAULT_M_COST,
-		Params::DEFAULT_T_COST,
-		Params::DEFAULT_P_COST,
-		Some(DERIVED_KEY_LENGTH),
-	)
-	.map_err(|_| Error::PwHashFailed)?;
+	let params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(DERIVED_KEY_LENGTH)).map_err(|_| Error::PwHashFailed)?;
 
 	let argon2 = Argon2::new(Algorithm::default(), Version::default(), params);
 
@@ -214,7 +385,7 @@ pub(super) fn generate_salt(client_random_value: [u8; RECOMMENDED_LENGTH], add_s
 	result.to_vec()
 }
 
-fn generate_random_value<R: CryptoRng + RngCore>(rng: &mut R) -> [u8; RECOMMENDED_LENGTH]
+pub(super) fn generate_random_value<R: CryptoRng + RngCore>(rng: &mut R) -> [u8; RECOMMENDED_LENGTH]
 {
 	let mut bytes = [0u8; RECOMMENDED_LENGTH];
 	rng.fill_bytes(&mut bytes);
@@ -235,15 +406,14 @@ fn derived_single_key<R: CryptoRng + RngCore>(password: &[u8], rng: &mut R) -> R
 }
 
 fn get_derived_single_key(password: &[u8], salt: &[u8]) -> Result<[u8; 32], Error>
+{
+	get_derived_single_key_with_params(password, salt, &ARGON_2_DEFAULT_PARAMS)
+}
+
+fn get_derived_single_key_with_params(password: &[u8], salt: &[u8], params: &Argon2Params) -> Result<[u8; 32], Error>
 {
 	//aes 256 key
-	let params = Params::new(
-		Params::DEFAULT_M_COST,
-		Params::DEFAULT_T_COST,
-		Params::DEFAULT_P_COST,
-		Some(32),
-	)
-	.map_err(|_| Error::PwHashFailed)?;
+	let params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(32)).map_err(|_| Error::PwHashFailed)?;
 
 	let argon2 = Argon2::new(Algorithm::default(), Version::default(), params);
 
@@ -272,12 +442,44 @@ mod test
 		let key = Aes256GcmKey::generate().unwrap();
 
 		let (client_random_value, _hashed_authentication_key_bytes, _encrypted_master_key, encrypted_master_key_alg) =
-			derived_keys_from_password(b"abc", &key).unwrap();
+			derived_keys_from_password(b"abc", &key, ARGON_2_OUTPUT).unwrap();
 
 		assert_eq!(client_random_value.get_alg_str(), ARGON_2_OUTPUT);
 		assert_eq!(encrypted_master_key_alg, AES_GCM_OUTPUT);
 	}
 
+	#[test]
+	fn test_decode_argon2_alg()
+	{
+		assert_eq!(decode_argon2_alg(ARGON_2_OUTPUT).unwrap(), ARGON_2_DEFAULT_PARAMS);
+
+		let params = decode_argon2_alg(ARGON_2_M19456_T2_P1).unwrap();
+
+		assert_eq!(
+			params,
+			Argon2Params {
+				m_cost: 19456,
+				t_cost: 2,
+				p_cost: 1,
+			}
+		);
+		assert!(ARGON_2_DEFAULT_PARAMS.is_below(&params));
+		assert!(!params.is_below(&decode_argon2_alg(ARGON_2_M19456_T2_P1).unwrap()));
+
+		assert!(params.is_below(&decode_argon2_alg(ARGON_2_M65536_T3_P4).unwrap()));
+		assert!(params.is_below(&Argon2Params {
+			p_cost: 2,
+			..params
+		}));
+
+		//only known params are accepted
+		assert!(decode_argon2_alg("ARGON-2-SHA256-m19456-t1-p1").is_err());
+		assert!(decode_argon2_alg("ARGON-2-SHA256-m4194304-t2-p1").is_err());
+		assert!(decode_argon2_alg("ARGON-2-SHA256-m19456-t2").is_err());
+		assert!(decode_argon2_alg("ARGON-2-SHA256m19456-t2-p1").is_err());
+		assert!(decode_argon2_alg("HKDF-SHA256").is_err());
+	}
+
 	#[test]
 	fn test_derive_keys_for_auth()
 	{
@@ -285,15 +487,16 @@ mod test
 		let key = Aes256GcmKey::generate().unwrap();
 
 		let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, _encrypted_master_key_alg) =
-			derived_keys_from_password(b"abc", &key).unwrap();
+			derived_keys_from_password(b"abc", &key, ARGON_2_OUTPUT).unwrap();
 
 		//create fake salt. this will be created on the server with the client random value
 		let salt = client_random_value.generate_salt("");
 
-		let (master_key_encryption_key, auth_key) = derive_keys_for_auth(b"abc", &salt).unwrap();
+		let (master_key_encryption_key, auth_key) = derive_keys_for_auth(b"abc", &salt, &ARGON_2_DEFAULT_PARAMS).unwrap();
 
 		let auth_key = match &auth_key {
 			DeriveAuthKeyForAuth::Argon2(k) => k,
+			_ => panic!("Wrong auth key alg"),
 		};
 
 		//send the auth key to the server and valid it there
@@ -305,6 +508,7 @@ mod test
 
 		let hashed_authentication_key_bytes = match hashed_authentication_key_bytes {
 			HashedAuthenticationKey::Argon2(k) => k,
+			_ => panic!("Wrong hashed auth key alg"),
 		};
 
 		assert_eq!(hashed_authentication_key_16bytes, hashed_authentication_key_bytes);
@@ -337,4 +541,36 @@ mod test
 
 		assert_eq!(str, test);
 	}
+	#[test]
+	fn test_passphrase_to_encrypt_and_decrypt()
+	{
+		let (salt, key, alg) = passphrase_to_encrypt(b"1234").unwrap();
+
+		assert_eq!(alg, ARGON_2_PASSPHRASE_M65536_T4_P1);
+
+		let salt = match salt {
+			PasswordEncryptSalt::Argon2(s) => s,
+		};
+
+		let encrypted = key.encrypt(b"plaintext message").unwrap();
+
+		let decrypted = passphrase_to_decrypt(b"1234", &salt, alg)
+			.unwrap()
+			.decrypt(&encrypted)
+			.unwrap();
+
+		assert_eq!(decrypted, b"plaintext message");
+
+		//the login algs are not accepted for the passphrase
+		assert!(matches!(
+			passphrase_to_decrypt(b"1234", &salt, ARGON_2_OUTPUT),
+			Err(Error::AlgNotFound)
+		));
+
+		//the normal password hash must not open it
+		assert!(password_to_decrypt(b"1234", &salt)
+			.unwrap()
+			.decrypt(&encrypted)
+			.is_err());
+	}
 }
@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+use hkdf::Hkdf;
+use sentc_crypto_core::cryptomat::SymKey;
+use sentc_crypto_core::Error;
+use sha2::Sha256;
+
+use crate::core::pw_hash::argon2::{generate_random_value, generate_salt, hash_auth_key, HALF_DERIVED_KEY_LENGTH};
+use crate::core::pw_hash::{ClientRandomValue, DeriveAuthKeyForAuth, DeriveMasterKeyForAuth, HashedAuthenticationKey};
+use crate::core::sym::aes_gcm::{raw_encrypt as aes_encrypt, AES_GCM_OUTPUT};
+use crate::get_rand;
+
+const SECRET_LENGTH: usize = 32;
+
+const DERIVED_KEY_LENGTH: usize = HALF_DERIVED_KEY_LENGTH * 2;
+
+const HKDF_INFO: &[u8] = b"sentc_passkey_auth";
+
+pub const HKDF_SHA256_OUTPUT: &str = "HKDF-SHA256";
+
+/**
+# Prepare the registration of a passkey
+
+Same output as the password hash, only the keys are derived with hkdf from the secret of the passkey.
+ */
+pub(crate) fn derived_keys_from_secret<M: SymKey>(
+	secret: &[u8],
+	master_key: &M,
+) -> Result<(ClientRandomValue, HashedAuthenticationKey, Vec<u8>, &'static str), Error>
+{
+	let client_random_value = generate_random_value(&mut get_rand());
+
+	let salt = generate_salt(client_random_value, "");
+
+	let (derived_encryption_key_bytes, derived_authentication_key_bytes) = derived_keys(secret, &salt)?;
+
+	let hashed_authentication_key_16bytes = hash_auth_key(&derived_authentication_key_bytes)?;
+
+	let encrypted_master_key = aes_encrypt(&derived_encryption_key_bytes, master_key.as_ref())?;
+
+	Ok((
+		ClientRandomValue::Hkdf(client_random_value),
+		HashedAuthenticationKey::Hkdf(hashed_authentication_key_16bytes),
+		encrypted_master_key,
+		AES_GCM_OUTPUT,
+	))
+}
+
+pub(crate) fn derive_keys_for_auth(secret: &[u8], salt_bytes: &[u8]) -> Result<(DeriveMasterKeyForAuth, DeriveAuthKeyForAuth), Error>
+{
+	let (master_key_encryption_key, auth_key) = derived_keys(secret, salt_bytes)?;
+
+	Ok((
+		DeriveMasterKeyForAuth::Hkdf(master_key_encryption_key),
+		DeriveAuthKeyForAuth::Hkdf(auth_key),
+	))
+}
+
+fn derived_keys(secret: &[u8], salt_bytes: &[u8]) -> Result<([u8; HALF_DERIVED_KEY_LENGTH], [u8; HALF_DERIVED_KEY_LENGTH]), Error>
+{
+	//only for secrets with enough entropy, never for passwords
+	if secret.len() != SECRET_LENGTH {
+		return Err(Error::PasskeySecretWrongLength);
+	}
+
+	let h = Hkdf::<Sha256>::new(Some(salt_bytes), secret);
+
+	let mut derived_key = [0u8; DERIVED_KEY_LENGTH];
+
+	h.expand(HKDF_INFO, &mut derived_key)
+		.map_err(|_| Error::PwHashFailed)?;
+
+	//left is the encryption key for the master key
+	let left: [u8; HALF_DERIVED_KEY_LENGTH] = match derived_key[..HALF_DERIVED_KEY_LENGTH].as_ref().try_into() {
+		Err(_e) => return Err(Error::PwSplitFailedLeft),
+		Ok(bytes) => bytes,
+	};
+
+	//right is the authentication key
+	let right: [u8; HALF_DERIVED_KEY_LENGTH] = match derived_key[HALF_DERIVED_KEY_LENGTH..].as_ref().try_into() {
+		Err(_e) => return Err(Error::PwSplitFailedRight),
+		Ok(bytes) => bytes,
+	};
+
+	Ok((left, right))
+}
+
+#[cfg(test)]
+mod test
+{
+	use sentc_crypto_core::cryptomat::{ClientRandomValue, CryptoAlg, DeriveMasterKeyForAuth, SymKeyGen};
+
+	use super::*;
+	use crate::core::sym::aes_gcm::Aes256GcmKey;
+
+	#[test]
+	fn test_derive_keys_for_auth_from_secret()
+	{
+		let key = Aes256GcmKey::generate().unwrap();
+		let secret = [7u8; SECRET_LENGTH];
+
+		let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, _encrypted_master_key_alg) =
+			derived_keys_from_secret(&secret, &key).unwrap();
+
+		assert_eq!(client_random_value.get_alg_str(), HKDF_SHA256_OUTPUT);
+
+		let salt = client_random_value.generate_salt("");
+
+		let (master_key_encryption_key, auth_key) = derive_keys_for_auth(&secret, &salt).unwrap();
+
+		let auth_key = match &auth_key {
+			DeriveAuthKeyForAuth::Hkdf(k) => k,
+			_ => panic!("Wrong auth key alg"),
+		};
+
+		let hashed_authentication_key_bytes = match hashed_authentication_key_bytes {
+			HashedAuthenticationKey::Hkdf(k) => k,
+			_ => panic!("Wrong hashed auth key alg"),
+		};
+
+		assert_eq!(hash_auth_key(auth_key).unwrap(), hashed_authentication_key_bytes);
+
+		master_key_encryption_key
+			.get_master_key(&encrypted_master_key)
+			.unwrap();
+
+		//wrong secret
+		let (master_key_encryption_key, _) = derive_keys_for_auth(&[8u8; SECRET_LENGTH], &salt).unwrap();
+
+		assert!(master_key_encryption_key
+			.get_master_key(&encrypted_master_key)
+			.is_err());
+	}
+
+	#[test]
+	fn test_secret_with_wrong_length()
+	{
+		let key = Aes256GcmKey::generate().unwrap();
+
+		assert!(matches!(
+			derived_keys_from_secret(b"password", &key),
+			Err(Error::PasskeySecretWrongLength)
+		));
+	}
+}
@@ -1,41 +1,54 @@
 use alloc::vec::Vec;
+use core::marker::PhantomData;
 
 use sentc_crypto_core::cryptomat::{ClientRandomValueComposer, PwHash, PwPrepareExport, SymKey};
-use sentc_crypto_core::{crypto_alg_str_impl, cryptomat, Error};
+use sentc_crypto_core::{cryptomat, Error};
 
-use crate::core::pw_hash::argon2::ARGON_2_OUTPUT;
+use crate::core::pw_hash::argon2::{decode_argon2_alg, Argon2DefaultPolicy, Argon2Policy, ARGON_2_OUTPUT};
+use crate::core::pw_hash::hkdf::HKDF_SHA256_OUTPUT;
 
 pub(crate) mod argon2;
+pub(crate) mod hkdf;
+pub(crate) mod opaque;
 
 macro_rules! prepare_export_single_value {
-	($st:ty) => {
+	($st:ty, $($fn_name:ident => $variant:ident),+) => {
 		impl $st
 		{
-			pub fn argon2_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
-			{
-				Ok(Self::Argon2(bytes.try_into().map_err(|_| Error::KeyDecryptFailed)?))
-			}
+			$(
+				pub fn $fn_name(bytes: Vec<u8>) -> Result<Self, Error>
+				{
+					Ok(Self::$variant(bytes.try_into().map_err(|_| Error::KeyDecryptFailed)?))
+				}
+			)+
 		}
 	};
 }
 
 macro_rules! prepare_export {
-	($st:ty) => {
+	($st:ty, $($variant:ident),+) => {
 		impl PwPrepareExport for $st
 		{
 			fn prepare_export(&self) -> &[u8]
 			{
 				match self {
-					Self::Argon2(v) => v,
+					$(Self::$variant(v))|+ => v,
 				}
 			}
 		}
 	};
 }
 
-pub struct PwHasherGetter;
+/**
+Argon2 with the parameters of the policy for new password hashes.
+
+Logins with other parameters still work because the parameters are decoded from the alg of the user.
+ */
+pub struct PwHasher<P: Argon2Policy = Argon2DefaultPolicy>(PhantomData<P>);
 
-impl PwHash for PwHasherGetter
+pub type PwHasherGetter = PwHasher<Argon2DefaultPolicy>;
+
+impl<P: Argon2Policy> PwHash for PwHasher<P>
 {
 	type CRV = ClientRandomValue;
 	type HAK = HashedAuthenticationKey;
@@ -49,20 +62,38 @@ impl PwHash for PwHasherGetter
 		alg: Option<&str>,
 	) -> Result<(Self::CRV, Self::HAK, Vec<u8>, &'static str), Error>
 	{
+		//the old alg is only checked, new hashes always use the parameters of the policy
 		if let Some(alg) = alg {
-			match alg {
-				ARGON_2_OUTPUT => argon2::derived_keys_from_password(password, master_key),
-				_ => Err(Error::AlgNotFound),
-			}
-		} else {
-			argon2::derived_keys_from_password(password, master_key)
+			decode_argon2_alg(alg)?;
 		}
+
+		argon2::derived_keys_from_password(password, master_key, P::ALG)
 	}
 
 	fn derive_keys_for_auth(password: &[u8], salt_bytes: &[u8], alg: &str) -> Result<(Self::DMK, Self::DAK), Error>
+	{
+		let params = decode_argon2_alg(alg)?;
+
+		argon2::derive_keys_for_auth(password, salt_bytes, &params)
+	}
+
+	fn needs_upgrade(alg: &str) -> bool
+	{
+		match (decode_argon2_alg(alg), decode_argon2_alg(P::ALG)) {
+			(Ok(params), Ok(policy)) => params.is_below(&policy),
+			_ => false,
+		}
+	}
+
+	fn derived_keys_from_secret<M: SymKey>(secret: &[u8], master_key: &M) -> Result<(Self::CRV, Self::HAK, Vec<u8>, &'static str), Error>
+	{
+		hkdf::derived_keys_from_secret(secret, master_key)
+	}
+
+	fn derive_keys_for_auth_from_secret(secret: &[u8], salt_bytes: &[u8], alg: &str) -> Result<(Self::DMK, Self::DAK), Error>
 	{
 		match alg {
-			ARGON_2_OUTPUT => argon2::derive_keys_for_auth(password, salt_bytes),
+			HKDF_SHA256_OUTPUT => hkdf::derive_keys_for_auth(secret, salt_bytes),
 			_ => Err(Error::AlgNotFound),
 		}
 	}
@@ -76,24 +107,54 @@ impl PwHash for PwHasherGetter
 	{
 		argon2::password_to_decrypt(password, salt)
 	}
+
+	fn passphrase_to_encrypt(password: &[u8]) -> Result<(Self::PWS, impl SymKey, &'static str), Error>
+	{
+		argon2::passphrase_to_encrypt(password)
+	}
+
+	fn passphrase_to_decrypt(password: &[u8], salt: &[u8], alg: &str) -> Result<impl SymKey, Error>
+	{
+		argon2::passphrase_to_decrypt(password, salt, alg)
+	}
 }
 
 pub enum ClientRandomValue
 {
 	Argon2([u8; 16]),
+	Argon2Params([u8; 16], &'static str), //with the alg of the parameters
+	Hkdf([u8; 16]),
 }
 
-crypto_alg_str_impl!(ClientRandomValue, ARGON_2_OUTPUT);
+impl cryptomat::CryptoAlg for ClientRandomValue
+{
+	fn get_alg_str(&self) -> &'static str
+	{
+		match self {
+			Self::Argon2(_) => ARGON_2_OUTPUT,
+			Self::Argon2Params(_, alg) => alg,
+			Self::Hkdf(_) => HKDF_SHA256_OUTPUT,
+		}
+	}
+}
 
-prepare_export!(ClientRandomValue);
-prepare_export_single_value!(ClientRandomValue);
+impl PwPrepareExport for ClientRandomValue
+{
+	fn prepare_export(&self) -> &[u8]
+	{
+		match self {
+			Self::Argon2(v) | Self::Argon2Params(v, _) | Self::Hkdf(v) => v,
+		}
+	}
+}
+prepare_export_single_value!(ClientRandomValue, argon2_from_bytes_owned => Argon2, hkdf_from_bytes_owned => Hkdf);
 
 impl cryptomat::ClientRandomValue for ClientRandomValue
 {
 	fn generate_salt(self, add_str: &str) -> Vec<u8>
 	{
 		match self {
-			ClientRandomValue::Argon2(v) => argon2::generate_salt(v, add_str),
+			ClientRandomValue::Argon2(v) | ClientRandomValue::Argon2Params(v, _) | ClientRandomValue::Hkdf(v) => argon2::generate_salt(v, add_str),
 		}
 	}
 }
@@ -110,7 +171,18 @@ impl ClientRandomValueComposer for ClientRandomValue
 
 				Ok(Self::Argon2(v))
 			},
-			_ => Err(Error::AlgNotFound),
+			HKDF_SHA256_OUTPUT => {
+				let v = vec.try_into().map_err(|_| Error::KeyDecryptFailed)?;
+
+				Ok(Self::Hkdf(v))
+			},
+			_ => {
+				//argon2 with tuned parameters
+				let alg = argon2::get_argon2_alg(alg)?;
+				let v = vec.try_into().map_err(|_| Error::KeyDecryptFailed)?;
+
+				Ok(Self::Argon2Params(v, alg))
+			},
 		}
 	}
 }
@@ -118,27 +190,37 @@ impl ClientRandomValueComposer for ClientRandomValue
 pub enum HashedAuthenticationKey
 {
 	Argon2([u8; 16]), //16 bytes of the org. hashed key
+	Hkdf([u8; 16]),
 }
 
-prepare_export!(HashedAuthenticationKey);
-prepare_export_single_value!(HashedAuthenticationKey);
+prepare_export!(HashedAuthenticationKey, Argon2, Hkdf);
+prepare_export_single_value!(HashedAuthenticationKey, argon2_from_bytes_owned => Argon2, hkdf_from_bytes_owned => Hkdf);
 
 impl cryptomat::HashedAuthenticationKey for HashedAuthenticationKey {}
 
 pub enum DeriveMasterKeyForAuth
 {
 	Argon2([u8; 32]),
+	Hkdf([u8; 32]),
+	Opaque([u8; 32]), //derived from the export key
 }
 
-prepare_export!(DeriveMasterKeyForAuth);
-prepare_export_single_value!(DeriveMasterKeyForAuth);
+prepare_export!(DeriveMasterKeyForAuth, Argon2, Hkdf, Opaque);
+prepare_export_single_value!(
+	DeriveMasterKeyForAuth,
+	argon2_from_bytes_owned => Argon2,
+	hkdf_from_bytes_owned => Hkdf,
+	opaque_from_bytes_owned => Opaque
+);
 
 impl cryptomat::DeriveMasterKeyForAuth for DeriveMasterKeyForAuth
 {
 	fn get_master_key(&self, encrypted_master_key: &[u8]) -> Result<impl SymKey, Error>
 	{
 		match self {
-			DeriveMasterKeyForAuth::Argon2(k) => argon2::get_master_key(k, encrypted_master_key),
+			DeriveMasterKeyForAuth::Argon2(k) | DeriveMasterKeyForAuth::Hkdf(k) | DeriveMasterKeyForAuth::Opaque(k) => {
+				argon2::get_master_key(k, encrypted_master_key)
+			},
 		}
 	}
 }
@@ -146,17 +228,18 @@ impl cryptomat::DeriveMasterKeyForAuth for DeriveMasterKeyForAuth
 pub enum DeriveAuthKeyForAuth
 {
 	Argon2([u8; 32]),
+	Hkdf([u8; 32]),
 }
 
-prepare_export!(DeriveAuthKeyForAuth);
-prepare_export_single_value!(DeriveAuthKeyForAuth);
+prepare_export!(DeriveAuthKeyForAuth, Argon2, Hkdf);
+prepare_export_single_value!(DeriveAuthKeyForAuth, argon2_from_bytes_owned => Argon2, hkdf_from_bytes_owned => Hkdf);
 
 impl cryptomat::DeriveAuthKeyForAuth for DeriveAuthKeyForAuth
 {
 	fn hash_auth_key(&self) -> Result<Vec<u8>, Error>
 	{
 		match self {
-			DeriveAuthKeyForAuth::Argon2(k) => argon2::get_hashed_auth_key(k),
+			DeriveAuthKeyForAuth::Argon2(k) | DeriveAuthKeyForAuth::Hkdf(k) => argon2::get_hashed_auth_key(k),
 		}
 	}
 }
@@ -173,7 +256,18 @@ impl cryptomat::DeriveAuthKeyForAuthComposer for DeriveAuthKeyForAuth
 
 				Ok(Self::Argon2(v))
 			},
-			_ => Err(Error::AlgNotFound),
+			HKDF_SHA256_OUTPUT => {
+				let v = vec.try_into().map_err(|_| Error::KeyDecryptFailed)?;
+
+				Ok(Self::Hkdf(v))
+			},
+			_ => {
+				//the auth key is the same for every argon2 parameters
+				decode_argon2_alg(alg)?;
+				let v = vec.try_into().map_err(|_| Error::KeyDecryptFailed)?;
+
+				Ok(Self::Argon2(v))
+			},
 		}
 	}
 }
@@ -183,7 +277,7 @@ pub enum PasswordEncryptSalt
 	Argon2([u8; 16]), //export salt as enum because we can't know the length for every alg
 }
 
-prepare_export!(PasswordEncryptSalt);
-prepare_export_single_value!(PasswordEncryptSalt);
+prepare_export!(PasswordEncryptSalt, Argon2);
+prepare_export_single_value!(PasswordEncryptSalt, argon2_from_bytes_owned => Argon2);
 
 impl cryptomat::PasswordEncryptSalt for PasswordEncryptSalt {}
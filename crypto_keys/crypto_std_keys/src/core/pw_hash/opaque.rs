@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+
+use hkdf::Hkdf;
+use opaque_ke::ciphersuite::CipherSuite;
+use opaque_ke::key_exchange::tripledh::TripleDh;
+use opaque_ke::{
+	ClientLogin,
+	ClientLoginFinishParameters,
+	ClientRegistration,
+	ClientRegistrationFinishParameters,
+	CredentialFinalization,
+	CredentialRequest,
+	CredentialResponse,
+	RegistrationRequest,
+	RegistrationResponse,
+	RegistrationUpload,
+	Ristretto255,
+	ServerLogin,
+	ServerLoginStartParameters,
+	ServerRegistration,
+	ServerSetup,
+};
+use sentc_crypto_core::cryptomat::{PakeClient, PakeServer, SymKey};
+use sentc_crypto_core::Error;
+use sha2::Sha256;
+
+use crate::core::pw_hash::argon2::HALF_DERIVED_KEY_LENGTH;
+use crate::core::pw_hash::DeriveMasterKeyForAuth;
+use crate::core::sym::aes_gcm::{raw_encrypt as aes_encrypt, AES_GCM_OUTPUT};
+use crate::get_rand;
+
+pub const OPAQUE_OUTPUT: &str = "OPAQUE-RISTRETTO255-ARGON2";
+
+const EXPORT_KEY_INFO: &[u8] = b"sentc_opaque_master_key";
+
+struct OpaqueCipherSuite;
+
+impl CipherSuite for OpaqueCipherSuite
+{
+	type OprfCs = Ristretto255;
+	type KeGroup = Ristretto255;
+	type KeyExchange = TripleDh;
+	//the password file is still protected by a slow hash if the oprf seed of the server got leaked
+	type Ksf = argon2::Argon2<'static>;
+}
+
+/**
+The export key is only known by the client, it encrypts the master key like the derived key from the password
+ */
+fn master_key_encryption_key(export_key: &[u8]) -> Result<[u8; HALF_DERIVED_KEY_LENGTH], Error>
+{
+	let h = Hkdf::<Sha256>::new(None, export_key);
+
+	let mut key = [0u8; HALF_DERIVED_KEY_LENGTH];
+
+	h.expand(EXPORT_KEY_INFO, &mut key)
+		.map_err(|_| Error::PakeFailed)?;
+
+	Ok(key)
+}
+
+pub struct OpaqueGetter;
+
+impl PakeClient for OpaqueGetter
+{
+	type DMK = DeriveMasterKeyForAuth;
+
+	fn get_alg_str() -> &'static str
+	{
+		OPAQUE_OUTPUT
+	}
+
+	fn registration_start(password: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error>
+	{
+		let res = ClientRegistration::<OpaqueCipherSuite>::start(&mut get_rand(), password).map_err(|_| Error::PakeFailed)?;
+
+		Ok((res.state.serialize().to_vec(), res.message.serialize().to_vec()))
+	}
+
+	fn registration_finish<M: SymKey>(
+		state: &[u8],
+		password: &[u8],
+		registration_response: &[u8],
+		master_key: &M,
+	) -> Result<(Vec<u8>, Vec<u8>, &'static str), Error>
+	{
+		let state = ClientRegistration::<OpaqueCipherSuite>::deserialize(state).map_err(|_| Error::PakeMessageWrongFormat)?;
+		let registration_response =
+			RegistrationResponse::<OpaqueCipherSuite>::deserialize(registration_response).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		let res = state
+			.finish(
+				&mut get_rand(),
+				password,
+				registration_response,
+				ClientRegistrationFinishParameters::default(),
+			)
+			.map_err(|_| Error::PakeFailed)?;
+
+		let key = master_key_encryption_key(&res.export_key)?;
+
+		let encrypted_master_key = aes_encrypt(&key, master_key.as_ref())?;
+
+		Ok((res.message.serialize().to_vec(), encrypted_master_key, AES_GCM_OUTPUT))
+	}
+
+	fn login_start(password: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error>
+	{
+		let res = ClientLogin::<OpaqueCipherSuite>::start(&mut get_rand(), password).map_err(|_| Error::PakeFailed)?;
+
+		Ok((res.state.serialize().to_vec(), res.message.serialize().to_vec()))
+	}
+
+	fn login_finish(state: &[u8], password: &[u8], credential_response: &[u8]) -> Result<(Vec<u8>, Self::DMK, Vec<u8>), Error>
+	{
+		let state = ClientLogin::<OpaqueCipherSuite>::deserialize(state).map_err(|_| Error::PakeMessageWrongFormat)?;
+		let credential_response =
+			CredentialResponse::<OpaqueCipherSuite>::deserialize(credential_response).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		let res = state
+			.finish(password, credential_response, ClientLoginFinishParameters::default())
+			.map_err(|_| Error::PakeFailed)?;
+
+		let key = master_key_encryption_key(&res.export_key)?;
+
+		Ok((
+			res.message.serialize().to_vec(),
+			DeriveMasterKeyForAuth::Opaque(key),
+			res.session_key.to_vec(),
+		))
+	}
+}
+
+impl PakeServer for OpaqueGetter
+{
+	fn setup() -> Result<Vec<u8>, Error>
+	{
+		Ok(ServerSetup::<OpaqueCipherSuite>::new(&mut get_rand())
+			.serialize()
+			.to_vec())
+	}
+
+	fn registration_response(setup: &[u8], registration_request: &[u8], credential_identifier: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let setup = ServerSetup::<OpaqueCipherSuite>::deserialize(setup).map_err(|_| Error::PakeMessageWrongFormat)?;
+		let registration_request =
+			RegistrationRequest::<OpaqueCipherSuite>::deserialize(registration_request).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		let res =
+			ServerRegistration::<OpaqueCipherSuite>::start(&setup, registration_request, credential_identifier).map_err(|_| Error::PakeFailed)?;
+
+		Ok(res.message.serialize().to_vec())
+	}
+
+	fn registration_finish(registration_upload: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let registration_upload =
+			RegistrationUpload::<OpaqueCipherSuite>::deserialize(registration_upload).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		Ok(ServerRegistration::<OpaqueCipherSuite>::finish(registration_upload)
+			.serialize()
+			.to_vec())
+	}
+
+	fn login_start(
+		setup: &[u8],
+		password_file: Option<&[u8]>,
+		credential_request: &[u8],
+		credential_identifier: &[u8],
+	) -> Result<(Vec<u8>, Vec<u8>), Error>
+	{
+		let setup = ServerSetup::<OpaqueCipherSuite>::deserialize(setup).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		let password_file = match password_file {
+			Some(f) => Some(ServerRegistration::<OpaqueCipherSuite>::deserialize(f).map_err(|_| Error::PakeMessageWrongFormat)?),
+			None => None,
+		};
+
+		let credential_request =
+			CredentialRequest::<OpaqueCipherSuite>::deserialize(credential_request).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		let res = ServerLogin::start(
+			&mut get_rand(),
+			&setup,
+			password_file,
+			credential_request,
+			credential_identifier,
+			ServerLoginStartParameters::default(),
+		)
+		.map_err(|_| Error::PakeFailed)?;
+
+		Ok((res.state.serialize().to_vec(), res.message.serialize().to_vec()))
+	}
+
+	fn login_finish(state: &[u8], credential_finalization: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let state = ServerLogin::<OpaqueCipherSuite>::deserialize(state).map_err(|_| Error::PakeMessageWrongFormat)?;
+		let credential_finalization =
+			CredentialFinalization::<OpaqueCipherSuite>::deserialize(credential_finalization).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+		let res = state
+			.finish(credential_finalization)
+			.map_err(|_| Error::PakeFailed)?;
+
+		Ok(res.session_key.to_vec())
+	}
+}
@@ -5,6 +5,7 @@ use hmac::digest::Digest;
 use rand_core::{CryptoRng, RngCore};
 use sentc_crypto_core::cryptomat::{Sig, SignK, SignKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, into_bytes_single_value, try_from_bytes_owned_single_value, Error};
+use zeroize::Zeroize;
 
 use crate::core::sign::{SignKey, VerifyKey};
 use crate::get_rand;
@@ -65,6 +66,15 @@ impl VerifyK for Ed25519VerifyK
 }
 
 pub struct Ed25519SignK([u8; 32]);
+
+impl Drop for Ed25519SignK
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 try_from_bytes_owned_single_value!(Ed25519SignK);
 crypto_alg_str_impl!(Ed25519SignK, ED25519_OUTPUT);
 as_ref_bytes_single_value!(Ed25519SignK);
@@ -5,6 +5,7 @@ use pqc_dilithium_edit::{Keypair, PUBLICKEYBYTES, SECRETKEYBYTES, SIGNBYTES};
 use rand_core::{CryptoRng, RngCore};
 use sentc_crypto_core::cryptomat::{Sig, SignK, SignKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, into_bytes_single_value, try_from_bytes_owned_single_value, Error};
+use zeroize::Zeroize;
 
 use crate::core::sign::{SignKey, Signature, VerifyKey};
 use crate::get_rand;
@@ -28,6 +29,15 @@ impl Into<Signature> for DilithiumSig
 impl Sig for DilithiumSig {}
 
 pub struct DilithiumSignKey([u8; SECRETKEYBYTES]);
+
+impl Drop for DilithiumSignKey
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 try_from_bytes_owned_single_value!(DilithiumSignKey);
 crypto_alg_str_impl!(DilithiumSignKey, DILITHIUM_OUTPUT);
 as_ref_bytes_single_value!(DilithiumSignKey);
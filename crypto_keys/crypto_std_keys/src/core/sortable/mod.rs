@@ -4,13 +4,16 @@ use sentc_crypto_core::cryptomat::{CryptoAlg, SortableKey, SortableKeyComposer,
 use sentc_crypto_core::Error;
 
 use crate::core::sortable::ope::OpeSortableKey;
+use crate::core::sortable::ore::OreSortableKey;
 
 pub(crate) mod ope;
+pub(crate) mod ore;
 
 macro_rules! deref_macro {
     ($self:expr, $method:ident $(, $args:expr)*) => {
         match $self {
            	Self::Ope(inner) => inner.$method($($args),*),
+           	Self::Ore(inner) => inner.$method($($args),*),
         }
     };
 }
@@ -18,6 +21,7 @@ macro_rules! deref_macro {
 pub enum SortKeys
 {
 	Ope(OpeSortableKey),
+	Ore(OreSortableKey),
 }
 
 impl SortKeys
@@ -26,6 +30,11 @@ impl SortKeys
 	{
 		Ok(SortKeys::Ope(bytes.try_into()?))
 	}
+
+	pub fn ore_key_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+	{
+		Ok(SortKeys::Ore(bytes.try_into()?))
+	}
 }
 
 impl CryptoAlg for SortKeys
@@ -55,6 +64,21 @@ impl SortableKey for SortKeys
 	{
 		deref_macro!(self, encrypt_sortable, data)
 	}
+
+	fn max_sortable(&self) -> u64
+	{
+		deref_macro!(self, max_sortable)
+	}
+
+	fn encrypt_sortable_ore(&self, data: u64) -> Result<Vec<u8>, Error>
+	{
+		deref_macro!(self, encrypt_sortable_ore, data)
+	}
+
+	fn is_order_revealing(&self) -> bool
+	{
+		deref_macro!(self, is_order_revealing)
+	}
 }
 
 impl SortableKeyGen for SortKeys
@@ -63,8 +87,16 @@ impl SortableKeyGen for SortKeys
 
 	fn generate() -> Result<Self::SortableKey, Error>
 	{
+		//ope stays the default, ore only when ope is disabled
 		#[cfg(feature = "ope_sort")]
-		Ok(OpeSortableKey::generate()?.into())
+		{
+			Ok(OpeSortableKey::generate()?.into())
+		}
+
+		#[cfg(all(feature = "ore_sort", not(feature = "ope_sort")))]
+		{
+			Ok(OreSortableKey::generate()?.into())
+		}
 	}
 }
 
@@ -78,6 +110,7 @@ impl SortableKeyComposer for SortKeys
 
 		match alg_str {
 			ope::OPE_OUT => Ok(SortKeys::Ope(key.try_into()?)),
+			ore::ORE_OUT => Ok(SortKeys::Ore(key.try_into()?)),
 			_ => Err(Error::AlgNotFound),
 		}
 	}
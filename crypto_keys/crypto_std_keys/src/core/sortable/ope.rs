@@ -10,6 +10,8 @@ use crate::get_rand;
 
 pub const OPE_OUT: &str = "OPE-16";
 
+const OPE_MAX: u64 = 65532;
+
 pub struct OpeSortableKey(OpeKey);
 
 try_from_bytes_owned_single_value!(OpeSortableKey);
@@ -24,9 +26,14 @@ impl SortableKey for OpeSortableKey
 		master_key.encrypt(&self.0)
 	}
 
+	fn max_sortable(&self) -> u64
+	{
+		OPE_MAX
+	}
+
 	fn encrypt_sortable(&self, data: u64) -> Result<u64, Error>
 	{
-		if data > 65532 {
+		if data > OPE_MAX {
 			return Err(Error::OpeStringToLarge);
 		}
 
@@ -6,6 +6,7 @@ use aes_gcm::{Aes256Gcm, Key};
 use rand_core::{CryptoRng, RngCore};
 use sentc_crypto_core::cryptomat::{Pk, SymKey, SymKeyGen};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
+use zeroize::Zeroize;
 
 use crate::core::sym::SymmetricKey;
 use crate::get_rand;
@@ -18,6 +19,14 @@ pub(crate) type AesKey = [u8; 32];
 
 pub struct Aes256GcmKey(AesKey);
 
+impl Drop for Aes256GcmKey
+{
+	fn drop(&mut self)
+	{
+		self.0.zeroize();
+	}
+}
+
 impl Aes256GcmKey
 {
 	pub(crate) fn from_raw_key(raw: AesKey) -> Self
@@ -172,6 +181,7 @@ mod test
 {
 	use core::str::from_utf8;
 
+	use sentc_crypto_core::cryptomat::SymKeyComposer;
 	use sentc_crypto_core::Error::DecryptionFailed;
 
 	use super::*;
@@ -200,6 +210,20 @@ mod test
 		assert_eq!(text, decrypted_text);
 	}
 
+	#[test]
+	fn test_derive_key()
+	{
+		let master_key = SymmetricKey::generate().unwrap();
+
+		let key_1 = SymmetricKey::derive_key_by_sym_key(&master_key, b"part_0").unwrap();
+		let key_2 = SymmetricKey::derive_key_by_sym_key(&master_key, b"part_0").unwrap();
+		let key_3 = SymmetricKey::derive_key_by_sym_key(&master_key, b"part_1").unwrap();
+
+		assert_eq!(key_1.as_ref(), key_2.as_ref());
+		assert_ne!(key_1.as_ref(), key_3.as_ref());
+		assert_ne!(key_1.as_ref(), master_key.as_ref());
+	}
+
 	#[test]
 	fn test_not_decrypt_with_wrong_key()
 	{
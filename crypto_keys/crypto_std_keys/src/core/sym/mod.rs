@@ -1,7 +1,9 @@
 use alloc::vec::Vec;
 
+use hkdf::Hkdf;
 use sentc_crypto_core::cryptomat::{CryptoAlg, Pk, SymKey, SymKeyComposer, SymKeyGen};
 use sentc_crypto_core::Error;
+use sha2::Sha256;
 
 use crate::core::sym::aes_gcm::Aes256GcmKey;
 
@@ -39,6 +41,20 @@ impl SymKeyComposer for SymmetricKey
 			_ => Err(Error::AlgNotFound),
 		}
 	}
+
+	/**
+	Hkdf sha256 with the master key as ikm. The derived key is always an aes key.
+	 */
+	fn derive_key_by_sym_key<M: SymKey>(master_key: &M, info: &[u8]) -> Result<Self::SymmetricKey, Error>
+	{
+		let h = Hkdf::<Sha256>::new(None, master_key.as_ref());
+
+		let mut key = [0u8; 32];
+		h.expand(info, &mut key)
+			.map_err(|_| Error::KeyCreationFailed)?;
+
+		Ok(Self::Aes(Aes256GcmKey::from_raw_key(key)))
+	}
 }
 
 impl SymKeyGen for SymmetricKey
@@ -93,6 +93,10 @@ pub enum SortableFormatExport
 	{
 		key: String, key_id: SymKeyId
 	},
+	Ore
+	{
+		key: String, key_id: SymKeyId
+	},
 }
 
 impl From<SortableKey> for SortableFormatExport
@@ -108,6 +112,12 @@ impl From<SortableKey> for SortableFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CoreSortableKey::Ore(_) => {
+				Self::Ore {
+					key,
+					key_id: value.key_id,
+				}
+			},
 		}
 	}
 }
@@ -130,6 +140,17 @@ impl TryInto<SortableKey> for SortableFormatExport
 					key_id,
 				})
 			},
+			SortableFormatExport::Ore {
+				key,
+				key_id,
+			} => {
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportSymmetricKeyFailed)?;
+
+				Ok(SortableKey {
+					key: CoreSortableKey::ore_key_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
 		}
 	}
 }
@@ -670,6 +670,8 @@ it is used to temporary store the key for the next process
 pub enum MasterKeyFormat
 {
 	Argon2(String), //Base64 encoded string from prepare login, is used in done_login
+	Hkdf(String),   //from the login with a passkey
+	Opaque(String), //from the login with opaque
 }
 
 impl MasterKeyFormat
@@ -702,6 +704,16 @@ impl TryInto<crate::core::DeriveMasterKeyForAuth> for MasterKeyFormat
 
 				Ok(crate::core::DeriveMasterKeyForAuth::argon2_from_bytes_owned(bytes)?)
 			},
+			Self::Hkdf(mk) => {
+				let bytes = Base64::decode_vec(mk.as_str()).map_err(|_e| SdkUtilError::ImportAuthMasterKeyFailed)?;
+
+				Ok(crate::core::DeriveMasterKeyForAuth::hkdf_from_bytes_owned(bytes)?)
+			},
+			Self::Opaque(mk) => {
+				let bytes = Base64::decode_vec(mk.as_str()).map_err(|_e| SdkUtilError::ImportAuthMasterKeyFailed)?;
+
+				Ok(crate::core::DeriveMasterKeyForAuth::opaque_from_bytes_owned(bytes)?)
+			},
 		}
 	}
 }
@@ -716,6 +728,16 @@ impl From<crate::core::DeriveMasterKeyForAuth> for MasterKeyFormat
 
 				Self::Argon2(key)
 			},
+			crate::core::DeriveMasterKeyForAuth::Hkdf(k) => {
+				let key = Base64::encode_string(&k);
+
+				Self::Hkdf(key)
+			},
+			crate::core::DeriveMasterKeyForAuth::Opaque(k) => {
+				let key = Base64::encode_string(&k);
+
+				Self::Opaque(key)
+			},
 		}
 	}
 }
@@ -4,9 +4,48 @@ extern crate alloc;
 
 use core::str::from_utf8;
 
-use sentc_crypto_core::cryptomat::{ClientRandomValue, DeriveMasterKeyForAuth, Pk, SignK, Sk, VerifyK};
-use sentc_crypto_core::user::{change_password, done_login, password_reset, prepare_login, register, safety_number, LoginDoneOutput};
-use sentc_crypto_std_keys::core::{PwHasherGetter, SecretKey, SignKey, SymmetricKey, VerifyKey};
+use sentc_crypto_core::cryptomat::{
+	ClientRandomValue,
+	CryptoAlg,
+	DeriveAuthKeyForAuth,
+	DeriveMasterKeyForAuth,
+	Pk,
+	PwHash,
+	PwPrepareExport,
+	SignK,
+	Sk,
+	VerifyK,
+};
+use sentc_crypto_core::user::{
+	add_passkey,
+	add_password,
+	change_password,
+	done_login,
+	password_reset,
+	prepare_login,
+	prepare_login_pake_finish,
+	prepare_login_pake_start,
+	prepare_login_with_passkey,
+	register,
+	register_pake_finish,
+	register_pake_start,
+	register_with_passkey,
+	safety_number,
+	upgrade_password_hash,
+	LoginDoneOutput,
+};
+use sentc_crypto_std_keys::core::{
+	Argon2ModeratePolicy,
+	OpaqueGetter,
+	PwHasher,
+	PwHasherGetter,
+	SecretKey,
+	SignKey,
+	SymmetricKey,
+	VerifyKey,
+	ARGON_2_M19456_T2_P1,
+	ARGON_2_OUTPUT,
+};
 
 #[test]
 fn test_register()
@@ -107,6 +146,67 @@ fn test_pw_change()
 	assert_eq!(key_old.as_ref(), key_new.as_ref());
 }
 
+#[test]
+fn test_upgrade_password_hash()
+{
+	let password = "abc*èéöäüê";
+
+	//registered with the old parameters
+	let out = register::<SymmetricKey, SecretKey, SignKey, PwHasherGetter>(password).unwrap();
+
+	assert_eq!(out.derived_alg, ARGON_2_OUTPUT);
+
+	let salt_from_rand_value = out.client_random_value.generate_salt("");
+
+	//the old policy needs no upgrade
+	assert!(!PwHasherGetter::needs_upgrade(out.derived_alg));
+	assert!(PwHasher::<Argon2ModeratePolicy>::needs_upgrade(out.derived_alg));
+
+	let prep_login_out = prepare_login::<PwHasher<Argon2ModeratePolicy>>(password, &salt_from_rand_value, out.derived_alg).unwrap();
+
+	let upgrade_out = upgrade_password_hash::<PwHasher<Argon2ModeratePolicy>>(
+		password,
+		&salt_from_rand_value,
+		&out.encrypted_master_key,
+		out.derived_alg,
+	)
+	.unwrap();
+
+	assert_eq!(upgrade_out.derived_alg, ARGON_2_M19456_T2_P1);
+
+	//login with the same password and the new parameters
+	let new_salt = upgrade_out.client_random_value.generate_salt("");
+	let prep_login_new = prepare_login::<PwHasher<Argon2ModeratePolicy>>(password, &new_salt, upgrade_out.derived_alg).unwrap();
+
+	assert!(!PwHasher::<Argon2ModeratePolicy>::needs_upgrade(
+		upgrade_out.derived_alg
+	));
+
+	let key_old = prep_login_out
+		.master_key_encryption_key
+		.get_master_key(&out.encrypted_master_key)
+		.unwrap();
+	let key_new = prep_login_new
+		.master_key_encryption_key
+		.get_master_key(&upgrade_out.encrypted_master_key)
+		.unwrap();
+
+	assert_eq!(key_old.as_ref(), key_new.as_ref());
+
+	//the default hasher can still login with the new parameters
+	let prep_login_default = prepare_login::<PwHasherGetter>(password, &new_salt, upgrade_out.derived_alg).unwrap();
+	let key_default = prep_login_default
+		.master_key_encryption_key
+		.get_master_key(&upgrade_out.encrypted_master_key)
+		.unwrap();
+
+	assert_eq!(key_old.as_ref(), key_default.as_ref());
+	assert!(!PwHasherGetter::needs_upgrade(upgrade_out.derived_alg));
+
+	//unknown parameters from the server are rejected
+	assert!(prepare_login::<PwHasherGetter>(password, &new_salt, "ARGON-2-SHA256-m8-t1-p1").is_err());
+}
+
 #[test]
 fn test_password_reset()
 {
@@ -169,6 +269,175 @@ fn test_password_reset()
 	}
 }
 
+#[test]
+fn test_login_with_password_and_passkey()
+{
+	let password = "abc*èéöäüê";
+	let secret = [42u8; 32];
+
+	let out = register::<SymmetricKey, SecretKey, SignKey, PwHasherGetter>(password).unwrap();
+
+	let salt = out.client_random_value.generate_salt("");
+
+	let prep_login_out = prepare_login::<PwHasherGetter>(password, &salt, out.derived_alg).unwrap();
+
+	//add the passkey with the key from the password login
+	let passkey_out = add_passkey::<PwHasherGetter>(
+		&prep_login_out.master_key_encryption_key,
+		&out.encrypted_master_key,
+		&secret,
+	)
+	.unwrap();
+
+	assert_ne!(passkey_out.derived_alg, out.derived_alg);
+
+	let passkey_salt = passkey_out.client_random_value.generate_salt("");
+
+	let passkey_login_out = prepare_login_with_passkey::<PwHasherGetter>(&secret, &passkey_salt, passkey_out.derived_alg).unwrap();
+
+	//the server checks the auth key of the passkey wrapper
+	assert_eq!(
+		passkey_login_out.auth_key.hash_auth_key().unwrap(),
+		passkey_out.hashed_authentication_key_bytes.prepare_export()
+	);
+
+	//both wrappers decrypt the same user keys
+	let login_out = done_login::<SecretKey, SignKey>(
+		&passkey_login_out.master_key_encryption_key,
+		&passkey_out.encrypted_master_key,
+		&out.encrypted_private_key,
+		out.keypair_encrypt_alg,
+		&out.encrypted_sign_key,
+		out.keypair_sign_alg,
+	)
+	.unwrap();
+
+	let encrypted = out.public_key.encrypt(b"hello").unwrap();
+
+	assert_eq!(login_out.private_key.decrypt(&encrypted).unwrap(), b"hello");
+
+	done_login::<SecretKey, SignKey>(
+		&prep_login_out.master_key_encryption_key,
+		&out.encrypted_master_key,
+		&out.encrypted_private_key,
+		out.keypair_encrypt_alg,
+		&out.encrypted_sign_key,
+		out.keypair_sign_alg,
+	)
+	.unwrap();
+
+	//the password can't be used for the passkey wrapper
+	assert!(prepare_login::<PwHasherGetter>(password, &passkey_salt, passkey_out.derived_alg).is_err());
+}
+
+#[test]
+fn test_register_with_passkey_and_add_password()
+{
+	let secret = [1u8; 32];
+	let password = "abc";
+
+	let out = register_with_passkey::<SymmetricKey, SecretKey, SignKey, PwHasherGetter>(&secret).unwrap();
+
+	assert_eq!(out.client_random_value.get_alg_str(), out.derived_alg);
+
+	let salt = out.client_random_value.generate_salt("");
+
+	let prep_login_out = prepare_login_with_passkey::<PwHasherGetter>(&secret, &salt, out.derived_alg).unwrap();
+
+	let pw_out = add_password::<PwHasherGetter>(
+		&prep_login_out.master_key_encryption_key,
+		&out.encrypted_master_key,
+		password,
+	)
+	.unwrap();
+
+	let pw_salt = pw_out.client_random_value.generate_salt("");
+
+	let pw_login_out = prepare_login::<PwHasherGetter>(password, &pw_salt, pw_out.derived_alg).unwrap();
+
+	done_login::<SecretKey, SignKey>(
+		&pw_login_out.master_key_encryption_key,
+		&pw_out.encrypted_master_key,
+		&out.encrypted_private_key,
+		out.keypair_encrypt_alg,
+		&out.encrypted_sign_key,
+		out.keypair_sign_alg,
+	)
+	.unwrap();
+
+	//a secret with the wrong length
+	assert!(register_with_passkey::<SymmetricKey, SecretKey, SignKey, PwHasherGetter>(b"abc").is_err());
+}
+
+#[test]
+fn test_register_and_login_with_opaque()
+{
+	let password = "abc*èéöäüê";
+	let credential_identifier = b"device_1";
+
+	let setup = OpaqueGetter::setup().unwrap();
+
+	//register
+	let (state, registration_request) = register_pake_start::<OpaqueGetter>(password).unwrap();
+
+	let registration_response = OpaqueGetter::registration_response(&setup, &registration_request, credential_identifier).unwrap();
+
+	let out = register_pake_finish::<SymmetricKey, SecretKey, SignKey, OpaqueGetter>(&state, password, &registration_response).unwrap();
+
+	let password_file = OpaqueGetter::registration_finish(&out.registration_upload).unwrap();
+
+	//login
+	let (state, credential_request) = prepare_login_pake_start::<OpaqueGetter>(password).unwrap();
+
+	let (server_state, credential_response) = OpaqueGetter::login_start(
+		&setup,
+		Some(&password_file),
+		&credential_request,
+		credential_identifier,
+	)
+	.unwrap();
+
+	let login_out = prepare_login_pake_finish::<OpaqueGetter>(&state, password, &credential_response).unwrap();
+
+	let server_session_key = OpaqueGetter::login_finish(&server_state, &login_out.credential_finalization).unwrap();
+
+	assert_eq!(server_session_key, login_out.session_key);
+
+	let done_login_out = done_login::<SecretKey, SignKey>(
+		&login_out.master_key_encryption_key,
+		&out.encrypted_master_key,
+		&out.encrypted_private_key,
+		out.keypair_encrypt_alg,
+		&out.encrypted_sign_key,
+		out.keypair_sign_alg,
+	)
+	.unwrap();
+
+	let encrypted = out.public_key.encrypt(b"hello").unwrap();
+
+	assert_eq!(done_login_out.private_key.decrypt(&encrypted).unwrap(), b"hello");
+
+	//wrong password
+	let (state, credential_request) = prepare_login_pake_start::<OpaqueGetter>("abc").unwrap();
+
+	let (_, credential_response) = OpaqueGetter::login_start(
+		&setup,
+		Some(&password_file),
+		&credential_request,
+		credential_identifier,
+	)
+	.unwrap();
+
+	assert!(prepare_login_pake_finish::<OpaqueGetter>(&state, "abc", &credential_response).is_err());
+
+	//unknown user got a fake response
+	let (state, credential_request) = prepare_login_pake_start::<OpaqueGetter>(password).unwrap();
+
+	let (_, credential_response) = OpaqueGetter::login_start(&setup, None, &credential_request, b"device_2").unwrap();
+
+	assert!(prepare_login_pake_finish::<OpaqueGetter>(&state, password, &credential_response).is_err());
+}
+
 fn create_dummy_user_for_safety_number() -> (VerifyKey, LoginDoneOutput<SecretKey, SignKey>)
 {
 	let password = "abc*èéöäüê";
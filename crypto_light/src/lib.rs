@@ -12,8 +12,10 @@ use alloc::string::String;
 
 #[cfg(feature = "server_test")]
 pub use sentc_crypto_common as sdk_common;
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
 use sentc_crypto_common::{DeviceId, UserId};
 use sentc_crypto_utils::cryptomat::KeyToString;
+use sentc_crypto_utils::session::wipe_string;
 use serde::{Deserialize, Serialize};
 pub use {sentc_crypto_core as sdk_core, sentc_crypto_utils as sdk_utils, sentc_crypto_std_keys as sdk_keys};
 
@@ -68,6 +70,51 @@ impl TryFrom<UserDataInt> for UserDataExport
 	}
 }
 
+impl TryFrom<&UserDataExport> for UserDataInt
+{
+	type Error = SdkLightError;
+
+	fn try_from(value: &UserDataExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			device_keys: (&value.device_keys).try_into()?,
+			jwt: value.jwt.clone(),
+			refresh_token: value.refresh_token.clone(),
+			user_id: value.user_id.clone(),
+			device_id: value.device_id.clone(),
+		})
+	}
+}
+
+impl TryFrom<UserDataExport> for UserDataInt
+{
+	type Error = SdkLightError;
+
+	fn try_from(mut value: UserDataExport) -> Result<Self, Self::Error>
+	{
+		let out = (&value).try_into();
+
+		//the exported secret keys and tokens are not needed after the import
+		value.wipe();
+
+		out
+	}
+}
+
+impl UserDataExport
+{
+	/**
+	Overwrites the tokens and the exported secret keys, e.g. after the data was sealed or at the logout
+	 */
+	pub fn wipe(&mut self)
+	{
+		wipe_string(&mut self.jwt);
+		wipe_string(&mut self.refresh_token);
+
+		self.device_keys.wipe();
+	}
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct DeviceKeyDataExport
 {
@@ -101,3 +148,29 @@ impl TryFrom<StdDeviceKeyDataInt> for DeviceKeyDataExport
 		})
 	}
 }
+
+impl TryFrom<&DeviceKeyDataExport> for StdDeviceKeyDataInt
+{
+	type Error = SdkLightError;
+
+	fn try_from(value: &DeviceKeyDataExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			private_key: value.private_key.parse()?,
+			public_key: value.public_key.parse()?,
+			sign_key: value.sign_key.parse()?,
+			verify_key: value.verify_key.parse()?,
+			exported_public_key: UserPublicKeyData::from_string(&value.exported_public_key)?,
+			exported_verify_key: UserVerifyKeyData::from_string(&value.exported_verify_key)?,
+		})
+	}
+}
+
+impl DeviceKeyDataExport
+{
+	pub fn wipe(&mut self)
+	{
+		wipe_string(&mut self.private_key);
+		wipe_string(&mut self.sign_key);
+	}
+}
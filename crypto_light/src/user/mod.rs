@@ -5,6 +5,7 @@ mod user;
 mod user_rust;
 
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use base64ct::{Base64, Base64UrlUnpadded, Encoding};
 use sentc_crypto_common::group::GroupNewMemberLightInput;
@@ -24,7 +25,9 @@ use sentc_crypto_common::user::{
 use sentc_crypto_common::{DeviceId, UserId};
 use sentc_crypto_std_keys::core::{DeriveMasterKeyForAuth, PwHasherGetter, SecretKey, SignKey, SymmetricKey};
 use sentc_crypto_std_keys::util::export::{export_raw_public_key_to_pem, export_raw_verify_key_to_pem};
-use sentc_crypto_utils::{client_random_value_to_string, handle_server_response, hashed_authentication_key_to_string};
+use sentc_crypto_utils::cryptomat::SymKeyWrapper;
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::{client_random_value_to_string, handle_server_response, hashed_authentication_key_to_string, session};
 
 #[cfg(feature = "export")]
 pub use self::user::{
@@ -33,6 +36,8 @@ pub use self::user::{
 	done_register_device_start,
 	done_validate_mfa,
 	generate_user_register_data,
+	lock_user_data_with_key,
+	lock_user_data_with_pin,
 	prepare_check_user_identifier_available,
 	prepare_login_start,
 	prepare_refresh_jwt,
@@ -40,6 +45,8 @@ pub use self::user::{
 	prepare_user_identifier_update,
 	register,
 	register_typed,
+	unlock_user_data_with_key,
+	unlock_user_data_with_pin,
 	verify_login,
 };
 #[cfg(not(feature = "export"))]
@@ -49,6 +56,8 @@ pub use self::user_rust::{
 	done_register_device_start,
 	done_validate_mfa,
 	generate_user_register_data,
+	lock_user_data_with_key,
+	lock_user_data_with_pin,
 	prepare_check_user_identifier_available,
 	prepare_login_start,
 	prepare_refresh_jwt,
@@ -56,10 +65,12 @@ pub use self::user_rust::{
 	prepare_user_identifier_update,
 	register,
 	register_typed,
+	unlock_user_data_with_key,
+	unlock_user_data_with_pin,
 	verify_login,
 };
 use crate::error::SdkLightError;
-use crate::{StdDeviceKeyDataInt, StdUserPreVerifyLogin, UserDataInt};
+use crate::{StdDeviceKeyDataInt, StdUserPreVerifyLogin, UserDataExport, UserDataInt};
 
 fn generate_user_register_data_internally() -> Result<(String, String), SdkLightError>
 {
@@ -295,6 +306,62 @@ pub fn change_password(
 	)?)
 }
 
+//__________________________________________________________________________________________________
+//lock the user data on the device
+
+fn lock_user_data(data: UserDataInt, seal: impl FnOnce(&[u8]) -> Result<String, SdkUtilError>) -> Result<String, SdkLightError>
+{
+	let mut data: UserDataExport = data.try_into()?;
+
+	let res = serde_json::to_string(&data).map_err(|_| SdkLightError::JsonToStringFailed);
+
+	data.wipe();
+
+	let mut json = res?;
+
+	let sealed = seal(json.as_bytes());
+
+	session::wipe_string(&mut json);
+
+	Ok(sealed?)
+}
+
+fn unlock_user_data(mut data: Vec<u8>) -> Result<UserDataInt, SdkLightError>
+{
+	let res = serde_json::from_slice::<UserDataExport>(&data);
+
+	//the decrypted json contains the secret keys
+	session::wipe_bytes(&mut data);
+
+	let mut export = res?;
+
+	let out = (&export).try_into();
+
+	export.wipe();
+
+	out
+}
+
+fn lock_user_data_with_pin_internally(pin: &str, data: UserDataInt) -> Result<String, SdkLightError>
+{
+	lock_user_data(data, |data| session::seal_with_pin::<PwHasherGetter>(pin, data))
+}
+
+fn lock_user_data_with_key_internally(key: &sentc_crypto_std_keys::util::SymmetricKey, data: UserDataInt) -> Result<String, SdkLightError>
+{
+	lock_user_data(data, |data| session::seal_with_key(key.get_key(), data))
+}
+
+fn unlock_user_data_with_pin_internally(pin: &str, sealed: &str) -> Result<UserDataInt, SdkLightError>
+{
+	unlock_user_data(session::unseal_with_pin::<PwHasherGetter>(pin, sealed)?)
+}
+
+fn unlock_user_data_with_key_internally(key: &sentc_crypto_std_keys::util::SymmetricKey, sealed: &str) -> Result<UserDataInt, SdkLightError>
+{
+	unlock_user_data(session::unseal_with_key(key.get_key(), sealed)?)
+}
+
 #[cfg(test)]
 pub(crate) mod test_fn
 {
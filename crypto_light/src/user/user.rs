@@ -2,7 +2,7 @@ use alloc::string::String;
 
 use sentc_crypto_common::user::UserDeviceRegisterInput;
 use sentc_crypto_common::{DeviceId, UserId};
-use sentc_crypto_std_keys::util::MasterKeyFormat;
+use sentc_crypto_std_keys::util::{MasterKeyFormat, SymmetricKey};
 
 use crate::error::SdkLightError;
 use crate::user::{
@@ -11,6 +11,8 @@ use crate::user::{
 	done_register_internally,
 	done_validate_mfa_internally,
 	generate_user_register_data_internally,
+	lock_user_data_with_key_internally,
+	lock_user_data_with_pin_internally,
 	prepare_check_user_identifier_available_internally,
 	prepare_login_start_internally,
 	prepare_refresh_jwt_internally,
@@ -18,6 +20,8 @@ use crate::user::{
 	prepare_register_device_private_internally,
 	prepare_user_identifier_update_internally,
 	register_internally,
+	unlock_user_data_with_key_internally,
+	unlock_user_data_with_pin_internally,
 	verify_login_internally,
 };
 use crate::{StdDeviceKeyDataInt, StdUserPreVerifyLogin, UserDataExport};
@@ -105,6 +109,34 @@ pub fn prepare_refresh_jwt(refresh_token: String) -> Result<String, String>
 	Ok(prepare_refresh_jwt_internally(refresh_token)?)
 }
 
+pub fn lock_user_data_with_pin(pin: &str, data: UserDataExport) -> Result<String, String>
+{
+	Ok(lock_user_data_with_pin_internally(pin, data.try_into()?)?)
+}
+
+pub fn lock_user_data_with_key(key: &str, data: UserDataExport) -> Result<String, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	Ok(lock_user_data_with_key_internally(&key, data.try_into()?)?)
+}
+
+pub fn unlock_user_data_with_pin(pin: &str, sealed: &str) -> Result<UserDataExport, String>
+{
+	let out = unlock_user_data_with_pin_internally(pin, sealed)?;
+
+	Ok(out.try_into()?)
+}
+
+pub fn unlock_user_data_with_key(key: &str, sealed: &str) -> Result<UserDataExport, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let out = unlock_user_data_with_key_internally(&key, sealed)?;
+
+	Ok(out.try_into()?)
+}
+
 #[cfg(test)]
 mod test
 {
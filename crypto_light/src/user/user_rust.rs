@@ -3,6 +3,7 @@ use alloc::string::String;
 use sentc_crypto_common::user::UserDeviceRegisterInput;
 use sentc_crypto_common::{DeviceId, UserId};
 use sentc_crypto_std_keys::core::DeriveMasterKeyForAuth;
+use sentc_crypto_std_keys::util::SymmetricKey;
 
 use crate::error::SdkLightError;
 use crate::user::{
@@ -11,6 +12,8 @@ use crate::user::{
 	done_register_internally,
 	done_validate_mfa_internally,
 	generate_user_register_data_internally,
+	lock_user_data_with_key_internally,
+	lock_user_data_with_pin_internally,
 	prepare_check_user_identifier_available_internally,
 	prepare_login_start_internally,
 	prepare_refresh_jwt_internally,
@@ -18,6 +21,8 @@ use crate::user::{
 	prepare_register_device_private_internally,
 	prepare_user_identifier_update_internally,
 	register_internally,
+	unlock_user_data_with_key_internally,
+	unlock_user_data_with_pin_internally,
 	verify_login_internally,
 };
 use crate::{StdDeviceKeyDataInt, StdUserPreVerifyLogin, UserDataInt};
@@ -93,6 +98,26 @@ pub fn prepare_refresh_jwt(refresh_token: String) -> Result<String, SdkLightErro
 	prepare_refresh_jwt_internally(refresh_token)
 }
 
+pub fn lock_user_data_with_pin(pin: &str, data: UserDataInt) -> Result<String, SdkLightError>
+{
+	lock_user_data_with_pin_internally(pin, data)
+}
+
+pub fn lock_user_data_with_key(key: &SymmetricKey, data: UserDataInt) -> Result<String, SdkLightError>
+{
+	lock_user_data_with_key_internally(key, data)
+}
+
+pub fn unlock_user_data_with_pin(pin: &str, sealed: &str) -> Result<UserDataInt, SdkLightError>
+{
+	unlock_user_data_with_pin_internally(pin, sealed)
+}
+
+pub fn unlock_user_data_with_key(key: &SymmetricKey, sealed: &str) -> Result<UserDataInt, SdkLightError>
+{
+	unlock_user_data_with_key_internally(key, sealed)
+}
+
 #[cfg(test)]
 mod test
 {
@@ -102,6 +127,7 @@ mod test
 
 	use sentc_crypto_common::user::{ChangePasswordData, UserDeviceDoneRegisterInputLight, UserDeviceRegisterOutput};
 	use sentc_crypto_common::ServerOutput;
+	use sentc_crypto_core::cryptomat::SymKeyGen;
 	use serde_json::{from_str, to_string};
 
 	use super::*;
@@ -282,4 +308,55 @@ mod test
 		)
 		.unwrap();
 	}
+
+	#[test]
+	fn test_lock_user_data()
+	{
+		let out_string = register("hello", "1234").unwrap();
+		let out: UserDeviceRegisterInput = from_str(&out_string).unwrap();
+
+		let server_output = simulate_server_prepare_login(&out.derived);
+		let (_, auth_key, master_key_encryption_key) = prepare_login("hello", "1234", server_output.as_str()).unwrap();
+
+		let server_output = simulate_server_done_login(out);
+
+		let login_out = done_login(
+			&master_key_encryption_key,
+			auth_key,
+			"hello".to_string(),
+			server_output,
+		)
+		.unwrap();
+
+		let server_output = simulate_verify_login(&login_out.challenge);
+		let user = verify_login(
+			&server_output,
+			login_out.user_id,
+			login_out.device_id,
+			login_out.device_keys,
+		)
+		.unwrap();
+
+		let device_id = user.device_id.clone();
+
+		let sealed = lock_user_data_with_pin("0000", user).unwrap();
+
+		assert!(unlock_user_data_with_pin("1111", &sealed).is_err());
+
+		let user = unlock_user_data_with_pin("0000", &sealed).unwrap();
+
+		assert_eq!(user.device_id, device_id);
+
+		//a key from the keystore of the platform
+		let key = SymmetricKey {
+			key: sentc_crypto_std_keys::core::SymmetricKey::generate().unwrap(),
+			key_id: "keystore".to_string(),
+		};
+
+		let sealed = lock_user_data_with_key(&key, user).unwrap();
+
+		let user = unlock_user_data_with_key(&key, &sealed).unwrap();
+
+		assert_eq!(user.device_id, device_id);
+	}
 }
@@ -1,13 +1,16 @@
 use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
+use core::ops::Bound;
 use core::str::FromStr;
 
+use base64ct::{Base64, Base64UrlUnpadded, Encoding};
 use sentc_crypto_common::content_searchable::SearchableCreateOutput;
 use sentc_crypto_common::content_sortable::SortableEncryptOutput;
 use sentc_crypto_common::crypto::{EncryptedHead, SignHead};
 use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
 use sentc_crypto_core::cryptomat::{CryptoAlg, Pk, SearchableKey, SearchableKeyComposer, SortableKey, SortableKeyComposer, VerifyK};
+use serde::{Deserialize, Serialize};
 
 use crate::cryptomat::{KeyToString, SignKWrapper};
 use crate::error::SdkUtilError;
@@ -15,6 +18,183 @@ use crate::{put_head_and_encrypted_data, split_head_and_encrypted_data};
 
 //searchable
 
+const SEARCHABLE_MIN_TRUNCATE_LEN: usize = 16;
+
+/**
+The encoding of the searchable hashes
+ */
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum SearchableEncoding
+{
+	Base64,
+	#[default]
+	Base64Url,
+	Hex,
+}
+
+impl SearchableEncoding
+{
+	pub fn encode(&self, data: &[u8]) -> String
+	{
+		match self {
+			Self::Base64 => Base64::encode_string(data),
+			Self::Base64Url => Base64UrlUnpadded::encode_string(data),
+			Self::Hex => {
+				const HEX: &[u8; 16] = b"0123456789abcdef";
+
+				let mut out = String::with_capacity(data.len() * 2);
+
+				for b in data {
+					out.push(HEX[(b >> 4) as usize] as char);
+					out.push(HEX[(b & 0xf) as usize] as char);
+				}
+
+				out
+			},
+		}
+	}
+}
+
+/**
+Options for the searchable hashes.
+
+- bucket_size: pad the number of hashes to the next multiple of this size with dummy hashes
+- truncate: only use the first bytes of each hash (at least 16 bytes)
+- encoding: the string encoding of the hashes, default is base64 url without padding
+
+The default options create the same hashes as create_searchable.
+ */
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct SearchableOptions
+{
+	pub bucket_size: Option<usize>,
+	pub truncate: Option<usize>,
+	#[serde(default)]
+	pub encoding: SearchableEncoding,
+}
+
+/**
+The parts of the data that are hashed.
+
+For full only the whole data, else every prefix of the bytes:
+like: word hello -> 1st hash('h'), 2nd hash('he'), 3rd hash('hel'), ...
+ */
+fn searchable_parts(data: &str, full: bool, limit: Option<usize>) -> Result<Vec<&[u8]>, SdkUtilError>
+{
+	if data.is_empty() {
+		return Err(SdkUtilError::SearchableEncryptionDataNotFound);
+	}
+
+	if full {
+		//create only one hash for 1:1 lookup. good for situations where the item should not be searched but checked
+		return Ok(vec![data.as_bytes()]);
+	}
+
+	//how many bytes should be hashed
+	let limit_length = if let Some(l) = limit {
+		if l > data.len() {
+			data.len()
+		} else {
+			l
+		}
+	} else {
+		data.len()
+	};
+
+	if limit_length > 200 {
+		return Err(SdkUtilError::SearchableEncryptionDataTooLong);
+	}
+
+	let bytes = data.as_bytes();
+	let mut parts = Vec::with_capacity(limit_length);
+
+	//make sure we not iterate over the limit when limit is set
+	for i in 0..bytes.len().min(limit_length + 1) {
+		//hash the next byte as a combination of the previous and the actual
+		parts.push(&bytes[..=i]);
+	}
+
+	Ok(parts)
+}
+
+/**
+How the value of a compound field is normalized before hashing.
+ */
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum SearchableNormalization
+{
+	#[default]
+	None,
+	Trim,
+	Lowercase,
+	TrimLowercase,
+	//only lowercase letters and digits, e.g. for phone numbers or ids with separators
+	Alphanumeric,
+}
+
+impl SearchableNormalization
+{
+	pub fn normalize(&self, value: &str) -> String
+	{
+		match self {
+			Self::None => value.to_string(),
+			Self::Trim => value.trim().to_string(),
+			Self::Lowercase => value.to_lowercase(),
+			Self::TrimLowercase => value.trim().to_lowercase(),
+			Self::Alphanumeric => {
+				value
+					.chars()
+					.filter(|c| c.is_alphanumeric())
+					.flat_map(char::to_lowercase)
+					.collect()
+			},
+		}
+	}
+}
+
+/**
+One field of a compound blind index.
+
+The name is part of the hash, so the same value in another field creates another hash.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CompoundField
+{
+	pub name: String,
+	pub value: String,
+	#[serde(default)]
+	pub normalization: SearchableNormalization,
+}
+
+//0xfe is never part of an utf-8 string, so a compound input can't be the input of a normal search
+const COMPOUND_DOMAIN: &[u8] = b"\xfesentc-compound-v1";
+
+/**
+Builds the input of a compound index.
+
+Every field name and value is length prefixed so the concatenation is not ambiguous.
+ */
+fn compound_input(fields: &[CompoundField]) -> Result<Vec<u8>, SdkUtilError>
+{
+	if fields.is_empty() {
+		return Err(SdkUtilError::SearchableEncryptionDataNotFound);
+	}
+
+	let mut out = Vec::from(COMPOUND_DOMAIN);
+	out.extend_from_slice(&(fields.len() as u32).to_be_bytes());
+
+	for field in fields {
+		let value = field.normalization.normalize(&field.value);
+
+		out.extend_from_slice(&(field.name.len() as u32).to_be_bytes());
+		out.extend_from_slice(field.name.as_bytes());
+		out.extend_from_slice(&(value.len() as u32).to_be_bytes());
+		out.extend_from_slice(value.as_bytes());
+	}
+
+	Ok(out)
+}
+
 pub trait SearchableKeyWrapper: FromStr + KeyToString
 {
 	type Inner: SearchableKey;
@@ -25,47 +205,52 @@ pub trait SearchableKeyWrapper: FromStr + KeyToString
 
 	fn create_searchable_raw(&self, data: &str, full: bool, limit: Option<usize>) -> Result<Vec<String>, SdkUtilError>
 	{
-		if data.is_empty() {
-			return Err(SdkUtilError::SearchableEncryptionDataNotFound);
-		}
+		searchable_parts(data, full, limit)?
+			.into_iter()
+			.map(|part| self.search_bytes(part))
+			.collect()
+	}
 
-		if full {
-			//create only one hash for 1:1 lookup. good for situations where the item should not be searched but checked
-			let hash = self.search_bytes(data.as_bytes())?;
+	/**
+	Like create_searchable_raw but with padding, truncation and encoding of the hashes.
 
-			return Ok(vec![hash]);
-		}
+	When a bucket size is set, the hashes are filled up with dummy hashes to the next multiple of the bucket size,
+	so the server can't learn the exact length of the value.
+	The dummy hashes are hmacs of an input that is never a valid utf-8 string, so they can't be found by a search.
 
-		//how many bytes should be hashed
-		let limit_length = if let Some(l) = limit {
-			if l > data.len() {
-				data.len()
-			} else {
-				l
+	The search for these hashes must be done with search_with_options and the same options.
+	 */
+	fn create_searchable_raw_with_options(
+		&self,
+		data: &str,
+		full: bool,
+		limit: Option<usize>,
+		options: &SearchableOptions,
+	) -> Result<Vec<String>, SdkUtilError>
+	{
+		let mut hashed = searchable_parts(data, full, limit)?
+			.into_iter()
+			.map(|part| self.search_bytes_with_options(part, options))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		if let Some(bucket_size) = options.bucket_size {
+			if bucket_size == 0 {
+				return Err(SdkUtilError::SearchableBucketSizeInvalid);
 			}
-		} else {
-			data.len()
-		};
 
-		if limit_length > 200 {
-			return Err(SdkUtilError::SearchableEncryptionDataTooLong);
-		}
+			let padded_len = hashed.len().div_ceil(bucket_size) * bucket_size;
 
-		let mut word_to_hash = Vec::with_capacity(limit_length);
-		let mut hashed = Vec::with_capacity(limit_length);
+			//0xff is never part of an utf-8 string
+			let mut dummy = Vec::with_capacity(data.len() + 9);
 
-		for (i, datum) in data.bytes().enumerate() {
-			//make sure we not iterate over the limit when limit is set
-			if i > limit_length {
-				break;
-			}
+			for i in hashed.len()..padded_len {
+				dummy.clear();
+				dummy.push(0xff);
+				dummy.extend_from_slice(&(i as u64).to_be_bytes());
+				dummy.extend_from_slice(data.as_bytes());
 
-			//hash each char or byte of the string.
-			//hash the next byte as a combination of the previous and the actual
-			//like: word hello -> 1st hash('h'), 2nd hash('he'), 3rd hash('hel'), ...
-			word_to_hash.push(datum);
-
-			hashed.push(self.search_bytes(&word_to_hash)?);
+				hashed.push(self.search_bytes_with_options(&dummy, options)?);
+			}
 		}
 
 		Ok(hashed)
@@ -82,11 +267,72 @@ pub trait SearchableKeyWrapper: FromStr + KeyToString
 		})
 	}
 
+	fn create_searchable_with_options(
+		&self,
+		data: &str,
+		full: bool,
+		limit: Option<usize>,
+		options: &SearchableOptions,
+	) -> Result<SearchableCreateOutput, SdkUtilError>
+	{
+		let hashes = self.create_searchable_raw_with_options(data, full, limit, options)?;
+
+		Ok(SearchableCreateOutput {
+			hashes,
+			alg: self.get_key().get_alg_str().to_string(),
+			key_id: self.get_id().to_string(),
+		})
+	}
+
 	fn search(&self, data: &str) -> Result<String, SdkUtilError>
 	{
 		self.search_bytes(data.as_bytes())
 	}
 
+	/**
+	Create one full-match hash for a combination of fields, e.g. first name, last name and birth year.
+
+	The order of the fields matters. Use search_compound with the same fields to look up the value.
+	 */
+	fn create_compound_searchable(&self, fields: &[CompoundField]) -> Result<SearchableCreateOutput, SdkUtilError>
+	{
+		let hash = self.search_compound(fields)?;
+
+		Ok(SearchableCreateOutput {
+			hashes: vec![hash],
+			alg: self.get_key().get_alg_str().to_string(),
+			key_id: self.get_id().to_string(),
+		})
+	}
+
+	fn search_compound(&self, fields: &[CompoundField]) -> Result<String, SdkUtilError>
+	{
+		self.search_bytes(&compound_input(fields)?)
+	}
+
+	fn search_with_options(&self, data: &str, options: &SearchableOptions) -> Result<String, SdkUtilError>
+	{
+		self.search_bytes_with_options(data.as_bytes(), options)
+	}
+
+	fn search_bytes_with_options(&self, data: &[u8], options: &SearchableOptions) -> Result<String, SdkUtilError>
+	{
+		let hash = self.get_key().encrypt_searchable(data)?;
+
+		let hash = match options.truncate {
+			Some(len) => {
+				if len < SEARCHABLE_MIN_TRUNCATE_LEN || len > hash.len() {
+					return Err(SdkUtilError::SearchableTruncateLenInvalid(len));
+				}
+
+				&hash[..len]
+			},
+			None => &hash[..],
+		};
+
+		Ok(options.encoding.encode(hash))
+	}
+
 	fn search_bytes(&self, data: &[u8]) -> Result<String, SdkUtilError>;
 }
 
@@ -101,6 +347,163 @@ pub trait SearchableKeyComposerWrapper
 //__________________________________________________________________________________________________
 //sortable
 
+/**
+The output of an order-revealing sortable key.
+
+The ciphertext is base64 encoded and can only be compared with the compare fn of the alg.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct SortableOreEncryptOutput
+{
+	pub ciphertext: String,
+	pub alg: String,
+	pub key_id: String,
+}
+
+const TIMESTAMP_DAY_MS: u64 = 86_400_000;
+
+/**
+Transforms a timestamp (in ms) to the value which is encrypted by a sortable key.
+
+Timestamps are encrypted per day, so the value fits into the domain of every sortable key.
+ */
+pub fn timestamp_to_sortable(timestamp: u64) -> u64
+{
+	timestamp / TIMESTAMP_DAY_MS
+}
+
+/**
+A plaintext range to search encrypted sortable values.
+
+For numbers the bounds are used exactly.
+Strings are transformed to a number like in encrypt_string so different strings can have the same number.
+Only ascii strings are supported and the bounds must be inclusive.
+A prefix matches all strings starting with the prefix (for the first max_len bytes).
+Timestamps (in ms) are encrypted per day, see timestamp_to_sortable.
+ */
+#[derive(Serialize, Deserialize)]
+pub enum SortableRange
+{
+	Number
+	{
+		lower: Bound<u64>, upper: Bound<u64>
+	},
+	String
+	{
+		lower: Bound<String>,
+		upper: Bound<String>,
+		max_len: Option<usize>,
+	},
+	Prefix
+	{
+		prefix: String, max_len: Option<usize>
+	},
+	Timestamp
+	{
+		lower: Bound<u64>, upper: Bound<u64>
+	},
+}
+
+/**
+An encrypted bound of a range.
+
+Ope is the number of an order-preserving key, Ore the base64 encoded ciphertext of an order-revealing key.
+Ore bounds must be compared with the compare fn of the alg.
+ */
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum SortableRangeBound
+{
+	Ope(u64),
+	Ore(String),
+}
+
+/**
+The encrypted bounds of a range for one sortable key.
+
+Both bounds are always inclusive: lower <= value <= upper.
+When exact is false the server result can contain values outside the plaintext range
+(e.g. strings with the same number or timestamps of the same day) and must be filtered after decryption.
+When clamped is true the upper bound was greater than the largest value of the key and was set to the largest value.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct SortableRangeOutput
+{
+	pub lower: SortableRangeBound,
+	pub upper: SortableRangeBound,
+	pub exact: bool,
+	pub clamped: bool,
+	pub alg: String,
+	pub key_id: String,
+}
+
+/**
+Transforms the bounds into inclusive bounds inside the domain of the key.
+
+Returns the lower and upper bound and if the upper bound was clamped.
+ */
+fn inclusive_range(lower: Bound<u64>, upper: Bound<u64>, max: u64) -> Result<(u64, u64, bool), SdkUtilError>
+{
+	let lower = match lower {
+		Bound::Included(n) => n,
+		Bound::Excluded(n) => n.checked_add(1).ok_or(SdkUtilError::SortableRangeEmpty)?,
+		Bound::Unbounded => 0,
+	};
+
+	let upper = match upper {
+		Bound::Included(n) => n,
+		Bound::Excluded(n) => n.checked_sub(1).ok_or(SdkUtilError::SortableRangeEmpty)?,
+		Bound::Unbounded => max,
+	};
+
+	if lower > upper {
+		return Err(SdkUtilError::SortableRangeEmpty);
+	}
+
+	if lower > max {
+		return Err(SdkUtilError::SortableRangeOutOfDomain(max));
+	}
+
+	if upper > max {
+		return Ok((lower, max, true));
+	}
+
+	Ok((lower, upper, false))
+}
+
+/**
+The string transformation only keeps the order for ascii strings.
+ */
+fn check_range_string(s: &str) -> Result<(), SdkUtilError>
+{
+	if !s.is_ascii() {
+		return Err(SdkUtilError::SortableRangeNotAscii);
+	}
+
+	Ok(())
+}
+
+/**
+Fills the prefix up to max_len bytes with the lowest or the highest ascii char.
+
+The prefix must be ascii, so every char is one byte like in the string transformation.
+ */
+fn prefix_bound(prefix: &str, max_len: usize, fill: u8) -> Result<String, SdkUtilError>
+{
+	check_range_string(prefix)?;
+
+	if prefix.len() > max_len {
+		return Err(SdkUtilError::SortableRangePrefixTooLong(max_len));
+	}
+
+	let mut out = String::from(prefix);
+
+	for _i in prefix.len()..max_len {
+		out.push(fill as char);
+	}
+
+	Ok(out)
+}
+
 pub trait SortableKeyWrapper: FromStr + KeyToString
 {
 	type Inner: SortableKey;
@@ -127,6 +530,179 @@ pub trait SortableKeyWrapper: FromStr + KeyToString
 		})
 	}
 
+	fn encrypt_number_ore_raw(&self, data: u64) -> Result<Vec<u8>, SdkUtilError>
+	{
+		Ok(self.get_key().encrypt_sortable_ore(data)?)
+	}
+
+	fn encrypt_number_ore(&self, data: u64) -> Result<SortableOreEncryptOutput, SdkUtilError>
+	{
+		let key = self.get_key();
+
+		let ciphertext = key.encrypt_sortable_ore(data)?;
+
+		Ok(SortableOreEncryptOutput {
+			ciphertext: Base64::encode_string(&ciphertext),
+			alg: key.get_alg_str().to_string(),
+			key_id: self.get_id().to_string(),
+		})
+	}
+
+	fn encrypt_timestamp(&self, timestamp: u64) -> Result<SortableEncryptOutput, SdkUtilError>
+	{
+		self.encrypt_number(timestamp_to_sortable(timestamp))
+	}
+
+	/**
+	Encrypts a bound of a range with the order-preserving or the order-revealing encryption of the key.
+	 */
+	fn encrypt_range_bound(&self, data: u64) -> Result<SortableRangeBound, SdkUtilError>
+	{
+		let key = self.get_key();
+
+		if key.is_order_revealing() {
+			Ok(SortableRangeBound::Ore(Base64::encode_string(
+				&key.encrypt_sortable_ore(data)?,
+			)))
+		} else {
+			Ok(SortableRangeBound::Ope(key.encrypt_sortable(data)?))
+		}
+	}
+
+	/**
+	Encrypts the bounds of a plaintext range with this key.
+
+	Use the output to query values that were encrypted with the same key.
+	String and prefix ranges are only supported by order-preserving keys.
+	 */
+	fn encrypt_range(&self, range: &SortableRange) -> Result<SortableRangeOutput, SdkUtilError>
+	{
+		let key = self.get_key();
+		let max = key.max_sortable();
+
+		let (lower, upper, exact, clamped) = match range {
+			SortableRange::Number {
+				lower,
+				upper,
+			} => {
+				let (lower, upper, clamped) = inclusive_range(*lower, *upper, max)?;
+
+				(
+					self.encrypt_range_bound(lower)?,
+					self.encrypt_range_bound(upper)?,
+					true,
+					clamped,
+				)
+			},
+			SortableRange::Timestamp {
+				lower,
+				upper,
+			} => {
+				//exclusive bounds must be moved before transforming them to days
+				let lower = match lower {
+					Bound::Included(n) => Bound::Included(timestamp_to_sortable(*n)),
+					Bound::Excluded(n) => {
+						Bound::Included(timestamp_to_sortable(
+							n.checked_add(1).ok_or(SdkUtilError::SortableRangeEmpty)?,
+						))
+					},
+					Bound::Unbounded => Bound::Unbounded,
+				};
+
+				let upper = match upper {
+					Bound::Included(n) => Bound::Included(timestamp_to_sortable(*n)),
+					Bound::Excluded(n) => {
+						Bound::Included(timestamp_to_sortable(
+							n.checked_sub(1).ok_or(SdkUtilError::SortableRangeEmpty)?,
+						))
+					},
+					Bound::Unbounded => Bound::Unbounded,
+				};
+
+				let (lower, upper, clamped) = inclusive_range(lower, upper, max)?;
+
+				(
+					self.encrypt_range_bound(lower)?,
+					self.encrypt_range_bound(upper)?,
+					false,
+					clamped,
+				)
+			},
+			SortableRange::String {
+				lower,
+				upper,
+				max_len,
+			} => {
+				if key.is_order_revealing() {
+					return Err(SdkUtilError::SortableRangeNotSupported);
+				}
+
+				/*
+				The string transformation is not strict, strings next to the bound can have the same number.
+				An exclusive bound can't be encrypted without excluding these strings too.
+				 */
+				let lower = match lower {
+					Bound::Included(s) => {
+						check_range_string(s)?;
+						self.encrypt_raw_string(s, *max_len)?
+					},
+					Bound::Excluded(_) => return Err(SdkUtilError::SortableRangeExclusiveString),
+					Bound::Unbounded => key.encrypt_sortable(0)?,
+				};
+
+				let upper = match upper {
+					Bound::Included(s) => {
+						check_range_string(s)?;
+						self.encrypt_raw_string(s, *max_len)?
+					},
+					Bound::Excluded(_) => return Err(SdkUtilError::SortableRangeExclusiveString),
+					Bound::Unbounded => key.encrypt_sortable(max)?,
+				};
+
+				if lower > upper {
+					return Err(SdkUtilError::SortableRangeEmpty);
+				}
+
+				(
+					SortableRangeBound::Ope(lower),
+					SortableRangeBound::Ope(upper),
+					false,
+					false,
+				)
+			},
+			SortableRange::Prefix {
+				prefix,
+				max_len,
+			} => {
+				if key.is_order_revealing() {
+					return Err(SdkUtilError::SortableRangeNotSupported);
+				}
+
+				let max_len = max_len.unwrap_or(4);
+
+				//fill the prefix with the lowest and the highest ascii char to get all ascii strings with this prefix
+				let lower = self.encrypt_raw_string(&prefix_bound(prefix, max_len, 0)?, Some(max_len))?;
+				let upper = self.encrypt_raw_string(&prefix_bound(prefix, max_len, 0x7f)?, Some(max_len))?;
+
+				(
+					SortableRangeBound::Ope(lower),
+					SortableRangeBound::Ope(upper),
+					false,
+					false,
+				)
+			},
+		};
+
+		Ok(SortableRangeOutput {
+			lower,
+			upper,
+			exact,
+			clamped,
+			alg: key.get_alg_str().to_string(),
+			key_id: self.get_id().to_string(),
+		})
+	}
+
 	fn encrypt_raw_string(&self, data: &str, max_len: Option<usize>) -> Result<u64, SdkUtilError>;
 
 	fn encrypt_string(&self, data: &str, max_len: Option<usize>) -> Result<SortableEncryptOutput, SdkUtilError>;
@@ -33,6 +33,10 @@ pub enum SdkUtilError
 
 	DecryptingLoginChallengeFailed,
 
+	SealedDataWrong,
+	SealedVersionNotSupported,
+	SealedLockWrong,
+
 	#[cfg(any(feature = "rustls", feature = "wasm"))]
 	RequestErr(String),
 	#[cfg(any(feature = "rustls", feature = "wasm"))]
@@ -50,6 +54,22 @@ pub enum SdkUtilError
 	SearchableEncryptionDataNotFound,
 	#[cfg(feature = "encryption")]
 	SearchableEncryptionDataTooLong,
+	#[cfg(feature = "encryption")]
+	SearchableBucketSizeInvalid,
+	#[cfg(feature = "encryption")]
+	SearchableTruncateLenInvalid(usize),
+	#[cfg(feature = "encryption")]
+	SortableRangeEmpty,
+	#[cfg(feature = "encryption")]
+	SortableRangeOutOfDomain(u64),
+	#[cfg(feature = "encryption")]
+	SortableRangeNotAscii,
+	#[cfg(feature = "encryption")]
+	SortableRangePrefixTooLong(usize),
+	#[cfg(feature = "encryption")]
+	SortableRangeExclusiveString,
+	#[cfg(feature = "encryption")]
+	SortableRangeNotSupported,
 }
 
 /**
@@ -117,6 +137,14 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 				Error::PwSplitFailedLeft => out_error("client_21", "Can't hash the password. The input is too short"),
 				Error::PwSplitFailedRight => out_error("client_21", "Can't hash the password. The input is too short"),
 				Error::HashAuthKeyFailed => out_error("client_22", "Can't hash the password"),
+				Error::PasskeySecretWrongLength => out_error("client_23", "The secret of the passkey must be 32 bytes long"),
+				Error::PakeFailed => {
+					out_error(
+						"client_24",
+						"The password authenticated key exchange failed. Maybe a wrong password was used.",
+					)
+				},
+				Error::PakeMessageWrongFormat => out_error("client_25", "The message of the key exchange has a wrong format"),
 
 				Error::KeyDecryptFailed => {
 					out_error(
@@ -134,6 +162,9 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 				Error::OpeRangeError => out_error("client_60", "Invalid input range"),
 				Error::OpeStringToLarge => out_error("client_61", "String is too large to process"),
 				Error::OpeHdgInvalidInputs => out_error("client_62", "Invalid inputs"),
+
+				Error::OreCiphertextInvalid => out_error("client_63", "The order-revealing ciphertext has a wrong format"),
+				Error::KeyOperationNotSupported => out_error("client_64", "The key type doesn't support this operation."),
 			}
 		},
 		SdkUtilError::AlgNotFound => out_error("client_1", "The algorithms for this action was not found."),
@@ -179,6 +210,15 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 
 		SdkUtilError::DecryptingLoginChallengeFailed => out_error("client_1102", "Can't verify login."),
 
+		SdkUtilError::SealedDataWrong => out_error("client_140", "The sealed data is damaged or not valid."),
+		SdkUtilError::SealedVersionNotSupported => out_error("client_141", "The version of the sealed data is not supported."),
+		SdkUtilError::SealedLockWrong => {
+			out_error(
+				"client_142",
+				"The sealed data is locked with another method. Use the pin or the key which was used to seal the data.",
+			)
+		},
+
 		#[cfg(any(feature = "rustls", feature = "wasm"))]
 		SdkUtilError::RequestErr(e) => out_error("client_1000", format!("Can't send the request: {}", e).as_str()),
 		#[cfg(any(feature = "rustls", feature = "wasm"))]
@@ -206,6 +246,63 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 		},
 		#[cfg(feature = "encryption")]
 		SdkUtilError::SearchableEncryptionDataNotFound => out_error("client_301", "No data found to hash. Empty Strings are not allowed."),
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SearchableBucketSizeInvalid => {
+			out_error(
+				"client_302",
+				"The bucket size for the padding must be greater than 0.",
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SearchableTruncateLenInvalid(len) => {
+			out_error(
+				"client_303",
+				&format!(
+					"The hashes can't be truncated to {} bytes. The length must be at least 16 bytes and not longer than the hash.",
+					len
+				),
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SortableRangeEmpty => {
+			out_error(
+				"client_310",
+				"The range is empty. The lower bound is greater than the upper bound.",
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SortableRangeOutOfDomain(max) => {
+			out_error(
+				"client_311",
+				&format!(
+					"The range is outside of the domain of the sortable key. The largest value is {}.",
+					max
+				),
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SortableRangeNotAscii => out_error("client_312", "Only ascii strings are supported for a string range."),
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SortableRangePrefixTooLong(max_len) => {
+			out_error(
+				"client_313",
+				&format!("The prefix is longer than the max length of {} chars.", max_len),
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SortableRangeExclusiveString => {
+			out_error(
+				"client_314",
+				"Exclusive bounds are not supported for strings because different strings can have the same number. Use an inclusive bound and filter the result after decryption.",
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SortableRangeNotSupported => {
+			out_error(
+				"client_315",
+				"String and prefix ranges are not supported for order-revealing keys.",
+			)
+		},
 	}
 }
 
@@ -7,7 +7,7 @@ use sentc_crypto_common::server_default::ServerSuccessOutput;
 
 use crate::error::SdkUtilError;
 use crate::handle_server_response;
-use crate::http::{auth_header, HttpMethod};
+use crate::http::{auth_header, Bytes, HttpMethod};
 
 pub(super) async fn make_req(
 	method: HttpMethod,
@@ -56,7 +56,7 @@ pub(super) async fn make_req_buffer_body(
 	method: HttpMethod,
 	url: &str,
 	auth_token: &str,
-	body: Vec<u8>,
+	body: impl Into<Bytes>,
 	jwt: Option<&str>,
 	group_as_member: Option<&str>,
 ) -> Result<String, SdkUtilError>
@@ -83,6 +83,7 @@ pub(super) async fn make_req_buffer_body(
 		None => builder,
 	};
 
+	let body: Bytes = body.into();
 	let builder = builder.body(body);
 
 	let res = builder
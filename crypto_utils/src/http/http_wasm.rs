@@ -9,7 +9,7 @@ use web_sys::{Request, RequestInit, RequestMode, Response};
 
 use crate::error::SdkUtilError;
 use crate::handle_server_response;
-use crate::http::{auth_header, HttpMethod};
+use crate::http::{auth_header, Bytes, HttpMethod};
 
 pub(super) async fn make_req(
 	method: HttpMethod,
@@ -79,7 +79,7 @@ pub(super) async fn make_req_buffer_body(
 	method: HttpMethod,
 	url: &str,
 	auth_token: &str,
-	body: Vec<u8>,
+	body: impl Into<Bytes>,
 	jwt: Option<&str>,
 	group_as_member: Option<&str>,
 ) -> Result<String, SdkUtilError>
@@ -96,6 +96,7 @@ pub(super) async fn make_req_buffer_body(
 	opts.method(method);
 	opts.mode(RequestMode::Cors);
 
+	let body: Bytes = body.into();
 	let body = Uint8Array::from(body.as_ref());
 	let body = JsValue::from(body);
 
@@ -7,6 +7,8 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::future::Future;
 
+pub use bytes::Bytes;
+
 use crate::error::SdkUtilError;
 
 pub fn auth_header(jwt: &str) -> String
@@ -55,11 +57,16 @@ pub fn make_req_buffer<'a>(
 	return http_reqwest::make_req_buffer(method, url, auth_token, body, jwt, group_as_member);
 }
 
+/**
+Sends the body as bytes.
+
+The body can be a Vec or Bytes. Cloning Bytes only increases the ref count, so a request can be retried without copying the body.
+ */
 pub fn make_req_buffer_body<'a>(
 	method: HttpMethod,
 	url: &'a str,
 	auth_token: &'a str,
-	body: Vec<u8>,
+	body: impl Into<Bytes> + 'a,
 	jwt: Option<&'a str>,
 	group_as_member: Option<&'a str>,
 ) -> impl Future<Output = Result<String, SdkUtilError>> + 'a
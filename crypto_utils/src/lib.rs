@@ -23,6 +23,7 @@ pub mod group;
 #[cfg(any(feature = "rustls", feature = "wasm"))]
 pub mod http;
 pub mod jwt;
+pub mod session;
 pub mod user;
 
 pub fn handle_server_response<'de, T: Deserialize<'de>>(res: &'de str) -> Result<T, SdkUtilError>
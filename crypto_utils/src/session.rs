@@ -0,0 +1,233 @@
+//! Sealed data for the storage on the device, e.g. the data of the logged-in user.
+//!
+//! The data is encrypted by a key derived from a local pin or by a key from the keystore of the platform.
+//! The version and the lock of the sealed data are authenticated as additional data.
+//!
+//! The key of the pin is derived with the expensive passphrase hash of the implementation (e.g. argon2 with 64 mb and 4 passes).
+//! This only slows down the guessing: a pin with 4 to 6 digits can still be found offline by someone who got the sealed data.
+//! Use the key lock with a key from the keystore of the platform (e.g. keychain, android keystore or a non-extractable web crypto key)
+//! whenever it is available, and the pin lock only with a long pin or passphrase.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_core::cryptomat::{CryptoAlg, PwHash, PwPrepareExport, SymKey};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use crate::error::SdkUtilError;
+
+pub const SEALED_VERSION: u32 = 1;
+
+/**
+How the sealed data can be unlocked
+ */
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "t")]
+pub enum SealedLock
+{
+	/**
+	alg is the alg of the sym key, derived_alg the passphrase hash with its parameters
+	 */
+	Pin
+	{
+		salt: String, alg: String, derived_alg: String
+	},
+	Key
+	{
+		alg: String
+	},
+	Passphrase
+	{
+		salt: String, alg: String, derived_alg: String
+	},
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Sealed
+{
+	pub version: u32,
+	pub lock: SealedLock,
+	pub data: String,
+}
+
+fn sealed_aad(version: u32, lock: &SealedLock) -> Result<Vec<u8>, SdkUtilError>
+{
+	serde_json::to_vec(&(version, lock)).map_err(|_| SdkUtilError::JsonToStringFailed)
+}
+
+fn seal(key: &impl SymKey, lock: SealedLock, data: &[u8]) -> Result<String, SdkUtilError>
+{
+	let aad = sealed_aad(SEALED_VERSION, &lock)?;
+
+	let encrypted = key.encrypt_with_aad(data, &aad)?;
+
+	serde_json::to_string(&Sealed {
+		version: SEALED_VERSION,
+		lock,
+		data: Base64::encode_string(&encrypted),
+	})
+	.map_err(|_| SdkUtilError::JsonToStringFailed)
+}
+
+fn open(sealed: &str) -> Result<(Sealed, Vec<u8>), SdkUtilError>
+{
+	let sealed: Sealed = serde_json::from_str(sealed).map_err(|_| SdkUtilError::SealedDataWrong)?;
+
+	if sealed.version != SEALED_VERSION {
+		return Err(SdkUtilError::SealedVersionNotSupported);
+	}
+
+	let aad = sealed_aad(sealed.version, &sealed.lock)?;
+
+	Ok((sealed, aad))
+}
+
+fn seal_with_passphrase_hash<H: PwHash>(
+	passphrase: &str,
+	data: &[u8],
+	lock: impl FnOnce(String, String, String) -> SealedLock,
+) -> Result<String, SdkUtilError>
+{
+	let (salt, key, derived_alg) = H::passphrase_to_encrypt(passphrase.as_bytes())?;
+
+	let lock = lock(
+		Base64::encode_string(salt.prepare_export()),
+		key.get_alg_str().to_string(),
+		derived_alg.to_string(),
+	);
+
+	seal(&key, lock, data)
+}
+
+fn unseal_with_passphrase_hash<H: PwHash>(
+	passphrase: &str,
+	salt: &str,
+	alg: &str,
+	derived_alg: &str,
+	sealed: &Sealed,
+	aad: &[u8],
+) -> Result<Vec<u8>, SdkUtilError>
+{
+	let salt = Base64::decode_vec(salt).map_err(|_| SdkUtilError::DecodeSaltFailed)?;
+
+	//the hash params are taken from the derived alg, so changed default params don't lock out the older data
+	let key = H::passphrase_to_decrypt(passphrase.as_bytes(), &salt, derived_alg)?;
+
+	if key.get_alg_str() != alg {
+		return Err(SdkUtilError::AlgNotFound);
+	}
+
+	let encrypted = Base64::decode_vec(&sealed.data).map_err(|_| SdkUtilError::SealedDataWrong)?;
+
+	Ok(key.decrypt_with_aad(&encrypted, aad)?)
+}
+
+/**
+Encrypts the data with a key derived from the pin with the passphrase hash.
+
+A short pin is not safe against offline guessing, see the module docs.
+ */
+pub fn seal_with_pin<H: PwHash>(pin: &str, data: &[u8]) -> Result<String, SdkUtilError>
+{
+	seal_with_passphrase_hash::<H>(pin, data, |salt, alg, derived_alg| {
+		SealedLock::Pin {
+			salt,
+			alg,
+			derived_alg,
+		}
+	})
+}
+
+/**
+Encrypts the data with a key derived from a passphrase, e.g. for an archive which is kept offline.
+
+The lock is different to the pin lock, so a sealed archive can't be opened as sealed user data and the other way around.
+ */
+pub fn seal_with_passphrase<H: PwHash>(passphrase: &str, data: &[u8]) -> Result<String, SdkUtilError>
+{
+	seal_with_passphrase_hash::<H>(passphrase, data, |salt, alg, derived_alg| {
+		SealedLock::Passphrase {
+			salt,
+			alg,
+			derived_alg,
+		}
+	})
+}
+
+/**
+Encrypts the data with a key from the keystore of the platform
+ */
+pub fn seal_with_key(key: &impl SymKey, data: &[u8]) -> Result<String, SdkUtilError>
+{
+	let lock = SealedLock::Key {
+		alg: key.get_alg_str().to_string(),
+	};
+
+	seal(key, lock, data)
+}
+
+pub fn unseal_with_pin<H: PwHash>(pin: &str, sealed: &str) -> Result<Vec<u8>, SdkUtilError>
+{
+	let (sealed, aad) = open(sealed)?;
+
+	match &sealed.lock {
+		SealedLock::Pin {
+			salt,
+			alg,
+			derived_alg,
+		} => unseal_with_passphrase_hash::<H>(pin, salt, alg, derived_alg, &sealed, &aad),
+		_ => Err(SdkUtilError::SealedLockWrong),
+	}
+}
+
+pub fn unseal_with_passphrase<H: PwHash>(passphrase: &str, sealed: &str) -> Result<Vec<u8>, SdkUtilError>
+{
+	let (sealed, aad) = open(sealed)?;
+
+	match &sealed.lock {
+		SealedLock::Passphrase {
+			salt,
+			alg,
+			derived_alg,
+		} => unseal_with_passphrase_hash::<H>(passphrase, salt, alg, derived_alg, &sealed, &aad),
+		_ => Err(SdkUtilError::SealedLockWrong),
+	}
+}
+
+pub fn unseal_with_key(key: &impl SymKey, sealed: &str) -> Result<Vec<u8>, SdkUtilError>
+{
+	let (sealed, aad) = open(sealed)?;
+
+	match &sealed.lock {
+		SealedLock::Key {
+			alg,
+		} => {
+			if key.get_alg_str() != alg {
+				return Err(SdkUtilError::AlgNotFound);
+			}
+		},
+		_ => return Err(SdkUtilError::SealedLockWrong),
+	}
+
+	let encrypted = Base64::decode_vec(&sealed.data).map_err(|_| SdkUtilError::SealedDataWrong)?;
+
+	Ok(key.decrypt_with_aad(&encrypted, &aad)?)
+}
+
+/**
+Overwrites the string with zeros, so the value is not left in the memory after the drop
+ */
+pub fn wipe_string(value: &mut String)
+{
+	value.zeroize();
+}
+
+/**
+Overwrites the bytes with zeros, e.g. the decrypted data after it was parsed
+ */
+pub fn wipe_bytes(value: &mut Vec<u8>)
+{
+	value.zeroize();
+}
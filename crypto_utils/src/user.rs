@@ -8,6 +8,7 @@ use sentc_crypto_common::user::{
 	DoneLoginServerOutput,
 	DoneLoginServerReturn,
 	JwtRefreshInput,
+	MasterKey,
 	OtpInput,
 	PrepareLoginSaltServerOutput,
 	PrepareLoginServerInput,
@@ -17,11 +18,23 @@ use sentc_crypto_common::user::{
 	VerifyLoginInput,
 };
 use sentc_crypto_common::{DeviceId, UserId};
-use sentc_crypto_core::cryptomat::{DeriveMasterKeyForAuth, PwHash, Sk};
+use sentc_crypto_core::cryptomat::{DeriveMasterKeyForAuth, PakeClient, PwHash, Sk};
+use sentc_crypto_core::Error;
 use serde::{Deserialize, Serialize};
 
-use crate::cryptomat::{PkWrapper, SignComposerWrapper, SignKWrapper, SkWrapper, StaticKeyComposerWrapper, VerifyKWrapper};
+use crate::cryptomat::{
+	PkWrapper,
+	SignComposerWrapper,
+	SignKWrapper,
+	SignKeyPairWrapper,
+	SkWrapper,
+	StaticKeyComposerWrapper,
+	StaticKeyPairWrapper,
+	SymKeyGenWrapper,
+	VerifyKWrapper,
+};
 use crate::error::SdkUtilError;
+use crate::session::wipe_string;
 use crate::{client_random_value_to_string, derive_auth_key_for_auth_to_string, handle_server_response, hashed_authentication_key_to_string};
 
 /**
@@ -76,6 +89,133 @@ impl<Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper> TryFro
 	}
 }
 
+impl DeviceKeyDataExport
+{
+	/**
+	Overwrites the exported secret keys
+	 */
+	pub fn wipe(&mut self)
+	{
+		wipe_string(&mut self.private_key);
+		wipe_string(&mut self.sign_key);
+	}
+}
+
+/**
+Imports the exported keys again, e.g. after unlocking the sealed user data
+ */
+impl<Sk, Pk, SiK, Vk> TryFrom<DeviceKeyDataExport> for DeviceKeyDataInt<Sk, Pk, SiK, Vk>
+where
+	Sk: SkWrapper,
+	Pk: PkWrapper,
+	SiK: SignKWrapper,
+	Vk: VerifyKWrapper,
+{
+	type Error = SdkUtilError;
+
+	fn try_from(value: DeviceKeyDataExport) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			private_key: value
+				.private_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?,
+			sign_key: value
+				.sign_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingSignKeyFailed)?,
+			public_key: value
+				.public_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportPublicKeyFailed)?,
+			verify_key: value
+				.verify_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportVerifyKeyFailed)?,
+			exported_public_key: UserPublicKeyData::from_string(&value.exported_public_key)?,
+			exported_verify_key: UserVerifyKeyData::from_string(&value.exported_verify_key)?,
+		})
+	}
+}
+
+/**
+The first request of the opaque registration. All messages are base64 encoded.
+
+The pake inputs and outputs are the server contract of the opaque endpoints.
+They are here until they are released in sentc-common, which is pinned to a git rev.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct PakeRegisterStartInput
+{
+	pub device_identifier: String,
+	pub registration_request: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PakeRegisterStartServerOutput
+{
+	pub registration_response: String,
+}
+
+/**
+Like the device register input, but with the registration upload instead of the client random value and the hashed auth key
+ */
+#[derive(Serialize, Deserialize)]
+pub struct PakeRegisterInput
+{
+	pub device_identifier: String,
+	pub registration_upload: String,
+	pub master_key: MasterKey,
+	pub derived_alg: String,
+	pub public_key: String,
+	pub verify_key: String,
+	pub encrypted_private_key: String,
+	pub encrypted_sign_key: String,
+	pub keypair_encrypt_alg: String,
+	pub keypair_sign_alg: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PakeLoginStartInput
+{
+	pub device_identifier: String,
+	pub credential_request: String,
+}
+
+/**
+The server stores the state of the key exchange under the login state id until the client finish the login.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct PakeLoginStartServerOutput
+{
+	pub login_state_id: String,
+	pub credential_response: String,
+}
+
+/**
+The server responds with the DoneLoginServerOutput like for the done login
+ */
+#[derive(Serialize, Deserialize)]
+pub struct PakeLoginFinishInput
+{
+	pub login_state_id: String,
+	pub device_identifier: String,
+	pub credential_finalization: String,
+}
+
+/**
+Like the VerifyLoginInput but with the login state id of the finished key exchange instead of the auth key.
+
+This input goes to the pake verify login endpoint, not to the verify login endpoint.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct PakeVerifyLoginInput
+{
+	pub login_state_id: String,
+	pub device_identifier: String,
+	pub challenge: String,
+}
+
 pub struct UserPreVerifyLogin<Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>
 {
 	pub challenge: String,
@@ -134,6 +274,112 @@ pub fn prepare_login<H: PwHash>(user_identifier: &str, password: &str, server_ou
 	Ok((input, auth_key, result.master_key_encryption_key))
 }
 
+/**
+# Starts the registration with opaque
+
+Returns the state for prepare_register_pake_finish and the input for the server.
+ */
+pub fn prepare_register_pake_start<P: PakeClient>(device_identifier: &str, password: &str) -> Result<(String, String), SdkUtilError>
+{
+	let (state, registration_request) = sentc_crypto_core::user::register_pake_start::<P>(password)?;
+
+	let input = serde_json::to_string(&PakeRegisterStartInput {
+		device_identifier: device_identifier.to_string(),
+		registration_request: Base64::encode_string(&registration_request),
+	})
+	.map_err(|_| SdkUtilError::JsonToStringFailed)?;
+
+	Ok((Base64::encode_string(&state), input))
+}
+
+/**
+# Finish the registration with opaque
+
+Creates the device keys and encrypts the master key with the export key of the protocol.
+ */
+pub fn prepare_register_pake_finish<SGen: SymKeyGenWrapper, StGen: StaticKeyPairWrapper, SignGen: SignKeyPairWrapper, P: PakeClient>(
+	device_identifier: &str,
+	password: &str,
+	state: &str,
+	server_output: &str,
+) -> Result<String, SdkUtilError>
+{
+	let server_output: PakeRegisterStartServerOutput = handle_server_response(server_output)?;
+
+	let state = Base64::decode_vec(state).map_err(|_| Error::PakeMessageWrongFormat)?;
+	let registration_response = Base64::decode_vec(&server_output.registration_response).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+	let out =
+		sentc_crypto_core::user::register_pake_finish::<SGen::KeyGen, StGen::KeyGen, SignGen::KeyGen, P>(&state, password, &registration_response)?;
+
+	serde_json::to_string(&PakeRegisterInput {
+		device_identifier: device_identifier.to_string(),
+		registration_upload: Base64::encode_string(&out.registration_upload),
+		master_key: MasterKey {
+			encrypted_master_key: Base64::encode_string(&out.encrypted_master_key),
+			master_key_alg: out.master_key_alg.to_string(),
+			encrypted_master_key_alg: out.encrypted_master_key_alg.to_string(),
+		},
+		derived_alg: out.derived_alg.to_string(),
+		public_key: StGen::pk_inner_to_pem(&out.public_key)?,
+		verify_key: SignGen::vk_inner_to_pem(&out.verify_key)?,
+		encrypted_private_key: Base64::encode_string(&out.encrypted_private_key),
+		encrypted_sign_key: Base64::encode_string(&out.encrypted_sign_key),
+		keypair_encrypt_alg: out.keypair_encrypt_alg.to_string(),
+		keypair_sign_alg: out.keypair_sign_alg.to_string(),
+	})
+	.map_err(|_| SdkUtilError::JsonToStringFailed)
+}
+
+/**
+# Starts the login with opaque
+
+Returns the state for prepare_login_pake_finish and the input for the server. No salt is needed.
+ */
+pub fn prepare_login_pake_start<P: PakeClient>(device_identifier: &str, password: &str) -> Result<(String, String), SdkUtilError>
+{
+	let (state, credential_request) = sentc_crypto_core::user::prepare_login_pake_start::<P>(password)?;
+
+	let input = serde_json::to_string(&PakeLoginStartInput {
+		device_identifier: device_identifier.to_string(),
+		credential_request: Base64::encode_string(&credential_request),
+	})
+	.map_err(|_| SdkUtilError::JsonToStringFailed)?;
+
+	Ok((Base64::encode_string(&state), input))
+}
+
+/**
+# Finish the key exchange of the opaque login
+
+Returns the input for the server, the login state id and the master key encryption key.
+The server authenticates the client with the credential finalization, the session key of the exchange stays on the client.
+Use the login state id and the master key encryption key for done_login_pake.
+ */
+pub fn prepare_login_pake_finish<P: PakeClient>(
+	device_identifier: &str,
+	password: &str,
+	state: &str,
+	server_output: &str,
+) -> Result<(String, String, P::DMK), SdkUtilError>
+{
+	let server_output: PakeLoginStartServerOutput = handle_server_response(server_output)?;
+
+	let state = Base64::decode_vec(state).map_err(|_| Error::PakeMessageWrongFormat)?;
+	let credential_response = Base64::decode_vec(&server_output.credential_response).map_err(|_| Error::PakeMessageWrongFormat)?;
+
+	let out = sentc_crypto_core::user::prepare_login_pake_finish::<P>(&state, password, &credential_response)?;
+
+	let input = serde_json::to_string(&PakeLoginFinishInput {
+		login_state_id: server_output.login_state_id.clone(),
+		device_identifier: device_identifier.to_string(),
+		credential_finalization: Base64::encode_string(&out.credential_finalization),
+	})
+	.map_err(|_| SdkUtilError::JsonToStringFailed)?;
+
+	Ok((input, server_output.login_state_id, out.master_key_encryption_key))
+}
+
 pub fn check_done_login(server_output: &str) -> Result<DoneLoginServerReturn, SdkUtilError>
 {
 	let server_output: DoneLoginServerReturn = handle_server_response(server_output)?;
@@ -198,6 +444,93 @@ pub fn done_login<SkC: StaticKeyComposerWrapper, SiKC: SignComposerWrapper>(
 	>,
 	SdkUtilError,
 >
+{
+	done_login_internally::<SkC, SiKC>(master_key_encryption, server_output, |challenge| {
+		serde_json::to_string(&VerifyLoginInput {
+			auth_key,
+			device_identifier,
+			challenge,
+		})
+	})
+}
+
+/**
+# finalize the login process and check the password hash
+
+Like done_login but also returns true if the password hash of the user (the alg of the prepare login output)
+is weaker than the policy of the hasher. Then the app should upgrade the hash with prepare_password_upgrade.
+ */
+#[allow(clippy::type_complexity)]
+pub fn done_login_with_upgrade_check<SkC: StaticKeyComposerWrapper, SiKC: SignComposerWrapper, H: PwHash>(
+	master_key_encryption: &impl DeriveMasterKeyForAuth,
+	auth_key: String,
+	device_identifier: String,
+	server_output_prep_login: &str,
+	server_output: DoneLoginServerOutput,
+) -> Result<
+	(
+		UserPreVerifyLogin<
+			<SkC as StaticKeyComposerWrapper>::SkWrapper,
+			<SkC as StaticKeyComposerWrapper>::PkWrapper,
+			<SiKC as SignComposerWrapper>::SignKWrapper,
+			<SiKC as SignComposerWrapper>::VerifyKWrapper,
+		>,
+		bool,
+	),
+	SdkUtilError,
+>
+{
+	let prep_login: PrepareLoginSaltServerOutput = handle_server_response(server_output_prep_login)?;
+
+	let out = done_login::<SkC, SiKC>(master_key_encryption, auth_key, device_identifier, server_output)?;
+
+	Ok((out, H::needs_upgrade(&prep_login.derived_encryption_key_alg)))
+}
+
+/**
+# finalize the opaque login
+
+Like done_login but without an auth key, the server already authenticated the client in the key exchange.
+The challenge is a PakeVerifyLoginInput with the login state id from prepare_login_pake_finish,
+send it to the pake verify login endpoint.
+ */
+pub fn done_login_pake<SkC: StaticKeyComposerWrapper, SiKC: SignComposerWrapper>(
+	master_key_encryption: &impl DeriveMasterKeyForAuth,
+	login_state_id: String,
+	device_identifier: String,
+	server_output: DoneLoginServerOutput,
+) -> Result<
+	UserPreVerifyLogin<
+		<SkC as StaticKeyComposerWrapper>::SkWrapper,
+		<SkC as StaticKeyComposerWrapper>::PkWrapper,
+		<SiKC as SignComposerWrapper>::SignKWrapper,
+		<SiKC as SignComposerWrapper>::VerifyKWrapper,
+	>,
+	SdkUtilError,
+>
+{
+	done_login_internally::<SkC, SiKC>(master_key_encryption, server_output, |challenge| {
+		serde_json::to_string(&PakeVerifyLoginInput {
+			login_state_id,
+			device_identifier,
+			challenge,
+		})
+	})
+}
+
+fn done_login_internally<SkC: StaticKeyComposerWrapper, SiKC: SignComposerWrapper>(
+	master_key_encryption: &impl DeriveMasterKeyForAuth,
+	server_output: DoneLoginServerOutput,
+	verify_input: impl FnOnce(String) -> serde_json::Result<String>,
+) -> Result<
+	UserPreVerifyLogin<
+		<SkC as StaticKeyComposerWrapper>::SkWrapper,
+		<SkC as StaticKeyComposerWrapper>::PkWrapper,
+		<SiKC as SignComposerWrapper>::SignKWrapper,
+		<SiKC as SignComposerWrapper>::VerifyKWrapper,
+	>,
+	SdkUtilError,
+>
 {
 	let device_data = server_output.device_keys;
 
@@ -207,12 +540,7 @@ pub fn done_login<SkC: StaticKeyComposerWrapper, SiKC: SignComposerWrapper>(
 
 	Ok(UserPreVerifyLogin {
 		device_keys,
-		challenge: serde_json::to_string(&VerifyLoginInput {
-			auth_key,
-			device_identifier,
-			challenge,
-		})
-		.map_err(|_e| SdkUtilError::JsonToStringFailed)?,
+		challenge: verify_input(challenge).map_err(|_e| SdkUtilError::JsonToStringFailed)?,
 		user_id: device_data.user_id,
 		device_id: device_data.device_id,
 	})
@@ -342,6 +670,27 @@ pub fn change_password<H: PwHash>(
 		.map_err(|_| SdkUtilError::JsonToStringFailed)
 }
 
+/**
+Checks after the login if the password hash of the user is weaker than the actual policy.
+
+Returns the input for the change password endpoint with the same password but the new parameters,
+or None if the password hash is already up to date.
+ */
+pub fn prepare_password_upgrade<H: PwHash>(
+	password: &str,
+	server_output_prep_login: &str,
+	server_output_done_login: DoneLoginServerOutput,
+) -> Result<Option<String>, SdkUtilError>
+{
+	let prep_login: PrepareLoginSaltServerOutput = handle_server_response(server_output_prep_login)?;
+
+	if !H::needs_upgrade(&prep_login.derived_encryption_key_alg) {
+		return Ok(None);
+	}
+
+	change_password::<H>(password, password, server_output_prep_login, server_output_done_login).map(Some)
+}
+
 pub fn prepare_refresh_jwt(refresh_token: String) -> Result<String, SdkUtilError>
 {
 	JwtRefreshInput {
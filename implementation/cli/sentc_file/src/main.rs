@@ -0,0 +1,250 @@
+//! Encrypt and decrypt files offline with an exported group or user key.
+//!
+//! The encrypted file is a sentc file container, so it can be stored in backups or moved to an air-gapped device.
+//! The keys are read from files to keep them out of the shell history.
+
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+use sentc_crypto::file::{
+	read_frame_head,
+	FileContainerFrame,
+	FILE_CONTAINER_FRAME_HEAD_LEN,
+	FILE_CONTAINER_MAX_PART_SIZE,
+	FILE_CONTAINER_PREFIX_LEN,
+};
+use sentc_crypto::keys::std::{StdFileContainerReader, StdFileContainerWriter};
+use sentc_crypto::std_keys::util::{SignKey, SymmetricKey};
+use sentc_crypto_common::user::UserVerifyKeyData;
+
+const DEFAULT_PART_SIZE: usize = 4 * 1024 * 1024;
+
+const USAGE: &str = "Usage:
+  sentc-file encrypt --key <key file> [--sign-key <sign key file>] [--part-size <bytes>] <input> <output>
+  sentc-file decrypt --key <key file> [--verify-key <verify key file>] <input> <output>
+
+The key file contains the exported symmetric key of the group or user.";
+
+struct Args
+{
+	key: String,
+	sign_key: Option<String>,
+	verify_key: Option<String>,
+	part_size: usize,
+	input: String,
+	output: String,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String>
+{
+	let mut key = None;
+	let mut sign_key = None;
+	let mut verify_key = None;
+	let mut part_size = DEFAULT_PART_SIZE;
+	let mut files = Vec::new();
+
+	while let Some(arg) = args.next() {
+		let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+
+		match arg.as_str() {
+			"--key" => key = Some(value()?),
+			"--sign-key" => sign_key = Some(value()?),
+			"--verify-key" => verify_key = Some(value()?),
+			"--part-size" => {
+				part_size = value()?
+					.parse()
+					.map_err(|_| "The part size must be a number".to_string())?
+			},
+			_ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+			_ => files.push(arg),
+		}
+	}
+
+	let (input, output) = match <[String; 2]>::try_from(files) {
+		Ok([input, output]) => (input, output),
+		Err(_) => return Err("Input and output file are required".to_string()),
+	};
+
+	Ok(Args {
+		key: key.ok_or("--key is required")?,
+		sign_key,
+		verify_key,
+		part_size,
+		input,
+		output,
+	})
+}
+
+fn read_key_file(path: &str) -> Result<String, String>
+{
+	fs::read_to_string(path)
+		.map(|k| k.trim().to_string())
+		.map_err(|e| format!("Can't read the key file {}: {}", path, e))
+}
+
+fn read_part(input: &mut impl Read, part_size: usize) -> Result<Vec<u8>, String>
+{
+	let mut part = Vec::with_capacity(part_size);
+
+	input
+		.take(part_size as u64)
+		.read_to_end(&mut part)
+		.map_err(|e| e.to_string())?;
+
+	Ok(part)
+}
+
+fn read_frame(input: &mut impl Read) -> Result<(FileContainerFrame, Vec<u8>), String>
+{
+	let mut head = [0u8; FILE_CONTAINER_FRAME_HEAD_LEN];
+	input.read_exact(&mut head).map_err(|e| e.to_string())?;
+
+	//the length is checked against the max frame length
+	let (frame, len) = read_frame_head(&head)?;
+
+	let mut data = vec![0u8; len];
+	input.read_exact(&mut data).map_err(|e| e.to_string())?;
+
+	Ok((frame, data))
+}
+
+fn encrypt(args: &Args) -> Result<(), String>
+{
+	let key: SymmetricKey = read_key_file(&args.key)?
+		.parse()
+		.map_err(sentc_crypto::SdkError::from)?;
+
+	let sign_key: Option<SignKey> = match &args.sign_key {
+		Some(path) => {
+			Some(
+				read_key_file(path)?
+					.parse()
+					.map_err(sentc_crypto::SdkError::from)?,
+			)
+		},
+		None => None,
+	};
+
+	if args.part_size == 0 || args.part_size > FILE_CONTAINER_MAX_PART_SIZE {
+		return Err(format!(
+			"The part size must be greater than 0 and not greater than {}",
+			FILE_CONTAINER_MAX_PART_SIZE
+		));
+	}
+
+	let mut input = BufReader::new(File::open(&args.input).map_err(|e| e.to_string())?);
+	let mut output = BufWriter::new(File::create(&args.output).map_err(|e| e.to_string())?);
+
+	let (mut writer, start) = StdFileContainerWriter::new(&key, sign_key.as_ref())?;
+	output.write_all(&start).map_err(|e| e.to_string())?;
+
+	loop {
+		let part = read_part(&mut input, args.part_size)?;
+
+		if part.is_empty() {
+			break;
+		}
+
+		output
+			.write_all(&writer.write_part(&part)?)
+			.map_err(|e| e.to_string())?;
+	}
+
+	output
+		.write_all(&writer.finish()?)
+		.map_err(|e| e.to_string())?;
+
+	output.flush().map_err(|e| e.to_string())
+}
+
+fn decrypt(args: &Args) -> Result<Option<String>, String>
+{
+	let key: SymmetricKey = read_key_file(&args.key)?
+		.parse()
+		.map_err(sentc_crypto::SdkError::from)?;
+
+	let verify_key: Option<UserVerifyKeyData> = match &args.verify_key {
+		Some(path) => Some(serde_json::from_str(&read_key_file(path)?).map_err(|_| "The verify key is not valid".to_string())?),
+		None => None,
+	};
+
+	let mut input = BufReader::new(File::open(&args.input).map_err(|e| e.to_string())?);
+
+	let mut prefix = [0u8; FILE_CONTAINER_PREFIX_LEN];
+	input.read_exact(&mut prefix).map_err(|e| e.to_string())?;
+
+	StdFileContainerReader::check_prefix(&prefix)?;
+
+	let (frame, header) = read_frame(&mut input)?;
+
+	if frame != FileContainerFrame::Header {
+		return Err("The file is not a sentc file container".to_string());
+	}
+
+	let mut reader = StdFileContainerReader::new(&key, &header, verify_key.as_ref())?;
+
+	//write to a temp file first, the output is only valid after the whole container was checked
+	let tmp_output = args.output.clone() + ".part";
+
+	let res = (|| -> Result<(), String> {
+		let mut output = BufWriter::new(File::create(&tmp_output).map_err(|e| e.to_string())?);
+
+		loop {
+			let (frame, data) = read_frame(&mut input)?;
+
+			if let Some(part) = reader.read_frame(frame, &data)? {
+				output.write_all(&part).map_err(|e| e.to_string())?;
+			}
+
+			if frame == FileContainerFrame::End {
+				break;
+			}
+		}
+
+		output.flush().map_err(|e| e.to_string())
+	})();
+
+	let res = res.and_then(|_| reader.finish().map_err(String::from));
+
+	match res {
+		Ok(verify_key_id) => {
+			fs::rename(&tmp_output, &args.output).map_err(|e| e.to_string())?;
+
+			Ok(verify_key_id)
+		},
+		Err(e) => {
+			let _ = fs::remove_file(&tmp_output);
+
+			Err(e)
+		},
+	}
+}
+
+fn main() -> ExitCode
+{
+	let mut args = std::env::args().skip(1);
+
+	let command = args.next();
+
+	let res = match (command.as_deref(), parse_args(args)) {
+		(Some("encrypt"), Ok(args)) => encrypt(&args),
+		(Some("decrypt"), Ok(args)) => {
+			decrypt(&args).map(|verify_key_id| {
+				if let Some(id) = verify_key_id {
+					println!("Signed by the verify key: {}", id);
+				}
+			})
+		},
+		(_, Err(e)) => Err(e + "\n\n" + USAGE),
+		_ => Err(USAGE.to_string()),
+	};
+
+	match res {
+		Ok(_) => ExitCode::SUCCESS,
+		Err(e) => {
+			eprintln!("{}", e);
+			ExitCode::FAILURE
+		},
+	}
+}
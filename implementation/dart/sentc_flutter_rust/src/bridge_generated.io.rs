@@ -91,6 +91,45 @@ pub extern "C" fn wire_register_device(
 	wire_register_device_impl(port_, base_url, auth_token, jwt, server_output, key_count, user_keys)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_prepare_device_pairing(
+	port_: i64,
+	server_output: *mut wire_uint_8_list,
+) {
+	wire_prepare_device_pairing_impl(port_, server_output)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_respond_device_pairing(
+	port_: i64,
+	payload: *mut wire_uint_8_list,
+	sign_key: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+) {
+	wire_respond_device_pairing_impl(port_, payload, sign_key, verify_key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_reveal_device_pairing(
+	port_: i64,
+	payload: *mut wire_uint_8_list,
+	nonce: *mut wire_uint_8_list,
+	response: *mut wire_uint_8_list,
+) {
+	wire_reveal_device_pairing_impl(port_, payload, nonce, response)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_verify_device_pairing(
+	port_: i64,
+	payload: *mut wire_uint_8_list,
+	server_output: *mut wire_uint_8_list,
+	response: *mut wire_uint_8_list,
+	reveal: *mut wire_uint_8_list,
+) {
+	wire_verify_device_pairing_impl(port_, payload, server_output, response, reveal)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_user_device_key_session_upload(
 	port_: i64,
@@ -217,11 +256,124 @@ pub extern "C" fn wire_user_create_safety_number(
 	wire_user_create_safety_number_impl(port_, verify_key_1, user_id_1, verify_key_2, user_id_2)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_user_create_safety_number_digits(
+	port_: i64,
+	verify_key_1: *mut wire_uint_8_list,
+	user_id_1: *mut wire_uint_8_list,
+	verify_key_2: *mut wire_uint_8_list,
+	user_id_2: *mut wire_uint_8_list,
+) {
+	wire_user_create_safety_number_digits_impl(port_, verify_key_1, user_id_1, verify_key_2, user_id_2)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_create_safety_number_words(
+	port_: i64,
+	verify_key_1: *mut wire_uint_8_list,
+	user_id_1: *mut wire_uint_8_list,
+	verify_key_2: *mut wire_uint_8_list,
+	user_id_2: *mut wire_uint_8_list,
+) {
+	wire_user_create_safety_number_words_impl(port_, verify_key_1, user_id_1, verify_key_2, user_id_2)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_create_safety_number_qr(
+	port_: i64,
+	verify_key_1: *mut wire_uint_8_list,
+	user_id_1: *mut wire_uint_8_list,
+	verify_key_2: *mut wire_uint_8_list,
+	user_id_2: *mut wire_uint_8_list,
+) {
+	wire_user_create_safety_number_qr_impl(port_, verify_key_1, user_id_1, verify_key_2, user_id_2)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_verify_safety_number_qr(
+	port_: i64,
+	payload: *mut wire_uint_8_list,
+	verify_key_1: *mut wire_uint_8_list,
+	user_id_1: *mut wire_uint_8_list,
+	verify_key_2: *mut wire_uint_8_list,
+	user_id_2: *mut wire_uint_8_list,
+) {
+	wire_user_verify_safety_number_qr_impl(port_, payload, verify_key_1, user_id_1, verify_key_2, user_id_2)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_create_verified_key_record(
+	port_: i64,
+	user_id: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+) {
+	wire_user_create_verified_key_record_impl(port_, user_id, verify_key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_check_verified_key_record(
+	port_: i64,
+	record: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+) {
+	wire_user_check_verified_key_record_impl(port_, record, verify_key)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_user_verify_user_public_key(port_: i64, verify_key: *mut wire_uint_8_list, public_key: *mut wire_uint_8_list) {
 	wire_user_verify_user_public_key_impl(port_, verify_key, public_key)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_user_pin_verify_key(
+	port_: i64,
+	store: *mut wire_uint_8_list,
+	user_id: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+) {
+	wire_user_pin_verify_key_impl(port_, store, user_id, verify_key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_check_pinned_public_key(
+	port_: i64,
+	store: *mut wire_uint_8_list,
+	id: *mut wire_uint_8_list,
+	public_key: *mut wire_uint_8_list,
+) {
+	wire_user_check_pinned_public_key_impl(port_, store, id, public_key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_create_key_rotation_link(
+	port_: i64,
+	previous_sign_key: *mut wire_uint_8_list,
+	previous_verify_key: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+	public_key: *mut wire_uint_8_list,
+) {
+	wire_user_create_key_rotation_link_impl(port_, previous_sign_key, previous_verify_key, verify_key, public_key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_verify_key_rotation_chain(
+	port_: i64,
+	verify_key: *mut wire_uint_8_list,
+	links: *mut wire_uint_8_list,
+) {
+	wire_user_verify_key_rotation_chain_impl(port_, verify_key, links)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_user_update_pin_by_rotation_chain(
+	port_: i64,
+	store: *mut wire_uint_8_list,
+	user_id: *mut wire_uint_8_list,
+	links: *mut wire_uint_8_list,
+) {
+	wire_user_update_pin_by_rotation_chain_impl(port_, store, user_id, links)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_get_user_devices(
 	port_: i64,
@@ -315,6 +467,17 @@ pub extern "C" fn wire_user_fetch_public_key(
 	wire_user_fetch_public_key_impl(port_, base_url, auth_token, user_id)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_user_fetch_public_key_pinned(
+	port_: i64,
+	base_url: *mut wire_uint_8_list,
+	auth_token: *mut wire_uint_8_list,
+	user_id: *mut wire_uint_8_list,
+	store: *mut wire_uint_8_list,
+) {
+	wire_user_fetch_public_key_pinned_impl(port_, base_url, auth_token, user_id, store)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_user_fetch_verify_key(
 	port_: i64,
@@ -1025,6 +1188,7 @@ pub extern "C" fn wire_group_key_rotation(
 	auth_token: *mut wire_uint_8_list,
 	jwt: *mut wire_uint_8_list,
 	id: *mut wire_uint_8_list,
+	admin_rank: i32,
 	public_key: *mut wire_uint_8_list,
 	pre_group_key: *mut wire_uint_8_list,
 	sign_key: *mut wire_uint_8_list,
@@ -1037,6 +1201,7 @@ pub extern "C" fn wire_group_key_rotation(
 		auth_token,
 		jwt,
 		id,
+		admin_rank,
 		public_key,
 		pre_group_key,
 		sign_key,
@@ -1348,6 +1513,33 @@ pub extern "C" fn wire_search(port_: i64, key: *mut wire_uint_8_list, data: *mut
 	wire_search_impl(port_, key, data)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_create_compound_searchable(port_: i64, key: *mut wire_uint_8_list, fields: *mut wire_uint_8_list) {
+	wire_create_compound_searchable_impl(port_, key, fields)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_search_compound(port_: i64, key: *mut wire_uint_8_list, fields: *mut wire_uint_8_list) {
+	wire_search_compound_impl(port_, key, fields)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_create_searchable_with_options(
+	port_: i64,
+	key: *mut wire_uint_8_list,
+	data: *mut wire_uint_8_list,
+	full: bool,
+	limit: *mut u32,
+	options: *mut wire_uint_8_list,
+) {
+	wire_create_searchable_with_options_impl(port_, key, data, full, limit, options)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_search_with_options(port_: i64, key: *mut wire_uint_8_list, data: *mut wire_uint_8_list, options: *mut wire_uint_8_list) {
+	wire_search_with_options_impl(port_, key, data, options)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_sortable_encrypt_raw_number(port_: i64, key: *mut wire_uint_8_list, data: u64) {
 	wire_sortable_encrypt_raw_number_impl(port_, key, data)
@@ -1368,6 +1560,11 @@ pub extern "C" fn wire_sortable_encrypt_string(port_: i64, key: *mut wire_uint_8
 	wire_sortable_encrypt_string_impl(port_, key, data)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_sortable_prepare_range(port_: i64, keys: *mut wire_uint_8_list, range: *mut wire_uint_8_list) {
+	wire_sortable_prepare_range_impl(port_, keys, range)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_file_download_file_meta(
 	port_: i64,
@@ -1412,13 +1609,30 @@ pub extern "C" fn wire_file_download_and_decrypt_file_part(
 	content_key: *mut wire_uint_8_list,
 	verify_key_data: *mut wire_uint_8_list,
 ) {
-	wire_file_download_and_decrypt_file_part_impl(
+	wire_file_download_and_decrypt_file_part_impl(port_, base_url, url_prefix, auth_token, part_id, content_key, verify_key_data)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_download_and_decrypt_file_part_auto(
+	port_: i64,
+	base_url: *mut wire_uint_8_list,
+	url_prefix: *mut wire_uint_8_list,
+	auth_token: *mut wire_uint_8_list,
+	part_id: *mut wire_uint_8_list,
+	sequence: i32,
+	content_key: *mut wire_uint_8_list,
+	pre_key: *mut wire_uint_8_list,
+	verify_key_data: *mut wire_uint_8_list,
+) {
+	wire_file_download_and_decrypt_file_part_auto_impl(
 		port_,
 		base_url,
 		url_prefix,
 		auth_token,
 		part_id,
+		sequence,
 		content_key,
+		pre_key,
 		verify_key_data,
 	)
 }
@@ -1549,6 +1763,97 @@ pub extern "C" fn wire_file_upload_part(
 	)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_file_download_and_decrypt_file_part_derived(
+	port_: i64,
+	base_url: *mut wire_uint_8_list,
+	url_prefix: *mut wire_uint_8_list,
+	auth_token: *mut wire_uint_8_list,
+	part_id: *mut wire_uint_8_list,
+	sequence: i32,
+	content_key: *mut wire_uint_8_list,
+	verify_key_data: *mut wire_uint_8_list,
+) {
+	wire_file_download_and_decrypt_file_part_derived_impl(
+		port_,
+		base_url,
+		url_prefix,
+		auth_token,
+		part_id,
+		sequence,
+		content_key,
+		verify_key_data,
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_upload_part_derived(
+	port_: i64,
+	base_url: *mut wire_uint_8_list,
+	url_prefix: *mut wire_uint_8_list,
+	auth_token: *mut wire_uint_8_list,
+	jwt: *mut wire_uint_8_list,
+	session_id: *mut wire_uint_8_list,
+	end: bool,
+	sequence: i32,
+	content_key: *mut wire_uint_8_list,
+	sign_key: *mut wire_uint_8_list,
+	part: *mut wire_uint_8_list,
+) {
+	wire_file_upload_part_derived_impl(
+		port_,
+		base_url,
+		url_prefix,
+		auth_token,
+		jwt,
+		session_id,
+		end,
+		sequence,
+		content_key,
+		sign_key,
+		part,
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_prepare_upload_checkpoint(
+	port_: i64,
+	checkpoint_key: *mut wire_uint_8_list,
+	file_id: *mut wire_uint_8_list,
+	session_id: *mut wire_uint_8_list,
+	sequence: i32,
+	next_file_key: *mut wire_uint_8_list,
+) {
+	wire_file_prepare_upload_checkpoint_impl(port_, checkpoint_key, file_id, session_id, sequence, next_file_key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_upload_part_resume(
+	port_: i64,
+	base_url: *mut wire_uint_8_list,
+	url_prefix: *mut wire_uint_8_list,
+	auth_token: *mut wire_uint_8_list,
+	jwt: *mut wire_uint_8_list,
+	end: bool,
+	checkpoint_key: *mut wire_uint_8_list,
+	checkpoint: *mut wire_uint_8_list,
+	sign_key: *mut wire_uint_8_list,
+	part: *mut wire_uint_8_list,
+) {
+	wire_file_upload_part_resume_impl(
+		port_,
+		base_url,
+		url_prefix,
+		auth_token,
+		jwt,
+		end,
+		checkpoint_key,
+		checkpoint,
+		sign_key,
+		part,
+	)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_file_file_name_update(
 	port_: i64,
@@ -1562,6 +1867,90 @@ pub extern "C" fn wire_file_file_name_update(
 	wire_file_file_name_update_impl(port_, base_url, auth_token, jwt, file_id, content_key, file_name)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_file_file_meta_update(
+	port_: i64,
+	base_url: *mut wire_uint_8_list,
+	auth_token: *mut wire_uint_8_list,
+	jwt: *mut wire_uint_8_list,
+	file_id: *mut wire_uint_8_list,
+	content_key: *mut wire_uint_8_list,
+	file_name: *mut wire_uint_8_list,
+	meta: *mut wire_uint_8_list,
+) {
+	wire_file_file_meta_update_impl(port_, base_url, auth_token, jwt, file_id, content_key, file_name, meta)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_encrypt_name_with_meta(
+	port_: i64,
+	content_key: *mut wire_uint_8_list,
+	file_name: *mut wire_uint_8_list,
+	meta: *mut wire_uint_8_list,
+) {
+	wire_file_encrypt_name_with_meta_impl(port_, content_key, file_name, meta)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_decrypt_name_with_meta(
+	port_: i64,
+	content_key: *mut wire_uint_8_list,
+	encrypted_file_name: *mut wire_uint_8_list,
+) {
+	wire_file_decrypt_name_with_meta_impl(port_, content_key, encrypted_file_name)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_prepare_file_move(
+	port_: i64,
+	old_master_key: *mut wire_uint_8_list,
+	new_master_key: *mut wire_uint_8_list,
+	encrypted_key: *mut wire_uint_8_list,
+	encrypted_key_alg: *mut wire_uint_8_list,
+	belongs_to_id: *mut wire_uint_8_list,
+	belongs_to_type: *mut wire_uint_8_list,
+) {
+	wire_file_prepare_file_move_impl(port_, old_master_key, new_master_key, encrypted_key, encrypted_key_alg, belongs_to_id, belongs_to_type)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_prepare_file_share(
+	port_: i64,
+	old_master_key: *mut wire_uint_8_list,
+	new_master_key: *mut wire_uint_8_list,
+	encrypted_key: *mut wire_uint_8_list,
+	encrypted_key_alg: *mut wire_uint_8_list,
+	group_id: *mut wire_uint_8_list,
+) {
+	wire_file_prepare_file_share_impl(port_, old_master_key, new_master_key, encrypted_key, encrypted_key_alg, group_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_prepare_file_share_by_public_key(
+	port_: i64,
+	old_master_key: *mut wire_uint_8_list,
+	public_key: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+	encrypted_key: *mut wire_uint_8_list,
+	encrypted_key_alg: *mut wire_uint_8_list,
+	user_id: *mut wire_uint_8_list,
+) {
+	wire_file_prepare_file_share_by_public_key_impl(port_, old_master_key, public_key, verify_key, encrypted_key, encrypted_key_alg, user_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_file_prepare_file_move_by_public_key(
+	port_: i64,
+	old_master_key: *mut wire_uint_8_list,
+	public_key: *mut wire_uint_8_list,
+	verify_key: *mut wire_uint_8_list,
+	encrypted_key: *mut wire_uint_8_list,
+	encrypted_key_alg: *mut wire_uint_8_list,
+	user_id: *mut wire_uint_8_list,
+) {
+	wire_file_prepare_file_move_by_public_key_impl(port_, old_master_key, public_key, verify_key, encrypted_key, encrypted_key_alg, user_id)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_file_delete_file(
 	port_: i64,
@@ -248,6 +248,107 @@ fn wire_register_device_impl(
 		},
 	)
 }
+fn wire_prepare_device_pairing_impl(
+	port_: MessagePort,
+	server_output: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, DevicePairingStart, _>(
+		WrapInfo {
+			debug_name: "prepare_device_pairing",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_server_output = server_output.wire2api();
+			move |task_callback| {
+				prepare_device_pairing(
+					api_server_output,
+				)
+			}
+		},
+	)
+}
+fn wire_respond_device_pairing_impl(
+	port_: MessagePort,
+	payload: impl Wire2Api<String> + UnwindSafe,
+	sign_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "respond_device_pairing",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_payload = payload.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			move |task_callback| {
+				respond_device_pairing(
+					api_payload,
+					api_sign_key,
+					api_verify_key,
+				)
+			}
+		},
+	)
+}
+fn wire_reveal_device_pairing_impl(
+	port_: MessagePort,
+	payload: impl Wire2Api<String> + UnwindSafe,
+	nonce: impl Wire2Api<String> + UnwindSafe,
+	response: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, DevicePairingRevealData, _>(
+		WrapInfo {
+			debug_name: "reveal_device_pairing",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_payload = payload.wire2api();
+			let api_nonce = nonce.wire2api();
+			let api_response = response.wire2api();
+			move |task_callback| {
+				reveal_device_pairing(
+					api_payload,
+					api_nonce,
+					api_response,
+				)
+			}
+		},
+	)
+}
+fn wire_verify_device_pairing_impl(
+	port_: MessagePort,
+	payload: impl Wire2Api<String> + UnwindSafe,
+	server_output: impl Wire2Api<String> + UnwindSafe,
+	response: impl Wire2Api<String> + UnwindSafe,
+	reveal: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "verify_device_pairing",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_payload = payload.wire2api();
+			let api_server_output = server_output.wire2api();
+			let api_response = response.wire2api();
+			let api_reveal = reveal.wire2api();
+			move |task_callback| {
+				verify_device_pairing(
+					api_payload,
+					api_server_output,
+					api_response,
+					api_reveal,
+				)
+			}
+		},
+	)
+}
 fn wire_user_device_key_session_upload_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
@@ -486,313 +587,330 @@ fn wire_user_create_safety_number_impl(
 		},
 	)
 }
-fn wire_user_verify_user_public_key_impl(
+fn wire_user_create_safety_number_digits_impl(
 	port_: MessagePort,
-	verify_key: impl Wire2Api<String> + UnwindSafe,
-	public_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key_1: impl Wire2Api<String> + UnwindSafe,
+	user_id_1: impl Wire2Api<String> + UnwindSafe,
+	verify_key_2: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id_2: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "user_verify_user_public_key",
+			debug_name: "user_create_safety_number_digits",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_verify_key = verify_key.wire2api();
-			let api_public_key = public_key.wire2api();
-			move |task_callback| user_verify_user_public_key(api_verify_key, api_public_key)
+			let api_verify_key_1 = verify_key_1.wire2api();
+			let api_user_id_1 = user_id_1.wire2api();
+			let api_verify_key_2 = verify_key_2.wire2api();
+			let api_user_id_2 = user_id_2.wire2api();
+			move |task_callback| {
+				user_create_safety_number_digits(
+					api_verify_key_1,
+					api_user_id_1,
+					api_verify_key_2,
+					api_user_id_2,
+				)
+			}
 		},
 	)
 }
-fn wire_get_user_devices_impl(
+fn wire_user_create_safety_number_words_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_id: impl Wire2Api<String> + UnwindSafe,
+	verify_key_1: impl Wire2Api<String> + UnwindSafe,
+	user_id_1: impl Wire2Api<String> + UnwindSafe,
+	verify_key_2: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id_2: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<UserDeviceList>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "get_user_devices",
+			debug_name: "user_create_safety_number_words",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_id = last_fetched_id.wire2api();
+			let api_verify_key_1 = verify_key_1.wire2api();
+			let api_user_id_1 = user_id_1.wire2api();
+			let api_verify_key_2 = verify_key_2.wire2api();
+			let api_user_id_2 = user_id_2.wire2api();
 			move |task_callback| {
-				get_user_devices(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_last_fetched_time,
-					api_last_fetched_id,
+				user_create_safety_number_words(
+					api_verify_key_1,
+					api_user_id_1,
+					api_verify_key_2,
+					api_user_id_2,
 				)
 			}
 		},
 	)
 }
-fn wire_reset_password_impl(
+fn wire_user_create_safety_number_qr_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	new_password: impl Wire2Api<String> + UnwindSafe,
-	decrypted_private_key: impl Wire2Api<String> + UnwindSafe,
-	decrypted_sign_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key_1: impl Wire2Api<String> + UnwindSafe,
+	user_id_1: impl Wire2Api<String> + UnwindSafe,
+	verify_key_2: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id_2: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "reset_password",
+			debug_name: "user_create_safety_number_qr",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_new_password = new_password.wire2api();
-			let api_decrypted_private_key = decrypted_private_key.wire2api();
-			let api_decrypted_sign_key = decrypted_sign_key.wire2api();
+			let api_verify_key_1 = verify_key_1.wire2api();
+			let api_user_id_1 = user_id_1.wire2api();
+			let api_verify_key_2 = verify_key_2.wire2api();
+			let api_user_id_2 = user_id_2.wire2api();
 			move |task_callback| {
-				reset_password(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_new_password,
-					api_decrypted_private_key,
-					api_decrypted_sign_key,
+				user_create_safety_number_qr(
+					api_verify_key_1,
+					api_user_id_1,
+					api_verify_key_2,
+					api_user_id_2,
 				)
 			}
 		},
 	)
 }
-fn wire_change_password_impl(
+fn wire_user_verify_safety_number_qr_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	user_identifier: impl Wire2Api<String> + UnwindSafe,
-	old_password: impl Wire2Api<String> + UnwindSafe,
-	new_password: impl Wire2Api<String> + UnwindSafe,
-	mfa_token: impl Wire2Api<Option<String>> + UnwindSafe,
-	mfa_recovery: impl Wire2Api<Option<bool>> + UnwindSafe,
+	payload: impl Wire2Api<String> + UnwindSafe,
+	verify_key_1: impl Wire2Api<String> + UnwindSafe,
+	user_id_1: impl Wire2Api<String> + UnwindSafe,
+	verify_key_2: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id_2: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
 		WrapInfo {
-			debug_name: "change_password",
+			debug_name: "user_verify_safety_number_qr",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_user_identifier = user_identifier.wire2api();
-			let api_old_password = old_password.wire2api();
-			let api_new_password = new_password.wire2api();
-			let api_mfa_token = mfa_token.wire2api();
-			let api_mfa_recovery = mfa_recovery.wire2api();
+			let api_payload = payload.wire2api();
+			let api_verify_key_1 = verify_key_1.wire2api();
+			let api_user_id_1 = user_id_1.wire2api();
+			let api_verify_key_2 = verify_key_2.wire2api();
+			let api_user_id_2 = user_id_2.wire2api();
 			move |task_callback| {
-				change_password(
-					api_base_url,
-					api_auth_token,
-					api_user_identifier,
-					api_old_password,
-					api_new_password,
-					api_mfa_token,
-					api_mfa_recovery,
+				user_verify_safety_number_qr(
+					api_payload,
+					api_verify_key_1,
+					api_user_id_1,
+					api_verify_key_2,
+					api_user_id_2,
 				)
 			}
 		},
 	)
 }
-fn wire_delete_user_impl(
+fn wire_user_create_verified_key_record_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	fresh_jwt: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "delete_user",
+			debug_name: "user_create_verified_key_record",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_fresh_jwt = fresh_jwt.wire2api();
-			move |task_callback| delete_user(api_base_url, api_auth_token, api_fresh_jwt)
+			let api_user_id = user_id.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			move |task_callback| {
+				user_create_verified_key_record(
+					api_user_id,
+					api_verify_key,
+				)
+			}
 		},
 	)
 }
-fn wire_delete_device_impl(
+fn wire_user_check_verified_key_record_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	fresh_jwt: impl Wire2Api<String> + UnwindSafe,
-	device_id: impl Wire2Api<String> + UnwindSafe,
+	record: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
 		WrapInfo {
-			debug_name: "delete_device",
+			debug_name: "user_check_verified_key_record",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_fresh_jwt = fresh_jwt.wire2api();
-			let api_device_id = device_id.wire2api();
-			move |task_callback| delete_device(api_base_url, api_auth_token, api_fresh_jwt, api_device_id)
+			let api_record = record.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			move |task_callback| {
+				user_check_verified_key_record(
+					api_record,
+					api_verify_key,
+				)
+			}
 		},
 	)
 }
-fn wire_update_user_impl(
+fn wire_user_verify_user_public_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	user_identifier: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
 		WrapInfo {
-			debug_name: "update_user",
+			debug_name: "user_verify_user_public_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_user_identifier = user_identifier.wire2api();
-			move |task_callback| update_user(api_base_url, api_auth_token, api_jwt, api_user_identifier)
+			let api_verify_key = verify_key.wire2api();
+			let api_public_key = public_key.wire2api();
+			move |task_callback| user_verify_user_public_key(api_verify_key, api_public_key)
 		},
 	)
 }
-fn wire_user_fetch_public_key_impl(
+fn wire_user_pin_verify_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
+	store: impl Wire2Api<String> + UnwindSafe,
 	user_id: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, UserPublicKeyData, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "user_fetch_public_key",
+			debug_name: "user_pin_verify_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
+			let api_store = store.wire2api();
 			let api_user_id = user_id.wire2api();
-			move |task_callback| user_fetch_public_key(api_base_url, api_auth_token, api_user_id)
+			let api_verify_key = verify_key.wire2api();
+			move |task_callback| {
+				user_pin_verify_key(
+					api_store,
+					api_user_id,
+					api_verify_key,
+				)
+			}
 		},
 	)
 }
-fn wire_user_fetch_verify_key_impl(
+fn wire_user_check_pinned_public_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	user_id: impl Wire2Api<String> + UnwindSafe,
-	verify_key_id: impl Wire2Api<String> + UnwindSafe,
+	store: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "user_fetch_verify_key",
+			debug_name: "user_check_pinned_public_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_user_id = user_id.wire2api();
-			let api_verify_key_id = verify_key_id.wire2api();
-			move |task_callback| user_fetch_verify_key(api_base_url, api_auth_token, api_user_id, api_verify_key_id)
+			let api_store = store.wire2api();
+			let api_id = id.wire2api();
+			let api_public_key = public_key.wire2api();
+			move |task_callback| {
+				user_check_pinned_public_key(
+					api_store,
+					api_id,
+					api_public_key,
+				)
+			}
 		},
 	)
 }
-fn wire_user_key_rotation_impl(
+fn wire_user_create_key_rotation_link_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	public_device_key: impl Wire2Api<String> + UnwindSafe,
-	pre_user_key: impl Wire2Api<String> + UnwindSafe,
+	previous_sign_key: impl Wire2Api<String> + UnwindSafe,
+	previous_verify_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "user_key_rotation",
+			debug_name: "user_create_key_rotation_link",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_public_device_key = public_device_key.wire2api();
-			let api_pre_user_key = pre_user_key.wire2api();
+			let api_previous_sign_key = previous_sign_key.wire2api();
+			let api_previous_verify_key = previous_verify_key.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			let api_public_key = public_key.wire2api();
 			move |task_callback| {
-				user_key_rotation(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_public_device_key,
-					api_pre_user_key,
+				user_create_key_rotation_link(
+					api_previous_sign_key,
+					api_previous_verify_key,
+					api_verify_key,
+					api_public_key,
 				)
 			}
 		},
 	)
 }
-fn wire_user_pre_done_key_rotation_impl(
+fn wire_user_verify_key_rotation_chain_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
+	links: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<KeyRotationGetOut>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "user_pre_done_key_rotation",
+			debug_name: "user_verify_key_rotation_chain",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			move |task_callback| user_pre_done_key_rotation(api_base_url, api_auth_token, api_jwt)
+			let api_verify_key = verify_key.wire2api();
+			let api_links = links.wire2api();
+			move |task_callback| {
+				user_verify_key_rotation_chain(
+					api_verify_key,
+					api_links,
+				)
+			}
 		},
 	)
 }
-fn wire_user_get_done_key_rotation_server_input_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, KeyRotationInput, _>(
+fn wire_user_update_pin_by_rotation_chain_impl(
+	port_: MessagePort,
+	store: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	links: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "user_get_done_key_rotation_server_input",
+			debug_name: "user_update_pin_by_rotation_chain",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_server_output = server_output.wire2api();
-			move |task_callback| user_get_done_key_rotation_server_input(api_server_output)
+			let api_store = store.wire2api();
+			let api_user_id = user_id.wire2api();
+			let api_links = links.wire2api();
+			move |task_callback| {
+				user_update_pin_by_rotation_chain(
+					api_store,
+					api_user_id,
+					api_links,
+				)
+			}
 		},
 	)
 }
-fn wire_user_finish_key_rotation_impl(
+fn wire_get_user_devices_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	server_output: impl Wire2Api<String> + UnwindSafe,
-	pre_group_key: impl Wire2Api<String> + UnwindSafe,
-	public_key: impl Wire2Api<String> + UnwindSafe,
-	private_key: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_id: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<UserDeviceList>, _>(
 		WrapInfo {
-			debug_name: "user_finish_key_rotation",
+			debug_name: "get_user_devices",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -800,33 +918,32 @@ fn wire_user_finish_key_rotation_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_server_output = server_output.wire2api();
-			let api_pre_group_key = pre_group_key.wire2api();
-			let api_public_key = public_key.wire2api();
-			let api_private_key = private_key.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_id = last_fetched_id.wire2api();
 			move |task_callback| {
-				user_finish_key_rotation(
+				get_user_devices(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_server_output,
-					api_pre_group_key,
-					api_public_key,
-					api_private_key,
+					api_last_fetched_time,
+					api_last_fetched_id,
 				)
 			}
 		},
 	)
 }
-fn wire_register_raw_otp_impl(
+fn wire_reset_password_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
+	new_password: impl Wire2Api<String> + UnwindSafe,
+	decrypted_private_key: impl Wire2Api<String> + UnwindSafe,
+	decrypted_sign_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegister, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "register_raw_otp",
+			debug_name: "reset_password",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -834,85 +951,112 @@ fn wire_register_raw_otp_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			move |task_callback| register_raw_otp(api_base_url, api_auth_token, api_jwt)
+			let api_new_password = new_password.wire2api();
+			let api_decrypted_private_key = decrypted_private_key.wire2api();
+			let api_decrypted_sign_key = decrypted_sign_key.wire2api();
+			move |task_callback| {
+				reset_password(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_new_password,
+					api_decrypted_private_key,
+					api_decrypted_sign_key,
+				)
+			}
 		},
 	)
 }
-fn wire_register_otp_impl(
+fn wire_change_password_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	issuer: impl Wire2Api<String> + UnwindSafe,
-	audience: impl Wire2Api<String> + UnwindSafe,
+	user_identifier: impl Wire2Api<String> + UnwindSafe,
+	old_password: impl Wire2Api<String> + UnwindSafe,
+	new_password: impl Wire2Api<String> + UnwindSafe,
+	mfa_token: impl Wire2Api<Option<String>> + UnwindSafe,
+	mfa_recovery: impl Wire2Api<Option<bool>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegisterUrl, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "register_otp",
+			debug_name: "change_password",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_issuer = issuer.wire2api();
-			let api_audience = audience.wire2api();
-			move |task_callback| register_otp(api_base_url, api_auth_token, api_jwt, api_issuer, api_audience)
+			let api_user_identifier = user_identifier.wire2api();
+			let api_old_password = old_password.wire2api();
+			let api_new_password = new_password.wire2api();
+			let api_mfa_token = mfa_token.wire2api();
+			let api_mfa_recovery = mfa_recovery.wire2api();
+			move |task_callback| {
+				change_password(
+					api_base_url,
+					api_auth_token,
+					api_user_identifier,
+					api_old_password,
+					api_new_password,
+					api_mfa_token,
+					api_mfa_recovery,
+				)
+			}
 		},
 	)
 }
-fn wire_get_otp_recover_keys_impl(
+fn wire_delete_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
+	fresh_jwt: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRecoveryKeysOutput, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "get_otp_recover_keys",
+			debug_name: "delete_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			move |task_callback| get_otp_recover_keys(api_base_url, api_auth_token, api_jwt)
+			let api_fresh_jwt = fresh_jwt.wire2api();
+			move |task_callback| delete_user(api_base_url, api_auth_token, api_fresh_jwt)
 		},
 	)
 }
-fn wire_reset_raw_otp_impl(
+fn wire_delete_device_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
+	fresh_jwt: impl Wire2Api<String> + UnwindSafe,
+	device_id: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegister, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "reset_raw_otp",
+			debug_name: "delete_device",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			move |task_callback| reset_raw_otp(api_base_url, api_auth_token, api_jwt)
+			let api_fresh_jwt = fresh_jwt.wire2api();
+			let api_device_id = device_id.wire2api();
+			move |task_callback| delete_device(api_base_url, api_auth_token, api_fresh_jwt, api_device_id)
 		},
 	)
 }
-fn wire_reset_otp_impl(
+fn wire_update_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	issuer: impl Wire2Api<String> + UnwindSafe,
-	audience: impl Wire2Api<String> + UnwindSafe,
+	user_identifier: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegisterUrl, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "reset_otp",
+			debug_name: "update_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -920,128 +1064,93 @@ fn wire_reset_otp_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_issuer = issuer.wire2api();
-			let api_audience = audience.wire2api();
-			move |task_callback| reset_otp(api_base_url, api_auth_token, api_jwt, api_issuer, api_audience)
+			let api_user_identifier = user_identifier.wire2api();
+			move |task_callback| update_user(api_base_url, api_auth_token, api_jwt, api_user_identifier)
 		},
 	)
 }
-fn wire_disable_otp_impl(
+fn wire_user_fetch_public_key_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, UserPublicKeyData, _>(
 		WrapInfo {
-			debug_name: "disable_otp",
+			debug_name: "user_fetch_public_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			move |task_callback| disable_otp(api_base_url, api_auth_token, api_jwt)
-		},
-	)
-}
-fn wire_group_prepare_create_group_impl(port_: MessagePort, creators_public_key: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
-		WrapInfo {
-			debug_name: "group_prepare_create_group",
-			port: Some(port_),
-			mode: FfiCallMode::Normal,
-		},
-		move || {
-			let api_creators_public_key = creators_public_key.wire2api();
-			move |task_callback| group_prepare_create_group(api_creators_public_key)
+			let api_user_id = user_id.wire2api();
+			move |task_callback| user_fetch_public_key(api_base_url, api_auth_token, api_user_id)
 		},
 	)
 }
-fn wire_group_create_group_impl(
+fn wire_user_fetch_public_key_pinned_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	creators_public_key: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	store: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
 		WrapInfo {
-			debug_name: "group_create_group",
+			debug_name: "user_fetch_public_key_pinned",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_creators_public_key = creators_public_key.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
+			let api_user_id = user_id.wire2api();
+			let api_store = store.wire2api();
 			move |task_callback| {
-				group_create_group(
+				user_fetch_public_key_pinned(
 					api_base_url,
 					api_auth_token,
-					api_jwt,
-					api_creators_public_key,
-					api_group_as_member,
+					api_user_id,
+					api_store,
 				)
 			}
 		},
 	)
 }
-fn wire_group_create_child_group_impl(
+fn wire_user_fetch_verify_key_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	parent_public_key: impl Wire2Api<String> + UnwindSafe,
-	parent_id: impl Wire2Api<String> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	verify_key_id: impl Wire2Api<String> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_create_child_group",
+			debug_name: "user_fetch_verify_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_parent_public_key = parent_public_key.wire2api();
-			let api_parent_id = parent_id.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_create_child_group(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_parent_public_key,
-					api_parent_id,
-					api_admin_rank,
-					api_group_as_member,
-				)
-			}
+			let api_user_id = user_id.wire2api();
+			let api_verify_key_id = verify_key_id.wire2api();
+			move |task_callback| user_fetch_verify_key(api_base_url, api_auth_token, api_user_id, api_verify_key_id)
 		},
 	)
 }
-fn wire_group_create_connected_group_impl(
+fn wire_user_key_rotation_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	connected_group_id: impl Wire2Api<String> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-	parent_public_key: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	public_device_key: impl Wire2Api<String> + UnwindSafe,
+	pre_user_key: impl Wire2Api<String> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_create_connected_group",
+			debug_name: "user_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1049,61 +1158,66 @@ fn wire_group_create_connected_group_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_connected_group_id = connected_group_id.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
-			let api_parent_public_key = parent_public_key.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
+			let api_public_device_key = public_device_key.wire2api();
+			let api_pre_user_key = pre_user_key.wire2api();
 			move |task_callback| {
-				group_create_connected_group(
+				user_key_rotation(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_connected_group_id,
-					api_admin_rank,
-					api_parent_public_key,
-					api_group_as_member,
+					api_public_device_key,
+					api_pre_user_key,
 				)
 			}
 		},
 	)
 }
-fn wire_group_extract_group_data_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupOutData, _>(
+fn wire_user_pre_done_key_rotation_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<KeyRotationGetOut>, _>(
 		WrapInfo {
-			debug_name: "group_extract_group_data",
+			debug_name: "user_pre_done_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_server_output = server_output.wire2api();
-			move |task_callback| group_extract_group_data(api_server_output)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			move |task_callback| user_pre_done_key_rotation(api_base_url, api_auth_token, api_jwt)
 		},
 	)
 }
-fn wire_group_extract_group_keys_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupOutDataKeys>, _>(
+fn wire_user_get_done_key_rotation_server_input_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, KeyRotationInput, _>(
 		WrapInfo {
-			debug_name: "group_extract_group_keys",
+			debug_name: "user_get_done_key_rotation_server_input",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_server_output = server_output.wire2api();
-			move |task_callback| group_extract_group_keys(api_server_output)
+			move |task_callback| user_get_done_key_rotation_server_input(api_server_output)
 		},
 	)
 }
-fn wire_group_get_group_data_impl(
+fn wire_user_finish_key_rotation_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	server_output: impl Wire2Api<String> + UnwindSafe,
+	pre_group_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
+	private_key: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupOutData, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_get_group_data",
+			debug_name: "user_finish_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1111,25 +1225,33 @@ fn wire_group_get_group_data_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| group_get_group_data(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
+			let api_server_output = server_output.wire2api();
+			let api_pre_group_key = pre_group_key.wire2api();
+			let api_public_key = public_key.wire2api();
+			let api_private_key = private_key.wire2api();
+			move |task_callback| {
+				user_finish_key_rotation(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_server_output,
+					api_pre_group_key,
+					api_public_key,
+					api_private_key,
+				)
+			}
 		},
 	)
 }
-fn wire_group_get_group_keys_impl(
+fn wire_register_raw_otp_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_key_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupOutDataKeys>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegister, _>(
 		WrapInfo {
-			debug_name: "group_get_group_keys",
+			debug_name: "register_raw_otp",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1137,36 +1259,21 @@ fn wire_group_get_group_keys_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_key_id = last_fetched_key_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_get_group_keys(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_last_fetched_time,
-					api_last_fetched_key_id,
-					api_group_as_member,
-				)
-			}
+			move |task_callback| register_raw_otp(api_base_url, api_auth_token, api_jwt)
 		},
 	)
 }
-fn wire_group_get_group_key_impl(
+fn wire_register_otp_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	key_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	issuer: impl Wire2Api<String> + UnwindSafe,
+	audience: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupOutDataKeys, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegisterUrl, _>(
 		WrapInfo {
-			debug_name: "group_get_group_key",
+			debug_name: "register_otp",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1174,89 +1281,85 @@ fn wire_group_get_group_key_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_key_id = key_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_get_group_key(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_key_id,
-					api_group_as_member,
-				)
-			}
+			let api_issuer = issuer.wire2api();
+			let api_audience = audience.wire2api();
+			move |task_callback| register_otp(api_base_url, api_auth_token, api_jwt, api_issuer, api_audience)
 		},
 	)
 }
-fn wire_group_decrypt_key_impl(
+fn wire_get_otp_recover_keys_impl(
 	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	server_key_data: impl Wire2Api<String> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupKeyData, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRecoveryKeysOutput, _>(
 		WrapInfo {
-			debug_name: "group_decrypt_key",
+			debug_name: "get_otp_recover_keys",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_server_key_data = server_key_data.wire2api();
-			move |task_callback| group_decrypt_key(api_private_key, api_server_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			move |task_callback| get_otp_recover_keys(api_base_url, api_auth_token, api_jwt)
 		},
 	)
 }
-fn wire_group_decrypt_hmac_key_impl(
+fn wire_reset_raw_otp_impl(
 	port_: MessagePort,
-	group_key: impl Wire2Api<String> + UnwindSafe,
-	server_key_data: impl Wire2Api<String> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegister, _>(
 		WrapInfo {
-			debug_name: "group_decrypt_hmac_key",
+			debug_name: "reset_raw_otp",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_group_key = group_key.wire2api();
-			let api_server_key_data = server_key_data.wire2api();
-			move |task_callback| group_decrypt_hmac_key(api_group_key, api_server_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			move |task_callback| reset_raw_otp(api_base_url, api_auth_token, api_jwt)
 		},
 	)
 }
-fn wire_group_decrypt_sortable_key_impl(
+fn wire_reset_otp_impl(
 	port_: MessagePort,
-	group_key: impl Wire2Api<String> + UnwindSafe,
-	server_key_data: impl Wire2Api<String> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	issuer: impl Wire2Api<String> + UnwindSafe,
+	audience: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OtpRegisterUrl, _>(
 		WrapInfo {
-			debug_name: "group_decrypt_sortable_key",
+			debug_name: "reset_otp",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_group_key = group_key.wire2api();
-			let api_server_key_data = server_key_data.wire2api();
-			move |task_callback| group_decrypt_sortable_key(api_group_key, api_server_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_issuer = issuer.wire2api();
+			let api_audience = audience.wire2api();
+			move |task_callback| reset_otp(api_base_url, api_auth_token, api_jwt, api_issuer, api_audience)
 		},
 	)
 }
-fn wire_group_get_member_impl(
+fn wire_disable_otp_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupUserListItem>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_get_member",
+			debug_name: "disable_otp",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1264,35 +1367,34 @@ fn wire_group_get_member_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_id = last_fetched_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_get_member(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_last_fetched_time,
-					api_last_fetched_id,
-					api_group_as_member,
-				)
-			}
+			move |task_callback| disable_otp(api_base_url, api_auth_token, api_jwt)
 		},
 	)
 }
-fn wire_group_get_group_updates_impl(
+fn wire_group_prepare_create_group_impl(port_: MessagePort, creators_public_key: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "group_prepare_create_group",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_creators_public_key = creators_public_key.wire2api();
+			move |task_callback| group_prepare_create_group(api_creators_public_key)
+		},
+	)
+}
+fn wire_group_create_group_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
+	creators_public_key: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupDataCheckUpdateServerOutput, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_get_group_updates",
+			debug_name: "group_create_group",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1300,62 +1402,33 @@ fn wire_group_get_group_updates_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| group_get_group_updates(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
-		},
-	)
-}
-fn wire_group_get_all_first_level_children_impl(
-	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupChildrenList>, _>(
-		WrapInfo {
-			debug_name: "group_get_all_first_level_children",
-			port: Some(port_),
-			mode: FfiCallMode::Normal,
-		},
-		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
+			let api_creators_public_key = creators_public_key.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_get_all_first_level_children(
+				group_create_group(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_id,
-					api_last_fetched_time,
-					api_last_fetched_group_id,
+					api_creators_public_key,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_get_groups_for_user_impl(
+fn wire_group_create_child_group_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
-	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	parent_public_key: impl Wire2Api<String> + UnwindSafe,
+	parent_id: impl Wire2Api<String> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<ListGroups>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_get_groups_for_user",
+			debug_name: "group_create_child_group",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1363,74 +1436,37 @@ fn wire_group_get_groups_for_user_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
-			let api_group_id = group_id.wire2api();
+			let api_parent_public_key = parent_public_key.wire2api();
+			let api_parent_id = parent_id.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_get_groups_for_user(
+				group_create_child_group(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_last_fetched_time,
-					api_last_fetched_group_id,
-					api_group_id,
-				)
-			}
-		},
-	)
-}
-fn wire_group_prepare_keys_for_new_member_impl(
-	port_: MessagePort,
-	user_public_key: impl Wire2Api<String> + UnwindSafe,
-	group_keys: impl Wire2Api<String> + UnwindSafe,
-	key_count: impl Wire2Api<i32> + UnwindSafe,
-	rank: impl Wire2Api<Option<i32>> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
-		WrapInfo {
-			debug_name: "group_prepare_keys_for_new_member",
-			port: Some(port_),
-			mode: FfiCallMode::Normal,
-		},
-		move || {
-			let api_user_public_key = user_public_key.wire2api();
-			let api_group_keys = group_keys.wire2api();
-			let api_key_count = key_count.wire2api();
-			let api_rank = rank.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
-			move |task_callback| {
-				group_prepare_keys_for_new_member(
-					api_user_public_key,
-					api_group_keys,
-					api_key_count,
-					api_rank,
+					api_parent_public_key,
+					api_parent_id,
 					api_admin_rank,
+					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_invite_user_impl(
+fn wire_group_create_connected_group_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	user_id: impl Wire2Api<String> + UnwindSafe,
-	key_count: impl Wire2Api<i32> + UnwindSafe,
-	rank: impl Wire2Api<Option<i32>> + UnwindSafe,
+	connected_group_id: impl Wire2Api<String> + UnwindSafe,
 	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-	auto_invite: impl Wire2Api<bool> + UnwindSafe,
-	group_invite: impl Wire2Api<bool> + UnwindSafe,
-	re_invite: impl Wire2Api<bool> + UnwindSafe,
-	user_public_key: impl Wire2Api<String> + UnwindSafe,
-	group_keys: impl Wire2Api<String> + UnwindSafe,
+	parent_public_key: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_invite_user",
+			debug_name: "group_create_connected_group",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1438,95 +1474,61 @@ fn wire_group_invite_user_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_user_id = user_id.wire2api();
-			let api_key_count = key_count.wire2api();
-			let api_rank = rank.wire2api();
+			let api_connected_group_id = connected_group_id.wire2api();
 			let api_admin_rank = admin_rank.wire2api();
-			let api_auto_invite = auto_invite.wire2api();
-			let api_group_invite = group_invite.wire2api();
-			let api_re_invite = re_invite.wire2api();
-			let api_user_public_key = user_public_key.wire2api();
-			let api_group_keys = group_keys.wire2api();
+			let api_parent_public_key = parent_public_key.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_invite_user(
+				group_create_connected_group(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_id,
-					api_user_id,
-					api_key_count,
-					api_rank,
+					api_connected_group_id,
 					api_admin_rank,
-					api_auto_invite,
-					api_group_invite,
-					api_re_invite,
-					api_user_public_key,
-					api_group_keys,
+					api_parent_public_key,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_invite_user_session_impl(
-	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	auto_invite: impl Wire2Api<bool> + UnwindSafe,
-	session_id: impl Wire2Api<String> + UnwindSafe,
-	user_public_key: impl Wire2Api<String> + UnwindSafe,
-	group_keys: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+fn wire_group_extract_group_data_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupOutData, _>(
 		WrapInfo {
-			debug_name: "group_invite_user_session",
+			debug_name: "group_extract_group_data",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_auto_invite = auto_invite.wire2api();
-			let api_session_id = session_id.wire2api();
-			let api_user_public_key = user_public_key.wire2api();
-			let api_group_keys = group_keys.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_invite_user_session(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_auto_invite,
-					api_session_id,
-					api_user_public_key,
-					api_group_keys,
-					api_group_as_member,
-				)
-			}
+			let api_server_output = server_output.wire2api();
+			move |task_callback| group_extract_group_data(api_server_output)
 		},
 	)
 }
-fn wire_group_get_invites_for_user_impl(
+fn wire_group_extract_group_keys_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupOutDataKeys>, _>(
+		WrapInfo {
+			debug_name: "group_extract_group_keys",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_server_output = server_output.wire2api();
+			move |task_callback| group_extract_group_keys(api_server_output)
+		},
+	)
+}
+fn wire_group_get_group_data_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
-	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupInviteReqList>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupOutData, _>(
 		WrapInfo {
-			debug_name: "group_get_invites_for_user",
+			debug_name: "group_get_group_data",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1534,36 +1536,25 @@ fn wire_group_get_invites_for_user_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
-			let api_group_id = group_id.wire2api();
+			let api_id = id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_get_invites_for_user(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_last_fetched_time,
-					api_last_fetched_group_id,
-					api_group_id,
-					api_group_as_member,
-				)
-			}
+			move |task_callback| group_get_group_data(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
 		},
 	)
 }
-fn wire_group_accept_invite_impl(
+fn wire_group_get_group_keys_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
-	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_key_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupOutDataKeys>, _>(
 		WrapInfo {
-			debug_name: "group_accept_invite",
+			debug_name: "group_get_group_keys",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1572,33 +1563,35 @@ fn wire_group_accept_invite_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			let api_group_id = group_id.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_key_id = last_fetched_key_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_accept_invite(
+				group_get_group_keys(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
-					api_group_id,
+					api_last_fetched_time,
+					api_last_fetched_key_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_reject_invite_impl(
+fn wire_group_get_group_key_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
-	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	key_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupOutDataKeys, _>(
 		WrapInfo {
-			debug_name: "group_reject_invite",
+			debug_name: "group_get_group_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1607,108 +1600,88 @@ fn wire_group_reject_invite_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			let api_group_id = group_id.wire2api();
+			let api_key_id = key_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_reject_invite(
+				group_get_group_key(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
-					api_group_id,
+					api_key_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_get_sent_join_req_user_impl(
+fn wire_group_decrypt_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	server_key_data: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupInviteReqList>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupKeyData, _>(
 		WrapInfo {
-			debug_name: "group_get_sent_join_req_user",
+			debug_name: "group_decrypt_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_get_sent_join_req_user(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_last_fetched_time,
-					api_last_fetched_group_id,
-					api_group_as_member,
-				)
-			}
+			let api_private_key = private_key.wire2api();
+			let api_server_key_data = server_key_data.wire2api();
+			move |task_callback| group_decrypt_key(api_private_key, api_server_key_data)
 		},
 	)
 }
-fn wire_group_get_sent_join_req_impl(
+fn wire_group_decrypt_hmac_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	group_key: impl Wire2Api<String> + UnwindSafe,
+	server_key_data: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupInviteReqList>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_get_sent_join_req",
+			debug_name: "group_decrypt_hmac_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
-			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_get_sent_join_req(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_admin_rank,
-					api_last_fetched_time,
-					api_last_fetched_group_id,
-					api_group_as_member,
-				)
-			}
+			let api_group_key = group_key.wire2api();
+			let api_server_key_data = server_key_data.wire2api();
+			move |task_callback| group_decrypt_hmac_key(api_group_key, api_server_key_data)
 		},
 	)
 }
-fn wire_group_delete_sent_join_req_user_impl(
+fn wire_group_decrypt_sortable_key_impl(
+	port_: MessagePort,
+	group_key: impl Wire2Api<String> + UnwindSafe,
+	server_key_data: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "group_decrypt_sortable_key",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_group_key = group_key.wire2api();
+			let api_server_key_data = server_key_data.wire2api();
+			move |task_callback| group_decrypt_sortable_key(api_group_key, api_server_key_data)
+		},
+	)
+}
+fn wire_group_get_member_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	join_req_group_id: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupUserListItem>, _>(
 		WrapInfo {
-			debug_name: "group_delete_sent_join_req_user",
+			debug_name: "group_get_member",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1716,33 +1689,35 @@ fn wire_group_delete_sent_join_req_user_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_join_req_group_id = join_req_group_id.wire2api();
+			let api_id = id.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_id = last_fetched_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_delete_sent_join_req_user(
+				group_get_member(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_join_req_group_id,
+					api_id,
+					api_last_fetched_time,
+					api_last_fetched_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_delete_sent_join_req_impl(
+fn wire_group_get_group_updates_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-	join_req_group_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupDataCheckUpdateServerOutput, _>(
 		WrapInfo {
-			debug_name: "group_delete_sent_join_req",
+			debug_name: "group_get_group_updates",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1751,35 +1726,24 @@ fn wire_group_delete_sent_join_req_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
-			let api_join_req_group_id = join_req_group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				group_delete_sent_join_req(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_admin_rank,
-					api_join_req_group_id,
-					api_group_as_member,
-				)
-			}
+			move |task_callback| group_get_group_updates(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
 		},
 	)
 }
-fn wire_group_join_req_impl(
+fn wire_group_get_all_first_level_children_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
-	group_id: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupChildrenList>, _>(
 		WrapInfo {
-			debug_name: "group_join_req",
+			debug_name: "group_get_all_first_level_children",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1788,35 +1752,35 @@ fn wire_group_join_req_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			let api_group_id = group_id.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_join_req(
+				group_get_all_first_level_children(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
-					api_group_id,
+					api_last_fetched_time,
+					api_last_fetched_group_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_get_join_reqs_impl(
+fn wire_group_get_groups_for_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
 	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
-	last_fetched_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
+	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupJoinReqList>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<ListGroups>, _>(
 		WrapInfo {
-			debug_name: "group_get_join_reqs",
+			debug_name: "group_get_groups_for_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1824,65 +1788,55 @@ fn wire_group_get_join_reqs_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
 			let api_last_fetched_time = last_fetched_time.wire2api();
-			let api_last_fetched_id = last_fetched_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
+			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
+			let api_group_id = group_id.wire2api();
 			move |task_callback| {
-				group_get_join_reqs(
+				group_get_groups_for_user(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_id,
-					api_admin_rank,
 					api_last_fetched_time,
-					api_last_fetched_id,
-					api_group_as_member,
+					api_last_fetched_group_id,
+					api_group_id,
 				)
 			}
 		},
 	)
 }
-fn wire_group_reject_join_req_impl(
+fn wire_group_prepare_keys_for_new_member_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
+	user_public_key: impl Wire2Api<String> + UnwindSafe,
+	group_keys: impl Wire2Api<String> + UnwindSafe,
+	key_count: impl Wire2Api<i32> + UnwindSafe,
+	rank: impl Wire2Api<Option<i32>> + UnwindSafe,
 	admin_rank: impl Wire2Api<i32> + UnwindSafe,
-	rejected_user_id: impl Wire2Api<String> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_reject_join_req",
+			debug_name: "group_prepare_keys_for_new_member",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
+			let api_user_public_key = user_public_key.wire2api();
+			let api_group_keys = group_keys.wire2api();
+			let api_key_count = key_count.wire2api();
+			let api_rank = rank.wire2api();
 			let api_admin_rank = admin_rank.wire2api();
-			let api_rejected_user_id = rejected_user_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_reject_join_req(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
+				group_prepare_keys_for_new_member(
+					api_user_public_key,
+					api_group_keys,
+					api_key_count,
+					api_rank,
 					api_admin_rank,
-					api_rejected_user_id,
-					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_accept_join_req_impl(
+fn wire_group_invite_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
@@ -1892,13 +1846,16 @@ fn wire_group_accept_join_req_impl(
 	key_count: impl Wire2Api<i32> + UnwindSafe,
 	rank: impl Wire2Api<Option<i32>> + UnwindSafe,
 	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	auto_invite: impl Wire2Api<bool> + UnwindSafe,
+	group_invite: impl Wire2Api<bool> + UnwindSafe,
+	re_invite: impl Wire2Api<bool> + UnwindSafe,
 	user_public_key: impl Wire2Api<String> + UnwindSafe,
 	group_keys: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_accept_join_req",
+			debug_name: "group_invite_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1911,11 +1868,14 @@ fn wire_group_accept_join_req_impl(
 			let api_key_count = key_count.wire2api();
 			let api_rank = rank.wire2api();
 			let api_admin_rank = admin_rank.wire2api();
+			let api_auto_invite = auto_invite.wire2api();
+			let api_group_invite = group_invite.wire2api();
+			let api_re_invite = re_invite.wire2api();
 			let api_user_public_key = user_public_key.wire2api();
 			let api_group_keys = group_keys.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_accept_join_req(
+				group_invite_user(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
@@ -1924,6 +1884,9 @@ fn wire_group_accept_join_req_impl(
 					api_key_count,
 					api_rank,
 					api_admin_rank,
+					api_auto_invite,
+					api_group_invite,
+					api_re_invite,
 					api_user_public_key,
 					api_group_keys,
 					api_group_as_member,
@@ -1932,12 +1895,13 @@ fn wire_group_accept_join_req_impl(
 		},
 	)
 }
-fn wire_group_join_user_session_impl(
+fn wire_group_invite_user_session_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
+	auto_invite: impl Wire2Api<bool> + UnwindSafe,
 	session_id: impl Wire2Api<String> + UnwindSafe,
 	user_public_key: impl Wire2Api<String> + UnwindSafe,
 	group_keys: impl Wire2Api<String> + UnwindSafe,
@@ -1945,7 +1909,7 @@ fn wire_group_join_user_session_impl(
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_join_user_session",
+			debug_name: "group_invite_user_session",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1954,16 +1918,18 @@ fn wire_group_join_user_session_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
+			let api_auto_invite = auto_invite.wire2api();
 			let api_session_id = session_id.wire2api();
 			let api_user_public_key = user_public_key.wire2api();
 			let api_group_keys = group_keys.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_join_user_session(
+				group_invite_user_session(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
+					api_auto_invite,
 					api_session_id,
 					api_user_public_key,
 					api_group_keys,
@@ -1973,18 +1939,19 @@ fn wire_group_join_user_session_impl(
 		},
 	)
 }
-fn wire_group_stop_group_invites_impl(
+fn wire_group_get_invites_for_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
+	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupInviteReqList>, _>(
 		WrapInfo {
-			debug_name: "group_stop_group_invites",
+			debug_name: "group_get_invites_for_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -1992,33 +1959,36 @@ fn wire_group_stop_group_invites_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
+			let api_group_id = group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_stop_group_invites(
+				group_get_invites_for_user(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_id,
-					api_admin_rank,
+					api_last_fetched_time,
+					api_last_fetched_group_id,
+					api_group_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_leave_group_impl(
+fn wire_group_accept_invite_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
+	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "leave_group",
+			debug_name: "group_accept_invite",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2027,80 +1997,68 @@ fn wire_leave_group_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
+			let api_group_id = group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| leave_group(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
-		},
-	)
-}
-fn wire_group_prepare_key_rotation_impl(
-	port_: MessagePort,
-	pre_group_key: impl Wire2Api<String> + UnwindSafe,
-	public_key: impl Wire2Api<String> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
-	starter: impl Wire2Api<String> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
-		WrapInfo {
-			debug_name: "group_prepare_key_rotation",
-			port: Some(port_),
-			mode: FfiCallMode::Normal,
-		},
-		move || {
-			let api_pre_group_key = pre_group_key.wire2api();
-			let api_public_key = public_key.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			let api_starter = starter.wire2api();
-			move |task_callback| group_prepare_key_rotation(api_pre_group_key, api_public_key, api_sign_key, api_starter)
+			move |task_callback| {
+				group_accept_invite(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_group_id,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_group_done_key_rotation_impl(
+fn wire_group_reject_invite_impl(
 	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	public_key: impl Wire2Api<String> + UnwindSafe,
-	pre_group_key: impl Wire2Api<String> + UnwindSafe,
-	server_output: impl Wire2Api<String> + UnwindSafe,
-	verify_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_done_key_rotation",
+			debug_name: "group_reject_invite",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_public_key = public_key.wire2api();
-			let api_pre_group_key = pre_group_key.wire2api();
-			let api_server_output = server_output.wire2api();
-			let api_verify_key = verify_key.wire2api();
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_group_id = group_id.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_done_key_rotation(
-					api_private_key,
-					api_public_key,
-					api_pre_group_key,
-					api_server_output,
-					api_verify_key,
+				group_reject_invite(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_group_id,
+					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_key_rotation_impl(
+fn wire_group_get_sent_join_req_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	public_key: impl Wire2Api<String> + UnwindSafe,
-	pre_group_key: impl Wire2Api<String> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
-	starter: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupInviteReqList>, _>(
 		WrapInfo {
-			debug_name: "group_key_rotation",
+			debug_name: "group_get_sent_join_req_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2108,39 +2066,36 @@ fn wire_group_key_rotation_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_public_key = public_key.wire2api();
-			let api_pre_group_key = pre_group_key.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			let api_starter = starter.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_key_rotation(
+				group_get_sent_join_req_user(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_id,
-					api_public_key,
-					api_pre_group_key,
-					api_sign_key,
-					api_starter,
+					api_last_fetched_time,
+					api_last_fetched_group_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_pre_done_key_rotation_impl(
+fn wire_group_get_sent_join_req_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_group_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<KeyRotationGetOut>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupInviteReqList>, _>(
 		WrapInfo {
-			debug_name: "group_pre_done_key_rotation",
+			debug_name: "group_get_sent_join_req",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2149,40 +2104,36 @@ fn wire_group_pre_done_key_rotation_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_group_id = last_fetched_group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| group_pre_done_key_rotation(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
-		},
-	)
-}
-fn wire_group_get_done_key_rotation_server_input_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, KeyRotationInput, _>(
-		WrapInfo {
-			debug_name: "group_get_done_key_rotation_server_input",
-			port: Some(port_),
-			mode: FfiCallMode::Normal,
-		},
-		move || {
-			let api_server_output = server_output.wire2api();
-			move |task_callback| group_get_done_key_rotation_server_input(api_server_output)
+			move |task_callback| {
+				group_get_sent_join_req(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_admin_rank,
+					api_last_fetched_time,
+					api_last_fetched_group_id,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_group_finish_key_rotation_impl(
+fn wire_group_delete_sent_join_req_user_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	server_output: impl Wire2Api<String> + UnwindSafe,
-	pre_group_key: impl Wire2Api<String> + UnwindSafe,
-	public_key: impl Wire2Api<String> + UnwindSafe,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	verify_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	join_req_group_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_finish_key_rotation",
+			debug_name: "group_delete_sent_join_req_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2190,64 +2141,70 @@ fn wire_group_finish_key_rotation_impl(
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_server_output = server_output.wire2api();
-			let api_pre_group_key = pre_group_key.wire2api();
-			let api_public_key = public_key.wire2api();
-			let api_private_key = private_key.wire2api();
-			let api_verify_key = verify_key.wire2api();
+			let api_join_req_group_id = join_req_group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_finish_key_rotation(
+				group_delete_sent_join_req_user(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
-					api_id,
-					api_server_output,
-					api_pre_group_key,
-					api_public_key,
-					api_private_key,
-					api_verify_key,
+					api_join_req_group_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_prepare_update_rank_impl(
+fn wire_group_delete_sent_join_req_impl(
 	port_: MessagePort,
-	user_id: impl Wire2Api<String> + UnwindSafe,
-	rank: impl Wire2Api<i32> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
 	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	join_req_group_id: impl Wire2Api<String> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_prepare_update_rank",
+			debug_name: "group_delete_sent_join_req",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_user_id = user_id.wire2api();
-			let api_rank = rank.wire2api();
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
 			let api_admin_rank = admin_rank.wire2api();
-			move |task_callback| group_prepare_update_rank(api_user_id, api_rank, api_admin_rank)
+			let api_join_req_group_id = join_req_group_id.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_delete_sent_join_req(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_admin_rank,
+					api_join_req_group_id,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_group_update_rank_impl(
+fn wire_group_join_req_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
-	user_id: impl Wire2Api<String> + UnwindSafe,
-	rank: impl Wire2Api<i32> + UnwindSafe,
-	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	group_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_update_rank",
+			debug_name: "group_join_req",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2256,38 +2213,35 @@ fn wire_group_update_rank_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			let api_user_id = user_id.wire2api();
-			let api_rank = rank.wire2api();
-			let api_admin_rank = admin_rank.wire2api();
+			let api_group_id = group_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_update_rank(
+				group_join_req(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
-					api_user_id,
-					api_rank,
-					api_admin_rank,
+					api_group_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_kick_user_impl(
+fn wire_group_get_join_reqs_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
-	user_id: impl Wire2Api<String> + UnwindSafe,
 	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	last_fetched_time: impl Wire2Api<String> + UnwindSafe,
+	last_fetched_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<GroupJoinReqList>, _>(
 		WrapInfo {
-			debug_name: "group_kick_user",
+			debug_name: "group_get_join_reqs",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2296,35 +2250,38 @@ fn wire_group_kick_user_impl(
 			let api_auth_token = auth_token.wire2api();
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			let api_user_id = user_id.wire2api();
 			let api_admin_rank = admin_rank.wire2api();
+			let api_last_fetched_time = last_fetched_time.wire2api();
+			let api_last_fetched_id = last_fetched_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_kick_user(
+				group_get_join_reqs(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
-					api_user_id,
 					api_admin_rank,
+					api_last_fetched_time,
+					api_last_fetched_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_delete_group_impl(
+fn wire_group_reject_join_req_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
 	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
 	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	rejected_user_id: impl Wire2Api<String> + UnwindSafe,
 	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "group_delete_group",
+			debug_name: "group_reject_join_req",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
@@ -2334,888 +2291,1871 @@ fn wire_group_delete_group_impl(
 			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
 			let api_admin_rank = admin_rank.wire2api();
+			let api_rejected_user_id = rejected_user_id.wire2api();
 			let api_group_as_member = group_as_member.wire2api();
 			move |task_callback| {
-				group_delete_group(
+				group_reject_join_req(
 					api_base_url,
 					api_auth_token,
 					api_jwt,
 					api_id,
 					api_admin_rank,
+					api_rejected_user_id,
 					api_group_as_member,
 				)
 			}
 		},
 	)
 }
-fn wire_group_get_public_key_data_impl(
+fn wire_group_accept_join_req_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
 	id: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	key_count: impl Wire2Api<i32> + UnwindSafe,
+	rank: impl Wire2Api<Option<i32>> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	user_public_key: impl Wire2Api<String> + UnwindSafe,
+	group_keys: impl Wire2Api<String> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupPublicKeyData, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "group_get_public_key_data",
+			debug_name: "group_accept_join_req",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
 			let api_id = id.wire2api();
-			move |task_callback| group_get_public_key_data(api_base_url, api_auth_token, api_id)
-		},
+			let api_user_id = user_id.wire2api();
+			let api_key_count = key_count.wire2api();
+			let api_rank = rank.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_user_public_key = user_public_key.wire2api();
+			let api_group_keys = group_keys.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_accept_join_req(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_user_id,
+					api_key_count,
+					api_rank,
+					api_admin_rank,
+					api_user_public_key,
+					api_group_keys,
+					api_group_as_member,
+				)
+			}
+		},
 	)
 }
-fn wire_split_head_and_encrypted_data_impl(port_: MessagePort, data: impl Wire2Api<Vec<u8>> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, EncryptedHead, _>(
+fn wire_group_join_user_session_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	session_id: impl Wire2Api<String> + UnwindSafe,
+	user_public_key: impl Wire2Api<String> + UnwindSafe,
+	group_keys: impl Wire2Api<String> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "split_head_and_encrypted_data",
+			debug_name: "group_join_user_session",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_data = data.wire2api();
-			move |task_callback| split_head_and_encrypted_data(api_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_session_id = session_id.wire2api();
+			let api_user_public_key = user_public_key.wire2api();
+			let api_group_keys = group_keys.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_join_user_session(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_session_id,
+					api_user_public_key,
+					api_group_keys,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_split_head_and_encrypted_string_impl(port_: MessagePort, data: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, EncryptedHead, _>(
+fn wire_group_stop_group_invites_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "split_head_and_encrypted_string",
+			debug_name: "group_stop_group_invites",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_data = data.wire2api();
-			move |task_callback| split_head_and_encrypted_string(api_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_stop_group_invites(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_admin_rank,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_deserialize_head_from_string_impl(port_: MessagePort, head: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, EncryptedHead, _>(
+fn wire_leave_group_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "deserialize_head_from_string",
+			debug_name: "leave_group",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_head = head.wire2api();
-			move |task_callback| deserialize_head_from_string(api_head)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| leave_group(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
 		},
 	)
 }
-fn wire_encrypt_raw_symmetric_impl(
+fn wire_group_prepare_key_rotation_impl(
 	port_: MessagePort,
-	key: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	pre_group_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
 	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	starter: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CryptoRawOutput, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "encrypt_raw_symmetric",
+			debug_name: "group_prepare_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_data = data.wire2api();
+			let api_pre_group_key = pre_group_key.wire2api();
+			let api_public_key = public_key.wire2api();
 			let api_sign_key = sign_key.wire2api();
-			move |task_callback| encrypt_raw_symmetric(api_key, api_data, api_sign_key)
+			let api_starter = starter.wire2api();
+			move |task_callback| group_prepare_key_rotation(api_pre_group_key, api_public_key, api_sign_key, api_starter)
 		},
 	)
 }
-fn wire_decrypt_raw_symmetric_impl(
+fn wire_group_done_key_rotation_impl(
 	port_: MessagePort,
-	key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
-	head: impl Wire2Api<String> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
+	pre_group_key: impl Wire2Api<String> + UnwindSafe,
+	server_output: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "decrypt_raw_symmetric",
+			debug_name: "group_done_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_encrypted_data = encrypted_data.wire2api();
-			let api_head = head.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
-			move |task_callback| decrypt_raw_symmetric(api_key, api_encrypted_data, api_head, api_verify_key_data)
+			let api_private_key = private_key.wire2api();
+			let api_public_key = public_key.wire2api();
+			let api_pre_group_key = pre_group_key.wire2api();
+			let api_server_output = server_output.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			move |task_callback| {
+				group_done_key_rotation(
+					api_private_key,
+					api_public_key,
+					api_pre_group_key,
+					api_server_output,
+					api_verify_key,
+				)
+			}
 		},
 	)
 }
-fn wire_encrypt_symmetric_impl(
+fn wire_group_key_rotation_impl(
 	port_: MessagePort,
-	key: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
+	pre_group_key: impl Wire2Api<String> + UnwindSafe,
 	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	starter: impl Wire2Api<String> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "encrypt_symmetric",
+			debug_name: "group_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_data = data.wire2api();
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_public_key = public_key.wire2api();
+			let api_pre_group_key = pre_group_key.wire2api();
 			let api_sign_key = sign_key.wire2api();
-			move |task_callback| encrypt_symmetric(api_key, api_data, api_sign_key)
+			let api_starter = starter.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_key_rotation(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_admin_rank,
+					api_public_key,
+					api_pre_group_key,
+					api_sign_key,
+					api_starter,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_decrypt_symmetric_impl(
+fn wire_group_pre_done_key_rotation_impl(
 	port_: MessagePort,
-	key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<KeyRotationGetOut>, _>(
 		WrapInfo {
-			debug_name: "decrypt_symmetric",
+			debug_name: "group_pre_done_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_encrypted_data = encrypted_data.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
-			move |task_callback| decrypt_symmetric(api_key, api_encrypted_data, api_verify_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| group_pre_done_key_rotation(api_base_url, api_auth_token, api_jwt, api_id, api_group_as_member)
 		},
 	)
 }
-fn wire_encrypt_string_symmetric_impl(
-	port_: MessagePort,
-	key: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<String> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+fn wire_group_get_done_key_rotation_server_input_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, KeyRotationInput, _>(
 		WrapInfo {
-			debug_name: "encrypt_string_symmetric",
+			debug_name: "group_get_done_key_rotation_server_input",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_data = data.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			move |task_callback| encrypt_string_symmetric(api_key, api_data, api_sign_key)
+			let api_server_output = server_output.wire2api();
+			move |task_callback| group_get_done_key_rotation_server_input(api_server_output)
 		},
 	)
 }
-fn wire_decrypt_string_symmetric_impl(
+fn wire_group_finish_key_rotation_impl(
 	port_: MessagePort,
-	key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_data: impl Wire2Api<String> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	server_output: impl Wire2Api<String> + UnwindSafe,
+	pre_group_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "decrypt_string_symmetric",
+			debug_name: "group_finish_key_rotation",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_encrypted_data = encrypted_data.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
-			move |task_callback| decrypt_string_symmetric(api_key, api_encrypted_data, api_verify_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_server_output = server_output.wire2api();
+			let api_pre_group_key = pre_group_key.wire2api();
+			let api_public_key = public_key.wire2api();
+			let api_private_key = private_key.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_finish_key_rotation(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_server_output,
+					api_pre_group_key,
+					api_public_key,
+					api_private_key,
+					api_verify_key,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_encrypt_raw_asymmetric_impl(
+fn wire_group_prepare_update_rank_impl(
 	port_: MessagePort,
-	reply_public_key_data: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	rank: impl Wire2Api<i32> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CryptoRawOutput, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "encrypt_raw_asymmetric",
+			debug_name: "group_prepare_update_rank",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_reply_public_key_data = reply_public_key_data.wire2api();
-			let api_data = data.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			move |task_callback| encrypt_raw_asymmetric(api_reply_public_key_data, api_data, api_sign_key)
+			let api_user_id = user_id.wire2api();
+			let api_rank = rank.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			move |task_callback| group_prepare_update_rank(api_user_id, api_rank, api_admin_rank)
 		},
 	)
 }
-fn wire_decrypt_raw_asymmetric_impl(
+fn wire_group_update_rank_impl(
 	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
-	head: impl Wire2Api<String> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	rank: impl Wire2Api<i32> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "decrypt_raw_asymmetric",
+			debug_name: "group_update_rank",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_encrypted_data = encrypted_data.wire2api();
-			let api_head = head.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
-			move |task_callback| decrypt_raw_asymmetric(api_private_key, api_encrypted_data, api_head, api_verify_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_user_id = user_id.wire2api();
+			let api_rank = rank.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_update_rank(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_user_id,
+					api_rank,
+					api_admin_rank,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_encrypt_asymmetric_impl(
+fn wire_group_kick_user_impl(
 	port_: MessagePort,
-	reply_public_key_data: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "encrypt_asymmetric",
+			debug_name: "group_kick_user",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_reply_public_key_data = reply_public_key_data.wire2api();
-			let api_data = data.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			move |task_callback| encrypt_asymmetric(api_reply_public_key_data, api_data, api_sign_key)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_user_id = user_id.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_kick_user(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_user_id,
+					api_admin_rank,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_decrypt_asymmetric_impl(
+fn wire_group_delete_group_impl(
 	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	admin_rank: impl Wire2Api<i32> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "decrypt_asymmetric",
+			debug_name: "group_delete_group",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_encrypted_data = encrypted_data.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
-			move |task_callback| decrypt_asymmetric(api_private_key, api_encrypted_data, api_verify_key_data)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_admin_rank = admin_rank.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				group_delete_group(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_admin_rank,
+					api_group_as_member,
+				)
+			}
 		},
 	)
 }
-fn wire_encrypt_string_asymmetric_impl(
+fn wire_group_get_public_key_data_impl(
 	port_: MessagePort,
-	reply_public_key_data: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<String> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, GroupPublicKeyData, _>(
 		WrapInfo {
-			debug_name: "encrypt_string_asymmetric",
+			debug_name: "group_get_public_key_data",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_reply_public_key_data = reply_public_key_data.wire2api();
-			let api_data = data.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			move |task_callback| encrypt_string_asymmetric(api_reply_public_key_data, api_data, api_sign_key)
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_id = id.wire2api();
+			move |task_callback| group_get_public_key_data(api_base_url, api_auth_token, api_id)
 		},
 	)
 }
-fn wire_decrypt_string_asymmetric_impl(
-	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_data: impl Wire2Api<String> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
-) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+fn wire_split_head_and_encrypted_data_impl(port_: MessagePort, data: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, EncryptedHead, _>(
 		WrapInfo {
-			debug_name: "decrypt_string_asymmetric",
+			debug_name: "split_head_and_encrypted_data",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_encrypted_data = encrypted_data.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
-			move |task_callback| decrypt_string_asymmetric(api_private_key, api_encrypted_data, api_verify_key_data)
+			let api_data = data.wire2api();
+			move |task_callback| split_head_and_encrypted_data(api_data)
 		},
 	)
 }
-fn wire_generate_non_register_sym_key_impl(port_: MessagePort, master_key: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, NonRegisteredKeyOutput, _>(
+fn wire_split_head_and_encrypted_string_impl(port_: MessagePort, data: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, EncryptedHead, _>(
 		WrapInfo {
-			debug_name: "generate_non_register_sym_key",
+			debug_name: "split_head_and_encrypted_string",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_master_key = master_key.wire2api();
-			move |task_callback| generate_non_register_sym_key(api_master_key)
+			let api_data = data.wire2api();
+			move |task_callback| split_head_and_encrypted_string(api_data)
 		},
 	)
 }
-fn wire_generate_non_register_sym_key_by_public_key_impl(port_: MessagePort, reply_public_key: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, NonRegisteredKeyOutput, _>(
+fn wire_deserialize_head_from_string_impl(port_: MessagePort, head: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, EncryptedHead, _>(
 		WrapInfo {
-			debug_name: "generate_non_register_sym_key_by_public_key",
+			debug_name: "deserialize_head_from_string",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_reply_public_key = reply_public_key.wire2api();
-			move |task_callback| generate_non_register_sym_key_by_public_key(api_reply_public_key)
+			let api_head = head.wire2api();
+			move |task_callback| deserialize_head_from_string(api_head)
 		},
 	)
 }
-fn wire_decrypt_sym_key_impl(
+fn wire_encrypt_raw_symmetric_impl(
 	port_: MessagePort,
-	master_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_symmetric_key_info: impl Wire2Api<String> + UnwindSafe,
+	key: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CryptoRawOutput, _>(
 		WrapInfo {
-			debug_name: "decrypt_sym_key",
+			debug_name: "encrypt_raw_symmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_master_key = master_key.wire2api();
-			let api_encrypted_symmetric_key_info = encrypted_symmetric_key_info.wire2api();
-			move |task_callback| decrypt_sym_key(api_master_key, api_encrypted_symmetric_key_info)
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			move |task_callback| encrypt_raw_symmetric(api_key, api_data, api_sign_key)
 		},
 	)
 }
-fn wire_decrypt_sym_key_by_private_key_impl(
+fn wire_decrypt_raw_symmetric_impl(
 	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_symmetric_key_info: impl Wire2Api<String> + UnwindSafe,
+	key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	head: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
 		WrapInfo {
-			debug_name: "decrypt_sym_key_by_private_key",
+			debug_name: "decrypt_raw_symmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_encrypted_symmetric_key_info = encrypted_symmetric_key_info.wire2api();
-			move |task_callback| decrypt_sym_key_by_private_key(api_private_key, api_encrypted_symmetric_key_info)
+			let api_key = key.wire2api();
+			let api_encrypted_data = encrypted_data.wire2api();
+			let api_head = head.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| decrypt_raw_symmetric(api_key, api_encrypted_data, api_head, api_verify_key_data)
 		},
 	)
 }
-fn wire_done_fetch_sym_key_impl(
+fn wire_encrypt_symmetric_impl(
 	port_: MessagePort,
-	master_key: impl Wire2Api<String> + UnwindSafe,
-	server_out: impl Wire2Api<String> + UnwindSafe,
-	non_registered: impl Wire2Api<bool> + UnwindSafe,
+	key: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
 		WrapInfo {
-			debug_name: "done_fetch_sym_key",
+			debug_name: "encrypt_symmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_master_key = master_key.wire2api();
-			let api_server_out = server_out.wire2api();
-			let api_non_registered = non_registered.wire2api();
-			move |task_callback| done_fetch_sym_key(api_master_key, api_server_out, api_non_registered)
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			move |task_callback| encrypt_symmetric(api_key, api_data, api_sign_key)
 		},
 	)
 }
-fn wire_done_fetch_sym_key_by_private_key_impl(
+fn wire_decrypt_symmetric_impl(
 	port_: MessagePort,
-	private_key: impl Wire2Api<String> + UnwindSafe,
-	server_out: impl Wire2Api<String> + UnwindSafe,
-	non_registered: impl Wire2Api<bool> + UnwindSafe,
+	key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
 		WrapInfo {
-			debug_name: "done_fetch_sym_key_by_private_key",
+			debug_name: "decrypt_symmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_private_key = private_key.wire2api();
-			let api_server_out = server_out.wire2api();
-			let api_non_registered = non_registered.wire2api();
-			move |task_callback| done_fetch_sym_key_by_private_key(api_private_key, api_server_out, api_non_registered)
+			let api_key = key.wire2api();
+			let api_encrypted_data = encrypted_data.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| decrypt_symmetric(api_key, api_encrypted_data, api_verify_key_data)
 		},
 	)
 }
-fn wire_create_searchable_raw_impl(
+fn wire_encrypt_string_symmetric_impl(
 	port_: MessagePort,
 	key: impl Wire2Api<String> + UnwindSafe,
 	data: impl Wire2Api<String> + UnwindSafe,
-	full: impl Wire2Api<bool> + UnwindSafe,
-	limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "create_searchable_raw",
+			debug_name: "encrypt_string_symmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_key = key.wire2api();
 			let api_data = data.wire2api();
-			let api_full = full.wire2api();
-			let api_limit = limit.wire2api();
-			move |task_callback| create_searchable_raw(api_key, api_data, api_full, api_limit)
+			let api_sign_key = sign_key.wire2api();
+			move |task_callback| encrypt_string_symmetric(api_key, api_data, api_sign_key)
 		},
 	)
 }
-fn wire_create_searchable_impl(
+fn wire_decrypt_string_symmetric_impl(
 	port_: MessagePort,
 	key: impl Wire2Api<String> + UnwindSafe,
-	data: impl Wire2Api<String> + UnwindSafe,
-	full: impl Wire2Api<bool> + UnwindSafe,
-	limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+	encrypted_data: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SearchableCreateOutput, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "create_searchable",
+			debug_name: "decrypt_string_symmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_key = key.wire2api();
-			let api_data = data.wire2api();
-			let api_full = full.wire2api();
-			let api_limit = limit.wire2api();
-			move |task_callback| create_searchable(api_key, api_data, api_full, api_limit)
+			let api_encrypted_data = encrypted_data.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| decrypt_string_symmetric(api_key, api_encrypted_data, api_verify_key_data)
 		},
 	)
 }
-fn wire_search_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+fn wire_encrypt_raw_asymmetric_impl(
+	port_: MessagePort,
+	reply_public_key_data: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CryptoRawOutput, _>(
 		WrapInfo {
-			debug_name: "search",
+			debug_name: "encrypt_raw_asymmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
+			let api_reply_public_key_data = reply_public_key_data.wire2api();
 			let api_data = data.wire2api();
-			move |task_callback| search(api_key, api_data)
+			let api_sign_key = sign_key.wire2api();
+			move |task_callback| encrypt_raw_asymmetric(api_reply_public_key_data, api_data, api_sign_key)
 		},
 	)
 }
-fn wire_sortable_encrypt_raw_number_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<u64> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, u64, _>(
+fn wire_decrypt_raw_asymmetric_impl(
+	port_: MessagePort,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	head: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
 		WrapInfo {
-			debug_name: "sortable_encrypt_raw_number",
+			debug_name: "decrypt_raw_asymmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_data = data.wire2api();
-			move |task_callback| sortable_encrypt_raw_number(api_key, api_data)
+			let api_private_key = private_key.wire2api();
+			let api_encrypted_data = encrypted_data.wire2api();
+			let api_head = head.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| decrypt_raw_asymmetric(api_private_key, api_encrypted_data, api_head, api_verify_key_data)
 		},
 	)
 }
-fn wire_sortable_encrypt_number_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<u64> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SortableEncryptOutput, _>(
+fn wire_encrypt_asymmetric_impl(
+	port_: MessagePort,
+	reply_public_key_data: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
 		WrapInfo {
-			debug_name: "sortable_encrypt_number",
+			debug_name: "encrypt_asymmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
+			let api_reply_public_key_data = reply_public_key_data.wire2api();
 			let api_data = data.wire2api();
-			move |task_callback| sortable_encrypt_number(api_key, api_data)
+			let api_sign_key = sign_key.wire2api();
+			move |task_callback| encrypt_asymmetric(api_reply_public_key_data, api_data, api_sign_key)
 		},
 	)
 }
-fn wire_sortable_encrypt_raw_string_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, u64, _>(
+fn wire_decrypt_asymmetric_impl(
+	port_: MessagePort,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
 		WrapInfo {
-			debug_name: "sortable_encrypt_raw_string",
+			debug_name: "decrypt_asymmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
-			let api_data = data.wire2api();
-			move |task_callback| sortable_encrypt_raw_string(api_key, api_data)
+			let api_private_key = private_key.wire2api();
+			let api_encrypted_data = encrypted_data.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| decrypt_asymmetric(api_private_key, api_encrypted_data, api_verify_key_data)
 		},
 	)
 }
-fn wire_sortable_encrypt_string_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SortableEncryptOutput, _>(
+fn wire_encrypt_string_asymmetric_impl(
+	port_: MessagePort,
+	reply_public_key_data: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<String> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "sortable_encrypt_string",
+			debug_name: "encrypt_string_asymmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_key = key.wire2api();
+			let api_reply_public_key_data = reply_public_key_data.wire2api();
 			let api_data = data.wire2api();
-			move |task_callback| sortable_encrypt_string(api_key, api_data)
+			let api_sign_key = sign_key.wire2api();
+			move |task_callback| encrypt_string_asymmetric(api_reply_public_key_data, api_data, api_sign_key)
 		},
 	)
 }
-fn wire_file_download_file_meta_impl(
+fn wire_decrypt_string_asymmetric_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<Option<String>> + UnwindSafe,
-	id: impl Wire2Api<String> + UnwindSafe,
-	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_data: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileData, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_download_file_meta",
+			debug_name: "decrypt_string_asymmetric",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_id = id.wire2api();
-			let api_group_id = group_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
-			move |task_callback| {
-				file_download_file_meta(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_id,
-					api_group_id,
-					api_group_as_member,
-				)
-			}
+			let api_private_key = private_key.wire2api();
+			let api_encrypted_data = encrypted_data.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| decrypt_string_asymmetric(api_private_key, api_encrypted_data, api_verify_key_data)
 		},
 	)
 }
-fn wire_file_download_and_decrypt_file_part_start_impl(
+fn wire_generate_non_register_sym_key_impl(port_: MessagePort, master_key: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, NonRegisteredKeyOutput, _>(
+		WrapInfo {
+			debug_name: "generate_non_register_sym_key",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_master_key = master_key.wire2api();
+			move |task_callback| generate_non_register_sym_key(api_master_key)
+		},
+	)
+}
+fn wire_generate_non_register_sym_key_by_public_key_impl(port_: MessagePort, reply_public_key: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, NonRegisteredKeyOutput, _>(
+		WrapInfo {
+			debug_name: "generate_non_register_sym_key_by_public_key",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_reply_public_key = reply_public_key.wire2api();
+			move |task_callback| generate_non_register_sym_key_by_public_key(api_reply_public_key)
+		},
+	)
+}
+fn wire_decrypt_sym_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	part_id: impl Wire2Api<String> + UnwindSafe,
-	content_key: impl Wire2Api<String> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+	master_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_symmetric_key_info: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDownloadResult, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_download_and_decrypt_file_part_start",
+			debug_name: "decrypt_sym_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_url_prefix = url_prefix.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_part_id = part_id.wire2api();
-			let api_content_key = content_key.wire2api();
+			let api_master_key = master_key.wire2api();
+			let api_encrypted_symmetric_key_info = encrypted_symmetric_key_info.wire2api();
+			move |task_callback| decrypt_sym_key(api_master_key, api_encrypted_symmetric_key_info)
+		},
+	)
+}
+fn wire_decrypt_sym_key_by_private_key_impl(
+	port_: MessagePort,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_symmetric_key_info: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "decrypt_sym_key_by_private_key",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_private_key = private_key.wire2api();
+			let api_encrypted_symmetric_key_info = encrypted_symmetric_key_info.wire2api();
+			move |task_callback| decrypt_sym_key_by_private_key(api_private_key, api_encrypted_symmetric_key_info)
+		},
+	)
+}
+fn wire_done_fetch_sym_key_impl(
+	port_: MessagePort,
+	master_key: impl Wire2Api<String> + UnwindSafe,
+	server_out: impl Wire2Api<String> + UnwindSafe,
+	non_registered: impl Wire2Api<bool> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "done_fetch_sym_key",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_master_key = master_key.wire2api();
+			let api_server_out = server_out.wire2api();
+			let api_non_registered = non_registered.wire2api();
+			move |task_callback| done_fetch_sym_key(api_master_key, api_server_out, api_non_registered)
+		},
+	)
+}
+fn wire_done_fetch_sym_key_by_private_key_impl(
+	port_: MessagePort,
+	private_key: impl Wire2Api<String> + UnwindSafe,
+	server_out: impl Wire2Api<String> + UnwindSafe,
+	non_registered: impl Wire2Api<bool> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "done_fetch_sym_key_by_private_key",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_private_key = private_key.wire2api();
+			let api_server_out = server_out.wire2api();
+			let api_non_registered = non_registered.wire2api();
+			move |task_callback| done_fetch_sym_key_by_private_key(api_private_key, api_server_out, api_non_registered)
+		},
+	)
+}
+fn wire_create_searchable_raw_impl(
+	port_: MessagePort,
+	key: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<String> + UnwindSafe,
+	full: impl Wire2Api<bool> + UnwindSafe,
+	limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+		WrapInfo {
+			debug_name: "create_searchable_raw",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			let api_full = full.wire2api();
+			let api_limit = limit.wire2api();
+			move |task_callback| create_searchable_raw(api_key, api_data, api_full, api_limit)
+		},
+	)
+}
+fn wire_create_searchable_impl(
+	port_: MessagePort,
+	key: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<String> + UnwindSafe,
+	full: impl Wire2Api<bool> + UnwindSafe,
+	limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SearchableCreateOutput, _>(
+		WrapInfo {
+			debug_name: "create_searchable",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			let api_full = full.wire2api();
+			let api_limit = limit.wire2api();
+			move |task_callback| create_searchable(api_key, api_data, api_full, api_limit)
+		},
+	)
+}
+fn wire_create_searchable_with_options_impl(
+	port_: MessagePort,
+	key: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<String> + UnwindSafe,
+	full: impl Wire2Api<bool> + UnwindSafe,
+	limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+	options: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SearchableCreateOutput, _>(
+		WrapInfo {
+			debug_name: "create_searchable_with_options",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			let api_full = full.wire2api();
+			let api_limit = limit.wire2api();
+			let api_options = options.wire2api();
+			move |task_callback| create_searchable_with_options(api_key, api_data, api_full, api_limit, api_options)
+		},
+	)
+}
+fn wire_search_with_options_impl(
+	port_: MessagePort,
+	key: impl Wire2Api<String> + UnwindSafe,
+	data: impl Wire2Api<String> + UnwindSafe,
+	options: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "search_with_options",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			let api_options = options.wire2api();
+			move |task_callback| search_with_options(api_key, api_data, api_options)
+		},
+	)
+}
+fn wire_create_compound_searchable_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, fields: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SearchableCreateOutput, _>(
+		WrapInfo {
+			debug_name: "create_compound_searchable",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_fields = fields.wire2api();
+			move |task_callback| create_compound_searchable(api_key, api_fields)
+		},
+	)
+}
+fn wire_search_compound_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, fields: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "search_compound",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_fields = fields.wire2api();
+			move |task_callback| search_compound(api_key, api_fields)
+		},
+	)
+}
+fn wire_search_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "search",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			move |task_callback| search(api_key, api_data)
+		},
+	)
+}
+fn wire_sortable_encrypt_raw_number_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<u64> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, u64, _>(
+		WrapInfo {
+			debug_name: "sortable_encrypt_raw_number",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			move |task_callback| sortable_encrypt_raw_number(api_key, api_data)
+		},
+	)
+}
+fn wire_sortable_encrypt_number_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<u64> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SortableEncryptOutput, _>(
+		WrapInfo {
+			debug_name: "sortable_encrypt_number",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			move |task_callback| sortable_encrypt_number(api_key, api_data)
+		},
+	)
+}
+fn wire_sortable_encrypt_raw_string_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, u64, _>(
+		WrapInfo {
+			debug_name: "sortable_encrypt_raw_string",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			move |task_callback| sortable_encrypt_raw_string(api_key, api_data)
+		},
+	)
+}
+fn wire_sortable_encrypt_string_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe, data: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SortableEncryptOutput, _>(
+		WrapInfo {
+			debug_name: "sortable_encrypt_string",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_key = key.wire2api();
+			let api_data = data.wire2api();
+			move |task_callback| sortable_encrypt_string(api_key, api_data)
+		},
+	)
+}
+fn wire_sortable_prepare_range_impl(port_: MessagePort, keys: impl Wire2Api<String> + UnwindSafe, range: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "sortable_prepare_range",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_keys = keys.wire2api();
+			let api_range = range.wire2api();
+			move |task_callback| sortable_prepare_range(api_keys, api_range)
+		},
+	)
+}
+fn wire_file_download_file_meta_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<Option<String>> + UnwindSafe,
+	id: impl Wire2Api<String> + UnwindSafe,
+	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileData, _>(
+		WrapInfo {
+			debug_name: "file_download_file_meta",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_id = id.wire2api();
+			let api_group_id = group_id.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				file_download_file_meta(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_id,
+					api_group_id,
+					api_group_as_member,
+				)
+			}
+		},
+	)
+}
+fn wire_file_download_and_decrypt_file_part_start_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	part_id: impl Wire2Api<String> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDownloadResult, _>(
+		WrapInfo {
+			debug_name: "file_download_and_decrypt_file_part_start",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_part_id = part_id.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| {
+				file_download_and_decrypt_file_part_start(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_part_id,
+					api_content_key,
+					api_verify_key_data,
+				)
+			}
+		},
+	)
+}
+fn wire_file_download_and_decrypt_file_part_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	part_id: impl Wire2Api<String> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDownloadResult, _>(
+		WrapInfo {
+			debug_name: "file_download_and_decrypt_file_part",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_part_id = part_id.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| {
+				file_download_and_decrypt_file_part(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_part_id,
+					api_content_key,
+					api_verify_key_data,
+				)
+			}
+		},
+	)
+}
+fn wire_file_download_and_decrypt_file_part_auto_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	part_id: impl Wire2Api<String> + UnwindSafe,
+	sequence: impl Wire2Api<i32> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	pre_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDownloadResult, _>(
+		WrapInfo {
+			debug_name: "file_download_and_decrypt_file_part_auto",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_part_id = part_id.wire2api();
+			let api_sequence = sequence.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_pre_key = pre_key.wire2api();
+			let api_verify_key_data = verify_key_data.wire2api();
+			move |task_callback| {
+				file_download_and_decrypt_file_part_auto(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_part_id,
+					api_sequence,
+					api_content_key,
+					api_pre_key,
+					api_verify_key_data,
+				)
+			}
+		},
+	)
+}
+fn wire_file_download_part_list_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	file_id: impl Wire2Api<String> + UnwindSafe,
+	last_sequence: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<FilePartListItem>, _>(
+		WrapInfo {
+			debug_name: "file_download_part_list",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_file_id = file_id.wire2api();
+			let api_last_sequence = last_sequence.wire2api();
+			move |task_callback| file_download_part_list(api_base_url, api_auth_token, api_file_id, api_last_sequence)
+		},
+	)
+}
+fn wire_file_register_file_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	master_key_id: impl Wire2Api<String> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_content_key: impl Wire2Api<String> + UnwindSafe,
+	belongs_to_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	belongs_to_type: impl Wire2Api<String> + UnwindSafe,
+	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
+	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileRegisterOutput, _>(
+		WrapInfo {
+			debug_name: "file_register_file",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_master_key_id = master_key_id.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_encrypted_content_key = encrypted_content_key.wire2api();
+			let api_belongs_to_id = belongs_to_id.wire2api();
+			let api_belongs_to_type = belongs_to_type.wire2api();
+			let api_file_name = file_name.wire2api();
+			let api_group_id = group_id.wire2api();
+			let api_group_as_member = group_as_member.wire2api();
+			move |task_callback| {
+				file_register_file(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_master_key_id,
+					api_content_key,
+					api_encrypted_content_key,
+					api_belongs_to_id,
+					api_belongs_to_type,
+					api_file_name,
+					api_group_id,
+					api_group_as_member,
+				)
+			}
+		},
+	)
+}
+fn wire_file_prepare_register_file_impl(
+	port_: MessagePort,
+	master_key_id: impl Wire2Api<String> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_content_key: impl Wire2Api<String> + UnwindSafe,
+	belongs_to_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	belongs_to_type: impl Wire2Api<String> + UnwindSafe,
+	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FilePrepareRegister, _>(
+		WrapInfo {
+			debug_name: "file_prepare_register_file",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_master_key_id = master_key_id.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_encrypted_content_key = encrypted_content_key.wire2api();
+			let api_belongs_to_id = belongs_to_id.wire2api();
+			let api_belongs_to_type = belongs_to_type.wire2api();
+			let api_file_name = file_name.wire2api();
+			move |task_callback| {
+				file_prepare_register_file(
+					api_master_key_id,
+					api_content_key,
+					api_encrypted_content_key,
+					api_belongs_to_id,
+					api_belongs_to_type,
+					api_file_name,
+				)
+			}
+		},
+	)
+}
+fn wire_file_done_register_file_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDoneRegister, _>(
+		WrapInfo {
+			debug_name: "file_done_register_file",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_server_output = server_output.wire2api();
+			move |task_callback| file_done_register_file(api_server_output)
+		},
+	)
+}
+fn wire_file_upload_part_start_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	session_id: impl Wire2Api<String> + UnwindSafe,
+	end: impl Wire2Api<bool> + UnwindSafe,
+	sequence: impl Wire2Api<i32> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	part: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "file_upload_part_start",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_session_id = session_id.wire2api();
+			let api_end = end.wire2api();
+			let api_sequence = sequence.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			let api_part = part.wire2api();
+			move |task_callback| {
+				file_upload_part_start(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_jwt,
+					api_session_id,
+					api_end,
+					api_sequence,
+					api_content_key,
+					api_sign_key,
+					api_part,
+				)
+			}
+		},
+	)
+}
+fn wire_file_upload_part_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	session_id: impl Wire2Api<String> + UnwindSafe,
+	end: impl Wire2Api<bool> + UnwindSafe,
+	sequence: impl Wire2Api<i32> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	part: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "file_upload_part",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_session_id = session_id.wire2api();
+			let api_end = end.wire2api();
+			let api_sequence = sequence.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			let api_part = part.wire2api();
+			move |task_callback| {
+				file_upload_part(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_jwt,
+					api_session_id,
+					api_end,
+					api_sequence,
+					api_content_key,
+					api_sign_key,
+					api_part,
+				)
+			}
+		},
+	)
+}
+fn wire_file_download_and_decrypt_file_part_derived_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	part_id: impl Wire2Api<String> + UnwindSafe,
+	sequence: impl Wire2Api<i32> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ZeroCopyBuffer<Vec<u8>>, _>(
+		WrapInfo {
+			debug_name: "file_download_and_decrypt_file_part_derived",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_part_id = part_id.wire2api();
+			let api_sequence = sequence.wire2api();
+			let api_content_key = content_key.wire2api();
 			let api_verify_key_data = verify_key_data.wire2api();
 			move |task_callback| {
-				file_download_and_decrypt_file_part_start(
+				file_download_and_decrypt_file_part_derived(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_part_id,
+					api_sequence,
+					api_content_key,
+					api_verify_key_data,
+				)
+			}
+		},
+	)
+}
+fn wire_file_upload_part_derived_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	session_id: impl Wire2Api<String> + UnwindSafe,
+	end: impl Wire2Api<bool> + UnwindSafe,
+	sequence: impl Wire2Api<i32> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	part: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+		WrapInfo {
+			debug_name: "file_upload_part_derived",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_session_id = session_id.wire2api();
+			let api_end = end.wire2api();
+			let api_sequence = sequence.wire2api();
+			let api_content_key = content_key.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			let api_part = part.wire2api();
+			move |task_callback| {
+				file_upload_part_derived(
+					api_base_url,
+					api_url_prefix,
+					api_auth_token,
+					api_jwt,
+					api_session_id,
+					api_end,
+					api_sequence,
+					api_content_key,
+					api_sign_key,
+					api_part,
+				)
+			}
+		},
+	)
+}
+fn wire_file_prepare_upload_checkpoint_impl(
+	port_: MessagePort,
+	checkpoint_key: impl Wire2Api<String> + UnwindSafe,
+	file_id: impl Wire2Api<String> + UnwindSafe,
+	session_id: impl Wire2Api<String> + UnwindSafe,
+	sequence: impl Wire2Api<i32> + UnwindSafe,
+	next_file_key: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "file_prepare_upload_checkpoint",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_checkpoint_key = checkpoint_key.wire2api();
+			let api_file_id = file_id.wire2api();
+			let api_session_id = session_id.wire2api();
+			let api_sequence = sequence.wire2api();
+			let api_next_file_key = next_file_key.wire2api();
+			move |task_callback| {
+				file_prepare_upload_checkpoint(
+					api_checkpoint_key,
+					api_file_id,
+					api_session_id,
+					api_sequence,
+					api_next_file_key,
+				)
+			}
+		},
+	)
+}
+fn wire_file_upload_part_resume_impl(
+	port_: MessagePort,
+	base_url: impl Wire2Api<String> + UnwindSafe,
+	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
+	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	end: impl Wire2Api<bool> + UnwindSafe,
+	checkpoint_key: impl Wire2Api<String> + UnwindSafe,
+	checkpoint: impl Wire2Api<String> + UnwindSafe,
+	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
+	part: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+		WrapInfo {
+			debug_name: "file_upload_part_resume",
+			port: Some(port_),
+			mode: FfiCallMode::Normal,
+		},
+		move || {
+			let api_base_url = base_url.wire2api();
+			let api_url_prefix = url_prefix.wire2api();
+			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_end = end.wire2api();
+			let api_checkpoint_key = checkpoint_key.wire2api();
+			let api_checkpoint = checkpoint.wire2api();
+			let api_sign_key = sign_key.wire2api();
+			let api_part = part.wire2api();
+			move |task_callback| {
+				file_upload_part_resume(
 					api_base_url,
 					api_url_prefix,
 					api_auth_token,
-					api_part_id,
-					api_content_key,
-					api_verify_key_data,
+					api_jwt,
+					api_end,
+					api_checkpoint_key,
+					api_checkpoint,
+					api_sign_key,
+					api_part,
 				)
 			}
 		},
 	)
 }
-fn wire_file_download_and_decrypt_file_part_impl(
+fn wire_file_file_name_update_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
-	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
-	part_id: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
+	file_id: impl Wire2Api<String> + UnwindSafe,
 	content_key: impl Wire2Api<String> + UnwindSafe,
-	verify_key_data: impl Wire2Api<Option<String>> + UnwindSafe,
+	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDownloadResult, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "file_download_and_decrypt_file_part",
+			debug_name: "file_file_name_update",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
-			let api_url_prefix = url_prefix.wire2api();
 			let api_auth_token = auth_token.wire2api();
-			let api_part_id = part_id.wire2api();
+			let api_jwt = jwt.wire2api();
+			let api_file_id = file_id.wire2api();
 			let api_content_key = content_key.wire2api();
-			let api_verify_key_data = verify_key_data.wire2api();
+			let api_file_name = file_name.wire2api();
 			move |task_callback| {
-				file_download_and_decrypt_file_part(
+				file_file_name_update(
 					api_base_url,
-					api_url_prefix,
 					api_auth_token,
-					api_part_id,
+					api_jwt,
+					api_file_id,
 					api_content_key,
-					api_verify_key_data,
+					api_file_name,
 				)
 			}
 		},
 	)
 }
-fn wire_file_download_part_list_impl(
+fn wire_file_file_meta_update_impl(
 	port_: MessagePort,
 	base_url: impl Wire2Api<String> + UnwindSafe,
 	auth_token: impl Wire2Api<String> + UnwindSafe,
+	jwt: impl Wire2Api<String> + UnwindSafe,
 	file_id: impl Wire2Api<String> + UnwindSafe,
-	last_sequence: impl Wire2Api<String> + UnwindSafe,
+	content_key: impl Wire2Api<String> + UnwindSafe,
+	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
+	meta: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<FilePartListItem>, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
 		WrapInfo {
-			debug_name: "file_download_part_list",
+			debug_name: "file_file_meta_update",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
 			let api_base_url = base_url.wire2api();
 			let api_auth_token = auth_token.wire2api();
+			let api_jwt = jwt.wire2api();
 			let api_file_id = file_id.wire2api();
-			let api_last_sequence = last_sequence.wire2api();
-			move |task_callback| file_download_part_list(api_base_url, api_auth_token, api_file_id, api_last_sequence)
+			let api_content_key = content_key.wire2api();
+			let api_file_name = file_name.wire2api();
+			let api_meta = meta.wire2api();
+			move |task_callback| {
+				file_file_meta_update(
+					api_base_url,
+					api_auth_token,
+					api_jwt,
+					api_file_id,
+					api_content_key,
+					api_file_name,
+					api_meta,
+				)
+			}
 		},
 	)
 }
-fn wire_file_register_file_impl(
+fn wire_file_encrypt_name_with_meta_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	master_key_id: impl Wire2Api<String> + UnwindSafe,
 	content_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_content_key: impl Wire2Api<String> + UnwindSafe,
-	belongs_to_id: impl Wire2Api<Option<String>> + UnwindSafe,
-	belongs_to_type: impl Wire2Api<String> + UnwindSafe,
 	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
-	group_id: impl Wire2Api<Option<String>> + UnwindSafe,
-	group_as_member: impl Wire2Api<Option<String>> + UnwindSafe,
+	meta: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileRegisterOutput, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_register_file",
+			debug_name: "file_encrypt_name_with_meta",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_master_key_id = master_key_id.wire2api();
 			let api_content_key = content_key.wire2api();
-			let api_encrypted_content_key = encrypted_content_key.wire2api();
-			let api_belongs_to_id = belongs_to_id.wire2api();
-			let api_belongs_to_type = belongs_to_type.wire2api();
 			let api_file_name = file_name.wire2api();
-			let api_group_id = group_id.wire2api();
-			let api_group_as_member = group_as_member.wire2api();
+			let api_meta = meta.wire2api();
 			move |task_callback| {
-				file_register_file(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_master_key_id,
+				file_encrypt_name_with_meta(
 					api_content_key,
-					api_encrypted_content_key,
-					api_belongs_to_id,
-					api_belongs_to_type,
 					api_file_name,
-					api_group_id,
-					api_group_as_member,
+					api_meta,
 				)
 			}
 		},
 	)
 }
-fn wire_file_prepare_register_file_impl(
+fn wire_file_decrypt_name_with_meta_impl(
 	port_: MessagePort,
-	master_key_id: impl Wire2Api<String> + UnwindSafe,
 	content_key: impl Wire2Api<String> + UnwindSafe,
-	encrypted_content_key: impl Wire2Api<String> + UnwindSafe,
-	belongs_to_id: impl Wire2Api<Option<String>> + UnwindSafe,
-	belongs_to_type: impl Wire2Api<String> + UnwindSafe,
-	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
+	encrypted_file_name: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FilePrepareRegister, _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_prepare_register_file",
+			debug_name: "file_decrypt_name_with_meta",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_master_key_id = master_key_id.wire2api();
 			let api_content_key = content_key.wire2api();
-			let api_encrypted_content_key = encrypted_content_key.wire2api();
-			let api_belongs_to_id = belongs_to_id.wire2api();
-			let api_belongs_to_type = belongs_to_type.wire2api();
-			let api_file_name = file_name.wire2api();
+			let api_encrypted_file_name = encrypted_file_name.wire2api();
 			move |task_callback| {
-				file_prepare_register_file(
-					api_master_key_id,
+				file_decrypt_name_with_meta(
 					api_content_key,
-					api_encrypted_content_key,
-					api_belongs_to_id,
-					api_belongs_to_type,
-					api_file_name,
+					api_encrypted_file_name,
 				)
 			}
 		},
 	)
 }
-fn wire_file_done_register_file_impl(port_: MessagePort, server_output: impl Wire2Api<String> + UnwindSafe) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FileDoneRegister, _>(
+fn wire_file_prepare_file_move_impl(
+	port_: MessagePort,
+	old_master_key: impl Wire2Api<String> + UnwindSafe,
+	new_master_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key_alg: impl Wire2Api<String> + UnwindSafe,
+	belongs_to_id: impl Wire2Api<Option<String>> + UnwindSafe,
+	belongs_to_type: impl Wire2Api<String> + UnwindSafe,
+) {
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_done_register_file",
+			debug_name: "file_prepare_file_move",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_server_output = server_output.wire2api();
-			move |task_callback| file_done_register_file(api_server_output)
+			let api_old_master_key = old_master_key.wire2api();
+			let api_new_master_key = new_master_key.wire2api();
+			let api_encrypted_key = encrypted_key.wire2api();
+			let api_encrypted_key_alg = encrypted_key_alg.wire2api();
+			let api_belongs_to_id = belongs_to_id.wire2api();
+			let api_belongs_to_type = belongs_to_type.wire2api();
+			move |task_callback| {
+				file_prepare_file_move(
+					api_old_master_key,
+					api_new_master_key,
+					api_encrypted_key,
+					api_encrypted_key_alg,
+					api_belongs_to_id,
+					api_belongs_to_type,
+				)
+			}
 		},
 	)
 }
-fn wire_file_upload_part_start_impl(
+fn wire_file_prepare_file_share_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	session_id: impl Wire2Api<String> + UnwindSafe,
-	end: impl Wire2Api<bool> + UnwindSafe,
-	sequence: impl Wire2Api<i32> + UnwindSafe,
-	content_key: impl Wire2Api<String> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
-	part: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	old_master_key: impl Wire2Api<String> + UnwindSafe,
+	new_master_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key_alg: impl Wire2Api<String> + UnwindSafe,
+	group_id: impl Wire2Api<String> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_upload_part_start",
+			debug_name: "file_prepare_file_share",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_url_prefix = url_prefix.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_session_id = session_id.wire2api();
-			let api_end = end.wire2api();
-			let api_sequence = sequence.wire2api();
-			let api_content_key = content_key.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			let api_part = part.wire2api();
+			let api_old_master_key = old_master_key.wire2api();
+			let api_new_master_key = new_master_key.wire2api();
+			let api_encrypted_key = encrypted_key.wire2api();
+			let api_encrypted_key_alg = encrypted_key_alg.wire2api();
+			let api_group_id = group_id.wire2api();
 			move |task_callback| {
-				file_upload_part_start(
-					api_base_url,
-					api_url_prefix,
-					api_auth_token,
-					api_jwt,
-					api_session_id,
-					api_end,
-					api_sequence,
-					api_content_key,
-					api_sign_key,
-					api_part,
+				file_prepare_file_share(
+					api_old_master_key,
+					api_new_master_key,
+					api_encrypted_key,
+					api_encrypted_key_alg,
+					api_group_id,
 				)
 			}
 		},
 	)
 }
-fn wire_file_upload_part_impl(
+fn wire_file_prepare_file_share_by_public_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	url_prefix: impl Wire2Api<Option<String>> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	session_id: impl Wire2Api<String> + UnwindSafe,
-	end: impl Wire2Api<bool> + UnwindSafe,
-	sequence: impl Wire2Api<i32> + UnwindSafe,
-	content_key: impl Wire2Api<String> + UnwindSafe,
-	sign_key: impl Wire2Api<Option<String>> + UnwindSafe,
-	part: impl Wire2Api<Vec<u8>> + UnwindSafe,
+	old_master_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key_alg: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
 ) {
 	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_upload_part",
+			debug_name: "file_prepare_file_share_by_public_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_url_prefix = url_prefix.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_session_id = session_id.wire2api();
-			let api_end = end.wire2api();
-			let api_sequence = sequence.wire2api();
-			let api_content_key = content_key.wire2api();
-			let api_sign_key = sign_key.wire2api();
-			let api_part = part.wire2api();
+			let api_old_master_key = old_master_key.wire2api();
+			let api_public_key = public_key.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			let api_encrypted_key = encrypted_key.wire2api();
+			let api_encrypted_key_alg = encrypted_key_alg.wire2api();
+			let api_user_id = user_id.wire2api();
 			move |task_callback| {
-				file_upload_part(
-					api_base_url,
-					api_url_prefix,
-					api_auth_token,
-					api_jwt,
-					api_session_id,
-					api_end,
-					api_sequence,
-					api_content_key,
-					api_sign_key,
-					api_part,
+				file_prepare_file_share_by_public_key(
+					api_old_master_key,
+					api_public_key,
+					api_verify_key,
+					api_encrypted_key,
+					api_encrypted_key_alg,
+					api_user_id,
 				)
 			}
 		},
 	)
 }
-fn wire_file_file_name_update_impl(
+fn wire_file_prepare_file_move_by_public_key_impl(
 	port_: MessagePort,
-	base_url: impl Wire2Api<String> + UnwindSafe,
-	auth_token: impl Wire2Api<String> + UnwindSafe,
-	jwt: impl Wire2Api<String> + UnwindSafe,
-	file_id: impl Wire2Api<String> + UnwindSafe,
-	content_key: impl Wire2Api<String> + UnwindSafe,
-	file_name: impl Wire2Api<Option<String>> + UnwindSafe,
+	old_master_key: impl Wire2Api<String> + UnwindSafe,
+	public_key: impl Wire2Api<String> + UnwindSafe,
+	verify_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key: impl Wire2Api<String> + UnwindSafe,
+	encrypted_key_alg: impl Wire2Api<String> + UnwindSafe,
+	user_id: impl Wire2Api<String> + UnwindSafe,
 ) {
-	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+	FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
 		WrapInfo {
-			debug_name: "file_file_name_update",
+			debug_name: "file_prepare_file_move_by_public_key",
 			port: Some(port_),
 			mode: FfiCallMode::Normal,
 		},
 		move || {
-			let api_base_url = base_url.wire2api();
-			let api_auth_token = auth_token.wire2api();
-			let api_jwt = jwt.wire2api();
-			let api_file_id = file_id.wire2api();
-			let api_content_key = content_key.wire2api();
-			let api_file_name = file_name.wire2api();
+			let api_old_master_key = old_master_key.wire2api();
+			let api_public_key = public_key.wire2api();
+			let api_verify_key = verify_key.wire2api();
+			let api_encrypted_key = encrypted_key.wire2api();
+			let api_encrypted_key_alg = encrypted_key_alg.wire2api();
+			let api_user_id = user_id.wire2api();
 			move |task_callback| {
-				file_file_name_update(
-					api_base_url,
-					api_auth_token,
-					api_jwt,
-					api_file_id,
-					api_content_key,
-					api_file_name,
+				file_prepare_file_move_by_public_key(
+					api_old_master_key,
+					api_public_key,
+					api_verify_key,
+					api_encrypted_key,
+					api_encrypted_key_alg,
+					api_user_id,
 				)
 			}
 		},
@@ -3377,6 +4317,35 @@ impl rust2dart::IntoIntoDart<DeviceKeyData> for DeviceKeyData {
 	}
 }
 
+impl support::IntoDart for DevicePairingRevealData {
+	fn into_dart(self) -> support::DartAbi {
+		vec![
+			self.reveal.into_into_dart().into_dart(),
+			self.code.into_into_dart().into_dart(),
+			self.verify_key.into_into_dart().into_dart(),
+		]
+		.into_dart()
+	}
+}
+impl support::IntoDartExceptPrimitive for DevicePairingRevealData {}
+impl rust2dart::IntoIntoDart<DevicePairingRevealData> for DevicePairingRevealData {
+	fn into_into_dart(self) -> Self {
+		self
+	}
+}
+
+impl support::IntoDart for DevicePairingStart {
+	fn into_dart(self) -> support::DartAbi {
+		vec![self.payload.into_into_dart().into_dart(), self.nonce.into_into_dart().into_dart()].into_dart()
+	}
+}
+impl support::IntoDartExceptPrimitive for DevicePairingStart {}
+impl rust2dart::IntoIntoDart<DevicePairingStart> for DevicePairingStart {
+	fn into_into_dart(self) -> Self {
+		self
+	}
+}
+
 impl support::IntoDart for EncryptedHead {
 	fn into_dart(self) -> support::DartAbi {
 		vec![self.id.into_into_dart().into_dart(), self.sign.into_dart()].into_dart()
@@ -380,6 +380,57 @@ pub fn register_device(
 	})
 }
 
+#[repr(C)]
+pub struct DevicePairingStart
+{
+	pub payload: String,
+	pub nonce: String,
+}
+
+#[repr(C)]
+pub struct DevicePairingRevealData
+{
+	pub reveal: String,
+	pub code: String,
+	pub verify_key: String,
+}
+
+pub fn prepare_device_pairing(server_output: String) -> Result<DevicePairingStart>
+{
+	let out = user::prepare_device_pairing(server_output.as_str())?;
+
+	Ok(DevicePairingStart {
+		payload: out.payload,
+		nonce: out.nonce,
+	})
+}
+
+pub fn respond_device_pairing(payload: String, sign_key: String, verify_key: String) -> Result<String>
+{
+	user::respond_device_pairing(payload.as_str(), sign_key.as_str(), verify_key.as_str())
+}
+
+pub fn reveal_device_pairing(payload: String, nonce: String, response: String) -> Result<DevicePairingRevealData>
+{
+	let (reveal, code, verify_key) = user::reveal_device_pairing(payload.as_str(), nonce.as_str(), response.as_str())?;
+
+	Ok(DevicePairingRevealData {
+		reveal,
+		code,
+		verify_key,
+	})
+}
+
+pub fn verify_device_pairing(payload: String, server_output: String, response: String, reveal: String) -> Result<String>
+{
+	user::verify_device_pairing(
+		payload.as_str(),
+		server_output.as_str(),
+		response.as_str(),
+		reveal.as_str(),
+	)
+}
+
 pub fn user_device_key_session_upload(
 	base_url: String,
 	auth_token: String,
@@ -532,11 +583,109 @@ pub fn user_create_safety_number(verify_key_1: String, user_id_1: String, verify
 	)
 }
 
+pub fn user_create_safety_number_digits(
+	verify_key_1: String,
+	user_id_1: String,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<String>
+{
+	user::create_safety_number_digits(
+		&verify_key_1,
+		&user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)
+}
+
+pub fn user_create_safety_number_words(
+	verify_key_1: String,
+	user_id_1: String,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<String>
+{
+	user::create_safety_number_words(
+		&verify_key_1,
+		&user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)
+}
+
+pub fn user_create_safety_number_qr(
+	verify_key_1: String,
+	user_id_1: String,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<String>
+{
+	user::create_safety_number_qr(
+		&verify_key_1,
+		&user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)
+}
+
+pub fn user_verify_safety_number_qr(
+	payload: String,
+	verify_key_1: String,
+	user_id_1: String,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<bool>
+{
+	user::verify_safety_number_qr(
+		&payload,
+		&verify_key_1,
+		&user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)
+}
+
+pub fn user_create_verified_key_record(user_id: String, verify_key: String) -> Result<String>
+{
+	user::create_verified_key_record(&user_id, &verify_key)
+}
+
+pub fn user_check_verified_key_record(record: String, verify_key: String) -> Result<bool>
+{
+	user::check_verified_key_record(&record, &verify_key)
+}
+
 pub fn user_verify_user_public_key(verify_key: String, public_key: String) -> Result<bool>
 {
 	user::verify_user_public_key(&verify_key, &public_key)
 }
 
+pub fn user_pin_verify_key(store: String, user_id: String, verify_key: String) -> Result<String>
+{
+	user::pin_verify_key(&store, &user_id, &verify_key)
+}
+
+pub fn user_check_pinned_public_key(store: String, id: String, public_key: String) -> Result<()>
+{
+	user::check_pinned_public_key(&store, &id, &public_key)
+}
+
+pub fn user_create_key_rotation_link(previous_sign_key: String, previous_verify_key: String, verify_key: String, public_key: String)
+	-> Result<String>
+{
+	user::create_key_rotation_link(&previous_sign_key, &previous_verify_key, &verify_key, &public_key)
+}
+
+pub fn user_verify_key_rotation_chain(verify_key: String, links: String) -> Result<String>
+{
+	user::verify_key_rotation_chain(&verify_key, &links)
+}
+
+pub fn user_update_pin_by_rotation_chain(store: String, user_id: String, links: String) -> Result<String>
+{
+	user::update_pin_by_rotation_chain(&store, &user_id, &links)
+}
+
 //__________________________________________________________________________________________________
 
 #[repr(C)]
@@ -668,6 +817,21 @@ pub fn user_fetch_public_key(base_url: String, auth_token: String, user_id: Stri
 	})
 }
 
+/**
+Returns the public key and the new pin store
+ */
+pub fn user_fetch_public_key_pinned(base_url: String, auth_token: String, user_id: String, store: String) -> Result<Vec<String>>
+{
+	let (public_key, store) = rt(util_req_full::user::fetch_user_public_key_pinned(
+		base_url,
+		auth_token.as_str(),
+		user_id.as_str(),
+		store.as_str(),
+	))?;
+
+	Ok(vec![public_key, store])
+}
+
 pub fn user_fetch_verify_key(base_url: String, auth_token: String, user_id: String, verify_key_id: String) -> Result<String>
 {
 	let key = rt(util_req_full::user::fetch_user_verify_key_by_id(
@@ -1824,6 +1988,7 @@ pub fn group_key_rotation(
 	auth_token: String,
 	jwt: String,
 	id: String,
+	admin_rank: i32,
 	public_key: String,
 	pre_group_key: String,
 	sign_key: Option<String>,
@@ -1836,6 +2001,7 @@ pub fn group_key_rotation(
 		auth_token.as_str(),
 		jwt.as_str(),
 		id.as_str(),
+		admin_rank,
 		public_key.as_str(),
 		pre_group_key.as_str(),
 		false,
@@ -2261,6 +2427,42 @@ pub fn search(key: String, data: String) -> Result<String>
 	sentc_crypto::crypto_searchable::search(&key, &data)
 }
 
+/**
+Create the hashes with padding, truncation or another encoding.
+
+options is the json of the searchable options, e.g. {"bucket_size":32,"truncate":16,"encoding":"Hex"}.
+ */
+pub fn create_searchable_with_options(key: String, data: String, full: bool, limit: Option<u32>, options: String) -> Result<SearchableCreateOutput>
+{
+	let limit = limit.map(|l| l as usize);
+
+	let out = sentc_crypto::crypto_searchable::create_searchable_with_options(&key, &data, full, limit, &options)?;
+
+	Ok(out.into())
+}
+
+pub fn search_with_options(key: String, data: String, options: String) -> Result<String>
+{
+	sentc_crypto::crypto_searchable::search_with_options(&key, &data, &options)
+}
+
+/**
+Create one hash for a combination of fields.
+
+fields is a json array of the fields, e.g. [{"name":"first_name","value":"Max","normalization":"TrimLowercase"}].
+ */
+pub fn create_compound_searchable(key: String, fields: String) -> Result<SearchableCreateOutput>
+{
+	let out = sentc_crypto::crypto_searchable::create_compound_searchable(&key, &fields)?;
+
+	Ok(out.into())
+}
+
+pub fn search_compound(key: String, fields: String) -> Result<String>
+{
+	sentc_crypto::crypto_searchable::search_compound(&key, &fields)
+}
+
 //__________________________________________________________________________________________________
 //sortable
 
@@ -2308,6 +2510,18 @@ pub fn sortable_encrypt_string(key: String, data: String) -> Result<SortableEncr
 	Ok(out.into())
 }
 
+/**
+Encrypts the bounds of a range with every sortable key of the group.
+
+keys is a json array of the sortable keys, range the json of the range.
+Returns a json array with the encrypted lower and upper bound (both inclusive) for each key id.
+The bounds are {"Ope":number} for order-preserving keys and {"Ore":ciphertext} for order-revealing keys.
+ */
+pub fn sortable_prepare_range(keys: String, range: String) -> Result<String>
+{
+	sentc_crypto::crypto_sortable::prepare_range(&keys, &range)
+}
+
 //==================================================================================================
 //file
 
@@ -2459,6 +2673,66 @@ pub fn file_download_and_decrypt_file_part(
 	})
 }
 
+/**
+Download and decrypt a part of the chained or the derived file format.
+
+pre_key is the next_file_key of the previous part, none for the first part.
+For derived files the next_file_key is empty, every part is decrypted with the content key.
+ */
+pub fn file_download_and_decrypt_file_part_auto(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	part_id: String,
+	sequence: i32,
+	content_key: String,
+	pre_key: Option<String>,
+	verify_key_data: Option<String>,
+) -> Result<FileDownloadResult>
+{
+	let (file, next_file_key) = rt(util_req_full::file::download_and_decrypt_file_part_auto(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		part_id.as_str(),
+		sequence,
+		content_key.as_str(),
+		pre_key.as_deref(),
+		verify_key_data.as_deref(),
+	))?;
+
+	Ok(FileDownloadResult {
+		next_file_key: next_file_key.unwrap_or_default(),
+		file: ZeroCopyBuffer(file),
+	})
+}
+
+/**
+Download and decrypt a single part of the derived file format without the previous parts.
+ */
+pub fn file_download_and_decrypt_file_part_derived(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	part_id: String,
+	sequence: i32,
+	content_key: String,
+	verify_key_data: Option<String>,
+) -> Result<ZeroCopyBuffer<Vec<u8>>>
+{
+	let file = rt(util_req_full::file::download_and_decrypt_file_part_derived(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		part_id.as_str(),
+		sequence,
+		content_key.as_str(),
+		verify_key_data.as_deref(),
+	))?;
+
+	Ok(ZeroCopyBuffer(file))
+}
+
 pub fn file_download_part_list(base_url: String, auth_token: String, file_id: String, last_sequence: String) -> Result<Vec<FilePartListItem>>
 {
 	let out = rt(util_req_full::file::download_part_list(
@@ -2618,6 +2892,77 @@ pub fn file_upload_part(
 	))
 }
 
+pub fn file_upload_part_derived(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	jwt: String,
+	session_id: String,
+	end: bool,
+	sequence: i32,
+	content_key: String,
+	sign_key: Option<String>,
+	part: Vec<u8>,
+) -> Result<()>
+{
+	rt(util_req_full::file::upload_part_derived(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		jwt.as_str(),
+		session_id.as_str(),
+		end,
+		sequence,
+		content_key.as_str(),
+		sign_key.as_deref(),
+		&part,
+	))
+}
+
+/**
+Create a checkpoint after the part with this sequence was uploaded.
+
+Store the checkpoint on the device to continue the upload with file_upload_part_resume after the app was closed.
+ */
+pub fn file_prepare_upload_checkpoint(
+	checkpoint_key: String,
+	file_id: String,
+	session_id: String,
+	sequence: i32,
+	next_file_key: String,
+) -> Result<String>
+{
+	sentc_crypto::file::prepare_upload_checkpoint(&checkpoint_key, file_id, session_id, sequence, &next_file_key)
+}
+
+/**
+Upload the next part after the checkpoint and return the new checkpoint.
+ */
+pub fn file_upload_part_resume(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	jwt: String,
+	end: bool,
+	checkpoint_key: String,
+	checkpoint: String,
+	sign_key: Option<String>,
+	part: Vec<u8>,
+) -> Result<String>
+{
+	rt(util_req_full::file::upload_part_resume(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		jwt.as_str(),
+		end,
+		checkpoint_key.as_str(),
+		checkpoint.as_str(),
+		sign_key.as_deref(),
+		&part,
+	))
+}
+
 pub fn file_file_name_update(
 	base_url: String,
 	auth_token: String,
@@ -2637,6 +2982,114 @@ pub fn file_file_name_update(
 	))
 }
 
+pub fn file_file_meta_update(
+	base_url: String,
+	auth_token: String,
+	jwt: String,
+	file_id: String,
+	content_key: String,
+	file_name: Option<String>,
+	meta: Option<String>,
+) -> Result<()>
+{
+	rt(util_req_full::file::update_file_meta(
+		base_url,
+		auth_token.as_str(),
+		jwt.as_str(),
+		file_id.as_str(),
+		content_key.as_str(),
+		file_name,
+		meta,
+	))
+}
+
+pub fn file_encrypt_name_with_meta(content_key: String, file_name: Option<String>, meta: String) -> Result<String>
+{
+	sentc_crypto::file::encrypt_file_name_with_meta(&content_key, file_name, &meta)
+}
+
+/**
+Returns the json of the file name and the meta data
+ */
+pub fn file_decrypt_name_with_meta(content_key: String, encrypted_file_name: String) -> Result<String>
+{
+	sentc_crypto::file::decrypt_file_name_with_meta(&content_key, &encrypted_file_name)
+}
+
+pub fn file_prepare_file_move(
+	old_master_key: String,
+	new_master_key: String,
+	encrypted_key: String,
+	encrypted_key_alg: String,
+	belongs_to_id: Option<String>,
+	belongs_to_type: String,
+) -> Result<String>
+{
+	sentc_crypto::file::prepare_file_move(
+		&old_master_key,
+		&new_master_key,
+		&encrypted_key,
+		&encrypted_key_alg,
+		belongs_to_id,
+		&belongs_to_type,
+	)
+}
+
+pub fn file_prepare_file_share(
+	old_master_key: String,
+	new_master_key: String,
+	encrypted_key: String,
+	encrypted_key_alg: String,
+	group_id: String,
+) -> Result<String>
+{
+	sentc_crypto::file::prepare_file_share(
+		&old_master_key,
+		&new_master_key,
+		&encrypted_key,
+		&encrypted_key_alg,
+		group_id,
+	)
+}
+
+pub fn file_prepare_file_share_by_public_key(
+	old_master_key: String,
+	public_key: String,
+	verify_key: String,
+	encrypted_key: String,
+	encrypted_key_alg: String,
+	user_id: String,
+) -> Result<String>
+{
+	sentc_crypto::file::prepare_file_share_by_public_key(
+		&old_master_key,
+		&public_key,
+		&verify_key,
+		&encrypted_key,
+		&encrypted_key_alg,
+		user_id,
+	)
+}
+
+pub fn file_prepare_file_move_by_public_key(
+	old_master_key: String,
+	public_key: String,
+	verify_key: String,
+	encrypted_key: String,
+	encrypted_key_alg: String,
+	user_id: String,
+) -> Result<String>
+{
+	sentc_crypto::file::prepare_file_move_by_public_key(
+		&old_master_key,
+		&public_key,
+		&verify_key,
+		&encrypted_key,
+		&encrypted_key_alg,
+		user_id,
+	)
+}
+
 pub fn file_delete_file(
 	base_url: String,
 	auth_token: String,
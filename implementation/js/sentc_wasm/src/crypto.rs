@@ -326,6 +326,52 @@ pub fn search(key: &str, data: &str) -> Result<String, JsValue>
 	Ok(sentc_crypto::crypto_searchable::search(key, data)?)
 }
 
+/**
+Create the hashes with padding, truncation or another encoding.
+
+options is the json of the searchable options, e.g. {"bucket_size":32,"truncate":16,"encoding":"Hex"}.
+ */
+#[wasm_bindgen]
+pub fn create_searchable_with_options(
+	key: &str,
+	data: &str,
+	full: bool,
+	limit: Option<usize>,
+	options: &str,
+) -> Result<SearchableCreateOutput, JsValue>
+{
+	let out = sentc_crypto::crypto_searchable::create_searchable_with_options(key, data, full, limit, options)?;
+
+	Ok(out.into())
+}
+
+#[wasm_bindgen]
+pub fn search_with_options(key: &str, data: &str, options: &str) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::crypto_searchable::search_with_options(
+		key, data, options,
+	)?)
+}
+
+/**
+Create one hash for a combination of fields.
+
+fields is a json array of the fields, e.g. [{"name":"first_name","value":"Max","normalization":"TrimLowercase"}].
+ */
+#[wasm_bindgen]
+pub fn create_compound_searchable(key: &str, fields: &str) -> Result<SearchableCreateOutput, JsValue>
+{
+	let out = sentc_crypto::crypto_searchable::create_compound_searchable(key, fields)?;
+
+	Ok(out.into())
+}
+
+#[wasm_bindgen]
+pub fn search_compound(key: &str, fields: &str) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::crypto_searchable::search_compound(key, fields)?)
+}
+
 //__________________________________________________________________________________________________
 //sortable
 
@@ -395,3 +441,16 @@ pub fn sortable_encrypt_string(key: &str, data: &str) -> Result<SortableEncryptO
 
 	Ok(out.into())
 }
+
+/**
+Encrypts the bounds of a range with every sortable key of the group.
+
+keys is a json array of the sortable keys, range the json of the range, e.g. {"Number":{"lower":{"Included":10},"upper":"Unbounded"}}.
+Returns a json array with the encrypted lower and upper bound (both inclusive) for each key id.
+The bounds are {"Ope":number} for order-preserving keys and {"Ore":ciphertext} for order-revealing keys.
+ */
+#[wasm_bindgen]
+pub fn sortable_prepare_range(keys: &str, range: &str) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::crypto_sortable::prepare_range(keys, range)?)
+}
@@ -1,9 +1,15 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 
-use js_sys::Uint8Array;
+use futures::channel::mpsc;
+use futures::io::{self, AsyncRead};
+use futures::sink::SinkExt;
+use futures::stream::{Stream, StreamExt, TryStreamExt};
+use js_sys::{Promise, Uint8Array};
 use sentc_crypto::util_req_full;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+use wasm_streams::{ReadableStream, WritableStream};
 
 #[wasm_bindgen]
 pub struct FilePrepareRegister
@@ -150,6 +156,69 @@ pub async fn file_download_and_decrypt_file_part(
 	})
 }
 
+/**
+Download and decrypt a part of the chained or the derived file format.
+
+pre_key is the next_file_key of the previous part, none for the first part.
+For derived files the next_file_key is empty, every part is decrypted with the content key.
+ */
+#[wasm_bindgen]
+pub async fn file_download_and_decrypt_file_part_auto(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	part_id: String,
+	sequence: i32,
+	content_key: String,
+	pre_key: Option<String>,
+	verify_key_data: Option<String>,
+) -> Result<FileDownloadResult, JsValue>
+{
+	let (file, next_file_key) = util_req_full::file::download_and_decrypt_file_part_auto(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		part_id.as_str(),
+		sequence,
+		content_key.as_str(),
+		pre_key.as_deref(),
+		verify_key_data.as_deref(),
+	)
+	.await?;
+
+	//fastest way to convert vec to Uint8Array
+	Ok(FileDownloadResult {
+		next_file_key: next_file_key.unwrap_or_default(),
+		file,
+	})
+}
+
+/**
+Download and decrypt a single part of the derived file format, e.g. for seeking in a video.
+ */
+#[wasm_bindgen]
+pub async fn file_download_and_decrypt_file_part_derived(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	part_id: String,
+	sequence: i32,
+	content_key: String,
+	verify_key_data: Option<String>,
+) -> Result<Vec<u8>, JsValue>
+{
+	Ok(util_req_full::file::download_and_decrypt_file_part_derived(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		part_id.as_str(),
+		sequence,
+		content_key.as_str(),
+		verify_key_data.as_deref(),
+	)
+	.await?)
+}
+
 //__________________________________________________________________________________________________
 
 #[wasm_bindgen]
@@ -283,8 +352,386 @@ pub async fn file_upload_part(
 	.await?)
 }
 
+#[wasm_bindgen]
+pub async fn file_upload_part_derived(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	jwt: String,
+	session_id: String,
+	end: bool,
+	sequence: i32,
+	content_key: String,
+	sign_key: Option<String>,
+	part: Vec<u8>,
+) -> Result<(), JsValue>
+{
+	Ok(util_req_full::file::upload_part_derived(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		jwt.as_str(),
+		session_id.as_str(),
+		end,
+		sequence,
+		content_key.as_str(),
+		sign_key.as_deref(),
+		&part,
+	)
+	.await?)
+}
+
+#[wasm_bindgen]
+pub fn file_prepare_upload_checkpoint(
+	checkpoint_key: &str,
+	file_id: String,
+	session_id: String,
+	sequence: i32,
+	next_file_key: &str,
+) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::prepare_upload_checkpoint(
+		checkpoint_key,
+		file_id,
+		session_id,
+		sequence,
+		next_file_key,
+	)?)
+}
+
+#[wasm_bindgen]
+pub async fn file_upload_part_resume(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	jwt: String,
+	end: bool,
+	checkpoint_key: String,
+	checkpoint: String,
+	sign_key: Option<String>,
+	part: Vec<u8>,
+) -> Result<String, JsValue>
+{
+	Ok(util_req_full::file::upload_part_resume(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		jwt.as_str(),
+		end,
+		checkpoint_key.as_str(),
+		checkpoint.as_str(),
+		sign_key.as_deref(),
+		&part,
+	)
+	.await?)
+}
+
 #[wasm_bindgen]
 pub fn file_prepare_file_name_update(content_key: &str, file_name: Option<String>) -> Result<String, JsValue>
 {
 	Ok(sentc_crypto::file::prepare_file_name_update(content_key, file_name)?)
 }
+
+#[wasm_bindgen]
+pub fn file_prepare_file_meta_update(content_key: &str, file_name: Option<String>, meta: Option<String>) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::prepare_file_meta_update(
+		content_key,
+		file_name,
+		meta,
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn file_encrypt_name_with_meta(content_key: &str, file_name: Option<String>, meta: &str) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::encrypt_file_name_with_meta(
+		content_key,
+		file_name,
+		meta,
+	)?)
+}
+
+/**
+Returns the json of the file name and the meta data
+ */
+#[wasm_bindgen]
+pub fn file_decrypt_name_with_meta(content_key: &str, encrypted_file_name: &str) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::decrypt_file_name_with_meta(
+		content_key,
+		encrypted_file_name,
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn file_prepare_file_move(
+	old_master_key: &str,
+	new_master_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	belongs_to_id: Option<String>,
+	belongs_to_type: &str,
+) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::prepare_file_move(
+		old_master_key,
+		new_master_key,
+		encrypted_key,
+		encrypted_key_alg,
+		belongs_to_id,
+		belongs_to_type,
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn file_prepare_file_share(
+	old_master_key: &str,
+	new_master_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	group_id: String,
+) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::prepare_file_share(
+		old_master_key,
+		new_master_key,
+		encrypted_key,
+		encrypted_key_alg,
+		group_id,
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn file_prepare_file_share_by_public_key(
+	old_master_key: &str,
+	public_key: &str,
+	verify_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	user_id: String,
+) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::prepare_file_share_by_public_key(
+		old_master_key,
+		public_key,
+		verify_key,
+		encrypted_key,
+		encrypted_key_alg,
+		user_id,
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn file_prepare_file_move_by_public_key(
+	old_master_key: &str,
+	public_key: &str,
+	verify_key: &str,
+	encrypted_key: &str,
+	encrypted_key_alg: &str,
+	user_id: String,
+) -> Result<String, JsValue>
+{
+	Ok(sentc_crypto::file::prepare_file_move_by_public_key(
+		old_master_key,
+		public_key,
+		verify_key,
+		encrypted_key,
+		encrypted_key_alg,
+		user_id,
+	)?)
+}
+
+//__________________________________________________________________________________________________
+
+#[wasm_bindgen]
+pub struct FileUploadWritable
+{
+	writable: wasm_streams::writable::sys::WritableStream,
+	done: Promise,
+}
+
+#[wasm_bindgen]
+impl FileUploadWritable
+{
+	pub fn get_writable(&self) -> wasm_streams::writable::sys::WritableStream
+	{
+		self.writable.clone()
+	}
+
+	pub fn get_done(&self) -> Promise
+	{
+		self.done.clone()
+	}
+}
+
+fn chunk_reader(chunks: impl Stream<Item = Result<JsValue, JsValue>> + Unpin) -> impl AsyncRead + Unpin
+{
+	chunks
+		.map(|chunk| {
+			chunk
+				.map(|c| Uint8Array::new(&c).to_vec())
+				.map_err(|_| io::Error::new(io::ErrorKind::Other, "Can't read the stream"))
+		})
+		.into_async_read()
+}
+
+/**
+Encrypts and uploads the file from a ReadableStream, e.g. from File.stream().
+
+Only a few parts are in memory at the same time, so big files can be uploaded.
+concurrency is the number of parts in transfer at the same time, without it the parts are uploaded one after another.
+Returns the number of uploaded parts.
+ */
+#[wasm_bindgen]
+pub async fn file_upload_stream(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	jwt: String,
+	session_id: String,
+	content_key: String,
+	sign_key: Option<String>,
+	stream: wasm_streams::readable::sys::ReadableStream,
+	part_size: Option<usize>,
+	concurrency: Option<usize>,
+) -> Result<i32, JsValue>
+{
+	let reader = chunk_reader(ReadableStream::from_raw(stream).into_stream());
+
+	Ok(util_req_full::file::upload_file_from_reader(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		jwt.as_str(),
+		session_id.as_str(),
+		content_key.as_str(),
+		sign_key.as_deref(),
+		reader,
+		part_size.unwrap_or(util_req_full::file::DEFAULT_PART_SIZE),
+		concurrency,
+	)
+	.await?)
+}
+
+/**
+Encrypts and uploads the file which is written to the WritableStream, e.g. with File.stream().pipeTo(writable).
+
+A write only resolves when there is space for the next part, so only a few parts are in memory.
+The done promise resolves with the number of uploaded parts after the writable stream was closed.
+When the upload fails, the writes are rejected and the done promise is rejected with the error.
+ */
+#[wasm_bindgen]
+pub fn file_upload_writable_stream(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	jwt: String,
+	session_id: String,
+	content_key: String,
+	sign_key: Option<String>,
+	part_size: Option<usize>,
+	concurrency: Option<usize>,
+) -> FileUploadWritable
+{
+	let (sender, receiver) = mpsc::channel::<JsValue>(1);
+
+	//the receiver is dropped when the upload failed
+	let sink = sender.sink_map_err(|_| JsValue::from_str("The upload was stopped"));
+
+	let writable = WritableStream::from_sink(sink).into_raw();
+
+	let done = future_to_promise(async move {
+		let reader = chunk_reader(receiver.map(Ok));
+
+		let part_count = util_req_full::file::upload_file_from_reader(
+			base_url,
+			url_prefix,
+			auth_token.as_str(),
+			jwt.as_str(),
+			session_id.as_str(),
+			content_key.as_str(),
+			sign_key.as_deref(),
+			reader,
+			part_size.unwrap_or(util_req_full::file::DEFAULT_PART_SIZE),
+			concurrency,
+		)
+		.await?;
+
+		Ok(JsValue::from(part_count))
+	});
+
+	FileUploadWritable {
+		writable,
+		done,
+	}
+}
+
+/**
+Downloads and decrypts the file into a ReadableStream of Uint8Array.
+
+The stream can be piped to a WritableStream of a download sink, see file_download_to_writable_stream.
+part_list is the json of the first page of the part list from the file meta data, the next pages are fetched by the stream.
+The stream errors when parts of the file are missing, so the file is only complete when the stream was closed without an error.
+ */
+#[wasm_bindgen]
+pub fn file_download_stream(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	file_id: String,
+	part_list: &str,
+	content_key: &str,
+	verify_key_data: Option<String>,
+	concurrency: Option<usize>,
+) -> Result<wasm_streams::readable::sys::ReadableStream, JsValue>
+{
+	let stream = util_req_full::file::download_file_stream(
+		base_url,
+		url_prefix,
+		auth_token,
+		file_id,
+		part_list,
+		content_key,
+		verify_key_data.as_deref(),
+		concurrency,
+	)?
+	.map_ok(|part| JsValue::from(Uint8Array::from(part.as_slice())))
+	.map_err(JsValue::from);
+
+	Ok(ReadableStream::from_stream(stream).into_raw())
+}
+
+/**
+Downloads and decrypts the file into the WritableStream, e.g. of a file handle or a download sink.
+
+The writable stream is aborted when the download fails and closed when the file is complete.
+ */
+#[wasm_bindgen]
+pub async fn file_download_to_writable_stream(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	file_id: String,
+	part_list: String,
+	content_key: String,
+	verify_key_data: Option<String>,
+	concurrency: Option<usize>,
+	sink: wasm_streams::writable::sys::WritableStream,
+) -> Result<(), JsValue>
+{
+	let stream = file_download_stream(
+		base_url,
+		url_prefix,
+		auth_token,
+		file_id,
+		&part_list,
+		&content_key,
+		verify_key_data,
+		concurrency,
+	)?;
+
+	let mut sink = WritableStream::from_raw(sink);
+
+	ReadableStream::from_raw(stream).pipe_to(&mut sink).await
+}
@@ -575,8 +575,16 @@ pub async fn group_join_user_session(
 //key rotation
 
 #[wasm_bindgen]
-pub fn group_prepare_key_rotation(pre_group_key: &str, public_key: &str, sign_key: Option<String>, starter: String) -> Result<String, JsValue>
+pub fn group_prepare_key_rotation(
+	pre_group_key: &str,
+	public_key: &str,
+	sign_key: Option<String>,
+	starter: String,
+	admin_rank: i32,
+) -> Result<String, JsValue>
 {
+	group::check_key_rotation(admin_rank).map_err(Into::<String>::into)?;
+
 	Ok(group::key_rotation(
 		pre_group_key,
 		public_key,
@@ -659,6 +659,95 @@ pub async fn register_device(
 	})
 }
 
+#[wasm_bindgen]
+pub struct DevicePairingStart
+{
+	payload: String,
+	nonce: String,
+}
+
+#[wasm_bindgen]
+impl DevicePairingStart
+{
+	pub fn get_payload(&self) -> String
+	{
+		self.payload.clone()
+	}
+
+	pub fn get_nonce(&self) -> String
+	{
+		self.nonce.clone()
+	}
+}
+
+#[wasm_bindgen]
+pub struct DevicePairingRevealData
+{
+	reveal: String,
+	code: String,
+	verify_key: String,
+}
+
+#[wasm_bindgen]
+impl DevicePairingRevealData
+{
+	pub fn get_reveal(&self) -> String
+	{
+		self.reveal.clone()
+	}
+
+	pub fn get_code(&self) -> String
+	{
+		self.code.clone()
+	}
+
+	pub fn get_verify_key(&self) -> String
+	{
+		self.verify_key.clone()
+	}
+}
+
+/**
+Call this fn at the new device with the output of register_device_start and show the payload as qr code
+ */
+#[wasm_bindgen]
+pub fn prepare_device_pairing(server_output: &str) -> Result<DevicePairingStart, JsValue>
+{
+	let out = user::prepare_device_pairing(server_output)?;
+
+	Ok(DevicePairingStart {
+		payload: out.payload,
+		nonce: out.nonce,
+	})
+}
+
+#[wasm_bindgen]
+pub fn respond_device_pairing(payload: &str, sign_key: &str, verify_key: &str) -> Result<String, JsValue>
+{
+	Ok(user::respond_device_pairing(payload, sign_key, verify_key)?)
+}
+
+#[wasm_bindgen]
+pub fn reveal_device_pairing(payload: &str, nonce: &str, response: &str) -> Result<DevicePairingRevealData, JsValue>
+{
+	let (reveal, code, verify_key) = user::reveal_device_pairing(payload, nonce, response)?;
+
+	Ok(DevicePairingRevealData {
+		reveal,
+		code,
+		verify_key,
+	})
+}
+
+/**
+Returns the code. Only call register_device with the server output after the user confirmed the code
+ */
+#[wasm_bindgen]
+pub fn verify_device_pairing(payload: &str, server_output: &str, response: &str, reveal: &str) -> Result<String, JsValue>
+{
+	Ok(user::verify_device_pairing(payload, server_output, response, reveal)?)
+}
+
 #[wasm_bindgen]
 pub async fn user_device_key_session_upload(
 	base_url: String,
@@ -927,8 +1016,167 @@ pub fn user_create_safety_number(
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn user_create_safety_number_digits(
+	verify_key_1: &str,
+	user_id_1: &str,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<String, JsValue>
+{
+	Ok(user::create_safety_number_digits(
+		verify_key_1,
+		user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn user_create_safety_number_words(
+	verify_key_1: &str,
+	user_id_1: &str,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<String, JsValue>
+{
+	Ok(user::create_safety_number_words(
+		verify_key_1,
+		user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn user_create_safety_number_qr(
+	verify_key_1: &str,
+	user_id_1: &str,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<String, JsValue>
+{
+	Ok(user::create_safety_number_qr(
+		verify_key_1,
+		user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn user_verify_safety_number_qr(
+	payload: &str,
+	verify_key_1: &str,
+	user_id_1: &str,
+	verify_key_2: Option<String>,
+	user_id_2: Option<String>,
+) -> Result<bool, JsValue>
+{
+	Ok(user::verify_safety_number_qr(
+		payload,
+		verify_key_1,
+		user_id_1,
+		verify_key_2.as_deref(),
+		user_id_2.as_deref(),
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn user_create_verified_key_record(user_id: &str, verify_key: &str) -> Result<String, JsValue>
+{
+	Ok(user::create_verified_key_record(user_id, verify_key)?)
+}
+
+#[wasm_bindgen]
+pub fn user_check_verified_key_record(record: &str, verify_key: &str) -> Result<bool, JsValue>
+{
+	Ok(user::check_verified_key_record(record, verify_key)?)
+}
+
 #[wasm_bindgen]
 pub fn user_verify_user_public_key(verify_key: &str, public_key: &str) -> Result<bool, JsValue>
 {
 	Ok(user::verify_user_public_key(verify_key, public_key)?)
 }
+
+#[wasm_bindgen]
+pub fn user_pin_verify_key(store: &str, user_id: &str, verify_key: &str) -> Result<String, JsValue>
+{
+	Ok(user::pin_verify_key(store, user_id, verify_key)?)
+}
+
+#[wasm_bindgen]
+pub fn user_check_pinned_public_key(store: &str, id: &str, public_key: &str) -> Result<(), JsValue>
+{
+	Ok(user::check_pinned_public_key(store, id, public_key)?)
+}
+
+#[wasm_bindgen]
+pub struct UserPublicKeyPinned
+{
+	public_key: String,
+	store: String,
+}
+
+#[wasm_bindgen]
+impl UserPublicKeyPinned
+{
+	pub fn get_public_key(&self) -> String
+	{
+		self.public_key.clone()
+	}
+
+	/**
+	The pin store with the new pin. Save it to keep the pin.
+	 */
+	pub fn get_store(&self) -> String
+	{
+		self.store.clone()
+	}
+}
+
+#[wasm_bindgen]
+pub async fn user_fetch_public_key_pinned(
+	base_url: String,
+	auth_token: String,
+	user_id: String,
+	store: String,
+) -> Result<UserPublicKeyPinned, JsValue>
+{
+	let (public_key, store) =
+		util_req_full::user::fetch_user_public_key_pinned(base_url, auth_token.as_str(), user_id.as_str(), store.as_str()).await?;
+
+	Ok(UserPublicKeyPinned {
+		public_key,
+		store,
+	})
+}
+
+#[wasm_bindgen]
+pub fn user_create_key_rotation_link(
+	previous_sign_key: &str,
+	previous_verify_key: &str,
+	verify_key: &str,
+	public_key: &str,
+) -> Result<String, JsValue>
+{
+	Ok(user::create_key_rotation_link(
+		previous_sign_key,
+		previous_verify_key,
+		verify_key,
+		public_key,
+	)?)
+}
+
+#[wasm_bindgen]
+pub fn user_verify_key_rotation_chain(verify_key: &str, links: &str) -> Result<String, JsValue>
+{
+	Ok(user::verify_key_rotation_chain(verify_key, links)?)
+}
+
+#[wasm_bindgen]
+pub fn user_update_pin_by_rotation_chain(store: &str, user_id: &str, links: &str) -> Result<String, JsValue>
+{
+	Ok(user::update_pin_by_rotation_chain(store, user_id, links)?)
+}